@@ -1,4 +1,5 @@
 use fs_node_runtime::{
+	pallet_nft::{AssetClass, CollectionClass},
 	pallet_roles, AccountId, AuraConfig, BalancesConfig, CouncilConfig, GenesisConfig,
 	GrandpaConfig, NftModuleConfig, RoleModuleConfig, Signature, SudoConfig, SystemConfig,
 	WASM_BINARY,
@@ -272,7 +273,11 @@ fn testnet_genesis(
 		},
 		nft_module: NftModuleConfig {
 			owner: Some(root_key),
-			collection_id: Some(3),
+			collections: vec![
+				(0, CollectionClass::new(AssetClass::HOUSE, 3)),
+				(1, CollectionClass::new(AssetClass::OFFICE, 3)),
+				(2, CollectionClass::new(AssetClass::APPARTMENT, 3)),
+			],
 			created_by: Some(pallet_roles::Accounts::SERVICER),
 			metadata: Some(b"metadata".to_vec().try_into().unwrap()),
 		},
@@ -326,7 +331,7 @@ fn square_one(
 		},
 		nft_module: NftModuleConfig {
 			owner: Some(root_key),
-			collection_id: Some(1),
+			collections: vec![(0, CollectionClass::new(AssetClass::HOUSE, 3))],
 			created_by: Some(pallet_roles::Accounts::SERVICER),
 			metadata: Some(b"metadata".to_vec().try_into().unwrap()),
 		},
@@ -11,6 +11,7 @@ impl<T: Config> Pallet<T> {
 
 		let mut representative = Roles::Pallet::<T>::get_pending_representatives(&who).unwrap();
 		Roles::RepApprovalList::<T>::remove(&who);
+		AssetRepresentatives::<T>::insert(&caller, &who);
 		let who2 = T::Lookup::unlookup(who.clone());
 		//get Rep number
 		let mut index = Roles::Pallet::<T>::rep_num();
@@ -117,11 +118,16 @@ impl<T: Config> Pallet<T> {
 		let amount1 = bals0.payment_bal;
 
 		//create payment_request
-		Payment::Pallet::<T>::request_payment(origin, from.clone(), amount1).ok();
+		//the guaranty is paid in the currency chosen for the asset
+		let asset = Self::payment_asset(&creator);
+		let payment_id = Payment::Pallet::<T>::next_payment_id();
+		Payment::Pallet::<T>::request_payment(origin, from.clone(), asset, amount1)
+			.map_err(|e| e.error)?;
 
 		//Store payment details
-		let details = Payment::Pallet::<T>::get_payment_details(&from, &creator).unwrap();
-		GuarantyPayment::<T>::insert(from, creator, details);
+		let detail = Payment::Pallet::<T>::get_payment_details(&from, &creator, payment_id)
+			.ok_or(Error::<T>::PaymentRequestNotFound)?;
+		GuarantyPayment::<T>::insert(from, creator, GuarantyPaymentInfo { payment_id, detail });
 
 		Ok(())
 	}
@@ -289,6 +295,19 @@ impl<T: Config> Pallet<T> {
 						} else {
 							val0.vote_result = VoteResult::REJECTED
 						}
+						// The referendum lock of the asset is released
+						if Nft::Pallet::<T>::remove_lock(
+							val0.collection_id,
+							val0.item_id,
+							Nft::LockReason::Referendum(index.1),
+						)
+						.is_err()
+						{
+							frame_support::log::warn!(
+								target: "runtime::asset_management",
+								"Warning: Unable to release the referendum lock of an asset!"
+							);
+						}
 						*val = Some(val0)
 					});
 				}
@@ -334,8 +353,7 @@ impl<T: Config> Pallet<T> {
 					//check how many rents were payed
 					let payed = (time as u128 - remaining_p as u128) * rent;
 					let asset_account = tenant.asset_account.clone().unwrap();
-					let asset_account_free_balance =
-						<T as Config>::Currency::free_balance(&asset_account);
+					let payment_asset = Self::payment_asset(&asset_account);
 
 					let infos = Self::owners_infos(asset_account.clone()).unwrap();
 
@@ -359,16 +377,35 @@ impl<T: Config> Pallet<T> {
 						let distribute = rent1.saturating_sub(maintenance);
 
 						//Get the total amount to distribute
-						let distribute_float = (Self::manage_bal_to_u128(distribute).unwrap()
-							* infos.rent_nbr as u128) as f64;
+						let mut total =
+							Self::manage_bal_to_u128(distribute).unwrap() * infos.rent_nbr as u128;
+
+						//Credit the housing fund contributors with their share of a native rent
+						let fund_income = T::FundRentShare::get().mul_floor(total);
+						if payment_asset == Payment::PaymentAsset::Native && fund_income > 0 {
+							let income =
+								BalanceType::<T>::convert_to_balance(fund_income).hfund_bal;
+							if HFund::Pallet::<T>::distribute_income(&asset_account, income).is_ok()
+							{
+								total = total.saturating_sub(fund_income);
+							}
+						}
+						let distribute_float = total as f64;
 
 						debug_assert!(distribute > Zero::zero());
 						debug_assert!(distribute < rent1);
-						debug_assert!(maintenance < asset_account_free_balance);
 
-						//Reserve maintenance fees
-						let reservation =
-							<T as Config>::Currency::reserve(&asset_account, maintenance);
+						//Reserve maintenance fees in the currency of the rent
+						let maintenance0 = BalanceType::<T>::convert_to_balance(
+							Self::manage_bal_to_u128(maintenance).unwrap(),
+						)
+						.payment_bal;
+						let reservation = Payment::Pallet::<T>::reserve_asset(
+							&payment_asset,
+							&asset_account,
+							maintenance0,
+						);
+						debug_assert!(reservation.is_ok());
 
 						//Emmit maintenance fee payment event
 						Self::deposit_event(Event::MaintenanceFeesPayment {
@@ -378,8 +415,6 @@ impl<T: Config> Pallet<T> {
 							amount: maintenance,
 						});
 
-						debug_assert!(reservation.is_ok());
-
 						//Now distribute rent between owners according to their share
 						for i in owners.clone() {
 							//Get owner's share: we divide
@@ -390,12 +425,12 @@ impl<T: Config> Pallet<T> {
 								/ total_issuance_float;
 							let amount_float = share_float * distribute_float;
 							let bals0 = BalanceType::<T>::convert_to_balance(amount_float as u128);
-							let amount = bals0.manage_bal;
-							<T as Config>::Currency::transfer(
+							let amount = bals0.payment_bal;
+							Payment::Pallet::<T>::transfer_asset(
+								&payment_asset,
 								&asset_account,
 								&i,
 								amount,
-								ExistenceRequirement::AllowDeath,
 							)
 							.ok();
 						}
@@ -440,3 +475,25 @@ impl<T: Config> Pallet<T> {
 		Weight::zero()
 	}
 }
+
+impl<T: Config> Payment::OnPaymentExpiry<T> for Pallet<T> {
+	/// Remove the expired guaranty payment requests, so that the tenant can
+	/// be proposed again for the asset
+	fn on_payment_expired(
+		from: &T::AccountId,
+		to: &T::AccountId,
+		payment_id: Payment::PaymentId,
+		_detail: &Payment::PaymentDetail<T>,
+	) {
+		let guaranty = Self::guaranty(from, to);
+		if guaranty.map(|infos| infos.payment_id) == Some(payment_id) {
+			GuarantyPayment::<T>::remove(from, to);
+			Self::deposit_event(Event::GuarantyPaymentExpired {
+				tenant: from.clone(),
+				asset_account: to.clone(),
+				payment_id,
+				when: <frame_system::Pallet<T>>::block_number(),
+			});
+		}
+	}
+}
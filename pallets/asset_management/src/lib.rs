@@ -19,7 +19,8 @@
 //!   - Elect a Representative.
 //!   - Demote a Representative.
 //!
-//! * `owners_vote` - Each asset owner can vote in an ongoing referendum.
+//! * `owners_vote` - Each asset owner can vote in an ongoing referendum. The NFT of the asset and
+//!   its ownership tokens are locked while a referendum is running.
 //!
 //! * `request_asset_management` - An active Representative can request an additional asset to manage.
 //!
@@ -45,6 +46,9 @@
 //!   asset.
 //!
 //! * `request_guaranty_payment` - Call used to send a guaranty deposit payment request to a tenant.
+//!
+//! * `set_payment_asset` - The Representative of an asset chooses the currency used for the
+//!   guaranty deposit and the rent of this asset: the native currency or a fungible asset.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -60,6 +64,7 @@ pub use pallet_roles as Roles;
 pub use pallet_share_distributor as Share;
 
 mod functions;
+pub mod migrations;
 mod types;
 pub use crate::types::*;
 pub use functions::*;
@@ -78,8 +83,11 @@ pub mod pallet {
 	use super::*;
 	use frame_system::WeightInfo;
 
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
@@ -143,6 +151,10 @@ pub mod pallet {
 		/// Maintenance fees percentage taken on monthly rent
 		#[pallet::constant]
 		type Maintenance: Get<Percent>;
+
+		/// Part of the distributed rent credited to the housing fund contributors
+		#[pallet::constant]
+		type FundRentShare: Get<Percent>;
 	}
 
 	//Store the referendum_index and the struct containing the
@@ -160,9 +172,22 @@ pub mod pallet {
 		T::AccountId, // payment issuer
 		Blake2_128Concat,
 		T::AccountId, // payment recipient
-		Payment::PaymentDetail<T>,
+		GuarantyPaymentInfo<T>,
 	>;
 
+	/// Currency in which the guaranty deposit and the rent of an asset are paid,
+	/// indexed by the virtual account of the asset
+	#[pallet::storage]
+	#[pallet::getter(fn payment_asset)]
+	pub type PaymentAssets<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, Payment::PaymentAssetOf<T>, ValueQuery>;
+
+	/// Representative in charge of an asset, indexed by the virtual account of the asset
+	#[pallet::storage]
+	#[pallet::getter(fn asset_representative)]
+	pub type AssetRepresentatives<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId, OptionQuery>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn indexes)]
 	pub type ProposalsIndexes<T: Config> =
@@ -238,6 +263,21 @@ pub mod pallet {
 			asset_account: T::AccountId,
 			amount: BalanceOf<T>,
 		},
+
+		/// A guaranty payment request expired before being paid by the tenant
+		GuarantyPaymentExpired {
+			tenant: T::AccountId,
+			asset_account: T::AccountId,
+			payment_id: Payment::PaymentId,
+			when: BlockNumberOf<T>,
+		},
+
+		/// The payment currency of an asset was updated
+		PaymentAssetSet {
+			asset_account: T::AccountId,
+			asset: Payment::PaymentAssetOf<T>,
+			when: BlockNumberOf<T>,
+		},
 	}
 
 	// Errors inform users that something went wrong.
@@ -293,6 +333,8 @@ pub mod pallet {
 		ExistingPendingRequest,
 		/// Maximum number of tenants reached
 		MaximumNumberOfTenantsReached,
+		/// The guaranty payment request was not created
+		PaymentRequestNotFound,
 	}
 
 	#[pallet::hooks]
@@ -304,6 +346,10 @@ pub mod pallet {
 		fn on_idle(n: T::BlockNumber, _max_weight: Weight) -> Weight {
 			Self::finish_block(n)
 		}
+
+		fn on_runtime_upgrade() -> Weight {
+			migrations::migrate::<T>()
+		}
 	}
 
 	#[pallet::call]
@@ -364,14 +410,14 @@ pub mod pallet {
 
 		/// Using the function below, an owner triggers a vote session with a proposal for an asset
 		/// The origin must be an owner of the asset
-		/// - asset_type: type of the asset
+		/// - collection_id: collection of the asset
 		/// - asset_id: id of the asset
 		/// - representative: an account with the representative role to be designed
 		/// - proposal contains the extrinsics to be executed depending on the vote result
 		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1).ref_time())]
 		pub fn launch_representative_session(
 			origin: OriginFor<T>,
-			asset_type: Nft::PossibleCollections,
+			collection_id: T::NftCollectionId,
 			asset_id: T::NftItemId,
 			representative: T::AccountId,
 			proposal: VoteProposals,
@@ -379,8 +425,6 @@ pub mod pallet {
 			let caller = ensure_signed(origin.clone())?;
 
 			// Get asset virtual account if it exists
-			let collection_id: T::NftCollectionId = asset_type.value().into();
-
 			let ownership = Share::Pallet::<T>::virtual_acc(collection_id, asset_id);
 			ensure!(ownership.is_some(), Error::<T>::NotAnAsset);
 			let ownership = ownership.unwrap();
@@ -467,6 +511,12 @@ pub mod pallet {
 				asset_id,
 			)
 			.ok();
+			// The asset and its ownership tokens are locked until the end of the referendum
+			Nft::Pallet::<T>::set_lock(
+				collection_id,
+				asset_id,
+				Nft::LockReason::Referendum(referendum_index),
+			)?;
 
 			//Emit Event
 			Self::deposit_event(Event::RepresentativeVoteSessionStarted {
@@ -589,6 +639,7 @@ pub mod pallet {
 
 			//revoke Representative Role
 			Self::revoke_representative_role(rep_account.clone()).ok();
+			AssetRepresentatives::<T>::remove(&caller);
 			Onboarding::Houses::<T>::mutate(collection, item, |asset| {
 				let mut asset0 = asset.clone().unwrap();
 				asset0.representative = None;
@@ -606,13 +657,13 @@ pub mod pallet {
 
 		/// Using the function below, a representative triggers a vote session with a proposal for a
 		/// tenant to be linked with an asset The origin must be a representative
-		/// - asset_type: type of the asset
+		/// - collection_id: collection of the asset
 		/// - asset_id: id of the asset
 		/// - tenant: an account with the tenant role
 		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1).ref_time())]
 		pub fn launch_tenant_session(
 			origin: OriginFor<T>,
-			asset_type: Nft::PossibleCollections,
+			collection_id: T::NftCollectionId,
 			asset_id: T::NftItemId,
 			tenant: T::AccountId,
 			proposal: VoteProposals,
@@ -627,7 +678,6 @@ pub mod pallet {
 			ensure!(rep.activated, Error::<T>::NotAnActiveRepresentative);
 
 			// Get the asset virtual account if exists
-			let collection_id: T::NftCollectionId = asset_type.value().into();
 			let ownership = Share::Pallet::<T>::virtual_acc(collection_id, asset_id);
 			ensure!(ownership.is_some(), Error::<T>::NotAnAsset);
 			
@@ -637,10 +687,11 @@ pub mod pallet {
 			let bals0 = BalanceType::<T>::convert_to_balance(guaranty);
 			let fee1 = T::IncentivePercentage::get() * bals0.manage_bal;
 			let total_amount = guaranty + fee0 + Self::manage_bal_to_u128(fee1).unwrap();
-			let tenant_bal0: BalanceOf<T> = <T as Config>::Currency::free_balance(&tenant);
-			let tenant_bal = Self::manage_bal_to_u128(tenant_bal0).unwrap();
-
 			let asset_account = ownership.unwrap().virtual_account;
+			let tenant_bal0 =
+				Payment::Pallet::<T>::free_balance(&Self::payment_asset(&asset_account), &tenant);
+			let tenant_bal = Payment::Pallet::<T>::balance_to_u128_option(tenant_bal0).unwrap();
+
 			ensure!(rep.assets_accounts.contains(&asset_account), Error::<T>::AssetOutOfControl);
 
 			// Ensure that provided account is a valid tenant
@@ -726,6 +777,12 @@ pub mod pallet {
 				asset_id,
 			)
 			.ok();
+			// The asset and its ownership tokens are locked until the end of the referendum
+			Nft::Pallet::<T>::set_lock(
+				collection_id,
+				asset_id,
+				Nft::LockReason::Referendum(referendum_index),
+			)?;
 
 			//Emit Event
 			Self::deposit_event(Event::TenantVoteSessionStarted {
@@ -799,14 +856,15 @@ pub mod pallet {
 			ensure!(vacancy > requests as u8, Error::<T>::MaximumNumberOfTenantsReached);
 			
 			//Launch payment request
-			Self::guaranty_payment(origin, from.clone(), collection, item).ok();
-			let payment = Self::guaranty(from.clone(), asset_account).unwrap();
+			Self::guaranty_payment(origin, from.clone(), collection, item)?;
+			let payment = Self::guaranty(from.clone(), asset_account)
+				.ok_or(Error::<T>::PaymentRequestNotFound)?;
 			let now = <frame_system::Pallet<T>>::block_number();
 
 			Self::deposit_event(Event::GuarantyPaymentRequested {
 				tenant: from,
 				asset_account: creator,
-				amount: payment.amount,
+				amount: payment.detail.amount,
 				when: now,
 			});
 
@@ -838,5 +896,43 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// The function below sets the currency used for the guaranty deposit and the rent of an
+		/// asset. Payment requests already sent are not affected.
+		/// The origin must be the active representative of the asset
+		/// - collection_id: collection of the asset
+		/// - asset_id: id of the asset
+		/// - asset: native currency or fungible asset used for the payments
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(2,1).ref_time())]
+		pub fn set_payment_asset(
+			origin: OriginFor<T>,
+			collection_id: T::NftCollectionId,
+			asset_id: T::NftItemId,
+			asset: Payment::PaymentAssetOf<T>,
+		) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+
+			// Ensure that the caller is an active representative
+			let rep = Roles::Pallet::<T>::reps(caller);
+			ensure!(rep.is_some(), Error::<T>::NotARepresentative);
+			let rep = rep.unwrap();
+			ensure!(rep.activated, Error::<T>::NotAnActiveRepresentative);
+
+			// Ensure that the asset is managed by the representative
+			let ownership = Share::Pallet::<T>::virtual_acc(collection_id, asset_id);
+			ensure!(ownership.is_some(), Error::<T>::NotAnAsset);
+			let asset_account = ownership.unwrap().virtual_account;
+			ensure!(rep.assets_accounts.contains(&asset_account), Error::<T>::AssetOutOfControl);
+
+			PaymentAssets::<T>::insert(&asset_account, asset);
+
+			Self::deposit_event(Event::PaymentAssetSet {
+				asset_account,
+				asset,
+				when: <frame_system::Pallet<T>>::block_number(),
+			});
+
+			Ok(())
+		}
 	}
 }
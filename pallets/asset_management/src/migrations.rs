@@ -0,0 +1,74 @@
+pub use super::*;
+use frame_support::traits::{GetStorageVersion, StorageVersion};
+
+/// Apply the migrations from the on chain storage version to the current one
+pub fn migrate<T: Config>() -> Weight {
+	let onchain_version = Pallet::<T>::on_chain_storage_version();
+	let mut weight = T::DbWeight::get().reads(1);
+	if onchain_version >= 2 {
+		return weight
+	}
+
+	if onchain_version < 1 {
+		weight = weight.saturating_add(v1::migrate::<T>());
+	}
+	weight = weight.saturating_add(v2::migrate::<T>());
+
+	StorageVersion::new(2).put::<Pallet<T>>();
+	weight.saturating_add(T::DbWeight::get().writes(1))
+}
+
+pub mod v1 {
+	use super::*;
+	use Payment::migrations::v1::OldPaymentDetail;
+
+	/// Reference the guaranty payments by their payment id.
+	/// The payments are migrated first, so that the id of the pending requests can be found.
+	/// The guaranties which are no longer in the payment pallet get an unused id.
+	pub fn migrate<T: Config>() -> Weight {
+		let weight = Payment::migrations::migrate::<T>();
+		let now = <frame_system::Pallet<T>>::block_number();
+		let mut translated: u64 = 0;
+
+		GuarantyPayment::<T>::translate::<OldPaymentDetail<T>, _>(|from, to, old| {
+			translated += 1;
+			let info = match Payment::Payment::<T>::iter_prefix((from.clone(), to.clone())).next() {
+				Some((payment_id, detail)) => GuarantyPaymentInfo { payment_id, detail },
+				None => {
+					let payment_id = Payment::NextPaymentId::<T>::mutate(|next_id| {
+						let payment_id = *next_id;
+						*next_id = next_id.saturating_add(1);
+						payment_id
+					});
+					GuarantyPaymentInfo { payment_id, detail: old.migrate(now) }
+				},
+			};
+			Some(info)
+		});
+
+		weight.saturating_add(T::DbWeight::get().reads_writes(2 * translated, translated))
+	}
+}
+
+pub mod v2 {
+	use super::*;
+
+	/// Index the assets by their virtual account to find their active representative
+	pub fn migrate<T: Config>() -> Weight {
+		let mut reads: u64 = 0;
+		let mut writes: u64 = 0;
+
+		for rep in Roles::RepresentativeLog::<T>::iter_values() {
+			reads += 1;
+			if !rep.activated {
+				continue
+			}
+			for asset_account in rep.assets_accounts {
+				AssetRepresentatives::<T>::insert(asset_account, rep.account_id.clone());
+				writes += 1;
+			}
+		}
+
+		T::DbWeight::get().reads_writes(reads, writes)
+	}
+}
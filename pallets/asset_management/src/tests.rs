@@ -2,6 +2,7 @@ pub use super::*;
 pub use frame_support::{assert_err, assert_ok};
 use frame_system::pallet_prelude::OriginFor;
 use mock::*;
+use Onboarding::AssetStatus;
 
 pub type Bvec<Test> = BoundedVec<u8, <Test as pallet_uniques::Config>::StringLimit>;
 
@@ -47,33 +48,37 @@ pub fn prep_test(
 	//Charlie creates a collection
 	assert_ok!(NftModule::create_collection(
 		Origin::signed(CHARLIE),
-		NftColl::OFFICESTEST,
+		OFFICESTEST,
+		CollectionClass::new(AssetClass::OFFICE, 3),
 		metadata0.clone()
 	));
 	//Charlie creates a second collection
 	assert_ok!(NftModule::create_collection(
 		Origin::signed(CHARLIE),
-		NftColl::APPARTMENTSTEST,
+		APPARTMENTSTEST,
+		CollectionClass::new(AssetClass::APPARTMENT, 3),
 		metadata0
 	));
 	// Bob creates a proposal without submiting for review
 
 	assert_ok!(OnboardingModule::create_and_submit_proposal(
 		Origin::signed(BOB),
-		NftColl::OFFICESTEST,
+		OFFICESTEST,
 		Some(price1),
 		metadata1,
 		false,
-		3
+		Some(3),
+		None
 	));
 
 	assert_ok!(OnboardingModule::create_and_submit_proposal(
 		Origin::signed(BOB),
-		NftColl::APPARTMENTSTEST,
+		APPARTMENTSTEST,
 		Some(price2),
 		metadata2,
 		false,
-		3
+		Some(3),
+		None
 	));
 }
 
@@ -105,19 +110,24 @@ fn test_integration_test() {
 		let price1 = 40_000;
 		let price2 = 30_000;
 		prep_test(price1, price2, metadata0, metadata1, metadata2);
-		let coll_id0 = NftColl::OFFICESTEST.value();
-		let item_id0 = pallet_nft::ItemsCount::<Test>::get()[coll_id0 as usize] - 1;
+		let coll_id0 = OFFICESTEST;
+		let item_id0 = NftModule::next_item_id(coll_id0) - 1;
 		let origin: OriginFor<Test> = frame_system::RawOrigin::Root.into();
 		let origin_bob = Origin::signed(BOB);
 
 		//Change first asset status to FINALISED
-		OnboardingModule::change_status(
-			origin_bob.clone(),
-			NftColl::OFFICESTEST,
+		assert_ok!(Onboarding::Pallet::<Test>::move_asset(
+			coll_id0,
 			item_id0,
-			Onboarding::AssetStatus::FINALISED,
-		)
-		.ok();
+			&[
+				AssetStatus::APPRAISING,
+				AssetStatus::REVIEWING,
+				AssetStatus::VOTING,
+				AssetStatus::ONBOARDED,
+				AssetStatus::FINALISING,
+				AssetStatus::FINALISED,
+			],
+		));
 
 		//Store initial owner
 		let old_owner0 = pallet_nft::Pallet::<Test>::owner(coll_id0, item_id0).unwrap();
@@ -164,20 +174,25 @@ fn test_integration_test() {
 		println!("Total supply {:?}", Assets::total_supply(id));
 
 		// Bob creates a second proposal without submiting for review
-		let coll_id1 = NftColl::APPARTMENTSTEST.value();
-		let item_id1 = pallet_nft::ItemsCount::<Test>::get()[coll_id1 as usize] - 1;
+		let coll_id1 = APPARTMENTSTEST;
+		let item_id1 = NftModule::next_item_id(coll_id1) - 1;
 
 		//Store initial owner
 		let old_owner1 = pallet_nft::Pallet::<Test>::owner(coll_id1, item_id1).unwrap();
 
 		//Change first asset status to FINALISED
-		OnboardingModule::change_status(
-			origin_bob.clone(),
-			NftColl::APPARTMENTSTEST,
+		assert_ok!(Onboarding::Pallet::<Test>::move_asset(
+			coll_id1,
 			item_id1,
-			Onboarding::AssetStatus::FINALISED,
-		)
-		.ok();
+			&[
+				AssetStatus::APPRAISING,
+				AssetStatus::REVIEWING,
+				AssetStatus::VOTING,
+				AssetStatus::ONBOARDED,
+				AssetStatus::FINALISING,
+				AssetStatus::FINALISED,
+			],
+		));
 
 		//Execute virtual account transactions
 		assert_ok!(ShareDistributor::virtual_account(coll_id1, item_id1));
@@ -229,12 +244,22 @@ fn test_integration_test() {
 		//Create voting session, aka Referendum to elect FERDIE as a representative.
 		assert_ok!(AssetManagement::launch_representative_session(
 			origin_eve.clone(),
-			NftColl::OFFICESTEST,
+			OFFICESTEST,
 			item_id0,
 			FERDIE,
 			VoteProposals::Election
 		));
 		let mut ref_index = 0;
+		// The asset and its ownership tokens are locked during the referendum
+		assert_eq!(NftModule::locks(OFFICESTEST, item_id0), vec![Nft::LockReason::Referendum(0)]);
+		assert_err!(
+			NftModule::transfer(frame_system::RawOrigin::Root.into(), OFFICESTEST, item_id0, EVE),
+			Nft::Error::<Test>::Locked
+		);
+		assert_err!(
+			Assets::transfer(origin_dave.clone(), id, EVE, 1),
+			pallet_assets::Error::<Test>::Frozen
+		);
 		//Get Referendum status before vote
 		let mut ref_infos = Democracy::referendum_info(0).unwrap();
 		println!(
@@ -284,15 +309,65 @@ fn test_integration_test() {
 
 		//Proposal enactement should happen 2 blocks later
 		fast_forward_to(end_block_number.saturating_add(<Test as crate::Config>::Delay::get()));
+		// The lock is released at the end of the referendum
+		assert!(!NftModule::is_locked(OFFICESTEST, item_id0));
+		assert_ok!(Assets::transfer(origin_dave.clone(), id, EVE, 1));
+		assert_ok!(Assets::transfer(origin_eve.clone(), id, DAVE, 1));
 
 		//The line below evaluate the results of TEST_0, TEST_1, & TEST_2 by looking for the result
 		// of a correctly executed call.
 		assert!(Roles::RepresentativeLog::<Test>::contains_key(FERDIE));
 		assert!(Roles::AccountsRolesLog::<Test>::contains_key(FERDIE));
 
-		let asset = Onboarding::Pallet::<Test>::houses(NftColl::OFFICESTEST.value(), item_id0);
+		let asset = Onboarding::Pallet::<Test>::houses(OFFICESTEST, item_id0);
 		assert!(asset.is_some());
 		assert_eq!(asset.unwrap().representative, Some(FERDIE));
+		let asset_account = ShareDistributor::virtual_acc(OFFICESTEST, item_id0).unwrap();
+		assert_eq!(
+			AssetManagement::asset_representative(&asset_account.virtual_account),
+			Some(FERDIE)
+		);
+
+		//////////////////////////////////////////////////////////////////////////////////////////
+		/////							TEST set_payment_asset								//////
+		//////////////////////////////////////////////////////////////////////////////////////////
+		// Payments are made in the native currency by default
+		assert_eq!(
+			AssetManagement::payment_asset(&virtual0.virtual_account),
+			Payment::PaymentAsset::Native
+		);
+		// Bob(Not a representative) cannot choose the payment currency
+		assert_err!(
+			AssetManagement::set_payment_asset(
+				origin_bob.clone(),
+				OFFICESTEST,
+				item_id0,
+				Payment::PaymentAsset::Fungible(1)
+			),
+			Error::<Test>::NotARepresentative
+		);
+		assert_ok!(AssetManagement::set_payment_asset(
+			Origin::signed(FERDIE),
+			OFFICESTEST,
+			item_id0,
+			Payment::PaymentAsset::Fungible(1)
+		));
+		assert_eq!(
+			AssetManagement::payment_asset(&virtual0.virtual_account),
+			Payment::PaymentAsset::Fungible(1)
+		);
+		expect_events(vec![mock::Event::AssetManagement(crate::Event::PaymentAssetSet {
+			asset_account: virtual0.virtual_account.clone(),
+			asset: Payment::PaymentAsset::Fungible(1),
+			when: System::block_number(),
+		})]);
+		// Go back to the native currency for the tenant session below
+		assert_ok!(AssetManagement::set_payment_asset(
+			Origin::signed(FERDIE),
+			OFFICESTEST,
+			item_id0,
+			Payment::PaymentAsset::Native
+		));
 
 		//////////////////////////////////////////////////////////////////////////////////////////
 		/////							TEST launch_tenant_session							//////
@@ -302,7 +377,7 @@ fn test_integration_test() {
 		assert_err!(
 			AssetManagement::launch_tenant_session(
 				origin_bob,
-				NftColl::OFFICESTEST,
+				OFFICESTEST,
 				item_id0,
 				GERARD,
 				VoteProposals::Election,
@@ -318,7 +393,7 @@ fn test_integration_test() {
 		assert_err!(
 			AssetManagement::launch_tenant_session(
 				origin_ferdie.clone(),
-				NftColl::OFFICES,
+				OFFICES,
 				10,
 				GERARD,
 				VoteProposals::Election,
@@ -336,7 +411,7 @@ fn test_integration_test() {
 		assert_err!(
 			AssetManagement::launch_tenant_session(
 				origin_ferdie.clone(),
-				NftColl::APPARTMENTSTEST,
+				APPARTMENTSTEST,
 				item_id1,
 				GERARD,
 				VoteProposals::Election,
@@ -350,7 +425,7 @@ fn test_integration_test() {
 		assert_err!(
 			AssetManagement::launch_tenant_session(
 				origin_ferdie.clone(),
-				NftColl::OFFICESTEST,
+				OFFICESTEST,
 				item_id0,
 				BOB,
 				VoteProposals::Election,
@@ -377,7 +452,7 @@ fn test_integration_test() {
 		assert_err!(
 			AssetManagement::launch_tenant_session(
 				origin_ferdie.clone(),
-				NftColl::OFFICESTEST,
+				OFFICESTEST,
 				item_id0,
 				GERARD,
 				VoteProposals::Election,
@@ -397,7 +472,7 @@ fn test_integration_test() {
 		// Create a voting session, aka referendum to propose GERARD as a tenant for the first house
 		assert_ok!(AssetManagement::launch_tenant_session(
 			origin_ferdie.clone(),
-			NftColl::OFFICESTEST,
+			OFFICESTEST,
 			item_id0,
 			GERARD,
 			VoteProposals::Election,
@@ -461,7 +536,7 @@ fn test_integration_test() {
 		assert_err!(
 			AssetManagement::launch_tenant_session(
 				origin_ferdie.clone(),
-				NftColl::OFFICESTEST,
+				OFFICESTEST,
 				item_id0,
 				GERARD,
 				VoteProposals::Election,
@@ -482,7 +557,7 @@ fn test_integration_test() {
 		assert_err!(
 			AssetManagement::launch_tenant_session(
 				origin_ferdie.clone(),
-				NftColl::OFFICESTEST,
+				OFFICESTEST,
 				item_id0,
 				PEGGY,
 				VoteProposals::Election,
@@ -503,7 +578,7 @@ fn test_integration_test() {
 		assert_err!(
 			AssetManagement::launch_tenant_session(
 				origin_ferdie.clone(),
-				NftColl::OFFICESTEST,
+				OFFICESTEST,
 				item_id0,
 				HUNTER,
 				VoteProposals::Demotion,
@@ -516,7 +591,7 @@ fn test_integration_test() {
 		// Multiple tenants for an asset
 		assert_ok!(AssetManagement::launch_tenant_session(
 			origin_ferdie.clone(),
-			NftColl::OFFICESTEST,
+			OFFICESTEST,
 			item_id0,
 			HUNTER,
 			VoteProposals::Election,
@@ -573,7 +648,7 @@ fn test_integration_test() {
 
 		assert_ok!(AssetManagement::launch_tenant_session(
 			origin_ferdie,
-			NftColl::OFFICESTEST,
+			OFFICESTEST,
 			item_id0,
 			HUNTER,
 			VoteProposals::Demotion,
@@ -624,7 +699,7 @@ fn test_integration_test() {
 		//Create voting session, aka Referendum to demote FERDIE from her/his representative role.
 		assert_ok!(AssetManagement::launch_representative_session(
 			origin_eve.clone(),
-			NftColl::OFFICESTEST,
+			OFFICESTEST,
 			item_id0,
 			FERDIE,
 			VoteProposals::Demotion
@@ -663,7 +738,125 @@ fn test_integration_test() {
 		// of a correctly executed call.
 		assert!(!Roles::AccountsRolesLog::<Test>::contains_key(FERDIE));
 
-		let asset = Onboarding::Pallet::<Test>::houses(NftColl::OFFICESTEST.value(), item_id0);
+		let asset = Onboarding::Pallet::<Test>::houses(OFFICESTEST, item_id0);
 		assert!(asset.unwrap().representative.is_none());
+		assert!(AssetManagement::asset_representative(&asset_account.virtual_account).is_none());
+	});
+}
+
+#[test]
+fn test_expired_guaranty_payment_is_removed() {
+	ExtBuilder::default().build().execute_with(|| {
+		let payment_id = pallet_payment::NextPaymentId::<Test>::get();
+		assert_ok!(pallet_payment::Pallet::<Test>::request_payment(
+			Origin::signed(FERDIE),
+			GERARD,
+			Default::default(),
+			100,
+		));
+		let detail = pallet_payment::Payment::<Test>::get((GERARD, FERDIE, payment_id)).unwrap();
+		GuarantyPayment::<Test>::insert(
+			GERARD,
+			FERDIE,
+			GuarantyPaymentInfo { payment_id, detail: detail.clone() },
+		);
+
+		// the expiry of another payment between the same accounts is ignored
+		<AssetManagement as pallet_payment::OnPaymentExpiry<Test>>::on_payment_expired(
+			&GERARD,
+			&FERDIE,
+			payment_id + 1,
+			&detail,
+		);
+		assert!(GuarantyPayment::<Test>::contains_key(GERARD, FERDIE));
+
+		<AssetManagement as pallet_payment::OnPaymentExpiry<Test>>::on_payment_expired(
+			&GERARD, &FERDIE, payment_id, &detail,
+		);
+		assert!(!GuarantyPayment::<Test>::contains_key(GERARD, FERDIE));
+		expect_events(vec![mock::Event::AssetManagement(crate::Event::GuarantyPaymentExpired {
+			tenant: GERARD,
+			asset_account: FERDIE,
+			payment_id,
+			when: System::block_number(),
+		})]);
+	});
+}
+
+#[test]
+fn test_migration_should_reference_the_guaranty_payments_by_id() {
+	ExtBuilder::default().build().execute_with(|| {
+		use frame_support::traits::{GetStorageVersion, StorageVersion};
+		use pallet_payment::{migrations::v1, PaymentState};
+		let old_payment = |state| v1::OldPaymentDetail::<Test> {
+			amount: 100,
+			incentive_amount: 0,
+			state,
+			resolver_account: ALICE,
+			fee_detail: None,
+		};
+
+		// a pending guaranty request, and a guaranty paid before the upgrade
+		v1::Payment::<Test>::insert(GERARD, FERDIE, old_payment(PaymentState::PaymentRequested));
+		frame_support::storage::unhashed::put(
+			&GuarantyPayment::<Test>::hashed_key_for(GERARD, FERDIE),
+			&old_payment(PaymentState::PaymentRequested),
+		);
+		frame_support::storage::unhashed::put(
+			&GuarantyPayment::<Test>::hashed_key_for(HUNTER, FERDIE),
+			&old_payment(PaymentState::PaymentCompleted),
+		);
+		StorageVersion::new(0).put::<pallet_payment::Pallet<Test>>();
+		StorageVersion::new(0).put::<AssetManagement>();
+
+		crate::migrations::migrate::<Test>();
+		assert_eq!(AssetManagement::on_chain_storage_version(), 2);
+		assert_eq!(pallet_payment::Pallet::<Test>::on_chain_storage_version(), 1);
+
+		// the pending request references the migrated payment
+		let pending = AssetManagement::guaranty(GERARD, FERDIE).unwrap();
+		assert_eq!(pending.payment_id, 0);
+		assert_eq!(
+			pallet_payment::Payment::<Test>::get((GERARD, FERDIE, 0)),
+			Some(pending.detail.clone())
+		);
+		assert_eq!(pending.detail.state, PaymentState::PaymentRequested);
+
+		// the paid guaranty gets an unused id
+		let paid = AssetManagement::guaranty(HUNTER, FERDIE).unwrap();
+		assert_eq!(paid.payment_id, 1);
+		assert_eq!(paid.detail.state, PaymentState::PaymentCompleted);
+		assert_eq!(pallet_payment::NextPaymentId::<Test>::get(), 2);
+	});
+}
+
+#[test]
+fn test_migration_should_index_the_representatives_by_asset() {
+	ExtBuilder::default().build().execute_with(|| {
+		use frame_support::traits::{GetStorageVersion, StorageVersion};
+		let representative = |account_id, activated, assets_accounts| Roles::Representative::<Test> {
+			account_id,
+			age: 1,
+			activated,
+			assets_accounts,
+			index: 0,
+		};
+		Roles::RepresentativeLog::<Test>::insert(
+			FERDIE,
+			representative(FERDIE, true, vec![DAVE, EVE]),
+		);
+		Roles::RepresentativeLog::<Test>::insert(
+			HUNTER,
+			representative(HUNTER, false, vec![GERARD]),
+		);
+		StorageVersion::new(1).put::<AssetManagement>();
+
+		crate::migrations::migrate::<Test>();
+		assert_eq!(AssetManagement::on_chain_storage_version(), 2);
+
+		// only the active representatives are indexed
+		assert_eq!(AssetManagement::asset_representative(DAVE), Some(FERDIE));
+		assert_eq!(AssetManagement::asset_representative(EVE), Some(FERDIE));
+		assert_eq!(AssetManagement::asset_representative(GERARD), None);
 	});
 }
@@ -119,6 +119,16 @@ impl<T: Config> ProposalRecord<T> {
 	}
 }
 
+#[derive(Clone, Encode, Decode, PartialEq, Eq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct GuarantyPaymentInfo<T: Config> {
+	///Id of the guaranty payment request in the payment pallet
+	pub payment_id: Payment::PaymentId,
+	///Details of the guaranty payment request
+	pub detail: Payment::PaymentDetail<T>,
+}
+
 #[derive(Clone, Encode, Decode, PartialEq, Eq, TypeInfo)]
 #[scale_info(skip_type_params(T))]
 #[cfg_attr(feature = "std", derive(Debug))]
@@ -0,0 +1,91 @@
+//! RPC interface for the bidding pallet.
+//! It exposes the bidding dry-run of the `BiddingApi` runtime API.
+
+use std::sync::Arc;
+
+use codec::Codec;
+use jsonrpsee::{
+	core::{async_trait, RpcResult},
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+pub use pallet_bidding::{BidFailure, BidSimulation, BiddingApi as BiddingRuntimeApi};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+#[rpc(client, server)]
+pub trait BiddingApi<BlockHash, AccountId, Balance, CollectionId, ItemId> {
+	/// Projected investors list of an asset, the price of the onboarded asset is used if no
+	/// `price` is given
+	#[method(name = "bidding_simulateBid")]
+	fn simulate_bid(
+		&self,
+		collection_id: CollectionId,
+		item_id: ItemId,
+		price: Option<Balance>,
+		at: Option<BlockHash>,
+	) -> RpcResult<BidSimulation<AccountId, Balance>>;
+}
+
+/// Provides RPC methods to query the bidding pallet.
+pub struct Bidding<C, P> {
+	/// Shared reference to the client.
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<P>,
+}
+
+impl<C, P> Bidding<C, P> {
+	/// Creates a new instance of the Bidding RPC helper.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+#[async_trait]
+impl<C, Block, AccountId, Balance, CollectionId, ItemId>
+	BiddingApiServer<<Block as BlockT>::Hash, AccountId, Balance, CollectionId, ItemId>
+	for Bidding<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: BiddingRuntimeApi<Block, AccountId, Balance, CollectionId, ItemId>,
+	AccountId: Codec + Send + Sync + 'static,
+	Balance: Codec + Send + Sync + 'static,
+	CollectionId: Codec + Send + Sync + 'static,
+	ItemId: Codec + Send + Sync + 'static,
+{
+	fn simulate_bid(
+		&self,
+		collection_id: CollectionId,
+		item_id: ItemId,
+		price: Option<Balance>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<BidSimulation<AccountId, Balance>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.simulate_bid(&at, collection_id, item_id, price).map_err(|e| {
+			CallError::Custom(ErrorObject::owned(
+				Error::RuntimeError.into(),
+				"Unable to simulate the bid.",
+				Some(e.to_string()),
+			))
+			.into()
+		})
+	}
+}
@@ -12,6 +12,14 @@
 //!
 //! * 'force_process_onboarded_asset' - extrinsic to manually launch the process of onboarded assets
 //! * 'force_process_onboarded_asset' - extrinsic to manually launch the process of finalised assets
+//! * 'set_asset_priority' - the council changes the priority of an asset in the bidding queue
+//!
+//! The onboarded assets wait in a queue ordered by priority, then by order of arrival. Each scan
+//! processes at most `MaxAssetsProcessedPerBlock` of them, within the remaining weight of the
+//! block, and the next scan resumes after the last processed asset. The onboarded assets are not
+//! processed while the house biddings of the housing fund are paused. The queue holds at most
+//! `MaxQueuedAssets` assets, the assets onboarded while it is full are queued by the next scans
+//! once it has room for them.
 //!
 //! #### Functions
 //! * 'process_finalised_finalised_assets' - execute the token distribution between investors for
@@ -20,6 +28,8 @@
 //!   finalised assets
 //! * 'process_onboarded_assets' - execute the workflow to associate an onboarded onboarded asset to
 //!   a list of investors and make and make
+//! * 'simulate_bid' - run the bidding checks on an asset without side effects, it is exposed
+//!   through the `BiddingApi` runtime API
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -38,6 +48,11 @@ mod benchmarking;
 
 mod structs;
 pub use crate::structs::*;
+mod selection;
+pub use crate::selection::*;
+pub mod migrations;
+pub mod runtime_api;
+pub use runtime_api::BiddingApi;
 
 pub use pallet_housing_fund as Housing_Fund;
 pub use pallet_nft as Nft;
@@ -52,6 +67,9 @@ pub mod pallet {
 
 	pub const PERCENT_FACTOR: u64 = 100;
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	/// Configure the pallet by specifying the parameters and types on which it depends.
 	#[pallet::config]
 	pub trait Config: frame_system::Config + ShareDistributor::Config {
@@ -64,20 +82,67 @@ pub mod pallet {
 		type MaxTriesAseemblingInvestor: Get<u64>;
 		type MaximumSharePerInvestor: Get<u64>;
 		type MinimumSharePerInvestor: Get<u64>;
+		/// Strategy used to select the investors of an asset
+		type SelectionStrategy: SelectionStrategy<Self>;
 		#[pallet::constant]
 		type NewAssetScanPeriod: Get<Self::BlockNumber>;
+		/// Maximum number of queued assets processed in a block
+		#[pallet::constant]
+		type MaxAssetsProcessedPerBlock: Get<u32>;
+		/// Maximum number of assets waiting in the bidding queue
+		#[pallet::constant]
+		type MaxQueuedAssets: Get<u32>;
+		/// Origin allowed to change the priority of a queued asset
+		type PriorityOrigin: EnsureOrigin<Self::Origin>;
 	}
 
 	pub type HousingFundAccount<T> = Housing_Fund::AccountIdOf<T>;
 	pub type HousingFundBalance<T> = Housing_Fund::BalanceOf<T>;
 	pub type EligibleContribution<T> = (HousingFundAccount<T>, HousingFundBalance<T>, HousingFundBalance<T>);
 	pub type UserBalance<T> = (HousingFundAccount<T>, HousingFundBalance<T>);
+	pub type AssetQueueOf<T> = BoundedVec<QueuedAsset<T>, <T as Config>::MaxQueuedAssets>;
 
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
+	#[pallet::storage]
+	#[pallet::getter(fn bid_attempts)]
+	/// Failed bidding attempts of the onboarded assets
+	pub type Attempts<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		(T::NftCollectionId, T::NftItemId),
+		BidAttempts,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn asset_queue)]
+	/// Onboarded assets waiting for a bid, ordered by priority then by order of arrival
+	pub type AssetQueue<T: Config> = StorageValue<_, AssetQueueOf<T>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn queue_overflow)]
+	/// Whether onboarded assets could not be queued because the queue was full
+	pub type QueueOverflow<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn queue_cursor)]
+	/// Position of the last processed asset, the processing of the queue resumes after it
+	pub type QueueCursor<T: Config> = StorageValue<_, QueuePosition, OptionQuery>;
+
+	#[pallet::storage]
+	/// Sequence number given to the next queued asset
+	pub type NextSequence<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn bidding_paused)]
+	/// Whether the pause of the house biddings was reported, it is reported once per pause
+	pub type BiddingPaused<T: Config> = StorageValue<_, bool, ValueQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -137,6 +202,34 @@ pub mod pallet {
 			HousingFundBalance<T>,
 			Vec<UserBalance<T>>,
 		),
+
+		/// The maximum number of simultaneous bids is reached, the remaining assets are processed
+		/// later
+		SimultaneousBidsLimitReached(BlockNumberOf<T>),
+
+		/// The bidding attempts on an asset are exhausted, the asset is sent back to the seller
+		AssetSentBackForRepricing(T::NftCollectionId, T::NftItemId, BidAttempts, BlockNumberOf<T>),
+
+		/// An onboarded asset was added to the bidding queue
+		AssetQueued(T::NftCollectionId, T::NftItemId, BlockNumberOf<T>),
+
+		/// The priority of a queued asset was changed
+		AssetPriorityChanged(T::NftCollectionId, T::NftItemId, u8, BlockNumberOf<T>),
+
+		/// The house biddings of the housing fund are paused, the onboarded assets are processed
+		/// once they are resumed. It is emitted once per pause
+		HouseBiddingPaused(BlockNumberOf<T>),
+
+		/// The bidding queue is full, the onboarded asset is queued once the queue has room for it
+		AssetQueueFull(T::NftCollectionId, T::NftItemId, BlockNumberOf<T>),
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The asset is not in the bidding queue
+		AssetNotQueued,
+		/// The bidding queue is full
+		AssetQueueFull,
 	}
 
 	#[pallet::hooks]
@@ -145,6 +238,10 @@ pub mod pallet {
 		fn on_initialize(n: T::BlockNumber) -> Weight {
 			Self::begin_block(n)
 		}
+
+		fn on_runtime_upgrade() -> Weight {
+			migrations::migrate::<T>()
+		}
 	}
 
 	#[pallet::call]
@@ -158,22 +255,64 @@ pub mod pallet {
 		pub fn force_process_finalised_asset(_origin: OriginFor<T>) -> DispatchResultWithPostInfo {
 			Self::process_finalised_assets()
 		}
+
+		/// Change the priority of a queued asset, assets with a higher priority are processed
+		/// first
+		/// The origin must be T::PriorityOrigin
+		/// - collection_id : collection of the asset
+		/// - item_id : id of the asset in the collection
+		/// - priority : new priority of the asset
+		/// Emits AssetPriorityChanged when successful
+		#[pallet::weight(10_000)]
+		pub fn set_asset_priority(
+			origin: OriginFor<T>,
+			collection_id: T::NftCollectionId,
+			item_id: T::NftItemId,
+			priority: u8,
+		) -> DispatchResultWithPostInfo {
+			T::PriorityOrigin::ensure_origin(origin)?;
+
+			AssetQueue::<T>::try_mutate(|queue| -> DispatchResult {
+				let index = queue
+					.iter()
+					.position(|asset| asset.is(collection_id, item_id))
+					.ok_or(Error::<T>::AssetNotQueued)?;
+				let mut asset = queue.remove(index);
+				asset.priority = priority;
+				Self::insert_in_queue(queue, asset)?;
+				Ok(())
+			})?;
+
+			let block_number = <frame_system::Pallet<T>>::block_number();
+			Self::deposit_event(Event::AssetPriorityChanged(
+				collection_id,
+				item_id,
+				priority,
+				block_number,
+			));
+
+			Ok(().into())
+		}
 	}
 }
 
-use enum_iterator::all;
 use frame_support::pallet_prelude::*;
 
 impl<T: Config> Pallet<T> {
 	fn begin_block(now: T::BlockNumber) -> Weight {
 		let max_block_weight = Weight::from_ref_time(1000_u64);
 
+		let mut weight = max_block_weight;
 		if (now % T::NewAssetScanPeriod::get()).is_zero() {
-			Self::process_onboarded_assets().ok();
+			if let Ok(Some(processing_weight)) =
+				Self::process_onboarded_assets().map(|info| info.actual_weight)
+			{
+				weight = weight.saturating_add(processing_weight);
+			}
 			Self::process_finalised_assets().ok();
 		}
 
-		max_block_weight
+		weight
 	}
 
 	/// Process finalised assets to distribute tokens among investors for assets
@@ -220,17 +359,74 @@ impl<T: Config> Pallet<T> {
 		Ok(().into())
 	}
 
-	/// Process onboarded assets to make make a bid on them and define a investors list
+	/// Process queued onboarded assets to make make a bid on them and define a investors list
+	/// No more than T::MaxAssetsProcessedPerBlock assets are processed, starting after the last
+	/// processed asset so that every queued asset gets its turn
+	/// The processing stops when the remaining weight of the block can't cover the next asset
 	pub fn process_onboarded_assets() -> DispatchResultWithPostInfo {
-		let houses = Onboarding::Pallet::<T>::get_onboarded_houses();
+		// The assets onboarded while the queue was full are queued once it has room for them
+		let mut checked: u64 = 0;
+		let queued = AssetQueue::<T>::decode_len().unwrap_or_default() as u32;
+		if QueueOverflow::<T>::get() && queued < T::MaxQueuedAssets::get() {
+			checked = Self::fill_queue();
+		}
+		let queue = AssetQueue::<T>::get();
 		let block_number = <frame_system::Pallet<T>>::block_number();
 
-		if houses.is_empty() {
+		if queue.is_empty() {
 			Self::deposit_event(Event::NoHousesOnboardedFound(block_number));
 			return Ok(().into())
 		}
 
-		for (collection_id, item_id, house) in houses.into_iter() {
+		// The assets wait in the queue while the house biddings are paused
+		if Housing_Fund::Pallet::<T>::is_paused(Housing_Fund::PausableOperation::HouseBidding) {
+			if !BiddingPaused::<T>::get() {
+				BiddingPaused::<T>::put(true);
+				Self::deposit_event(Event::HouseBiddingPaused(block_number));
+			}
+			return Ok(().into())
+		}
+		BiddingPaused::<T>::kill();
+
+		// Resume after the last processed asset, or restart from the head of the queue
+		let start = QueueCursor::<T>::get()
+			.and_then(|cursor| queue.iter().position(|asset| precedes(&cursor, &asset.position())))
+			.unwrap_or_default();
+		let batch = queue
+			.into_iter()
+			.skip(start)
+			.take(T::MaxAssetsProcessedPerBlock::get() as usize)
+			.collect::<Vec<_>>();
+
+		let remaining_weight = T::BlockWeights::get()
+			.max_block
+			.saturating_sub(frame_system::Pallet::<T>::block_weight().total());
+		let asset_weight =
+			Self::processing_weight(1, Housing_Fund::Pallet::<T>::contributor_count());
+		let mut weight = T::DbWeight::get().reads_writes(checked, checked);
+		for queued_asset in batch.into_iter() {
+			// No more than T::SimultaneousAssetBidder bids are waiting for the purchase
+			if Self::simultaneous_bids_limit_reached() {
+				Self::deposit_event(Event::SimultaneousBidsLimitReached(block_number));
+				break
+			}
+			// The next scans process the assets which don't fit in the block
+			if weight.saturating_add(asset_weight) > remaining_weight {
+				break
+			}
+
+			weight = weight.saturating_add(asset_weight);
+			QueueCursor::<T>::put(queued_asset.position());
+
+			let (collection_id, item_id) = (queued_asset.collection_id, queued_asset.item_id);
+			let house = match Onboarding::Pallet::<T>::houses(collection_id, item_id) {
+				Some(house) if house.status == Onboarding::AssetStatus::ONBOARDED => house,
+				_ => {
+					Self::remove_from_queue(collection_id, item_id);
+					continue
+				},
+			};
+
 			// Checks on price format
 			if house.price.is_none() {
 				continue
@@ -244,30 +440,28 @@ impl<T: Config> Pallet<T> {
 			let amount = amount_wrap.unwrap();
 			Self::deposit_event(Event::ProcessingAsset(collection_id, item_id, amount));
 
-			// Check if Housing Fund has enough fund for the asset
-			if !Housing_Fund::Pallet::<T>::check_available_fund(amount) {
-				Self::deposit_event(Event::HousingFundNotEnough(
-					collection_id,
-					item_id,
-					amount,
-					block_number,
-				));
-				continue
-			}
-
-			// Retrieves the investors list and their contributions
-			let investor_shares = Self::create_investor_list(amount);
-
-			// Check that the investor list creation was successful
-			if investor_shares.is_empty() {
-				Self::deposit_event(Event::FailedToAssembleInvestors(
-					collection_id,
-					item_id,
-					amount,
-					block_number,
-				));
-				continue
-			}
+			let investor_shares = match Self::assemble_bid(collection_id, item_id, amount) {
+				Ok(investor_shares) => investor_shares,
+				Err(BidFailure::HousingFundNotEnough) => {
+					Self::deposit_event(Event::HousingFundNotEnough(
+						collection_id,
+						item_id,
+						amount,
+						block_number,
+					));
+					continue
+				},
+				Err(_) => {
+					Self::deposit_event(Event::FailedToAssembleInvestors(
+						collection_id,
+						item_id,
+						amount,
+						block_number,
+					));
+					Self::register_failed_assembly(collection_id, item_id);
+					continue
+				},
+			};
 
 			Self::deposit_event(Event::InvestorListCreationSuccessful(
 				collection_id,
@@ -285,6 +479,13 @@ impl<T: Config> Pallet<T> {
 
 			match result {
 				Ok(_) => {
+					Attempts::<T>::remove((collection_id, item_id));
+
+					// The selected investors are de-prioritised for the next assets
+					for (account_id, _) in investor_shares.iter() {
+						Housing_Fund::ROLES::Pallet::<T>::increase_investor_selections(account_id);
+					}
+
 					Self::deposit_event(Event::HouseBiddingSucceeded(
 						collection_id,
 						item_id,
@@ -292,26 +493,11 @@ impl<T: Config> Pallet<T> {
 						block_number,
 					));
 
-					let collections = all::<Nft::PossibleCollections>().collect::<Vec<_>>();
-					let mut possible_collection = Nft::PossibleCollections::HOUSES;
-					for item in collections.iter() {
-						let value: T::NftCollectionId = item.value().into();
-						if value == collection_id {
-							possible_collection = *item;
-							break
-						}
-					}
-
-					let owner: T::AccountId =
-						Nft::Pallet::<T>::owner(collection_id, item_id).unwrap();
-
-					Onboarding::Pallet::<T>::change_status(
-						frame_system::RawOrigin::Signed(owner).into(),
-						possible_collection,
+					Self::change_asset_status(
+						collection_id,
 						item_id,
 						Onboarding::AssetStatus::FINALISING,
-					)
-					.ok();
+					);
 				},
 				Err(_e) => {
 					Self::deposit_event(Event::HouseBiddingFailed(
@@ -321,6 +507,7 @@ impl<T: Config> Pallet<T> {
 						block_number,
 						investor_shares,
 					));
+					Self::register_failed_bid(collection_id, item_id);
 					continue
 				},
 			}
@@ -328,23 +515,234 @@ impl<T: Config> Pallet<T> {
 			Self::simulate_notary_intervention();
 		}
 
-		Ok(().into())
+		Ok(Some(weight).into())
+	}
+
+	/// Weight of the processing of `assets` queued assets
+	/// The contribution, the fund share tokens and the preferences of the `contributors` of the
+	/// housing fund are read for each asset, and at most T::MaxInvestorPerHouse contributions are
+	/// reserved
+	pub fn processing_weight(assets: u32, contributors: u32) -> Weight {
+		let investors = <T as Housing_Fund::Config>::MaxInvestorPerHouse::get() as u64;
+		let reads = 10_u64
+			.saturating_add(4_u64.saturating_mul(contributors as u64))
+			.saturating_add(4_u64.saturating_mul(investors));
+		let writes = 5_u64.saturating_add(4_u64.saturating_mul(investors));
+		T::DbWeight::get()
+			.reads_writes(reads.saturating_mul(assets as u64), writes.saturating_mul(assets as u64))
+	}
+
+	/// Queue the onboarded assets missing from the queue, as long as it has room for them
+	/// Returns the number of onboarded assets
+	pub fn fill_queue() -> u64 {
+		let onboarded = Onboarding::Pallet::<T>::get_onboarded_houses();
+		let checked = onboarded.len() as u64;
+		QueueOverflow::<T>::kill();
+		for (collection_id, item_id, _) in onboarded {
+			if !Self::enqueue_asset(collection_id, item_id) {
+				break
+			}
+		}
+
+		checked
+	}
+
+	/// Add an onboarded asset at the end of the queue of its priority
+	/// Returns false if the queue is full, the asset being queued by a later scan
+	fn enqueue_asset(collection_id: T::NftCollectionId, item_id: T::NftItemId) -> bool {
+		let mut queue = AssetQueue::<T>::get();
+		if queue.iter().any(|asset| asset.is(collection_id, item_id)) {
+			return true
+		}
+		let block_number = <frame_system::Pallet<T>>::block_number();
+		if queue.is_full() {
+			QueueOverflow::<T>::put(true);
+			Self::deposit_event(Event::AssetQueueFull(collection_id, item_id, block_number));
+			return false
+		}
+
+		let sequence = NextSequence::<T>::mutate(|next| {
+			let sequence = *next;
+			*next = next.saturating_add(1);
+			sequence
+		});
+		let asset = QueuedAsset::<T> {
+			collection_id,
+			item_id,
+			priority: 0,
+			sequence,
+			onboarded_at: block_number,
+		};
+		if Self::insert_in_queue(&mut queue, asset).is_err() {
+			return false
+		}
+		AssetQueue::<T>::put(queue);
+
+		Self::deposit_event(Event::AssetQueued(collection_id, item_id, block_number));
+		true
+	}
+
+	fn remove_from_queue(collection_id: T::NftCollectionId, item_id: T::NftItemId) {
+		AssetQueue::<T>::mutate(|queue| queue.retain(|asset| !asset.is(collection_id, item_id)));
+	}
+
+	/// Insert an asset in the queue, keeping it ordered by priority then by order of arrival
+	fn insert_in_queue(queue: &mut AssetQueueOf<T>, asset: QueuedAsset<T>) -> Result<(), Error<T>> {
+		let index = queue
+			.iter()
+			.position(|queued| precedes(&asset.position(), &queued.position()))
+			.unwrap_or(queue.len());
+		queue.try_insert(index, asset).map_err(|_| Error::<T>::AssetQueueFull)
+	}
+
+	/// Check that the housing fund can bid on an asset of price `amount` and retrieve the
+	/// investors list with their contributions
+	fn assemble_bid(
+		collection_id: T::NftCollectionId,
+		item_id: T::NftItemId,
+		amount: HousingFundBalance<T>,
+	) -> Result<Vec<UserBalance<T>>, BidFailure> {
+		// Check if Housing Fund has enough fund for the asset
+		if !Housing_Fund::Pallet::<T>::check_available_fund(amount) {
+			return Err(BidFailure::HousingFundNotEnough)
+		}
+
+		// Check that the investor list creation was successful
+		let investor_shares = Self::create_investor_list(collection_id, item_id, amount);
+		if investor_shares.is_empty() {
+			return Err(BidFailure::FailedToAssembleInvestors)
+		}
+
+		Ok(investor_shares)
+	}
+
+	/// No more than T::SimultaneousAssetBidder bids can wait for the purchase
+	fn simultaneous_bids_limit_reached() -> bool {
+		Housing_Fund::Pallet::<T>::reservation_count() as u64 >= T::SimultaneousAssetBidder::get()
+	}
+
+	/// Run the bidding checks on an asset without side effects
+	/// The price of the onboarded asset is used if no `price` is given
+	pub fn simulate_bid(
+		collection_id: T::NftCollectionId,
+		item_id: T::NftItemId,
+		price: Option<HousingFundBalance<T>>,
+	) -> BidSimulation<HousingFundAccount<T>, HousingFundBalance<T>> {
+		let mut simulation = BidSimulation { amount: None, investors: Vec::new(), failure: None };
+
+		let amount = match price {
+			Some(price) => price,
+			None => match Onboarding::Pallet::<T>::houses(collection_id, item_id) {
+				None => {
+					simulation.failure = Some(BidFailure::UnknownAsset);
+					return simulation
+				},
+				Some(house) if house.status != Onboarding::AssetStatus::ONBOARDED => {
+					simulation.failure = Some(BidFailure::AssetNotOnboarded);
+					return simulation
+				},
+				Some(house) => match house.price.and_then(Self::convert_balance) {
+					Some(amount) => amount,
+					None => {
+						simulation.failure = Some(BidFailure::NoPrice);
+						return simulation
+					},
+				},
+			},
+		};
+		simulation.amount = Some(amount);
+
+		match Self::assemble_bid(collection_id, item_id, amount) {
+			Ok(investor_shares) => {
+				simulation.investors = investor_shares
+					.into_iter()
+					.map(|(account_id, value)| {
+						(account_id, value, Percent::from_rational(value, amount))
+					})
+					.collect();
+				if Self::simultaneous_bids_limit_reached() {
+					simulation.failure = Some(BidFailure::SimultaneousBidsLimitReached);
+				}
+			},
+			Err(failure) => simulation.failure = Some(failure),
+		}
+
+		simulation
+	}
+
+	/// Record a failed attempt to assemble the investors of an asset
+	/// The asset is sent back to the seller after T::MaxTriesAseemblingInvestor failures
+	fn register_failed_assembly(collection_id: T::NftCollectionId, item_id: T::NftItemId) {
+		let mut attempts = Attempts::<T>::get((collection_id, item_id));
+		attempts.investor_assemblies = attempts.investor_assemblies.saturating_add(1);
+		Self::update_attempts(collection_id, item_id, attempts);
+	}
+
+	/// Record a failed bid on an asset
+	/// The asset is sent back to the seller after T::MaxTriesBid failures
+	fn register_failed_bid(collection_id: T::NftCollectionId, item_id: T::NftItemId) {
+		let mut attempts = Attempts::<T>::get((collection_id, item_id));
+		attempts.bids = attempts.bids.saturating_add(1);
+		Self::update_attempts(collection_id, item_id, attempts);
+	}
+
+	fn update_attempts(
+		collection_id: T::NftCollectionId,
+		item_id: T::NftItemId,
+		attempts: BidAttempts,
+	) {
+		if attempts.investor_assemblies < T::MaxTriesAseemblingInvestor::get() &&
+			attempts.bids < T::MaxTriesBid::get()
+		{
+			Attempts::<T>::insert((collection_id, item_id), attempts);
+			return
+		}
+
+		Attempts::<T>::remove((collection_id, item_id));
+		Self::change_asset_status(collection_id, item_id, Onboarding::AssetStatus::REPRICING);
+
+		let block_number = <frame_system::Pallet<T>>::block_number();
+		Self::deposit_event(Event::AssetSentBackForRepricing(
+			collection_id,
+			item_id,
+			attempts,
+			block_number,
+		));
+	}
+
+	/// Change the status of an asset in the onboarding pallet, as the bidding pallet
+	fn change_asset_status(
+		collection_id: T::NftCollectionId,
+		item_id: T::NftItemId,
+		status: Onboarding::AssetStatus,
+	) {
+		Onboarding::Pallet::<T>::do_change_status(
+			collection_id,
+			item_id,
+			status,
+			Onboarding::TransitionOrigin::Bidding,
+		)
+		.ok();
 	}
 
 	/// Create the list of investor and their contribution for a given asset's price
 	/// It follows the following rules:
-	/// - the oldest contribution comes first
+	/// - the investors are ordered following T::SelectionStrategy
 	/// - no more than T::MaximumSharePerInvestor share per investor
 	/// - no less than T::MinimumSharePerInvestor share per investor
+	/// - the investment preferences of the investor are respected
 	/// The total contribution from the investor list should be equal to the asset's price
 	fn create_investor_list(
+		collection_id: T::NftCollectionId,
+		item_id: T::NftItemId,
 		amount: HousingFundBalance<T>,
 	) -> Vec<UserBalance<T>> {
 		let mut result: Vec<UserBalance<T>> =
 			Vec::new();
 		let percent = Self::u64_to_balance_option(100).unwrap();
 		// We get contributions following the min-max rules
-		let contributions = Self::get_eligible_investors_contribution(amount);
+		let contributions =
+			Self::get_eligible_investors_contribution(collection_id, item_id, amount);
 
 		let contributions_length =
 			Self::u64_to_balance_option(contributions.1.len() as u64).unwrap();
@@ -474,9 +872,12 @@ impl<T: Config> Pallet<T> {
 	}
 
 	/// Get
-	/// - a list of tuples (AccountId, Share, Amount) following the min-max share rule
+	/// - a list of tuples (AccountId, Share, Amount) following the min-max share rule and the
+	///   investment preferences of the investors
 	/// - the total amount of the list
 	fn get_eligible_investors_contribution(
+		collection_id: T::NftCollectionId,
+		item_id: T::NftItemId,
 		amount: HousingFundBalance<T>,
 	) -> (
 		HousingFundBalance<T>,
@@ -487,25 +888,29 @@ impl<T: Config> Pallet<T> {
 			HousingFundBalance<T>,
 			HousingFundBalance<T>,
 		)> = Vec::new();
-		let contributions = Housing_Fund::Pallet::<T>::get_contributions();
-		let mut ordered_account_id_list: Vec<HousingFundAccount<T>> = Vec::new();
-		let mut ordered_contributions: Vec<(
-			HousingFundAccount<T>,
-			Housing_Fund::Contribution<T>,
-		)> = Vec::new();
 		let zero_percent = Self::u64_to_balance_option(0).unwrap();
 		let mut total_share: HousingFundBalance<T> = Self::u64_to_balance_option(0).unwrap();
-
-		// the contributions are ordered by block number ascending order
-		for _ in 0..contributions.len() {
-			let oldest_contribution = Self::get_oldest_contribution(
-				ordered_account_id_list.clone(),
-				contributions.clone(),
-			);
-			ordered_account_id_list.push(oldest_contribution.0.clone());
-			ordered_contributions.push(oldest_contribution.clone());
+		let seller = Nft::Pallet::<T>::owner(collection_id, item_id);
+
+		// Skip the investors whose preferences exclude the asset
+		let mut contributions: Vec<InvestorContribution<T>> = Vec::new();
+		for (account_id, mut contribution) in Housing_Fund::Pallet::<T>::get_contributions() {
+			// The available balance is read from the fund share tokens of the investor
+			contribution.available_balance =
+				Housing_Fund::Pallet::<T>::available_fund_tokens(&account_id);
+			if let Some(preferences) = Housing_Fund::Pallet::<T>::preferences(&account_id) {
+				if !preferences.accepts(collection_id, amount, seller.as_ref()) {
+					continue
+				}
+				contribution.available_balance =
+					preferences.cap_exposure(contribution.available_balance);
+			}
+			contributions.push((account_id, contribution));
 		}
 
+		// the contributions are ordered following the selection strategy
+		let ordered_contributions = T::SelectionStrategy::select(amount, contributions);
+
 		// Add only contribution matching the minimum share contribution condition
 		for (account_id, contribution) in ordered_contributions.into_iter() {
 			let (share, value) = Self::get_investor_share(amount, contribution.clone());
@@ -520,34 +925,6 @@ impl<T: Config> Pallet<T> {
 
 	fn simulate_notary_intervention() {}
 
-	/// Get the oldest contribution which accountId is not present in the ordered_list
-	fn get_oldest_contribution(
-		ordered_list: Vec<HousingFundAccount<T>>,
-		contributions: Vec<(HousingFundAccount<T>, Housing_Fund::Contribution<T>)>,
-	) -> (HousingFundAccount<T>, Housing_Fund::Contribution<T>) {
-		let mut contributions_cut: Vec<(
-			HousingFundAccount<T>,
-			Housing_Fund::Contribution<T>,
-		)> = Vec::new();
-
-		// We build the list where the min will be searched
-		for item in contributions.iter() {
-			if !ordered_list.contains(&item.0) {
-				contributions_cut.push(item.clone());
-			}
-		}
-
-		let mut min = contributions_cut[0].clone();
-
-		for item in contributions_cut.iter() {
-			if item.1.block_number < min.1.block_number {
-				min = item.clone();
-			}
-		}
-
-		min
-	}
-
 	// Get the share of the house price from a given contribution
 	fn get_investor_share(
 		amount: HousingFundBalance<T>,
@@ -594,3 +971,18 @@ impl<T: Config> Pallet<T> {
 		input.try_into().ok()
 	}
 }
+
+impl<T: Config> Onboarding::OnAssetStatusChange<T> for Pallet<T> {
+	fn on_status_change(
+		collection_id: T::NftCollectionId,
+		item_id: T::NftItemId,
+		status: Onboarding::AssetStatus,
+	) -> DispatchResult {
+		if status == Onboarding::AssetStatus::ONBOARDED {
+			Self::enqueue_asset(collection_id, item_id);
+		} else {
+			Self::remove_from_queue(collection_id, item_id);
+		}
+		Ok(())
+	}
+}
@@ -0,0 +1,26 @@
+pub use super::*;
+use frame_support::traits::{GetStorageVersion, StorageVersion};
+
+/// Apply the migrations from the on chain storage version to the current one
+pub fn migrate<T: Config>() -> Weight {
+	let onchain_version = Pallet::<T>::on_chain_storage_version();
+	let mut weight = T::DbWeight::get().reads(1);
+	if onchain_version >= 1 {
+		return weight
+	}
+
+	weight = weight.saturating_add(v1::migrate::<T>());
+
+	StorageVersion::new(1).put::<Pallet<T>>();
+	weight.saturating_add(T::DbWeight::get().writes(1))
+}
+
+pub mod v1 {
+	use super::*;
+
+	/// Queue the assets onboarded before the bidding queue was introduced
+	pub fn migrate<T: Config>() -> Weight {
+		let checked = Pallet::<T>::fill_queue();
+		T::DbWeight::get().reads_writes(checked.saturating_add(1), checked.saturating_add(2))
+	}
+}
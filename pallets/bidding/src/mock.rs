@@ -2,19 +2,19 @@ use super::*;
 use crate as pallet_bidding;
 use frame_support::{
 	parameter_types,
-	traits::{AsEnsureOriginWithArg, ConstU16, ConstU64, EqualPrivilegeOnly},
-	weights::Weight,
+	traits::{AsEnsureOriginWithArg, ConstU16, ConstU64, EitherOfDiverse, EqualPrivilegeOnly},
+	weights::{RuntimeDbWeight, Weight},
 	PalletId,
 };
 use frame_system as system;
 use frame_system::{EnsureRoot, EnsureSigned};
 use pallet_collective::{Instance1, PrimeDefaultVote};
-use pallet_nft::NftPermissions;
+pub use pallet_nft::{AssetClass, CollectionClass, NftPermissions};
 use pallet_roles::GenesisBuild;
 use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
-	traits::{BlakeTwo256, IdentityLookup},
+	traits::{BlakeTwo256, Hash, IdentityLookup},
 	Perbill,
 };
 
@@ -24,7 +24,8 @@ type Block = frame_system::mocking::MockBlock<Test>;
 pub(crate) type Balance = u128;
 pub type CollectionId = u32;
 pub type ItemId = u32;
-pub type NftCollection = pallet_nft::PossibleCollections;
+pub const HOUSESTEST: CollectionId = 4;
+pub const OFFICESTEST: CollectionId = 5;
 type AccountId = u64;
 pub type MaxProposals = u32;
 pub type BlockNumber = u64;
@@ -43,12 +44,13 @@ frame_support::construct_runtime!(
 		NftModule: pallet_nft::{Pallet, Call, Storage, Event<T>},
 		Uniques: pallet_uniques::{Pallet, Call, Storage, Event<T>},
 		HousingFund: pallet_housing_fund::{Pallet, Call, Storage, Event<T>},
+		FundAssets: pallet_assets::<Instance1>::{Pallet, Storage, Event<T>},
 		Sudo: pallet_sudo::{Pallet, Call, Storage, Event<T>},
 		RoleModule: pallet_roles::{Pallet, Call, Storage, Event<T>},
 		Scheduler: pallet_scheduler::{Pallet, Call, Storage, Event<T>},
 		Collective: pallet_collective::<Instance1>::{Pallet, Call, Event<T>, Origin<T>, Config<T>},
 		Democracy: pallet_democracy::{Pallet, Call, Storage, Config<T>, Event<T>},
-		VotingModule: pallet_voting::{Pallet, Call, Storage, Event<T>},
+		VotingModule: pallet_voting::{Pallet, Call, Storage, Event<T>, Origin<T>},
 		Assets: pallet_assets::{Pallet, Storage, Config<T>, Event<T>},
 		ShareDistributorModule: pallet_share_distributor::{Pallet, Call, Storage, Event<T>},
 	}
@@ -57,13 +59,14 @@ frame_support::construct_runtime!(
 parameter_types! {
 	pub BlockWeights: frame_system::limits::BlockWeights =
 		frame_system::limits::BlockWeights::simple_max(Weight::from_ref_time(1024_u64));
+	pub const DbWeight: RuntimeDbWeight = RuntimeDbWeight { read: 1, write: 10 };
 }
 
 impl system::Config for Test {
 	type BaseCallFilter = frame_support::traits::Everything;
 	type BlockWeights = ();
 	type BlockLength = ();
-	type DbWeight = ();
+	type DbWeight = DbWeight;
 	type Origin = Origin;
 	type Call = Call;
 	type Index = u64;
@@ -149,6 +152,8 @@ impl pallet_roles::Config for Test {
 
 parameter_types! {
 	pub ReserveCollectionIdUpTo: u32 = 3;
+	pub const MaxMetadataRevisions: u32 = 3;
+	pub const MaxAttributesPerItem: u32 = 5;
 }
 impl pallet_nft::Config for Test {
 	type Event = Event;
@@ -158,11 +163,21 @@ impl pallet_nft::Config for Test {
 	type ProtocolOrigin = EnsureRoot<AccountId>;
 	type Permissions = NftPermissions;
 	type ReserveCollectionIdUpTo = ReserveCollectionIdUpTo;
+	type ItemStages = ();
+	type TokenFreezer = ShareDistributorModule;
+	type MaxMetadataRevisions = MaxMetadataRevisions;
+	type MaxAttributesPerItem = MaxAttributesPerItem;
 }
 
 parameter_types! {
 	pub const ProposalFee:Percent = Percent::from_percent(5);
 	pub const SlashedFee: Percent = Percent::from_percent(10);
+	pub const SaleFee: Percent = Percent::from_percent(20);
+	pub const FundFeeShare: Percent = Percent::from_percent(0);
+	pub const AppraisalBand: Percent = Percent::from_percent(10);
+	pub const MaxAppraisalFee: Percent = Percent::from_percent(1);
+	pub const CounterOfferPeriod: BlockNumber = 10;
+	pub const ExpiryScanLimit: u32 = 2;
 	pub const FeesAccount: PalletId = PalletId(*b"feeslash");
 }
 
@@ -172,8 +187,22 @@ impl pallet_onboarding::Config for Test {
 	type Prop = Call;
 	type ProposalFee = ProposalFee;
 	type Slash = SlashedFee;
+	type SaleFee = SaleFee;
+	type FundFeeShare = FundFeeShare;
+	type MaxInspectionReports = frame_support::traits::ConstU32<5>;
+	type AppraisalBand = AppraisalBand;
+	type MaxAppraisalFee = MaxAppraisalFee;
+	type CounterOfferPeriod = CounterOfferPeriod;
+	type MaxCounterOffers = frame_support::traits::ConstU32<10>;
+	type ExpiryScanLimit = ExpiryScanLimit;
 	type WeightInfo = ();
 	type FeesAccount = FeesAccount;
+	type OnStatusChange = BiddingModule;
+	type MaxStatusHistory = frame_support::traits::ConstU32<20>;
+	type CouncilOrigin =
+		EitherOfDiverse<EnsureRoot<AccountId>, pallet_voting::EnsureCouncilDecision<Test>>;
+	type DemocracyOrigin =
+		EitherOfDiverse<EnsureRoot<AccountId>, pallet_voting::EnsureInvestorsDecision<Test>>;
 }
 
 parameter_types! {
@@ -181,6 +210,8 @@ parameter_types! {
 	pub const FundThreshold: u64 = 100;
 	pub const MaxFundContribution: u64 = 20;
 	pub const MaxInvestorPerHouse: u32 = 10;
+	pub const MaxExcludedSellers: u32 = 5;
+	pub const MaxPreferredCollections: u32 = 10;
 	pub const HousingFundPalletId: PalletId = PalletId(*b"housfund");
 }
 
@@ -194,6 +225,38 @@ impl pallet_housing_fund::Config for Test {
 	type WeightInfo = ();
 	type PalletId = HousingFundPalletId;
 	type MaxInvestorPerHouse = MaxInvestorPerHouse;
+	type MaxExcludedSellers = MaxExcludedSellers;
+	type MaxPreferredCollections = MaxPreferredCollections;
+	type FundAssets = FundAssets;
+	type FundAssetId = FundAssetId;
+	type MaxWithdrawalsProcessedPerBlock = MaxWithdrawalsProcessedPerBlock;
+	type MaxQueuedWithdrawals = MaxQueuedWithdrawals;
+	type BlocksPerMonth = BlocksPerMonth;
+	type PauseOrigin = EnsureRoot<AccountId>;
+}
+
+parameter_types! {
+	pub const FundAssetId: u32 = 0;
+	pub const MaxWithdrawalsProcessedPerBlock: u32 = 2;
+	pub const MaxQueuedWithdrawals: u32 = 10;
+	pub const BlocksPerMonth: u64 = 10;
+}
+
+impl pallet_assets::Config<pallet_assets::Instance1> for Test {
+	type Event = Event;
+	type Balance = Balance;
+	type AssetId = u32;
+	type Currency = Balances;
+	type ForceOrigin = EnsureRoot<AccountId>;
+	type AssetDeposit = ();
+	type AssetAccountDeposit = ();
+	type MetadataDepositBase = ();
+	type MetadataDepositPerByte = ();
+	type ApprovalDeposit = ();
+	type StringLimit = frame_support::traits::ConstU32<50>;
+	type Freezer = HousingFund;
+	type Extra = ();
+	type WeightInfo = ();
 }
 
 parameter_types! {
@@ -289,6 +352,7 @@ impl pallet_voting::Config for Test {
 		pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 1, 2>;
 	type MinimumDepositVote = MinimumDeposit;
 	type CheckPeriod = CheckPeriod;
+	type VotingOrigin = Origin;
 }
 
 parameter_types! {
@@ -327,6 +391,13 @@ impl pallet_share_distributor::Config for Test {
 	type Fees = AssetsFees;
 }
 
+pub struct MockRandomness;
+impl frame_support::traits::Randomness<H256, BlockNumber> for MockRandomness {
+	fn random(subject: &[u8]) -> (H256, BlockNumber) {
+		(BlakeTwo256::hash(subject), 0)
+	}
+}
+
 parameter_types! {
 	pub const SimultaneousAssetBidder: u64 = 1;
 	pub const MaxTriesBid: u64 = 3;
@@ -334,6 +405,8 @@ parameter_types! {
 	pub const MaximumSharePerInvestor: u64 = 20;
 	pub const MinimumSharePerInvestor: u64 = 10;
 	pub const NewAssetScanPeriod: u64 = 20;
+	pub const MaxAssetsProcessedPerBlock: u32 = 3;
+	pub const MaxQueuedAssets: u32 = 5;
 }
 
 impl pallet_bidding::Config for Test {
@@ -345,7 +418,11 @@ impl pallet_bidding::Config for Test {
 	type MaxTriesAseemblingInvestor = MaxTriesAseemblingInvestor;
 	type MaximumSharePerInvestor = MaximumSharePerInvestor;
 	type MinimumSharePerInvestor = MinimumSharePerInvestor;
+	type SelectionStrategy = pallet_bidding::OldestFirst;
 	type NewAssetScanPeriod = NewAssetScanPeriod;
+	type MaxAssetsProcessedPerBlock = MaxAssetsProcessedPerBlock;
+	type MaxQueuedAssets = MaxQueuedAssets;
+	type PriorityOrigin = EnsureRoot<AccountId>;
 }
 
 pub const ALICE: u64 = 1;
@@ -0,0 +1,24 @@
+//! Runtime API definition for the bidding pallet.
+//! It tells whether an asset would be funded by the housing fund, and by which investors,
+//! without running the bidding process.
+#![allow(clippy::unnecessary_mut_passed)]
+
+use crate::BidSimulation;
+use codec::Codec;
+
+sp_api::decl_runtime_apis! {
+	pub trait BiddingApi<AccountId, Balance, CollectionId, ItemId> where
+		AccountId: Codec,
+		Balance: Codec,
+		CollectionId: Codec,
+		ItemId: Codec,
+	{
+		/// Projected investors list of the asset `item_id` of the collection `collection_id`.
+		/// The price of the onboarded asset is used if no `price` is given.
+		fn simulate_bid(
+			collection_id: CollectionId,
+			item_id: ItemId,
+			price: Option<Balance>,
+		) -> BidSimulation<AccountId, Balance>;
+	}
+}
@@ -0,0 +1,109 @@
+pub use super::*;
+
+use frame_support::{
+	sp_runtime::{
+		traits::{Hash, Saturating},
+		PerThing, Perbill, Percent,
+	},
+	traits::Randomness,
+};
+use sp_std::{cmp::Reverse, marker::PhantomData};
+
+pub type InvestorContribution<T> = (HousingFundAccount<T>, Housing_Fund::Contribution<T>);
+
+/// Strategy used to select the investors of an asset among the eligible contributors
+pub trait SelectionStrategy<T: Config> {
+	/// Return the contributions in the order the investors are selected, with the available
+	/// balance each investor can put in an asset of price `amount`
+	fn select(
+		amount: HousingFundBalance<T>,
+		contributions: Vec<InvestorContribution<T>>,
+	) -> Vec<InvestorContribution<T>>;
+}
+
+/// Number of times an investor was already selected to buy an asset
+fn selections<T: Config>(account_id: &HousingFundAccount<T>) -> u32 {
+	Housing_Fund::ROLES::Pallet::<T>::investors(account_id)
+		.map(|investor| investor.selections)
+		.unwrap_or_default()
+}
+
+/// Priority boost of the active contribution lock-ups of an investor
+fn boost<T: Config>(account_id: &HousingFundAccount<T>) -> Percent {
+	Housing_Fund::Pallet::<T>::priority_boost(account_id)
+}
+
+/// The least selected investors come first, then the most boosted and the oldest contributions
+pub struct OldestFirst;
+impl<T: Config> SelectionStrategy<T> for OldestFirst {
+	fn select(
+		_amount: HousingFundBalance<T>,
+		mut contributions: Vec<InvestorContribution<T>>,
+	) -> Vec<InvestorContribution<T>> {
+		contributions.sort_by_cached_key(|(account_id, contribution)| {
+			(
+				selections::<T>(account_id),
+				Reverse(boost::<T>(account_id)),
+				contribution.block_number,
+			)
+		});
+		contributions
+	}
+}
+
+/// Every eligible investor puts a part of the price proportional to its available balance,
+/// increased by its lock-up boost and divided by the number of times it was already selected
+pub struct ProRata;
+impl<T: Config> SelectionStrategy<T> for ProRata {
+	fn select(
+		amount: HousingFundBalance<T>,
+		contributions: Vec<InvestorContribution<T>>,
+	) -> Vec<InvestorContribution<T>> {
+		let mut contributions =
+			<OldestFirst as SelectionStrategy<T>>::select(amount, contributions);
+
+		let weights: Vec<HousingFundBalance<T>> = contributions
+			.iter()
+			.map(|(account_id, contribution)| {
+				let selections = selections::<T>(account_id).saturating_add(1);
+				let balance = contribution.available_balance;
+				let boosted = balance.saturating_add(boost::<T>(account_id).mul_floor(balance));
+				boosted / HousingFundBalance::<T>::from(selections)
+			})
+			.collect();
+		let total_weight = weights
+			.iter()
+			.fold(HousingFundBalance::<T>::zero(), |total, weight| total.saturating_add(*weight));
+
+		if total_weight.is_zero() {
+			return contributions
+		}
+
+		for ((_, contribution), weight) in contributions.iter_mut().zip(weights.into_iter()) {
+			let part = Perbill::from_rational(weight, total_weight).mul_ceil(amount);
+			contribution.available_balance = contribution.available_balance.min(part);
+		}
+
+		contributions
+	}
+}
+
+/// The least selected investors come first, then the most boosted, remaining ties are drawn at
+/// random
+pub struct Lottery<R>(PhantomData<R>);
+impl<T: Config, R: Randomness<T::Hash, T::BlockNumber>> SelectionStrategy<T> for Lottery<R> {
+	fn select(
+		_amount: HousingFundBalance<T>,
+		mut contributions: Vec<InvestorContribution<T>>,
+	) -> Vec<InvestorContribution<T>> {
+		let (seed, _) = R::random(b"bidding/lottery");
+		contributions.sort_by_cached_key(|(account_id, _)| {
+			(
+				selections::<T>(account_id),
+				Reverse(boost::<T>(account_id)),
+				T::Hashing::hash_of(&(seed, account_id)),
+			)
+		});
+		contributions
+	}
+}
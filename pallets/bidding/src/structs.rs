@@ -1,11 +1,89 @@
 pub use super::*;
 
 pub use frame_support::{
+	codec::{Decode, Encode},
 	inherent::Vec,
 	sp_runtime::{traits::Zero, Percent},
 	traits::{Currency, ReservableCurrency},
+	RuntimeDebug,
 };
+pub use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 
 pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 pub type BalanceOf<T> = <<T as Config>::Currency as Currency<AccountIdOf<T>>>::Balance;
 pub type BlockNumberOf<T> = <T as frame_system::Config>::BlockNumber;
+
+/// Failed attempts of the bidding process on an onboarded asset
+#[derive(Clone, Encode, Decode, Default, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct BidAttempts {
+	/// Number of times the investors list could not be assembled
+	pub investor_assemblies: u64,
+	/// Number of times the bid failed with an assembled investors list
+	pub bids: u64,
+}
+
+/// Reason why the bidding process would fail on an asset
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum BidFailure {
+	/// The asset does not exist
+	UnknownAsset,
+	/// The asset is not onboarded
+	AssetNotOnboarded,
+	/// The asset has no valid price
+	NoPrice,
+	/// The housing fund doesn't have enough fund for the asset
+	HousingFundNotEnough,
+	/// No investors list matching the share rules could be assembled
+	FailedToAssembleInvestors,
+	/// The maximum number of simultaneous bids is reached
+	SimultaneousBidsLimitReached,
+}
+
+/// Projected outcome of the bidding process on an asset, computed without side effects
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct BidSimulation<AccountId, Balance> {
+	/// Price used for the bid
+	pub amount: Option<Balance>,
+	/// Selected investors with their contribution and their share of the asset
+	pub investors: Vec<(AccountId, Balance, Percent)>,
+	/// Reason of the failure, None if the bid would be made
+	pub failure: Option<BidFailure>,
+}
+
+/// Onboarded asset waiting in the bidding queue
+#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct QueuedAsset<T: Config> {
+	pub collection_id: T::NftCollectionId,
+	pub item_id: T::NftItemId,
+	/// Assets with a higher priority are processed first
+	pub priority: u8,
+	/// Order of arrival in the queue, assets with the same priority are processed first in,
+	/// first out
+	pub sequence: u64,
+	/// Block at which the asset was onboarded
+	pub onboarded_at: BlockNumberOf<T>,
+}
+
+impl<T: Config> QueuedAsset<T> {
+	/// Position of the asset in the queue
+	pub fn position(&self) -> QueuePosition {
+		(self.priority, self.sequence)
+	}
+
+	pub fn is(&self, collection_id: T::NftCollectionId, item_id: T::NftItemId) -> bool {
+		self.collection_id == collection_id && self.item_id == item_id
+	}
+}
+
+/// Position of an asset in the bidding queue, as (priority, sequence)
+pub type QueuePosition = (u8, u64);
+
+/// Whether the position `a` is processed before the position `b`
+pub fn precedes(a: &QueuePosition, b: &QueuePosition) -> bool {
+	a.0 > b.0 || (a.0 == b.0 && a.1 < b.1)
+}
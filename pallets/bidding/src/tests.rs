@@ -1,6 +1,13 @@
 use super::*;
-use crate::mock::*;
-use frame_support::{assert_ok, BoundedVec};
+use crate::{mock::*, Onboarding::AssetStatus};
+use frame_support::{
+	assert_noop, assert_ok,
+	dispatch::DispatchClass,
+	traits::{Get, Randomness},
+	weights::Weight,
+	BoundedVec,
+};
+use sp_runtime::traits::{BlakeTwo256, Hash};
 use std::any::type_name;
 
 fn type_of<T>(_: T) -> &'static str {
@@ -64,6 +71,7 @@ fn get_investor_share_should_succeed() {
 				contributions: vec![crate::Housing_Fund::ContributionLog {
 					amount: HousingFund::u64_to_balance_option(25).unwrap(),
 					block_number: 1,
+					lock: None,
 				}],
 				withdraws: Vec::new(),
 			};
@@ -76,49 +84,170 @@ fn get_investor_share_should_succeed() {
 	});
 }
 
+fn new_contribution(
+	account_id: u64,
+	available_balance: u128,
+	block_number: u64,
+) -> crate::Housing_Fund::Contribution<Test> {
+	crate::Housing_Fund::Contribution {
+		account_id,
+		available_balance,
+		reserved_balance: HousingFund::u64_to_balance_option(0).unwrap(),
+		contributed_balance: HousingFund::u64_to_balance_option(0).unwrap(),
+		has_withdrawn: false,
+		block_number,
+		contributions: vec![crate::Housing_Fund::ContributionLog {
+			amount: available_balance,
+			block_number,
+			lock: None,
+		}],
+		withdraws: Vec::new(),
+	}
+}
+
+fn select_investor(account_id: u64) {
+	assert_ok!(RoleModule::set_role(
+		Origin::signed(account_id),
+		account_id,
+		crate::Onboarding::HousingFund::ROLES::Accounts::INVESTOR
+	));
+	crate::Housing_Fund::ROLES::Pallet::<Test>::increase_investor_selections(&account_id);
+}
+
 #[test]
-fn get_oldest_contribution_should_succeed() {
+fn oldest_first_selection_should_succeed() {
 	new_test_ext().execute_with(|| {
-		let ordered_list = Vec::new();
+		let contributions = vec![
+			(2, new_contribution(2, 30, 2)),
+			(1, new_contribution(1, 25, 1)),
+			(3, new_contribution(3, 10, 3)),
+		];
 
-		let contribution: crate::Housing_Fund::Contribution<Test> =
-			crate::Housing_Fund::Contribution {
-				account_id: 1,
-				available_balance: HousingFund::u64_to_balance_option(25).unwrap(),
-				reserved_balance: HousingFund::u64_to_balance_option(0).unwrap(),
-				contributed_balance: HousingFund::u64_to_balance_option(0).unwrap(),
-				has_withdrawn: false,
-				block_number: 1,
-				contributions: vec![crate::Housing_Fund::ContributionLog {
-					amount: HousingFund::u64_to_balance_option(25).unwrap(),
-					block_number: 1,
-				}],
-				withdraws: Vec::new(),
-			};
+		let selection =
+			<OldestFirst as SelectionStrategy<Test>>::select(100, contributions.clone());
+		assert_eq!(
+			selection.iter().map(|(account_id, _)| *account_id).collect::<Vec<_>>(),
+			vec![1, 2, 3]
+		);
+		// the available balances are not changed
+		assert_eq!(selection[0].1, new_contribution(1, 25, 1));
+
+		// the investor already selected comes last
+		select_investor(1);
+		let selection = <OldestFirst as SelectionStrategy<Test>>::select(100, contributions);
+		assert_eq!(
+			selection.iter().map(|(account_id, _)| *account_id).collect::<Vec<_>>(),
+			vec![2, 3, 1]
+		);
+	});
+}
 
+#[test]
+fn pro_rata_selection_should_succeed() {
+	new_test_ext().execute_with(|| {
 		let contributions = vec![
-			(1, contribution.clone()),
-			(
-				2,
-				crate::Housing_Fund::Contribution {
-					account_id: 1,
-					available_balance: HousingFund::u64_to_balance_option(30).unwrap(),
-					reserved_balance: HousingFund::u64_to_balance_option(0).unwrap(),
-					contributed_balance: HousingFund::u64_to_balance_option(0).unwrap(),
-					has_withdrawn: false,
-					block_number: 2,
-					contributions: vec![crate::Housing_Fund::ContributionLog {
-						amount: HousingFund::u64_to_balance_option(25).unwrap(),
-						block_number: 1,
-					}],
-					withdraws: Vec::new(),
-				},
-			),
+			(1, new_contribution(1, 120, 1)),
+			(2, new_contribution(2, 60, 2)),
+			(3, new_contribution(3, 20, 3)),
 		];
 
+		// each investor puts a part of the price proportional to its available balance
+		let selection = <ProRata as SelectionStrategy<Test>>::select(100, contributions.clone());
+		assert_eq!(
+			selection
+				.iter()
+				.map(|(account_id, contribution)| (*account_id, contribution.available_balance))
+				.collect::<Vec<_>>(),
+			vec![(1, 60), (2, 30), (3, 10)]
+		);
+
+		// the weight of an investor is divided by the number of times it was selected
+		select_investor(1);
+		let selection = <ProRata as SelectionStrategy<Test>>::select(100, contributions);
+		assert_eq!(
+			selection
+				.iter()
+				.map(|(account_id, contribution)| (*account_id, contribution.available_balance))
+				.collect::<Vec<_>>(),
+			vec![(2, 43), (3, 15), (1, 43)]
+		);
+	});
+}
+
+#[test]
+fn locked_contributions_should_be_selected_first() {
+	new_test_ext().execute_with(|| {
+		let mut locked = new_contribution(3, 30, 3);
+		locked.contributions[0].lock = Some(crate::Housing_Fund::LockSchedule {
+			period: crate::Housing_Fund::LockPeriod::SixMonths,
+			unlock_block: 100,
+			amount: 30,
+		});
+		crate::Housing_Fund::Contributions::<Test>::insert(3, locked.clone());
+		let contributions =
+			vec![(1, new_contribution(1, 30, 1)), (2, new_contribution(2, 30, 2)), (3, locked)];
+
+		// the boosted investor comes before the oldest contributions
+		let selection =
+			<OldestFirst as SelectionStrategy<Test>>::select(100, contributions.clone());
+		assert_eq!(
+			selection.iter().map(|(account_id, _)| *account_id).collect::<Vec<_>>(),
+			vec![3, 1, 2]
+		);
+
+		// and its weight is increased by the boost
+		let selection = <ProRata as SelectionStrategy<Test>>::select(60, contributions.clone());
+		assert_eq!(
+			selection
+				.iter()
+				.map(|(account_id, contribution)| (*account_id, contribution.available_balance))
+				.collect::<Vec<_>>(),
+			vec![(3, 23), (1, 19), (2, 19)]
+		);
+
+		// the boost ends with the lock-up
+		System::set_block_number(100);
+		let selection = <OldestFirst as SelectionStrategy<Test>>::select(100, contributions);
 		assert_eq!(
-			BiddingModule::get_oldest_contribution(ordered_list, contributions),
-			(1, contribution)
+			selection.iter().map(|(account_id, _)| *account_id).collect::<Vec<_>>(),
+			vec![1, 2, 3]
+		);
+	});
+}
+
+#[test]
+fn lottery_selection_should_succeed() {
+	new_test_ext().execute_with(|| {
+		let contributions = vec![
+			(1, new_contribution(1, 25, 1)),
+			(2, new_contribution(2, 25, 2)),
+			(3, new_contribution(3, 25, 3)),
+			(4, new_contribution(4, 25, 4)),
+		];
+
+		// the investors are drawn following the random seed
+		let (seed, _) = MockRandomness::random(b"bidding/lottery");
+		let mut expected = vec![1u64, 2, 3, 4];
+		expected.sort_by_key(|account_id| BlakeTwo256::hash_of(&(seed, account_id)));
+
+		let selection = <Lottery<MockRandomness> as SelectionStrategy<Test>>::select(
+			100,
+			contributions.clone(),
+		);
+		assert_eq!(
+			selection.iter().map(|(account_id, _)| *account_id).collect::<Vec<_>>(),
+			expected
+		);
+
+		// the investor already selected comes last
+		let first = expected.remove(0);
+		expected.push(first);
+		select_investor(first);
+		let selection =
+			<Lottery<MockRandomness> as SelectionStrategy<Test>>::select(100, contributions);
+		assert_eq!(
+			selection.iter().map(|(account_id, _)| *account_id).collect::<Vec<_>>(),
+			expected
 		);
 	});
 }
@@ -150,7 +279,7 @@ fn get_eligible_investors_contribution_should_succeed() {
 			System::set_block_number(block_number);
 		}
 
-		let list = BiddingModule::get_eligible_investors_contribution(100);
+		let list = BiddingModule::get_eligible_investors_contribution(OFFICESTEST, 0, 100);
 
 		assert_eq!(list, (80, vec![(1, 20, 20), (2, 20, 20), (3, 20, 20), (4, 20, 20),]));
 	});
@@ -213,7 +342,7 @@ fn create_investor_list_should_succeed() {
 			System::set_block_number(block_number);
 		}
 
-		let investor_list = BiddingModule::create_investor_list(100);
+		let investor_list = BiddingModule::create_investor_list(OFFICESTEST, 0, 100);
 
 		assert!(investor_list.contains(&(1, 20)));
 		assert!(investor_list.contains(&(2, 20)));
@@ -251,7 +380,7 @@ fn create_investor_list_second_case_should_succeed() {
 			System::set_block_number(block_number);
 		}
 
-		let investor_list = BiddingModule::create_investor_list(100);
+		let investor_list = BiddingModule::create_investor_list(OFFICESTEST, 0, 100);
 
 		assert_eq!(investor_list.len(), 6);
 		assert!(investor_list.contains(&(1, 20)));
@@ -294,7 +423,7 @@ fn create_investor_list_third_case_should_succeed() {
 			System::set_block_number(block_number);
 		}
 
-		let investor_list = BiddingModule::create_investor_list(100);
+		let investor_list = BiddingModule::create_investor_list(OFFICESTEST, 0, 100);
 
 		assert_eq!(
 			investor_list,
@@ -334,7 +463,7 @@ fn create_investor_list_fourth_case_should_succeed() {
 			System::set_block_number(block_number);
 		}
 
-		let investor_list = BiddingModule::create_investor_list(100);
+		let investor_list = BiddingModule::create_investor_list(OFFICESTEST, 0, 100);
 
 		assert_eq!(investor_list, vec![(1, 20), (3, 20), (4, 20), (5, 20), (6, 10), (7, 10),]);
 	});
@@ -372,7 +501,7 @@ fn create_investor_list_should_fail() {
 			System::set_block_number(block_number);
 		}
 
-		let investor_list = BiddingModule::create_investor_list(100);
+		let investor_list = BiddingModule::create_investor_list(OFFICESTEST, 0, 100);
 
 		assert_eq!(investor_list.len(), 0);
 	});
@@ -420,27 +549,33 @@ fn process_onboarded_assets_not_enough_fund_should_fail() {
 
 		assert_ok!(NftModule::create_collection(
 			Origin::signed(KEZIA),
-			NftCollection::OFFICESTEST,
+			OFFICESTEST,
+			CollectionClass::new(AssetClass::OFFICE, 3),
 			metadata.clone()
 		));
 
 		assert_ok!(OnboardingModule::create_and_submit_proposal(
 			Origin::signed(AMANI),
-			NftCollection::OFFICESTEST,
+			OFFICESTEST,
 			Some(100),
 			metadata,
 			false,
-			3
+			Some(3),
+			None
 		));
 
-		let collection_id = NftCollection::OFFICESTEST.value();
-		let item_id = pallet_nft::ItemsCount::<Test>::get()[collection_id as usize] - 1;
+		let collection_id = OFFICESTEST;
+		let item_id = NftModule::next_item_id(collection_id) - 1;
 
-		assert_ok!(OnboardingModule::change_status(
-			Origin::signed(AMANI),
-			NftCollection::OFFICESTEST,
+		assert_ok!(Onboarding::Pallet::<Test>::move_asset(
+			collection_id,
 			item_id,
-			crate::Onboarding::AssetStatus::ONBOARDED
+			&[
+				AssetStatus::APPRAISING,
+				AssetStatus::REVIEWING,
+				AssetStatus::VOTING,
+				AssetStatus::ONBOARDED,
+			],
 		));
 
 		assert_ok!(BiddingModule::process_onboarded_assets());
@@ -505,27 +640,33 @@ fn process_onboarded_assets_not_enough_fund_among_investors_should_fail() {
 
 		assert_ok!(NftModule::create_collection(
 			Origin::signed(KEZIA),
-			NftCollection::OFFICESTEST,
+			OFFICESTEST,
+			CollectionClass::new(AssetClass::OFFICE, 3),
 			metadata.clone()
 		));
 
 		assert_ok!(OnboardingModule::create_and_submit_proposal(
 			Origin::signed(AMANI),
-			NftCollection::OFFICESTEST,
+			OFFICESTEST,
 			Some(100),
 			metadata,
 			false,
-			3
+			Some(3),
+			None
 		));
 
-		let collection_id = NftCollection::OFFICESTEST.value();
-		let item_id = pallet_nft::ItemsCount::<Test>::get()[collection_id as usize] - 1;
+		let collection_id = OFFICESTEST;
+		let item_id = NftModule::next_item_id(collection_id) - 1;
 
-		assert_ok!(OnboardingModule::change_status(
-			Origin::signed(AMANI),
-			NftCollection::OFFICESTEST,
+		assert_ok!(Onboarding::Pallet::<Test>::move_asset(
+			collection_id,
 			item_id,
-			crate::Onboarding::AssetStatus::ONBOARDED
+			&[
+				AssetStatus::APPRAISING,
+				AssetStatus::REVIEWING,
+				AssetStatus::VOTING,
+				AssetStatus::ONBOARDED,
+			],
 		));
 
 		assert_ok!(BiddingModule::process_onboarded_assets());
@@ -592,27 +733,33 @@ fn process_onboarded_assets_cannot_assemble_investor_should_fail() {
 
 		assert_ok!(NftModule::create_collection(
 			Origin::signed(KEZIA),
-			NftCollection::OFFICESTEST,
+			OFFICESTEST,
+			CollectionClass::new(AssetClass::OFFICE, 3),
 			metadata.clone()
 		));
 
 		assert_ok!(OnboardingModule::create_and_submit_proposal(
 			Origin::signed(AMANI),
-			NftCollection::OFFICESTEST,
+			OFFICESTEST,
 			Some(100),
 			metadata,
 			false,
-			3
+			Some(3),
+			None
 		));
 
-		let collection_id = NftCollection::OFFICESTEST.value();
-		let item_id = pallet_nft::ItemsCount::<Test>::get()[collection_id as usize] - 1;
+		let collection_id = OFFICESTEST;
+		let item_id = NftModule::next_item_id(collection_id) - 1;
 
-		assert_ok!(OnboardingModule::change_status(
-			Origin::signed(AMANI),
-			NftCollection::OFFICESTEST,
+		assert_ok!(Onboarding::Pallet::<Test>::move_asset(
+			collection_id,
 			item_id,
-			crate::Onboarding::AssetStatus::ONBOARDED
+			&[
+				AssetStatus::APPRAISING,
+				AssetStatus::REVIEWING,
+				AssetStatus::VOTING,
+				AssetStatus::ONBOARDED,
+			],
 		));
 
 		assert_ok!(BiddingModule::process_onboarded_assets());
@@ -677,27 +824,33 @@ fn process_onboarded_assets_should_succeed() {
 
 		assert_ok!(NftModule::create_collection(
 			Origin::signed(KEZIA),
-			NftCollection::OFFICESTEST,
+			OFFICESTEST,
+			CollectionClass::new(AssetClass::OFFICE, 3),
 			metadata.clone()
 		));
 
 		assert_ok!(OnboardingModule::create_and_submit_proposal(
 			Origin::signed(AMANI),
-			NftCollection::OFFICESTEST,
+			OFFICESTEST,
 			Some(100),
 			metadata,
 			false,
-			3
+			Some(3),
+			None
 		));
 
-		let collection_id = NftCollection::OFFICESTEST.value();
-		let item_id = pallet_nft::ItemsCount::<Test>::get()[collection_id as usize] - 1;
+		let collection_id = OFFICESTEST;
+		let item_id = NftModule::next_item_id(collection_id) - 1;
 
-		assert_ok!(OnboardingModule::change_status(
-			Origin::signed(AMANI),
-			NftCollection::OFFICESTEST,
+		assert_ok!(Onboarding::Pallet::<Test>::move_asset(
+			collection_id,
 			item_id,
-			crate::Onboarding::AssetStatus::ONBOARDED
+			&[
+				AssetStatus::APPRAISING,
+				AssetStatus::REVIEWING,
+				AssetStatus::VOTING,
+				AssetStatus::ONBOARDED,
+			],
 		));
 
 		assert_ok!(BiddingModule::process_onboarded_assets());
@@ -722,6 +875,11 @@ fn process_onboarded_assets_should_succeed() {
 		let asset = pallet_onboarding::Houses::<Test>::get(collection_id, item_id).unwrap();
 
 		assert_eq!(asset.status, crate::Onboarding::AssetStatus::FINALISING);
+
+		// the selected investors are recorded
+		for account_id in 1..6 {
+			assert_eq!(RoleModule::investors(account_id).unwrap().selections, 1);
+		}
 	});
 }
 
@@ -835,27 +993,33 @@ fn process_finalised_assets_should_succeed() {
 
 		assert_ok!(NftModule::create_collection(
 			Origin::signed(KEZIA),
-			NftCollection::OFFICESTEST,
+			OFFICESTEST,
+			CollectionClass::new(AssetClass::OFFICE, 3),
 			metadata.clone()
 		));
 
 		assert_ok!(OnboardingModule::create_and_submit_proposal(
 			Origin::signed(AMANI),
-			NftCollection::OFFICESTEST,
+			OFFICESTEST,
 			Some(100),
 			metadata,
 			false,
-			3
+			Some(3),
+			None
 		));
 
-		let collection_id = NftCollection::OFFICESTEST.value();
-		let item_id = pallet_nft::ItemsCount::<Test>::get()[collection_id as usize] - 1;
+		let collection_id = OFFICESTEST;
+		let item_id = NftModule::next_item_id(collection_id) - 1;
 
-		assert_ok!(OnboardingModule::change_status(
-			Origin::signed(AMANI),
-			NftCollection::OFFICESTEST,
+		assert_ok!(Onboarding::Pallet::<Test>::move_asset(
+			collection_id,
 			item_id,
-			crate::Onboarding::AssetStatus::ONBOARDED
+			&[
+				AssetStatus::APPRAISING,
+				AssetStatus::REVIEWING,
+				AssetStatus::VOTING,
+				AssetStatus::ONBOARDED,
+			],
 		));
 
 		assert_ok!(BiddingModule::process_onboarded_assets());
@@ -877,11 +1041,11 @@ fn process_finalised_assets_should_succeed() {
 			))
 		);
 
-		assert_ok!(OnboardingModule::change_status(
-			Origin::signed(AMANI),
-			NftCollection::OFFICESTEST,
+		// the notary validates the transaction
+		assert_ok!(Onboarding::Pallet::<Test>::move_asset(
+			collection_id,
 			item_id,
-			crate::Onboarding::AssetStatus::FINALISED
+			&[AssetStatus::FINALISED]
 		));
 
 		let fees_account = Onboarding::Pallet::<Test>::account_id();
@@ -904,11 +1068,665 @@ fn process_finalised_assets_should_succeed() {
 			))
 		);
 
-		assert_ok!(OnboardingModule::change_status(
+		assert_eq!(
+			Onboarding::Houses::<Test>::get(collection_id, item_id).unwrap().status,
+			AssetStatus::PURCHASED
+		);
+	});
+}
+
+#[test]
+fn get_eligible_investors_contribution_with_preferences_should_succeed() {
+	new_test_ext().execute_with(|| {
+		let mut block_number = System::block_number();
+		let amount = 20;
+
+		for account_id in 1..7 {
+			assert_ok!(RoleModule::set_role(
+				Origin::signed(account_id),
+				account_id,
+				crate::Onboarding::HousingFund::ROLES::Accounts::INVESTOR
+			));
+
+			// test contribute with sufficient contribution and free balance
+			assert_ok!(HousingFund::contribute_to_fund(Origin::signed(account_id), amount));
+
+			block_number = block_number.saturating_add(1);
+			System::set_block_number(block_number);
+		}
+
+		// The first investor only invests in houses
+		assert_ok!(HousingFund::set_investor_preferences(
+			Origin::signed(1),
+			vec![HOUSESTEST].try_into().unwrap(),
+			None,
+			None,
+			Default::default()
+		));
+		// The second investor does not invest in assets above 50
+		assert_ok!(HousingFund::set_investor_preferences(
+			Origin::signed(2),
+			Default::default(),
+			Some(50),
+			None,
+			Default::default()
+		));
+		// The third investor does not invest more than 10 in an asset
+		assert_ok!(HousingFund::set_investor_preferences(
+			Origin::signed(3),
+			Default::default(),
+			None,
+			Some(10),
+			Default::default()
+		));
+		// The fourth investor preferences match the asset
+		assert_ok!(HousingFund::set_investor_preferences(
+			Origin::signed(4),
+			vec![OFFICESTEST].try_into().unwrap(),
+			Some(100),
+			None,
+			Default::default()
+		));
+
+		let list = BiddingModule::get_eligible_investors_contribution(OFFICESTEST, 0, 100);
+
+		assert_eq!(list, (70, vec![(3, 10, 10), (4, 20, 20), (5, 20, 20), (6, 20, 20),]));
+	});
+}
+
+#[test]
+fn process_onboarded_assets_with_excluded_seller_should_fail() {
+	new_test_ext().execute_with(|| {
+		let mut block_number = System::block_number();
+		let amount = 100;
+
+		for account_id in 1..6 {
+			assert_ok!(RoleModule::set_role(
+				Origin::signed(account_id),
+				account_id,
+				crate::Onboarding::HousingFund::ROLES::Accounts::INVESTOR
+			));
+
+			// test contribute with sufficient contribution and free balance
+			assert_ok!(HousingFund::contribute_to_fund(Origin::signed(account_id), amount));
+
+			block_number = block_number.saturating_add(1);
+			System::set_block_number(block_number);
+		}
+
+		// Without this investor, there are not enough investors to respect the maximum share
+		assert_ok!(HousingFund::set_investor_preferences(
+			Origin::signed(EVE),
+			Default::default(),
+			None,
+			None,
+			vec![AMANI].try_into().unwrap()
+		));
+
+		assert_ok!(RoleModule::set_role(
+			Origin::signed(KEZIA),
+			KEZIA,
+			crate::Onboarding::HousingFund::ROLES::Accounts::SERVICER
+		));
+		assert_ok!(RoleModule::account_approval(Origin::signed(ALICE), KEZIA));
+		assert_ok!(RoleModule::set_role(
 			Origin::signed(AMANI),
-			NftCollection::OFFICESTEST,
+			AMANI,
+			crate::Onboarding::HousingFund::ROLES::Accounts::SELLER
+		));
+		assert_ok!(RoleModule::account_approval(Origin::signed(ALICE), AMANI));
+
+		let metadata: BoundedVec<u8, <Test as pallet_uniques::Config>::StringLimit> =
+			b"metadata0".to_vec().try_into().unwrap();
+
+		assert_ok!(NftModule::create_collection(
+			Origin::signed(KEZIA),
+			OFFICESTEST,
+			CollectionClass::new(AssetClass::OFFICE, 3),
+			metadata.clone()
+		));
+
+		assert_ok!(OnboardingModule::create_and_submit_proposal(
+			Origin::signed(AMANI),
+			OFFICESTEST,
+			Some(100),
+			metadata,
+			false,
+			Some(3),
+			None
+		));
+
+		let collection_id = OFFICESTEST;
+		let item_id = NftModule::next_item_id(collection_id) - 1;
+
+		assert_ok!(Onboarding::Pallet::<Test>::move_asset(
+			collection_id,
 			item_id,
-			crate::Onboarding::AssetStatus::PURCHASED
+			&[
+				AssetStatus::APPRAISING,
+				AssetStatus::REVIEWING,
+				AssetStatus::VOTING,
+				AssetStatus::ONBOARDED,
+			],
 		));
+
+		assert_ok!(BiddingModule::process_onboarded_assets());
+
+		let event = <frame_system::Pallet<Test>>::events()
+			.pop()
+			.expect("Expected at least one EventRecord to be found")
+			.event;
+
+		// check that the event has been raised
+		assert_eq!(
+			event,
+			mock::Event::BiddingModule(crate::Event::FailedToAssembleInvestors(
+				collection_id,
+				item_id,
+				100,
+				block_number
+			))
+		);
+
+		// The asset is skipped and stays onboarded
+		let asset = pallet_onboarding::Houses::<Test>::get(collection_id, item_id).unwrap();
+		assert_eq!(asset.status, crate::Onboarding::AssetStatus::ONBOARDED);
+	});
+}
+
+fn contribute_to_fund(amount: u128) -> u64 {
+	let mut block_number = System::block_number();
+	for account_id in 1..6 {
+		assert_ok!(RoleModule::set_role(
+			Origin::signed(account_id),
+			account_id,
+			crate::Onboarding::HousingFund::ROLES::Accounts::INVESTOR
+		));
+		assert_ok!(HousingFund::contribute_to_fund(Origin::signed(account_id), amount));
+
+		block_number = block_number.saturating_add(1);
+		System::set_block_number(block_number);
+	}
+	block_number
+}
+
+fn prepare_seller() {
+	assert_ok!(RoleModule::set_role(
+		Origin::signed(KEZIA),
+		KEZIA,
+		crate::Onboarding::HousingFund::ROLES::Accounts::SERVICER
+	));
+	assert_ok!(RoleModule::account_approval(Origin::signed(ALICE), KEZIA));
+	assert_ok!(RoleModule::set_role(
+		Origin::signed(AMANI),
+		AMANI,
+		crate::Onboarding::HousingFund::ROLES::Accounts::SELLER
+	));
+	assert_ok!(RoleModule::account_approval(Origin::signed(ALICE), AMANI));
+
+	let metadata: BoundedVec<u8, <Test as pallet_uniques::Config>::StringLimit> =
+		b"metadata0".to_vec().try_into().unwrap();
+	assert_ok!(NftModule::create_collection(
+		Origin::signed(KEZIA),
+		OFFICESTEST,
+		CollectionClass::new(AssetClass::OFFICE, 3),
+		metadata
+	));
+}
+
+fn onboard_asset(price: u128) -> (CollectionId, ItemId) {
+	let metadata: BoundedVec<u8, <Test as pallet_uniques::Config>::StringLimit> =
+		b"metadata0".to_vec().try_into().unwrap();
+	assert_ok!(OnboardingModule::create_and_submit_proposal(
+		Origin::signed(AMANI),
+		OFFICESTEST,
+		Some(price),
+		metadata,
+		false,
+		Some(3),
+		None
+	));
+
+	let collection_id = OFFICESTEST;
+	let item_id = NftModule::next_item_id(collection_id) - 1;
+
+	assert_ok!(Onboarding::Pallet::<Test>::move_asset(
+		collection_id,
+		item_id,
+		&[
+			AssetStatus::APPRAISING,
+			AssetStatus::REVIEWING,
+			AssetStatus::VOTING,
+			AssetStatus::ONBOARDED,
+		],
+	));
+
+	(collection_id, item_id)
+}
+
+#[test]
+fn process_onboarded_assets_max_tries_assembling_investor_should_send_back_asset() {
+	new_test_ext().execute_with(|| {
+		let block_number = contribute_to_fund(100);
+		assert_ok!(HousingFund::withdraw_fund(Origin::signed(EVE), 90));
+		prepare_seller();
+		let (collection_id, item_id) = onboard_asset(100);
+
+		// the failed attempts are counted until MaxTriesAseemblingInvestor is reached
+		for tries in 1..3 {
+			assert_ok!(BiddingModule::process_onboarded_assets());
+			assert_eq!(
+				BiddingModule::bid_attempts((collection_id, item_id)),
+				BidAttempts { investor_assemblies: tries, bids: 0 }
+			);
+			let asset = pallet_onboarding::Houses::<Test>::get(collection_id, item_id).unwrap();
+			assert_eq!(asset.status, crate::Onboarding::AssetStatus::ONBOARDED);
+		}
+
+		assert_ok!(BiddingModule::process_onboarded_assets());
+
+		let event = <frame_system::Pallet<Test>>::events()
+			.pop()
+			.expect("Expected at least one EventRecord to be found")
+			.event;
+		assert_eq!(
+			event,
+			mock::Event::BiddingModule(crate::Event::AssetSentBackForRepricing(
+				collection_id,
+				item_id,
+				BidAttempts { investor_assemblies: 3, bids: 0 },
+				block_number
+			))
+		);
+
+		// the asset is sent back to the seller who can change its price
+		let asset = pallet_onboarding::Houses::<Test>::get(collection_id, item_id).unwrap();
+		assert_eq!(asset.status, crate::Onboarding::AssetStatus::REPRICING);
+		assert_eq!(BiddingModule::bid_attempts((collection_id, item_id)), BidAttempts::default());
+		assert_ok!(OnboardingModule::set_price(
+			Origin::signed(AMANI),
+			OFFICESTEST,
+			item_id,
+			Some(90)
+		));
+
+		// the asset is not processed anymore
+		assert_ok!(BiddingModule::process_onboarded_assets());
+		let event = <frame_system::Pallet<Test>>::events()
+			.pop()
+			.expect("Expected at least one EventRecord to be found")
+			.event;
+		assert_eq!(
+			event,
+			mock::Event::BiddingModule(crate::Event::NoHousesOnboardedFound(block_number))
+		);
+	});
+}
+
+#[test]
+fn max_tries_bid_should_send_back_asset() {
+	new_test_ext().execute_with(|| {
+		prepare_seller();
+		let (collection_id, item_id) = onboard_asset(100);
+
+		BiddingModule::register_failed_bid(collection_id, item_id);
+		BiddingModule::register_failed_bid(collection_id, item_id);
+		assert_eq!(
+			BiddingModule::bid_attempts((collection_id, item_id)),
+			BidAttempts { investor_assemblies: 0, bids: 2 }
+		);
+		let asset = pallet_onboarding::Houses::<Test>::get(collection_id, item_id).unwrap();
+		assert_eq!(asset.status, crate::Onboarding::AssetStatus::ONBOARDED);
+
+		BiddingModule::register_failed_bid(collection_id, item_id);
+		let asset = pallet_onboarding::Houses::<Test>::get(collection_id, item_id).unwrap();
+		assert_eq!(asset.status, crate::Onboarding::AssetStatus::REPRICING);
+		assert_eq!(BiddingModule::bid_attempts((collection_id, item_id)), BidAttempts::default());
+	});
+}
+
+#[test]
+fn process_onboarded_assets_simultaneous_bids_limit_should_succeed() {
+	new_test_ext().execute_with(|| {
+		let block_number = contribute_to_fund(100);
+		prepare_seller();
+		let first_asset = onboard_asset(100);
+		let second_asset = onboard_asset(100);
+
+		assert_ok!(BiddingModule::process_onboarded_assets());
+
+		let event = <frame_system::Pallet<Test>>::events()
+			.pop()
+			.expect("Expected at least one EventRecord to be found")
+			.event;
+		assert_eq!(
+			event,
+			mock::Event::BiddingModule(crate::Event::SimultaneousBidsLimitReached(block_number))
+		);
+
+		// only one asset is bid on, SimultaneousAssetBidder is 1
+		let statuses = vec![first_asset, second_asset]
+			.into_iter()
+			.map(|(collection_id, item_id)| {
+				pallet_onboarding::Houses::<Test>::get(collection_id, item_id).unwrap().status
+			})
+			.collect::<Vec<_>>();
+		assert!(statuses.contains(&crate::Onboarding::AssetStatus::FINALISING));
+		assert!(statuses.contains(&crate::Onboarding::AssetStatus::ONBOARDED));
+	});
+}
+
+#[test]
+fn simulate_bid_should_succeed() {
+	new_test_ext().execute_with(|| {
+		contribute_to_fund(100);
+		prepare_seller();
+		let (collection_id, item_id) = onboard_asset(100);
+
+		let storage_root = sp_io::storage::root(sp_runtime::StateVersion::V1);
+
+		// the price of the onboarded asset is used
+		let simulation = BiddingModule::simulate_bid(collection_id, item_id, None);
+		assert_eq!(simulation.amount, Some(100));
+		assert_eq!(simulation.failure, None);
+		assert_eq!(
+			simulation.investors,
+			(1..6)
+				.map(|account_id| (account_id, 20, Percent::from_percent(20)))
+				.collect::<Vec<_>>()
+		);
+
+		// a hypothetical price can be given
+		let simulation = BiddingModule::simulate_bid(collection_id, item_id, Some(200));
+		assert_eq!(simulation.amount, Some(200));
+		assert_eq!(simulation.failure, None);
+		assert_eq!(
+			simulation.investors,
+			(1..6)
+				.map(|account_id| (account_id, 40, Percent::from_percent(20)))
+				.collect::<Vec<_>>()
+		);
+
+		// nothing was changed by the simulations
+		assert_eq!(sp_io::storage::root(sp_runtime::StateVersion::V1), storage_root);
+		let asset = pallet_onboarding::Houses::<Test>::get(collection_id, item_id).unwrap();
+		assert_eq!(asset.status, crate::Onboarding::AssetStatus::ONBOARDED);
+	});
+}
+
+#[test]
+fn simulate_bid_should_report_failure() {
+	new_test_ext().execute_with(|| {
+		prepare_seller();
+
+		// the asset doesn't exist
+		let simulation = BiddingModule::simulate_bid(OFFICESTEST, 0, None);
+		assert_eq!(simulation.amount, None);
+		assert_eq!(simulation.failure, Some(BidFailure::UnknownAsset));
+
+		let (collection_id, item_id) = onboard_asset(100);
+
+		// the housing fund is empty
+		let simulation = BiddingModule::simulate_bid(collection_id, item_id, None);
+		assert_eq!(simulation.amount, Some(100));
+		assert_eq!(simulation.failure, Some(BidFailure::HousingFundNotEnough));
+		assert!(simulation.investors.is_empty());
+
+		// a single investor cannot buy the asset
+		assert_ok!(RoleModule::set_role(
+			Origin::signed(1),
+			1,
+			crate::Onboarding::HousingFund::ROLES::Accounts::INVESTOR
+		));
+		assert_ok!(HousingFund::contribute_to_fund(Origin::signed(1), 200));
+		let simulation = BiddingModule::simulate_bid(collection_id, item_id, None);
+		assert_eq!(simulation.failure, Some(BidFailure::FailedToAssembleInvestors));
+		assert!(simulation.investors.is_empty());
+	});
+}
+
+#[test]
+fn simulate_bid_simultaneous_bids_limit_should_report_failure() {
+	new_test_ext().execute_with(|| {
+		contribute_to_fund(100);
+		prepare_seller();
+		let first_asset = onboard_asset(100);
+		assert_ok!(BiddingModule::process_onboarded_assets());
+		let asset = pallet_onboarding::Houses::<Test>::get(first_asset.0, first_asset.1).unwrap();
+		assert_eq!(asset.status, crate::Onboarding::AssetStatus::FINALISING);
+
+		// the bid on the first asset is not over yet
+		let (collection_id, item_id) = onboard_asset(100);
+		let simulation = BiddingModule::simulate_bid(collection_id, item_id, None);
+		assert_eq!(simulation.failure, Some(BidFailure::SimultaneousBidsLimitReached));
+		assert_eq!(simulation.investors.len(), 5);
+
+		// the first asset is not onboarded anymore
+		let simulation = BiddingModule::simulate_bid(first_asset.0, first_asset.1, None);
+		assert_eq!(simulation.failure, Some(BidFailure::AssetNotOnboarded));
+	});
+}
+
+fn queued_assets() -> Vec<(CollectionId, ItemId, u8)> {
+	BiddingModule::asset_queue()
+		.into_iter()
+		.map(|asset| (asset.collection_id, asset.item_id, asset.priority))
+		.collect()
+}
+
+fn processed_assets() -> Vec<(CollectionId, ItemId)> {
+	let processed = <frame_system::Pallet<Test>>::events()
+		.into_iter()
+		.filter_map(|record| match record.event {
+			mock::Event::BiddingModule(crate::Event::ProcessingAsset(
+				collection_id,
+				item_id,
+				_,
+			)) => Some((collection_id, item_id)),
+			_ => None,
+		})
+		.collect();
+	System::reset_events();
+	processed
+}
+
+#[test]
+fn onboarded_assets_should_be_queued() {
+	new_test_ext().execute_with(|| {
+		prepare_seller();
+		let (collection_id, first_item) = onboard_asset(100);
+		let (_, second_item) = onboard_asset(100);
+		let (_, third_item) = onboard_asset(100);
+
+		assert_eq!(
+			queued_assets(),
+			vec![
+				(collection_id, first_item, 0),
+				(collection_id, second_item, 0),
+				(collection_id, third_item, 0)
+			]
+		);
+
+		// the council moves the last asset to the head of the queue
+		assert_noop!(
+			BiddingModule::set_asset_priority(Origin::signed(AMANI), collection_id, third_item, 1),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(BiddingModule::set_asset_priority(Origin::root(), collection_id, third_item, 1));
+		assert_eq!(
+			queued_assets(),
+			vec![
+				(collection_id, third_item, 1),
+				(collection_id, first_item, 0),
+				(collection_id, second_item, 0)
+			]
+		);
+
+		// an asset that is not onboarded anymore leaves the queue
+		assert_ok!(Onboarding::Pallet::<Test>::move_asset(
+			collection_id,
+			first_item,
+			&[AssetStatus::REPRICING]
+		));
+		assert_eq!(
+			queued_assets(),
+			vec![(collection_id, third_item, 1), (collection_id, second_item, 0)]
+		);
+		assert_noop!(
+			BiddingModule::set_asset_priority(Origin::root(), collection_id, first_item, 1),
+			Error::<Test>::AssetNotQueued
+		);
+	});
+}
+
+#[test]
+fn process_onboarded_assets_should_respect_budget_and_resume() {
+	new_test_ext().execute_with(|| {
+		prepare_seller();
+		let assets = (0..4).map(|_| onboard_asset(100)).collect::<Vec<_>>();
+		System::reset_events();
+
+		// the housing fund is empty, every asset stays in the queue
+		// no more than MaxAssetsProcessedPerBlock assets are processed at once
+		// each asset costs 50 reads and 45 writes without contributors
+		let info = BiddingModule::process_onboarded_assets().unwrap();
+		assert_eq!(info.actual_weight, Some(Weight::from_ref_time(3 * 500)));
+		assert_eq!(processed_assets(), assets[0..3].to_vec());
+
+		// the processing resumes after the last processed asset
+		let info = BiddingModule::process_onboarded_assets().unwrap();
+		assert_eq!(info.actual_weight, Some(Weight::from_ref_time(500)));
+		assert_eq!(processed_assets(), assets[3..4].to_vec());
+
+		// the next pass starts from the head of the queue, which follows the priorities
+		let (collection_id, item_id) = assets[2];
+		assert_ok!(BiddingModule::set_asset_priority(Origin::root(), collection_id, item_id, 1));
+		System::reset_events();
+		assert_ok!(BiddingModule::process_onboarded_assets());
+		assert_eq!(processed_assets(), vec![assets[2], assets[0], assets[1]]);
+	});
+}
+
+#[test]
+fn process_onboarded_assets_should_fit_in_the_remaining_block_weight() {
+	new_test_ext().execute_with(|| {
+		// the fund is not enough for the assets, which stay in the queue
+		contribute_to_fund(10);
+		prepare_seller();
+		let assets = (0..3).map(|_| onboard_asset(100)).collect::<Vec<_>>();
+		System::reset_events();
+
+		// the contributions of the 5 contributors are read for each asset
+		assert_eq!(BiddingModule::processing_weight(1, 5), Weight::from_ref_time(520));
+
+		// the remaining weight of the block only covers two assets
+		let max_block = <Test as frame_system::Config>::BlockWeights::get().max_block;
+		System::register_extra_weight_unchecked(
+			max_block.saturating_sub(Weight::from_ref_time(3 * 520 - 1)),
+			DispatchClass::Mandatory,
+		);
+		let info = BiddingModule::process_onboarded_assets().unwrap();
+		assert_eq!(info.actual_weight, Some(Weight::from_ref_time(2 * 520)));
+		assert_eq!(processed_assets(), assets[0..2].to_vec());
+
+		// the next block resumes with the last asset
+		System::initialize(&(System::block_number() + 1), &Default::default(), &Default::default());
+		assert_ok!(BiddingModule::process_onboarded_assets());
+		assert_eq!(processed_assets(), assets[2..3].to_vec());
+	});
+}
+
+#[test]
+fn onboarded_assets_should_not_be_processed_while_house_biddings_are_paused() {
+	new_test_ext().execute_with(|| {
+		prepare_seller();
+		let assets = (0..2).map(|_| onboard_asset(100)).collect::<Vec<_>>();
+		System::reset_events();
+
+		assert_ok!(HousingFund::set_operation_paused(
+			Origin::root(),
+			crate::Housing_Fund::PausableOperation::HouseBidding,
+			true
+		));
+		let block_number = System::block_number();
+		assert_ok!(BiddingModule::process_onboarded_assets());
+		assert_eq!(
+			System::events().last().unwrap().event,
+			mock::Event::BiddingModule(crate::Event::HouseBiddingPaused(block_number))
+		);
+		assert!(processed_assets().is_empty());
+		assert_eq!(queued_assets().len(), 2);
+		assert!(BiddingModule::bidding_paused());
+
+		// the pause is only reported once
+		System::reset_events();
+		assert_ok!(BiddingModule::process_onboarded_assets());
+		assert!(System::events().is_empty());
+
+		// the processing starts over once the house biddings are resumed
+		assert_ok!(HousingFund::set_operation_paused(
+			Origin::root(),
+			crate::Housing_Fund::PausableOperation::HouseBidding,
+			false
+		));
+		System::reset_events();
+		assert_ok!(BiddingModule::process_onboarded_assets());
+		assert_eq!(processed_assets(), assets);
+		assert!(!BiddingModule::bidding_paused());
+	});
+}
+
+#[test]
+fn assets_onboarded_while_the_queue_is_full_should_be_queued_later() {
+	new_test_ext().execute_with(|| {
+		prepare_seller();
+		let assets = (0..5).map(|_| onboard_asset(100)).collect::<Vec<_>>();
+		assert!(!BiddingModule::queue_overflow());
+
+		// the queue holds at most MaxQueuedAssets assets
+		let (collection_id, item_id) = onboard_asset(100);
+		let block_number = System::block_number();
+		System::assert_has_event(mock::Event::BiddingModule(crate::Event::AssetQueueFull(
+			collection_id,
+			item_id,
+			block_number,
+		)));
+		assert!(BiddingModule::queue_overflow());
+		assert_eq!(queued_assets().len(), 5);
+
+		// the asset is queued once the queue has room for it
+		assert_ok!(Onboarding::Pallet::<Test>::move_asset(
+			assets[0].0,
+			assets[0].1,
+			&[AssetStatus::REPRICING]
+		));
+		assert_ok!(BiddingModule::process_onboarded_assets());
+		assert!(!BiddingModule::queue_overflow());
+		assert_eq!(queued_assets().last(), Some(&(collection_id, item_id, 0)));
+	});
+}
+
+#[test]
+fn migration_should_queue_the_onboarded_assets() {
+	new_test_ext().execute_with(|| {
+		use frame_support::traits::{GetStorageVersion, StorageVersion};
+
+		prepare_seller();
+		let assets = (0..3).map(|_| onboard_asset(100)).collect::<Vec<_>>();
+		// the assets were onboarded before the bidding queue existed
+		AssetQueue::<Test>::kill();
+		StorageVersion::new(0).put::<BiddingModule>();
+
+		crate::migrations::migrate::<Test>();
+		crate::migrations::migrate::<Test>();
+
+		assert_eq!(
+			queued_assets(),
+			assets
+				.into_iter()
+				.map(|(collection_id, item_id)| (collection_id, item_id, 0))
+				.collect::<Vec<_>>()
+		);
+		assert_eq!(BiddingModule::on_chain_storage_version(), 1);
 	});
 }
@@ -12,7 +12,7 @@
 // 	validate_transaction_asset {
 // 		let s in 0 .. 100;
 // 		let caller: T::AccountId = whitelisted_caller();
-// 		let collection = pallet_nft::PossibleCollections::OFFICESTEST.value();
+// 		let collection: u32 = 5;
 // 		let item_id: u32 = 1;
 // 	}: _(RawOrigin::Signed(caller), collection.into(), item_id.into())
 // 	verify {
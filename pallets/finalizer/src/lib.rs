@@ -7,6 +7,8 @@
 //!
 //! The finalizer pallet provides methods to the notary to validate or reject house purchase
 //! and to the seller to cancel a purchase
+//! The NFT of a house is locked while its purchase is checked by the notary, from the FINALISING
+//! status to its validation or rejection
 //!
 //! ## Interface
 //!
@@ -103,7 +105,7 @@ pub mod pallet {
 			collection_id: T::NftCollectionId,
 			nft_item_id: T::NftItemId,
 		) -> DispatchResult {
-			let who = ensure_signed(origin.clone())?;
+			let who = ensure_signed(origin)?;
 
 			// Check that the account has the notary role
 			ensure!(Roles::Pallet::<T>::notaries(who.clone()).is_some(), Error::<T>::NotANotary);
@@ -118,15 +120,12 @@ pub mod pallet {
 				Error::<T>::HouseHasNotFinalisingStatus
 			);
 
-			let collection = Self::get_possible_collection(collection_id);
-
-			Onboarding::Pallet::<T>::change_status(
-				origin,
-				collection,
+			Onboarding::Pallet::<T>::do_change_status(
+				collection_id,
 				nft_item_id,
 				Onboarding::AssetStatus::FINALISED,
-			)
-			.ok();
+				Onboarding::TransitionOrigin::Notary,
+			)?;
 
 			Self::deposit_event(Event::NotaryValidatedAssetTransaction(
 				who,
@@ -147,7 +146,7 @@ pub mod pallet {
 			collection_id: T::NftCollectionId,
 			nft_item_id: T::NftItemId,
 		) -> DispatchResult {
-			let who = ensure_signed(origin.clone())?;
+			let who = ensure_signed(origin)?;
 
 			// Check that the account has the notary role
 			ensure!(Roles::Pallet::<T>::notaries(who.clone()).is_some(), Error::<T>::NotANotary);
@@ -162,15 +161,12 @@ pub mod pallet {
 				Error::<T>::HouseHasNotFinalisingStatus
 			);
 
-			let collection = Self::get_possible_collection(collection_id);
-
-			Onboarding::Pallet::<T>::change_status(
-				origin,
-				collection,
+			Onboarding::Pallet::<T>::do_change_status(
+				collection_id,
 				nft_item_id,
 				Onboarding::AssetStatus::REJECTED,
-			)
-			.ok();
+				Onboarding::TransitionOrigin::Notary,
+			)?;
 
 			HousingFund::Pallet::<T>::cancel_house_bidding(collection_id, nft_item_id).ok();
 
@@ -193,7 +189,7 @@ pub mod pallet {
 			collection_id: T::NftCollectionId,
 			nft_item_id: T::NftItemId,
 		) -> DispatchResult {
-			let who = ensure_signed(origin.clone())?;
+			let who = ensure_signed(origin)?;
 
 			// Check that the account has the notary role
 			ensure!(Roles::Pallet::<T>::sellers(who.clone()).is_some(), Error::<T>::NotASeller);
@@ -213,15 +209,12 @@ pub mod pallet {
 				Error::<T>::HouseHasNotFinalisedStatus
 			);
 
-			let collection = Self::get_possible_collection(collection_id);
-
-			Onboarding::Pallet::<T>::change_status(
-				origin,
-				collection,
+			Onboarding::Pallet::<T>::do_change_status(
+				collection_id,
 				nft_item_id,
 				Onboarding::AssetStatus::CANCELLED,
-			)
-			.ok();
+				Onboarding::TransitionOrigin::Seller,
+			)?;
 
 			HousingFund::Pallet::<T>::cancel_house_bidding(collection_id, nft_item_id).ok();
 
@@ -236,19 +229,17 @@ pub mod pallet {
 	}
 }
 
-use enum_iterator::all;
-pub use frame_support::inherent::Vec;
-impl<T: Config> Pallet<T> {
-	fn get_possible_collection(collection_id: T::NftCollectionId) -> Nft::PossibleCollections {
-		let collections = all::<Nft::PossibleCollections>().collect::<Vec<_>>();
-		let mut possible_collection = Nft::PossibleCollections::HOUSES;
-		for item in collections.iter() {
-			let value: T::NftCollectionId = item.value().into();
-			if value == collection_id {
-				possible_collection = *item;
-				break
-			}
+// The NFT is locked while the purchase of the asset is checked by a notary
+impl<T: Config> Onboarding::OnAssetStatusChange<T> for Pallet<T> {
+	fn on_status_change(
+		collection_id: T::NftCollectionId,
+		item_id: T::NftItemId,
+		status: Onboarding::AssetStatus,
+	) -> frame_support::dispatch::DispatchResult {
+		if status == Onboarding::AssetStatus::FINALISING {
+			Nft::Pallet::<T>::set_lock(collection_id, item_id, Nft::LockReason::Finalising)
+		} else {
+			Nft::Pallet::<T>::remove_lock(collection_id, item_id, Nft::LockReason::Finalising)
 		}
-		possible_collection
 	}
 }
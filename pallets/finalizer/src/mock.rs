@@ -2,12 +2,14 @@ use super::*;
 use crate as pallet_finalizer;
 use frame_support::{
 	parameter_types,
-	traits::{AsEnsureOriginWithArg, ConstU16, ConstU32, ConstU64, EqualPrivilegeOnly},
+	traits::{
+		AsEnsureOriginWithArg, ConstU16, ConstU32, ConstU64, EitherOfDiverse, EqualPrivilegeOnly,
+	},
 	weights::Weight,
 	PalletId,
 };
 
-use crate::Nft::NftPermissions;
+pub use crate::Nft::{AssetClass, CollectionClass, NftPermissions};
 use frame_system::{EnsureRoot, EnsureSigned};
 use pallet_collective::{Instance1, PrimeDefaultVote};
 use pallet_roles::GenesisBuild;
@@ -27,7 +29,7 @@ pub type ItemId = u32;
 type CouncilCollective = pallet_collective::Instance1;
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
 type Block = frame_system::mocking::MockBlock<Test>;
-pub type NftCollection = pallet_nft::PossibleCollections;
+pub const OFFICESTEST: CollectionId = 5;
 
 // Configure a mock runtime to test the pallet.
 frame_support::construct_runtime!(
@@ -43,10 +45,11 @@ frame_support::construct_runtime!(
 		NftModule: pallet_nft::{Pallet, Call, Storage, Event<T>},
 		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
 		HousingFundModule: pallet_housing_fund::{Pallet, Call, Storage,Event<T>},
+		FundAssets: pallet_assets::<Instance1>::{Pallet, Storage, Event<T>},
 		Scheduler: pallet_scheduler::{Pallet, Call, Storage, Event<T>},
 		Collective: pallet_collective::<Instance1>::{Pallet, Call, Event<T>, Origin<T>, Config<T>},
 		Democracy: pallet_democracy::{Pallet, Call, Storage, Config<T>, Event<T>},
-		VotingModule: pallet_voting::{Pallet, Call, Storage, Event<T>},
+		VotingModule: pallet_voting::{Pallet, Call, Storage, Event<T>, Origin<T>},
 		OnboardingModule: pallet_onboarding::{Pallet, Call, Storage, Event<T>},
 		Assets: pallet_assets::{Pallet, Storage, Config<T>, Event<T>},
 		ShareDistributor: pallet_share_distributor::{Pallet, Call, Storage, Event<T>},
@@ -134,6 +137,8 @@ impl pallet_uniques::Config for Test {
 
 parameter_types! {
 	pub ReserveCollectionIdUpTo: u32 = 3;
+	pub const MaxMetadataRevisions: u32 = 3;
+	pub const MaxAttributesPerItem: u32 = 5;
 }
 impl pallet_nft::Config for Test {
 	type Event = Event;
@@ -143,6 +148,10 @@ impl pallet_nft::Config for Test {
 	type ProtocolOrigin = EnsureRoot<AccountId>;
 	type Permissions = NftPermissions;
 	type ReserveCollectionIdUpTo = ReserveCollectionIdUpTo;
+	type ItemStages = ();
+	type TokenFreezer = ShareDistributor;
+	type MaxMetadataRevisions = MaxMetadataRevisions;
+	type MaxAttributesPerItem = MaxAttributesPerItem;
 }
 
 impl pallet_balances::Config for Test {
@@ -162,6 +171,8 @@ parameter_types! {
 	pub const FundThreshold: u64 = 100;
 	pub const MaxFundContribution: u64 = 20;
 	pub const MaxInvestorPerHouse: u32 = 10;
+	pub const MaxExcludedSellers: u32 = 5;
+	pub const MaxPreferredCollections: u32 = 10;
 	pub const HousingFundPalletId: PalletId = PalletId(*b"housfund");
 }
 
@@ -174,6 +185,38 @@ impl pallet_housing_fund::Config for Test {
 	type WeightInfo = ();
 	type PalletId = HousingFundPalletId;
 	type MaxInvestorPerHouse = MaxInvestorPerHouse;
+	type MaxExcludedSellers = MaxExcludedSellers;
+	type MaxPreferredCollections = MaxPreferredCollections;
+	type FundAssets = FundAssets;
+	type FundAssetId = FundAssetId;
+	type MaxWithdrawalsProcessedPerBlock = MaxWithdrawalsProcessedPerBlock;
+	type MaxQueuedWithdrawals = MaxQueuedWithdrawals;
+	type BlocksPerMonth = BlocksPerMonth;
+	type PauseOrigin = EnsureRoot<AccountId>;
+}
+
+parameter_types! {
+	pub const FundAssetId: u32 = 0;
+	pub const MaxWithdrawalsProcessedPerBlock: u32 = 2;
+	pub const MaxQueuedWithdrawals: u32 = 10;
+	pub const BlocksPerMonth: u64 = 10;
+}
+
+impl pallet_assets::Config<pallet_assets::Instance1> for Test {
+	type Event = Event;
+	type Balance = Balance;
+	type AssetId = u32;
+	type Currency = Balances;
+	type ForceOrigin = EnsureRoot<AccountId>;
+	type AssetDeposit = ();
+	type AssetAccountDeposit = ();
+	type MetadataDepositBase = ();
+	type MetadataDepositPerByte = ();
+	type ApprovalDeposit = ();
+	type StringLimit = frame_support::traits::ConstU32<50>;
+	type Freezer = HousingFundModule;
+	type Extra = ();
+	type WeightInfo = ();
 }
 
 parameter_types! {
@@ -269,11 +312,18 @@ impl pallet_voting::Config for Test {
 		pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 1, 2>;
 	type MinimumDepositVote = MinimumDeposit;
 	type CheckPeriod = CheckPeriod;
+	type VotingOrigin = Origin;
 }
 
 parameter_types! {
 	pub const ProposalFee:Percent = Percent::from_percent(5);
 	pub const SlashedFee: Percent = Percent::from_percent(10);
+	pub const SaleFee: Percent = Percent::from_percent(20);
+	pub const FundFeeShare: Percent = Percent::from_percent(0);
+	pub const AppraisalBand: Percent = Percent::from_percent(10);
+	pub const MaxAppraisalFee: Percent = Percent::from_percent(1);
+	pub const CounterOfferPeriod: BlockNumber = 10;
+	pub const ExpiryScanLimit: u32 = 2;
 	pub const FeesAccount: PalletId = PalletId(*b"feeslash");
 }
 
@@ -283,8 +333,22 @@ impl pallet_onboarding::Config for Test {
 	type Prop = Call;
 	type ProposalFee = ProposalFee;
 	type Slash = SlashedFee;
+	type SaleFee = SaleFee;
+	type FundFeeShare = FundFeeShare;
+	type MaxInspectionReports = frame_support::traits::ConstU32<5>;
+	type AppraisalBand = AppraisalBand;
+	type MaxAppraisalFee = MaxAppraisalFee;
+	type CounterOfferPeriod = CounterOfferPeriod;
+	type MaxCounterOffers = frame_support::traits::ConstU32<10>;
+	type ExpiryScanLimit = ExpiryScanLimit;
 	type WeightInfo = ();
 	type FeesAccount = FeesAccount;
+	type OnStatusChange = (BiddingModule, FinalizerModule);
+	type MaxStatusHistory = frame_support::traits::ConstU32<20>;
+	type CouncilOrigin =
+		EitherOfDiverse<EnsureRoot<AccountId>, pallet_voting::EnsureCouncilDecision<Test>>;
+	type DemocracyOrigin =
+		EitherOfDiverse<EnsureRoot<AccountId>, pallet_voting::EnsureInvestorsDecision<Test>>;
 }
 
 parameter_types! {
@@ -330,6 +394,8 @@ parameter_types! {
 	pub const MaximumSharePerInvestor: u64 = 20;
 	pub const MinimumSharePerInvestor: u64 = 10;
 	pub const NewAssetScanPeriod: u64 = 20;
+	pub const MaxAssetsProcessedPerBlock: u32 = 3;
+	pub const MaxQueuedAssets: u32 = 5;
 }
 
 impl pallet_bidding::Config for Test {
@@ -341,7 +407,11 @@ impl pallet_bidding::Config for Test {
 	type MaxTriesAseemblingInvestor = MaxTriesAseemblingInvestor;
 	type MaximumSharePerInvestor = MaximumSharePerInvestor;
 	type MinimumSharePerInvestor = MinimumSharePerInvestor;
+	type SelectionStrategy = pallet_bidding::OldestFirst;
 	type NewAssetScanPeriod = NewAssetScanPeriod;
+	type MaxAssetsProcessedPerBlock = MaxAssetsProcessedPerBlock;
+	type MaxQueuedAssets = MaxQueuedAssets;
+	type PriorityOrigin = EnsureRoot<AccountId>;
 }
 
 impl pallet_finalizer::Config for Test {
@@ -1,6 +1,7 @@
 use super::*;
 use crate::{mock::*, Error};
 use frame_support::{assert_noop, assert_ok, BoundedVec};
+use Onboarding::AssetStatus;
 
 #[test]
 fn validate_transaction_asset_no_notary_role_should_fail() {
@@ -24,21 +25,23 @@ fn validate_transaction_asset_no_notary_role_should_fail() {
 
 		assert_ok!(NftModule::create_collection(
 			Origin::signed(KEZIA),
-			NftCollection::OFFICESTEST,
+			OFFICESTEST,
+			CollectionClass::new(AssetClass::OFFICE, 3),
 			metadata.clone()
 		));
 
 		assert_ok!(OnboardingModule::create_and_submit_proposal(
 			Origin::signed(AMANI),
-			NftCollection::OFFICESTEST,
+			OFFICESTEST,
 			Some(100),
 			metadata,
 			false,
-			3
+			Some(3),
+			None
 		));
 
-		let collection_id = NftCollection::OFFICESTEST.value();
-		let item_id = pallet_nft::ItemsCount::<Test>::get()[collection_id as usize] - 1;
+		let collection_id = OFFICESTEST;
+		let item_id = NftModule::next_item_id(collection_id) - 1;
 
 		assert_noop!(
 			FinalizerModule::validate_transaction_asset(
@@ -68,7 +71,7 @@ fn validate_transaction_asset_no_existing_house_should_fail() {
 		));
 		assert_ok!(RoleModule::account_approval(Origin::signed(ALICE), AMANI));
 
-		let collection_id = NftCollection::OFFICESTEST.value();
+		let collection_id = OFFICESTEST;
 
 		assert_noop!(
 			FinalizerModule::validate_transaction_asset(Origin::signed(DAN), collection_id, 1,),
@@ -106,21 +109,23 @@ fn validate_transaction_asset_no_finalising_status_should_fail() {
 
 		assert_ok!(NftModule::create_collection(
 			Origin::signed(KEZIA),
-			NftCollection::OFFICESTEST,
+			OFFICESTEST,
+			CollectionClass::new(AssetClass::OFFICE, 3),
 			metadata.clone()
 		));
 
 		assert_ok!(OnboardingModule::create_and_submit_proposal(
 			Origin::signed(AMANI),
-			NftCollection::OFFICESTEST,
+			OFFICESTEST,
 			Some(100),
 			metadata,
 			false,
-			3
+			Some(3),
+			None
 		));
 
-		let collection_id = NftCollection::OFFICESTEST.value();
-		let item_id = pallet_nft::ItemsCount::<Test>::get()[collection_id as usize] - 1;
+		let collection_id = OFFICESTEST;
+		let item_id = NftModule::next_item_id(collection_id) - 1;
 
 		assert_noop!(
 			FinalizerModule::validate_transaction_asset(
@@ -162,27 +167,34 @@ fn validate_transaction_asset_should_succeed() {
 
 		assert_ok!(NftModule::create_collection(
 			Origin::signed(KEZIA),
-			NftCollection::OFFICESTEST,
+			OFFICESTEST,
+			CollectionClass::new(AssetClass::OFFICE, 3),
 			metadata.clone()
 		));
 
 		assert_ok!(OnboardingModule::create_and_submit_proposal(
 			Origin::signed(AMANI),
-			NftCollection::OFFICESTEST,
+			OFFICESTEST,
 			Some(100),
 			metadata,
 			false,
-			3
+			Some(3),
+			None
 		));
 
-		let collection_id = NftCollection::OFFICESTEST.value();
-		let item_id = pallet_nft::ItemsCount::<Test>::get()[collection_id as usize] - 1;
+		let collection_id = OFFICESTEST;
+		let item_id = NftModule::next_item_id(collection_id) - 1;
 
-		assert_ok!(OnboardingModule::change_status(
-			Origin::signed(AMANI),
-			NftCollection::OFFICESTEST,
+		assert_ok!(Onboarding::Pallet::<Test>::move_asset(
+			collection_id,
 			item_id,
-			crate::Onboarding::AssetStatus::FINALISING
+			&[
+				AssetStatus::APPRAISING,
+				AssetStatus::REVIEWING,
+				AssetStatus::VOTING,
+				AssetStatus::ONBOARDED,
+				AssetStatus::FINALISING,
+			],
 		));
 
 		assert_ok!(FinalizerModule::validate_transaction_asset(
@@ -233,21 +245,23 @@ fn reject_transaction_asset_no_notary_role_should_fail() {
 
 		assert_ok!(NftModule::create_collection(
 			Origin::signed(KEZIA),
-			NftCollection::OFFICESTEST,
+			OFFICESTEST,
+			CollectionClass::new(AssetClass::OFFICE, 3),
 			metadata.clone()
 		));
 
 		assert_ok!(OnboardingModule::create_and_submit_proposal(
 			Origin::signed(AMANI),
-			NftCollection::OFFICESTEST,
+			OFFICESTEST,
 			Some(100),
 			metadata,
 			false,
-			3
+			Some(3),
+			None
 		));
 
-		let collection_id = NftCollection::OFFICESTEST.value();
-		let item_id = pallet_nft::ItemsCount::<Test>::get()[collection_id as usize] - 1;
+		let collection_id = OFFICESTEST;
+		let item_id = NftModule::next_item_id(collection_id) - 1;
 
 		assert_noop!(
 			FinalizerModule::reject_transaction_asset(
@@ -277,7 +291,7 @@ fn reject_transaction_asset_no_existing_house_should_fail() {
 		));
 		assert_ok!(RoleModule::account_approval(Origin::signed(ALICE), AMANI));
 
-		let collection_id = NftCollection::OFFICESTEST.value();
+		let collection_id = OFFICESTEST;
 
 		assert_noop!(
 			FinalizerModule::reject_transaction_asset(Origin::signed(DAN), collection_id, 1,),
@@ -315,21 +329,23 @@ fn reject_transaction_asset_no_finalising_status_should_fail() {
 
 		assert_ok!(NftModule::create_collection(
 			Origin::signed(KEZIA),
-			NftCollection::OFFICESTEST,
+			OFFICESTEST,
+			CollectionClass::new(AssetClass::OFFICE, 3),
 			metadata.clone()
 		));
 
 		assert_ok!(OnboardingModule::create_and_submit_proposal(
 			Origin::signed(AMANI),
-			NftCollection::OFFICESTEST,
+			OFFICESTEST,
 			Some(100),
 			metadata,
 			false,
-			3
+			Some(3),
+			None
 		));
 
-		let collection_id = NftCollection::OFFICESTEST.value();
-		let item_id = pallet_nft::ItemsCount::<Test>::get()[collection_id as usize] - 1;
+		let collection_id = OFFICESTEST;
+		let item_id = NftModule::next_item_id(collection_id) - 1;
 
 		assert_noop!(
 			FinalizerModule::reject_transaction_asset(Origin::signed(DAN), collection_id, item_id,),
@@ -380,27 +396,33 @@ fn reject_transaction_asset_should_succeed() {
 
 		assert_ok!(NftModule::create_collection(
 			Origin::signed(KEZIA),
-			NftCollection::OFFICESTEST,
+			OFFICESTEST,
+			CollectionClass::new(AssetClass::OFFICE, 3),
 			metadata.clone()
 		));
 
 		assert_ok!(OnboardingModule::create_and_submit_proposal(
 			Origin::signed(AMANI),
-			NftCollection::OFFICESTEST,
+			OFFICESTEST,
 			Some(100),
 			metadata,
 			false,
-			3
+			Some(3),
+			None
 		));
 
-		let collection_id = NftCollection::OFFICESTEST.value();
-		let item_id = pallet_nft::ItemsCount::<Test>::get()[collection_id as usize] - 1;
+		let collection_id = OFFICESTEST;
+		let item_id = NftModule::next_item_id(collection_id) - 1;
 
-		assert_ok!(OnboardingModule::change_status(
-			Origin::signed(AMANI),
-			NftCollection::OFFICESTEST,
+		assert_ok!(Onboarding::Pallet::<Test>::move_asset(
+			collection_id,
 			item_id,
-			crate::Onboarding::AssetStatus::ONBOARDED
+			&[
+				AssetStatus::APPRAISING,
+				AssetStatus::REVIEWING,
+				AssetStatus::VOTING,
+				AssetStatus::ONBOARDED,
+			],
 		));
 
 		assert_ok!(BiddingModule::process_onboarded_assets());
@@ -474,21 +496,23 @@ fn cancel_transaction_asset_no_seller_role_should_fail() {
 
 		assert_ok!(NftModule::create_collection(
 			Origin::signed(KEZIA),
-			NftCollection::OFFICESTEST,
+			OFFICESTEST,
+			CollectionClass::new(AssetClass::OFFICE, 3),
 			metadata.clone()
 		));
 
 		assert_ok!(OnboardingModule::create_and_submit_proposal(
 			Origin::signed(AMANI),
-			NftCollection::OFFICESTEST,
+			OFFICESTEST,
 			Some(100),
 			metadata,
 			false,
-			3
+			Some(3),
+			None
 		));
 
-		let collection_id = NftCollection::OFFICESTEST.value();
-		let item_id = pallet_nft::ItemsCount::<Test>::get()[collection_id as usize] - 1;
+		let collection_id = OFFICESTEST;
+		let item_id = NftModule::next_item_id(collection_id) - 1;
 
 		assert_noop!(
 			FinalizerModule::cancel_transaction_asset(
@@ -511,7 +535,7 @@ fn cancel_transaction_asset_no_existing_house_should_fail() {
 		));
 		assert_ok!(RoleModule::account_approval(Origin::signed(ALICE), AMANI));
 
-		let collection_id = NftCollection::OFFICESTEST.value();
+		let collection_id = OFFICESTEST;
 
 		assert_noop!(
 			FinalizerModule::cancel_transaction_asset(Origin::signed(AMANI), collection_id, 1,),
@@ -542,21 +566,23 @@ fn cancel_transaction_asset_no_finalised_status_should_fail() {
 
 		assert_ok!(NftModule::create_collection(
 			Origin::signed(KEZIA),
-			NftCollection::OFFICESTEST,
+			OFFICESTEST,
+			CollectionClass::new(AssetClass::OFFICE, 3),
 			metadata.clone()
 		));
 
 		assert_ok!(OnboardingModule::create_and_submit_proposal(
 			Origin::signed(AMANI),
-			NftCollection::OFFICESTEST,
+			OFFICESTEST,
 			Some(100),
 			metadata,
 			false,
-			3
+			Some(3),
+			None
 		));
 
-		let collection_id = NftCollection::OFFICESTEST.value();
-		let item_id = pallet_nft::ItemsCount::<Test>::get()[collection_id as usize] - 1;
+		let collection_id = OFFICESTEST;
+		let item_id = NftModule::next_item_id(collection_id) - 1;
 
 		assert_noop!(
 			FinalizerModule::cancel_transaction_asset(
@@ -611,27 +637,33 @@ fn cancel_transaction_asset_should_succeed() {
 
 		assert_ok!(NftModule::create_collection(
 			Origin::signed(KEZIA),
-			NftCollection::OFFICESTEST,
+			OFFICESTEST,
+			CollectionClass::new(AssetClass::OFFICE, 3),
 			metadata.clone()
 		));
 
 		assert_ok!(OnboardingModule::create_and_submit_proposal(
 			Origin::signed(AMANI),
-			NftCollection::OFFICESTEST,
+			OFFICESTEST,
 			Some(100),
 			metadata,
 			false,
-			3
+			Some(3),
+			None
 		));
 
-		let collection_id = NftCollection::OFFICESTEST.value();
-		let item_id = pallet_nft::ItemsCount::<Test>::get()[collection_id as usize] - 1;
+		let collection_id = OFFICESTEST;
+		let item_id = NftModule::next_item_id(collection_id) - 1;
 
-		assert_ok!(OnboardingModule::change_status(
-			Origin::signed(AMANI),
-			NftCollection::OFFICESTEST,
+		assert_ok!(Onboarding::Pallet::<Test>::move_asset(
+			collection_id,
 			item_id,
-			crate::Onboarding::AssetStatus::ONBOARDED
+			&[
+				AssetStatus::APPRAISING,
+				AssetStatus::REVIEWING,
+				AssetStatus::VOTING,
+				AssetStatus::ONBOARDED,
+			],
 		));
 
 		assert_ok!(BiddingModule::process_onboarded_assets());
@@ -688,3 +720,87 @@ fn cancel_transaction_asset_should_succeed() {
 		);
 	});
 }
+
+#[test]
+fn finalising_asset_should_be_locked_until_the_notary_decision() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(RoleModule::set_role(
+			Origin::signed(KEZIA),
+			KEZIA,
+			crate::Onboarding::HousingFund::ROLES::Accounts::SERVICER
+		));
+		assert_ok!(RoleModule::account_approval(Origin::signed(ALICE), KEZIA));
+		assert_ok!(RoleModule::set_role(
+			Origin::signed(DAN),
+			DAN,
+			crate::Onboarding::HousingFund::ROLES::Accounts::NOTARY
+		));
+		assert_ok!(RoleModule::account_approval(Origin::signed(ALICE), DAN));
+		assert_ok!(RoleModule::set_role(
+			Origin::signed(AMANI),
+			AMANI,
+			crate::Onboarding::HousingFund::ROLES::Accounts::SELLER
+		));
+		assert_ok!(RoleModule::account_approval(Origin::signed(ALICE), AMANI));
+
+		let metadata: BoundedVec<u8, <Test as pallet_uniques::Config>::StringLimit> =
+			b"metadata0".to_vec().try_into().unwrap();
+		assert_ok!(NftModule::create_collection(
+			Origin::signed(KEZIA),
+			OFFICESTEST,
+			CollectionClass::new(AssetClass::OFFICE, 3),
+			metadata.clone()
+		));
+
+		let collection_id = OFFICESTEST;
+		let mut items = vec![];
+		for _ in 0..2 {
+			assert_ok!(OnboardingModule::create_and_submit_proposal(
+				Origin::signed(AMANI),
+				OFFICESTEST,
+				Some(100),
+				metadata.clone(),
+				false,
+				Some(3),
+				None
+			));
+			let item_id = NftModule::next_item_id(collection_id) - 1;
+			assert_ok!(Onboarding::Pallet::<Test>::move_asset(
+				collection_id,
+				item_id,
+				&[
+					AssetStatus::APPRAISING,
+					AssetStatus::REVIEWING,
+					AssetStatus::VOTING,
+					AssetStatus::ONBOARDED,
+					AssetStatus::FINALISING,
+				],
+			));
+			// the onboarding lock is replaced by the finalizer lock
+			assert_eq!(
+				NftModule::locks(collection_id, item_id),
+				vec![crate::Nft::LockReason::Finalising]
+			);
+			assert_noop!(
+				NftModule::transfer(Origin::root(), collection_id, item_id, KEZIA),
+				crate::Nft::Error::<Test>::Locked
+			);
+			items.push(item_id);
+		}
+
+		// the lock is released when the notary validates or rejects the purchase
+		assert_ok!(FinalizerModule::validate_transaction_asset(
+			Origin::signed(DAN),
+			collection_id,
+			items[0],
+		));
+		assert!(!NftModule::is_locked(collection_id, items[0]));
+
+		assert_ok!(FinalizerModule::reject_transaction_asset(
+			Origin::signed(DAN),
+			collection_id,
+			items[1],
+		));
+		assert!(!NftModule::is_locked(collection_id, items[1]));
+	});
+}
@@ -20,6 +20,18 @@ benchmarks! {
 
 	}: _(RawOrigin::Signed(caller.clone()), 500u32.into())
 
+	contribute_with_lock {
+		let caller: T::AccountId = whitelisted_caller();
+		let caller_signed = <T as frame_system::Config>::Origin::from(RawOrigin::Signed(caller.clone()));
+		let _ = crate::ROLES::Pallet::<T>::set_role(
+			caller_signed.clone(),
+			caller.clone(),
+			crate::ROLES::Accounts::INVESTOR
+		);
+		<T as pallet::Config>::LocalCurrency::make_free_balance_be(&caller,10_000_000u32.into());
+
+	}: _(RawOrigin::Signed(caller.clone()), 500u32.into(), LockPeriod::ThreeMonths)
+
 	withdraw_fund {
 		let caller: T::AccountId = whitelisted_caller();
 		let caller_signed = <T as frame_system::Config>::Origin::from(RawOrigin::Signed(caller.clone()));
@@ -34,5 +46,89 @@ benchmarks! {
 
 	}: _(RawOrigin::Signed(caller), 200u32.into())
 
+	set_investor_preferences {
+		let caller: T::AccountId = whitelisted_caller();
+		let caller_signed = <T as frame_system::Config>::Origin::from(RawOrigin::Signed(caller.clone()));
+		let _ = crate::ROLES::Pallet::<T>::set_role(
+			caller_signed.clone(),
+			caller.clone(),
+			crate::ROLES::Accounts::INVESTOR
+		);
+		let collections: PreferredCollectionsOf<T> = (0..T::MaxPreferredCollections::get())
+			.map(|i| i.into())
+			.collect::<Vec<NftCollectionId<T>>>()
+			.try_into()
+			.unwrap();
+		let excluded_sellers: ExcludedSellersOf<T> = (0..T::MaxExcludedSellers::get())
+			.map(|i| account("seller", i, 0))
+			.collect::<Vec<T::AccountId>>()
+			.try_into()
+			.unwrap();
+
+	}: _(RawOrigin::Signed(caller), collections, Some(10_000_000u32.into()), Some(500u32.into()), excluded_sellers)
+
+	deposit_income {
+		let caller: T::AccountId = whitelisted_caller();
+		let caller_signed = <T as frame_system::Config>::Origin::from(RawOrigin::Signed(caller.clone()));
+		let _ = crate::ROLES::Pallet::<T>::set_role(
+			caller_signed.clone(),
+			caller.clone(),
+			crate::ROLES::Accounts::INVESTOR
+		);
+		<T as pallet::Config>::LocalCurrency::make_free_balance_be(&caller,10_000_000u32.into());
+
+		let res = HousingFund::<T>::contribute_to_fund(caller_signed.clone(), 500u32.into());
+
+	}: _(RawOrigin::Signed(caller), 100u32.into())
+
+	claim_rewards {
+		let caller: T::AccountId = whitelisted_caller();
+		let caller_signed = <T as frame_system::Config>::Origin::from(RawOrigin::Signed(caller.clone()));
+		let _ = crate::ROLES::Pallet::<T>::set_role(
+			caller_signed.clone(),
+			caller.clone(),
+			crate::ROLES::Accounts::INVESTOR
+		);
+		<T as pallet::Config>::LocalCurrency::make_free_balance_be(&caller,10_000_000u32.into());
+
+		let res = HousingFund::<T>::contribute_to_fund(caller_signed.clone(), 500u32.into());
+		let res = HousingFund::<T>::deposit_income(caller_signed.clone(), 100u32.into());
+
+	}: _(RawOrigin::Signed(caller))
+
+	sync_contribution {
+		let caller: T::AccountId = whitelisted_caller();
+		let caller_signed = <T as frame_system::Config>::Origin::from(RawOrigin::Signed(caller.clone()));
+		let _ = crate::ROLES::Pallet::<T>::set_role(
+			caller_signed.clone(),
+			caller.clone(),
+			crate::ROLES::Accounts::INVESTOR
+		);
+		<T as pallet::Config>::LocalCurrency::make_free_balance_be(&caller,10_000_000u32.into());
+
+		let res = HousingFund::<T>::contribute_to_fund(caller_signed.clone(), 500u32.into());
+
+	}: _(RawOrigin::Signed(caller.clone()), caller.clone())
+
+	cancel_withdrawal {
+		let caller: T::AccountId = whitelisted_caller();
+		let caller_signed = <T as frame_system::Config>::Origin::from(RawOrigin::Signed(caller.clone()));
+		let _ = crate::ROLES::Pallet::<T>::set_role(
+			caller_signed.clone(),
+			caller.clone(),
+			crate::ROLES::Accounts::INVESTOR
+		);
+		<T as pallet::Config>::LocalCurrency::make_free_balance_be(&caller,10_000_000u32.into());
+
+		let res = HousingFund::<T>::contribute_to_fund(caller_signed.clone(), 500u32.into());
+		HousingFund::<T>::queue_withdrawal(&caller, 200u32.into())?;
+
+	}: _(RawOrigin::Signed(caller))
+
+	set_operation_paused {
+		let origin = T::PauseOrigin::successful_origin();
+
+	}: _<T::Origin>(origin, PausableOperation::Withdraw, true)
+
 	impl_benchmark_test_suite!(HousingFund, crate::mock::new_test_ext(), crate::mock::Test);
 }
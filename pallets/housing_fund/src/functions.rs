@@ -1,4 +1,10 @@
 pub use crate::structs::*;
+use frame_support::{
+	sp_runtime::helpers_128bit::multiply_by_rational,
+	traits::tokens::fungibles::{Create, Inspect, Mutate},
+};
+use pallet_assets::FrozenBalance;
+use sp_std::vec;
 
 impl<T: Config> Pallet<T> {
 	// Conversion of u64 to BalanxceOf<T>
@@ -31,6 +37,345 @@ impl<T: Config> Pallet<T> {
 		contribution_shares
 	}
 
+	/// Fund share tokens held by an account
+	pub fn fund_tokens(account_id: &AccountIdOf<T>) -> BalanceOf<T> {
+		T::FundAssets::balance(T::FundAssetId::get(), account_id)
+	}
+
+	/// Fund share tokens of an account that are neither reserved for a house bidding nor waiting
+	/// for a withdrawal
+	pub fn available_fund_tokens(account_id: &AccountIdOf<T>) -> BalanceOf<T> {
+		let reserved_balance = Contributions::<T>::get(account_id)
+			.map_or_else(Zero::zero, |contribution| contribution.reserved_balance);
+		Self::fund_tokens(account_id)
+			.saturating_sub(reserved_balance.saturating_add(Self::queued_withdrawal(account_id)))
+	}
+
+	/// Fund share tokens of an account that cannot be transferred: reserved for a house bidding,
+	/// waiting for a withdrawal or locked
+	pub fn locked_fund_tokens(account_id: &AccountIdOf<T>) -> BalanceOf<T> {
+		let block_number = <frame_system::Pallet<T>>::block_number();
+		let locked = Contributions::<T>::get(account_id).map_or_else(Zero::zero, |contribution| {
+			contribution
+				.reserved_balance
+				.saturating_add(contribution.locked_amount(block_number))
+		});
+		locked.saturating_add(Self::queued_withdrawal(account_id))
+	}
+
+	/// Priority boost of an account in the investor selection, from its locked contributions
+	pub fn priority_boost(account_id: &AccountIdOf<T>) -> Percent {
+		let block_number = <frame_system::Pallet<T>>::block_number();
+		Contributions::<T>::get(account_id)
+			.map(|contribution| contribution.priority_boost(block_number))
+			.unwrap_or_default()
+	}
+
+	/// Amount of the withdrawal of an account waiting in the queue
+	pub fn queued_withdrawal(account_id: &AccountIdOf<T>) -> BalanceOf<T> {
+		WithdrawalRequests::<T>::get(account_id).map_or_else(Zero::zero, |request| request.amount)
+	}
+
+	/// Whether an operation of the fund is paused
+	pub fn is_paused(operation: PausableOperation) -> bool {
+		PausedOperations::<T>::get(operation)
+	}
+
+	/// Whether a call of the pallet belongs to a paused operation, used by the runtime to reject
+	/// the call before its dispatch
+	pub fn is_call_paused(call: &Call<T>) -> bool {
+		let operation = match call {
+			Call::contribute_to_fund { .. } | Call::contribute_with_lock { .. } =>
+				PausableOperation::Contribute,
+			Call::withdraw_fund { .. } => PausableOperation::Withdraw,
+			_ => return false,
+		};
+		Self::is_paused(operation)
+	}
+
+	/// Create the fund share token if it doesn't exist yet
+	pub(crate) fn create_fund_asset() -> DispatchResult {
+		let asset_id = T::FundAssetId::get();
+		// An existing asset always has a minimum balance
+		if T::FundAssets::minimum_balance(asset_id).is_zero() {
+			T::FundAssets::create(asset_id, Self::fund_account_id(), true, One::one())?;
+		}
+		Ok(())
+	}
+
+	/// Align the available balance of a contribution with the fund share tokens of the account,
+	/// which may have been transferred since the last update
+	/// A contribution is created for an account receiving tokens
+	pub(crate) fn sync_fund_tokens(account_id: &AccountIdOf<T>) -> Option<Contribution<T>> {
+		let tokens = Self::fund_tokens(account_id);
+		let block_number = <frame_system::Pallet<T>>::block_number();
+		let (mut contribution, exists) = match Contributions::<T>::get(account_id) {
+			Some(contribution) => (contribution, true),
+			None if tokens.is_zero() => return None,
+			None => (
+				Contribution {
+					account_id: account_id.clone(),
+					available_balance: Zero::zero(),
+					reserved_balance: Zero::zero(),
+					contributed_balance: Zero::zero(),
+					has_withdrawn: false,
+					block_number,
+					contributions: Vec::new(),
+					withdraws: Vec::new(),
+				},
+				false,
+			),
+		};
+
+		let available_balance = tokens.saturating_sub(contribution.reserved_balance);
+		if !exists || available_balance != contribution.available_balance {
+			// The rewards of the previous available balance are credited
+			Self::update_rewards(account_id, contribution.available_balance, available_balance);
+			contribution.available_balance = available_balance;
+			contribution.block_number = block_number;
+			Contributions::<T>::insert(account_id, contribution.clone());
+			if !exists {
+				ContributorCount::<T>::mutate(|count| *count = count.saturating_add(1));
+			}
+		}
+
+		Some(contribution)
+	}
+
+	/// Contribute to the fund, the amount is locked if a lock period is given
+	/// The fund share tokens of the amount are minted
+	/// Emits ContributeSucceeded when successful, ContributionLocked if the amount is locked
+	pub(crate) fn do_contribute(
+		who: AccountIdOf<T>,
+		amount: BalanceOf<T>,
+		lock_period: Option<LockPeriod>,
+	) -> DispatchResult {
+		// Check that the contributions are not paused
+		ensure!(!Self::is_paused(PausableOperation::Contribute), Error::<T>::OperationIsPaused);
+
+		// Check that the account has the investor role
+		ensure!(ROLES::Pallet::<T>::investors(who.clone()).is_some(), Error::<T>::NotAnInvestor);
+
+		// Check if it is the minimal contribution
+		ensure!(amount >= T::MinContribution::get(), Error::<T>::ContributionTooSmall);
+
+		// Check if account has enough to contribute
+		ensure!(T::LocalCurrency::free_balance(&who) >= amount, Error::<T>::NotEnoughToContribute);
+
+		// Get the block number for timestamp
+		let block_number = <frame_system::Pallet<T>>::block_number();
+
+		let lock = lock_period.map(|period| LockSchedule {
+			period,
+			unlock_block: block_number.saturating_add(period.blocks::<T>()),
+			amount,
+		});
+		let contribution_log = ContributionLog { amount, block_number, lock: lock.clone() };
+
+		// Get the fund balance
+		let mut fund = FundBalance::<T>::get();
+
+		// The contribution is aligned with the fund share tokens held by the account
+		let available_balance = Self::sync_fund_tokens(&who)
+			.map_or_else(Zero::zero, |contribution| contribution.available_balance);
+		// The rewards of the previous available balance are credited
+		Self::update_rewards(&who, available_balance, available_balance + amount);
+
+		if !Contributions::<T>::contains_key(&who) {
+			let contribution = Contribution {
+				account_id: who.clone(),
+				available_balance: amount,
+				reserved_balance: Self::u64_to_balance_option(0).unwrap(),
+				contributed_balance: Self::u64_to_balance_option(0).unwrap(),
+				has_withdrawn: false,
+				block_number,
+				contributions: vec![contribution_log],
+				withdraws: Vec::new(),
+			};
+
+			Contributions::<T>::insert(&who, contribution);
+			ContributorCount::<T>::mutate(|count| *count = count.saturating_add(1));
+		} else {
+			Contributions::<T>::mutate(&who, |val| {
+				let old_contrib = val.clone().unwrap();
+				let mut contribution_logs = old_contrib.contributions.clone();
+				// update the contributions history
+				contribution_logs.push(contribution_log.clone());
+
+				let new_contrib = Contribution {
+					account_id: who.clone(),
+					available_balance: old_contrib.available_balance + amount,
+					block_number,
+					contributions: contribution_logs,
+					..old_contrib
+				};
+				*val = Some(new_contrib);
+			});
+		}
+
+		// Update fund with new transferable amount
+		fund.contribute_transferable(amount);
+		FundBalance::<T>::mutate(|val| {
+			*val = fund.clone();
+		});
+
+		// The amount is transferred to the treasurery
+		T::LocalCurrency::transfer(
+			&who,
+			&Pallet::<T>::fund_account_id(),
+			amount,
+			ExistenceRequirement::AllowDeath,
+		)?;
+
+		// The fund share tokens are minted for the account
+		Self::create_fund_asset()?;
+		T::FundAssets::mint_into(T::FundAssetId::get(), &who, amount)?;
+
+		// Emit an event.
+		Self::deposit_event(Event::ContributeSucceeded(who.clone(), amount, block_number));
+		if let Some(lock) = lock {
+			Self::deposit_event(Event::ContributionLocked(who, amount, lock.unlock_block));
+		}
+
+		Ok(())
+	}
+
+	/// Withdraw an amount of the account contribution from the fund
+	/// The fund share tokens of the amount are burnt
+	/// Emits WithdrawalSucceeded when successful
+	#[transactional]
+	pub(crate) fn do_withdraw(who: &AccountIdOf<T>, amount: BalanceOf<T>) -> DispatchResult {
+		// Get the fund balance
+		let mut fund = FundBalance::<T>::get();
+
+		// Check that the fund has enough transferable for the withdraw
+		ensure!(fund.can_take_off(amount), Error::<T>::NotEnoughInTransferableForWithdraw);
+
+		// Get the contribution's account
+		let contribution = Contributions::<T>::get(who).ok_or(Error::<T>::NotAContributor)?;
+
+		// Get the block number for timestamp
+		let block_number = <frame_system::Pallet<T>>::block_number();
+
+		let withdraw_log = ContributionLog { amount, block_number, lock: None };
+
+		// The rewards of the previous available balance are credited
+		Self::update_rewards(
+			who,
+			contribution.available_balance,
+			contribution.available_balance.saturating_sub(amount),
+		);
+
+		Contributions::<T>::mutate(who, |val| {
+			let old_contrib = val.clone().unwrap();
+			let mut withdraw_logs = old_contrib.withdraws.clone();
+			// update the withdraws history
+			withdraw_logs.push(withdraw_log.clone());
+
+			let new_contrib = Contribution {
+				available_balance: old_contrib.available_balance - amount,
+				has_withdrawn: true,
+				block_number,
+				withdraws: withdraw_logs.clone(),
+				..old_contrib
+			};
+			*val = Some(new_contrib);
+		});
+
+		// Update fund with new transferable amount
+		fund.withdraw_transferable(amount);
+		FundBalance::<T>::mutate(|val| {
+			*val = fund.clone();
+		});
+
+		// The fund share tokens of the amount are burnt
+		T::FundAssets::burn_from(T::FundAssetId::get(), who, amount)?;
+
+		// The amount is transferred from the treasury to the account
+		T::LocalCurrency::transfer(
+			&Pallet::<T>::fund_account_id(),
+			who,
+			amount,
+			ExistenceRequirement::AllowDeath,
+		)?;
+
+		// Emit an event.
+		Self::deposit_event(Event::WithdrawalSucceeded(
+			who.clone(),
+			amount,
+			structs::WithdrawalReason::NotDefined,
+			block_number,
+		));
+
+		Ok(())
+	}
+
+	/// Add a withdrawal at the end of the queue
+	/// Emits WithdrawalQueued
+	pub(crate) fn queue_withdrawal(who: &AccountIdOf<T>, amount: BalanceOf<T>) -> DispatchResult {
+		let block_number = <frame_system::Pallet<T>>::block_number();
+
+		WithdrawalQueue::<T>::try_append(who).map_err(|_| Error::<T>::WithdrawalQueueFull)?;
+		WithdrawalRequests::<T>::insert(who, WithdrawalRequest { amount, block_number });
+
+		Self::deposit_event(Event::WithdrawalQueued(who.clone(), amount, block_number));
+		Ok(())
+	}
+
+	/// Execute the queued withdrawals in their order, as long as the fund has enough
+	/// transferable above the threshold
+	/// At most MaxWithdrawalsProcessedPerBlock withdrawals are executed within `remaining_weight`
+	pub fn process_withdrawal_queue(remaining_weight: Weight) -> Weight {
+		let withdraw_weight = <T as Config>::WeightInfo::withdraw_fund();
+		let mut weight = T::DbWeight::get().reads(2);
+		// The queued withdrawals wait while the withdrawals are paused
+		if Self::is_paused(PausableOperation::Withdraw) {
+			return weight
+		}
+		let mut queue = WithdrawalQueue::<T>::get();
+		let mut processed: u32 = 0;
+
+		while let Some(account_id) = queue.first().cloned() {
+			if processed >= T::MaxWithdrawalsProcessedPerBlock::get() ||
+				weight.saturating_add(withdraw_weight) > remaining_weight
+			{
+				break
+			}
+
+			weight = weight.saturating_add(T::DbWeight::get().reads(2));
+			if let Some(request) = WithdrawalRequests::<T>::get(&account_id) {
+				// The first withdrawal waits for the fund, the next ones wait behind it
+				if !FundBalance::<T>::get().can_take_off(request.amount) {
+					break
+				}
+
+				// The tokens are unlocked before being burnt
+				WithdrawalRequests::<T>::remove(&account_id);
+				// The amount is capped to the withdrawable balance of the contributor
+				let block_number = <frame_system::Pallet<T>>::block_number();
+				let amount = Self::sync_fund_tokens(&account_id)
+					.map_or_else(Zero::zero, |contribution| {
+						contribution.withdrawable_amount(block_number)
+					})
+					.min(request.amount);
+				// A withdrawal which can't be executed anymore leaves the queue
+				if amount.is_zero() || Self::do_withdraw(&account_id, amount).is_err() {
+					Self::deposit_event(Event::WithdrawalFailed(
+						account_id.clone(),
+						request.amount,
+						block_number,
+					));
+				}
+				weight = weight.saturating_add(withdraw_weight);
+				processed += 1;
+			}
+			queue.remove(0);
+		}
+
+		WithdrawalQueue::<T>::put(queue);
+		weight.saturating_add(T::DbWeight::get().writes(1))
+	}
+
 	/// Check that the fund can afford the amount
 	pub fn check_available_fund(value: BalanceOf<T>) -> bool {
 		let fund = FundBalance::<T>::get();
@@ -45,12 +390,16 @@ impl<T: Config> Pallet<T> {
 	/// - amount : amount used to buy the house
 	/// - contributions : list of investors contributions
 	/// Emits FundReservationSucceeded when successful
+	#[transactional]
 	pub fn house_bidding(
 		nft_collection_id: NftCollectionId<T>,
 		nft_item_id: NftItemId<T>,
 		amount: BalanceOf<T>,
 		contributions: Vec<(AccountIdOf<T>, BalanceOf<T>)>,
 	) -> DispatchResultWithPostInfo {
+		// Check that the house biddings are not paused
+		ensure!(!Self::is_paused(PausableOperation::HouseBidding), Error::<T>::OperationIsPaused);
+
 		// Check that the fund can afford the bid
 		let mut fund = FundBalance::<T>::get();
 
@@ -66,13 +415,27 @@ impl<T: Config> Pallet<T> {
 		let mut contribution_list = Vec::new();
 
 		for (account_id, balance) in contributions.into_iter() {
-			let entry = Contributions::<T>::get(account_id.clone());
+			let entry = Self::sync_fund_tokens(&account_id);
 			ensure!(entry.is_some(), Error::<T>::NotAContributor);
-			ensure!(entry.unwrap().can_reserve(balance), Error::<T>::NotEnoughAvailableBalance);
+			// The amount waiting for a withdrawal cannot be reserved
+			let queued = Self::queued_withdrawal(&account_id);
+			ensure!(
+				entry.unwrap().can_reserve(balance.saturating_add(queued)),
+				Error::<T>::NotEnoughAvailableBalance
+			);
 
 			Contributions::<T>::mutate(account_id.clone(), |val| {
 				let mut unwrap_val = val.clone().unwrap();
-				unwrap_val.reserve_amount(balance);
+				let available_balance = unwrap_val.available_balance;
+				let taken_from_locks = unwrap_val.reserve_amount(balance);
+				if !taken_from_locks.is_empty() {
+					ReservedLocks::<T>::insert(
+						(nft_collection_id, nft_item_id),
+						&account_id,
+						taken_from_locks,
+					);
+				}
+				Self::update_rewards(&account_id, available_balance, unwrap_val.available_balance);
 				let contribution = unwrap_val.clone();
 				*val = Some(contribution);
 			});
@@ -100,6 +463,9 @@ impl<T: Config> Pallet<T> {
 		};
 
 		// The reservation is added to the storage
+		if !Reservations::<T>::contains_key((nft_collection_id, nft_item_id)) {
+			ReservationCount::<T>::mutate(|count| *count = count.saturating_add(1));
+		}
 		Reservations::<T>::insert((nft_collection_id, nft_item_id), reservation);
 
 		// Emit an event.
@@ -135,7 +501,11 @@ impl<T: Config> Pallet<T> {
 		for (account_id, balance) in reservation.contributions.into_iter() {
 			Contributions::<T>::mutate(account_id.clone(), |val| {
 				let mut unwrap_val = val.clone().unwrap();
-				unwrap_val.unreserve_amount(balance);
+				let available_balance = unwrap_val.available_balance;
+				let taken_from_locks =
+					ReservedLocks::<T>::take((nft_collection_id, nft_item_id), &account_id);
+				unwrap_val.unreserve_amount(balance, taken_from_locks);
+				Self::update_rewards(&account_id, available_balance, unwrap_val.available_balance);
 				let contribution = unwrap_val.clone();
 				*val = Some(contribution);
 			});
@@ -146,6 +516,7 @@ impl<T: Config> Pallet<T> {
 		fund.unreserve(reservation.amount);
 
 		Reservations::<T>::remove((nft_collection_id, nft_item_id));
+		ReservationCount::<T>::mutate(|count| *count = count.saturating_sub(1));
 
 		// The amount is unreserved in the pot
 		FundBalance::<T>::mutate(|val| {
@@ -198,6 +569,8 @@ impl<T: Config> Pallet<T> {
 	/// Move the reserved funds as purchased
 	/// Unreserved fund from contributions and Fund
 	/// Add operation in Purchases storage
+	/// The fund share tokens of the used amount are burnt
+	#[transactional]
 	pub fn validate_house_bidding(
 		nft_collection_id: NftCollectionId<T>,
 		nft_item_id: NftItemId<T>,
@@ -216,6 +589,9 @@ impl<T: Config> Pallet<T> {
 				let contribution = unwrap_val.clone();
 				*val = Some(contribution);
 			});
+			// The locked funds are spent with the purchase
+			ReservedLocks::<T>::remove((nft_collection_id, nft_item_id), &account_id);
+			T::FundAssets::burn_from(T::FundAssetId::get(), &account_id, balance)?;
 		}
 
 		let mut fund = FundBalance::<T>::get();
@@ -224,6 +600,7 @@ impl<T: Config> Pallet<T> {
 
 		// Delete from reservation
 		Reservations::<T>::remove((nft_collection_id, nft_item_id));
+		ReservationCount::<T>::mutate(|count| *count = count.saturating_sub(1));
 		// Add to purchased operations
 		Purchases::<T>::insert((nft_collection_id, nft_item_id), reservation.clone());
 
@@ -245,4 +622,90 @@ impl<T: Config> Pallet<T> {
 
 		Ok(().into())
 	}
+
+	/// Distribute an income among the contributors, pro-rata to their available balance
+	/// - from : account paying the income
+	/// - amount : amount of the income
+	/// Emits IncomeDistributed when successful
+	pub fn distribute_income(from: &AccountIdOf<T>, amount: BalanceOf<T>) -> DispatchResult {
+		// The income is transferred to the fund
+		T::LocalCurrency::transfer(
+			from,
+			&Self::fund_account_id(),
+			amount,
+			ExistenceRequirement::AllowDeath,
+		)?;
+
+		// The income is shared among the available balances of the contributions
+		let total_stake = TotalStake::<T>::get();
+		let income = UndistributedIncome::<T>::get().saturating_add(amount);
+		let increase = Self::reward_per_share_increase(income, total_stake);
+
+		// The distributed amount is rounded up, the remainder waits for the next income
+		let distributed = mul_int_ceil::<T>(increase, total_stake).min(income);
+		UndistributedIncome::<T>::put(income.saturating_sub(distributed));
+		RewardPerShare::<T>::mutate(|reward_per_share| {
+			*reward_per_share = reward_per_share.saturating_add(increase)
+		});
+
+		// Get the block number for timestamp
+		let block_number = <frame_system::Pallet<T>>::block_number();
+
+		// Emit an event.
+		Self::deposit_event(Event::IncomeDistributed(from.clone(), amount, block_number));
+
+		Ok(())
+	}
+
+	/// Rewards that can be claimed by an account
+	pub fn pending_rewards(account_id: &AccountIdOf<T>) -> BalanceOf<T> {
+		let available_balance = Contributions::<T>::get(account_id)
+			.map_or_else(Zero::zero, |contribution| contribution.available_balance);
+		Rewards::<T>::get(account_id).pending(RewardPerShare::<T>::get(), available_balance)
+	}
+
+	/// Credit the rewards of a contributor before its available balance changes from `stake` to
+	/// `new_stake`
+	pub(crate) fn update_rewards(
+		account_id: &AccountIdOf<T>,
+		stake: BalanceOf<T>,
+		new_stake: BalanceOf<T>,
+	) {
+		let reward_per_share = RewardPerShare::<T>::get();
+		Rewards::<T>::mutate(account_id, |rewards| {
+			rewards.accrue(reward_per_share, stake, new_stake)
+		});
+		TotalStake::<T>::mutate(|total_stake| {
+			*total_stake = total_stake.saturating_sub(stake).saturating_add(new_stake)
+		});
+	}
+
+	// Increase of the reward per share when `income` is shared among `total_stake`, rounded down
+	fn reward_per_share_increase(income: BalanceOf<T>, total_stake: BalanceOf<T>) -> FixedU128 {
+		if total_stake.is_zero() {
+			return FixedU128::zero()
+		}
+
+		let income: u128 = income.unique_saturated_into();
+		let total_stake: u128 = total_stake.unique_saturated_into();
+		multiply_by_rational(income, FixedU128::DIV, total_stake)
+			.map(FixedU128::from_inner)
+			.unwrap_or_else(|_| FixedU128::zero())
+	}
+}
+
+// The fund share tokens reserved for a house bidding or waiting for a withdrawal are frozen
+impl<T: Config> FrozenBalance<FundAssetIdOf<T>, AccountIdOf<T>, BalanceOf<T>> for Pallet<T> {
+	fn frozen_balance(asset: FundAssetIdOf<T>, who: &AccountIdOf<T>) -> Option<BalanceOf<T>> {
+		if asset != T::FundAssetId::get() {
+			return None
+		}
+
+		let locked = Self::locked_fund_tokens(who);
+		// pallet_assets keeps the minimum balance on top of the frozen balance
+		let min_balance = T::FundAssets::minimum_balance(asset);
+		(!locked.is_zero()).then(|| locked.saturating_sub(min_balance))
+	}
+
+	fn died(_asset: FundAssetIdOf<T>, _who: &AccountIdOf<T>) {}
 }
@@ -12,8 +12,55 @@
 //! ### Dispatchable Functions
 //!
 //! * 'contribute_to_fund' - an account with the investor role can transfer funds to the pot
+//! * 'contribute_with_lock' - an account with the investor role can transfer funds to the pot and
+//!   lock them for a period, in return for a higher priority in the investor selection
 //! * 'withdraw_fund' - an account with the investor role can withdraw funds from the pot if the
 //!   amount is available
+//! * 'cancel_withdrawal' - a contributor cancels its withdrawal waiting in the queue
+//! * 'set_investor_preferences' - an account with the investor role can restrict the assets its
+//!   contribution is used for
+//! * 'deposit_income' - an account pays an income to the fund, shared among the contributors
+//! * 'claim_rewards' - a contributor claims its share of the fund income
+//! * 'sync_contribution' - update the contribution of an account after a transfer of fund share
+//!   tokens
+//! * 'set_operation_paused' - the `PauseOrigin` pauses or resumes an operation of the fund
+//!
+//! ### Emergency pause
+//!
+//! The contributions, the withdrawals and the house biddings can each be paused by the
+//! `PauseOrigin`, to halt the fund while an accounting issue is investigated. The queued
+//! withdrawals are not processed while the withdrawals are paused, and the bidding pallet doesn't
+//! process the onboarded assets while the house biddings are paused. `is_call_paused` lets the
+//! runtime reject the paused calls in its `BaseCallFilter`.
+//!
+//! ### Contribution lock-ups
+//!
+//! A contribution can be locked for 3, 6 or 12 months, measured in blocks with `BlocksPerMonth`.
+//! The locked amount cannot be withdrawn nor transferred before the end of the period, each
+//! contribution being unlocked on its own schedule. While a lock is active, the contributor gets
+//! a priority boost in the investor selection of the bidding pallet.
+//!
+//! ### Withdrawal queue
+//!
+//! A withdrawal that would bring the transferable fund below the threshold, or that comes while
+//! other withdrawals are waiting, is queued. The queue is processed in order in `on_idle`, for at
+//! most `MaxWithdrawalsProcessedPerBlock` withdrawals per block, as contributions and cancelled
+//! reservations free up the fund. The queued amount cannot be used for a house bidding. The queue
+//! holds at most `MaxQueuedWithdrawals` requests, a queued withdrawal which can't be executed
+//! anymore is removed with a `WithdrawalFailed` event.
+//!
+//! ### Fund share token
+//!
+//! The contributions are represented by a fungible token of `pallet_assets`, minted on
+//! contribution and burnt on withdrawal or when the reserved amount is used for a purchase. The
+//! tokens are transferable, except the part reserved for a house bidding which is frozen. The
+//! available balance of a contributor is its token balance minus its reserved balance.
+//!
+//! ### Fund income
+//!
+//! The fund income (rent share, fees, slashes...) is credited to the contributors pro-rata to their
+//! available balance, through a reward per share accumulator. Roundings always favour the fund so
+//! the claimed rewards never exceed the income.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -30,6 +77,7 @@ mod benchmarking;
 pub mod weights;
 
 mod functions;
+pub mod migrations;
 mod structs;
 
 pub use crate::structs::*;
@@ -41,7 +89,10 @@ pub use weights::WeightInfo;
 pub mod pallet {
 	use super::*;
 	use frame_support::{
-		traits::{Currency, ExistenceRequirement, Get},
+		traits::{
+			tokens::fungibles::{Create, Inspect, Mutate},
+			Currency, ExistenceRequirement, Get,
+		},
 		transactional, PalletId,
 	};
 	//use frame_system::WeightInfo;
@@ -49,6 +100,9 @@ pub mod pallet {
 
 	pub const PERCENT_FACTOR: u64 = 100000;
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+
 	/// Configure the pallet by specifying the parameters and types on which it depends.
 	#[pallet::config]
 	pub trait Config: frame_system::Config + NFT::Config {
@@ -60,7 +114,29 @@ pub mod pallet {
 		type FundThreshold: Get<BalanceOf<Self>>;
 		type MaxFundContribution: Get<BalanceOf<Self>>;
 		type MaxInvestorPerHouse: Get<u32>;
+		/// Maximum number of sellers excluded by the preferences of an investor
+		#[pallet::constant]
+		type MaxExcludedSellers: Get<u32>;
+		/// Maximum number of collections in the preferences of an investor
+		#[pallet::constant]
+		type MaxPreferredCollections: Get<u32>;
 		type PalletId: Get<PalletId>;
+		/// Fungible assets holding the fund share token
+		type FundAssets: Inspect<Self::AccountId, Balance = BalanceOf<Self>>
+			+ Mutate<Self::AccountId>
+			+ Create<Self::AccountId>;
+		/// Asset id of the fund share token
+		type FundAssetId: Get<FundAssetIdOf<Self>>;
+		/// Maximum number of queued withdrawals executed in a block
+		#[pallet::constant]
+		type MaxWithdrawalsProcessedPerBlock: Get<u32>;
+		/// Maximum number of withdrawals waiting in the queue
+		#[pallet::constant]
+		type MaxQueuedWithdrawals: Get<u32>;
+		/// Number of blocks in a month, used for the contribution lock periods
+		type BlocksPerMonth: Get<Self::BlockNumber>;
+		/// Origin allowed to pause and resume the operations of the fund
+		type PauseOrigin: EnsureOrigin<Self::Origin>;
 
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
@@ -68,6 +144,7 @@ pub mod pallet {
 
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
@@ -90,6 +167,17 @@ pub mod pallet {
 	pub type Contributions<T> =
 		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, Contribution<T>, OptionQuery>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn contributor_count)]
+	// Number of accounts in Contributions
+	pub type ContributorCount<T> = StorageValue<_, u32, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn preferences)]
+	// Investment preferences of the contributors
+	pub type Preferences<T> =
+		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, InvestorPreferences<T>, OptionQuery>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn reservations)]
 	// Housing fund reservations
@@ -101,6 +189,25 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn reservation_count)]
+	// Number of reservations in Reservations
+	pub type ReservationCount<T> = StorageValue<_, u32, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn reserved_locks)]
+	// Amounts taken from the locks of each contribution log by a reservation, restored when the
+	// reservation is cancelled
+	pub type ReservedLocks<T> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		(NftCollectionId<T>, NftItemId<T>),
+		Blake2_128Concat,
+		AccountIdOf<T>,
+		Vec<(u32, BalanceOf<T>)>,
+		ValueQuery,
+	>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn purchases)]
 	// Housing fund used for purchases
@@ -112,12 +219,54 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn reward_per_share)]
+	// Income credited to each unit of available balance since the fund creation
+	pub type RewardPerShare<T> = StorageValue<_, FixedU128, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn undistributed_income)]
+	// Income not credited yet to the contributors, because of the roundings or because no
+	// contributor had an available balance
+	pub type UndistributedIncome<T> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn rewards)]
+	// Rewards of the contributors from the fund income
+	pub type Rewards<T> =
+		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, RewardInfo<T>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn total_stake)]
+	// Sum of the available balances of the contributions, among which the fund income is shared
+	pub type TotalStake<T> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn withdrawal_queue)]
+	// Accounts waiting for their withdrawal, in the order of the requests
+	pub type WithdrawalQueue<T: Config> =
+		StorageValue<_, BoundedVec<AccountIdOf<T>, T::MaxQueuedWithdrawals>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn withdrawal_requests)]
+	// Withdrawals waiting for the fund to have enough transferable
+	pub type WithdrawalRequests<T> =
+		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, WithdrawalRequest<T>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn paused_operations)]
+	// Operations of the fund halted by the PauseOrigin
+	pub type PausedOperations<T> =
+		StorageMap<_, Blake2_128Concat, PausableOperation, bool, ValueQuery>;
+
 	// Pallets use events to inform users when important changes are made.
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
 		/// Account's contribution successfully added to the fund
 		ContributeSucceeded(AccountIdOf<T>, BalanceOf<T>, BlockNumberOf<T>),
+		/// Account's contribution locked until the given block
+		ContributionLocked(AccountIdOf<T>, BalanceOf<T>, BlockNumberOf<T>),
 		/// Withdraw by account succeeded
 		WithdrawalSucceeded(
 			AccountIdOf<T>,
@@ -130,6 +279,24 @@ pub mod pallet {
 		FundReservationCancelled(T::NftCollectionId, T::NftItemId, BalanceOf<T>, BlockNumberOf<T>),
 		PurchaseFundValidated(T::NftCollectionId, T::NftItemId, BalanceOf<T>, BlockNumberOf<T>),
 		FundUnreservedForPurchase(T::NftCollectionId, T::NftItemId, BalanceOf<T>, BlockNumberOf<T>),
+		/// Investment preferences of the account updated
+		InvestorPreferencesSet(AccountIdOf<T>, BlockNumberOf<T>),
+		/// An income was paid to the fund and distributed among the contributors
+		IncomeDistributed(AccountIdOf<T>, BalanceOf<T>, BlockNumberOf<T>),
+		/// A contributor claimed its rewards
+		RewardsClaimed(AccountIdOf<T>, BalanceOf<T>, BlockNumberOf<T>),
+		/// The available balance of a contribution was updated from the fund share tokens
+		ContributionSynced(AccountIdOf<T>, BalanceOf<T>, BlockNumberOf<T>),
+		/// The withdrawal waits in the queue for the fund to have enough transferable
+		WithdrawalQueued(AccountIdOf<T>, BalanceOf<T>, BlockNumberOf<T>),
+		/// A queued withdrawal was cancelled
+		WithdrawalCancelled(AccountIdOf<T>, BalanceOf<T>, BlockNumberOf<T>),
+		/// A queued withdrawal could not be executed and was removed from the queue
+		WithdrawalFailed(AccountIdOf<T>, BalanceOf<T>, BlockNumberOf<T>),
+		/// An operation of the fund was paused
+		OperationPaused(PausableOperation, BlockNumberOf<T>),
+		/// A paused operation of the fund was resumed
+		OperationResumed(PausableOperation, BlockNumberOf<T>),
 	}
 
 	// Errors inform users that something went wrong.
@@ -159,6 +326,29 @@ pub mod pallet {
 		NotMoreThanMaxInvestorPerHouse,
 		/// The reservation doesn't exist in the storage
 		NoFundReservationFound,
+		/// The account has no rewards to claim
+		NoRewardsToClaim,
+		/// The account already has a withdrawal waiting in the queue
+		WithdrawalAlreadyQueued,
+		/// The account has no withdrawal waiting in the queue
+		NoWithdrawalQueued,
+		/// The withdrawal queue is full
+		WithdrawalQueueFull,
+		/// The amount is locked until the end of the lock period
+		ContributionStillLocked,
+		/// The operation is paused by the PauseOrigin
+		OperationIsPaused,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::process_withdrawal_queue(remaining_weight)
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			migrations::migrate::<T>()
+		}
 	}
 
 	#[pallet::call]
@@ -176,89 +366,124 @@ pub mod pallet {
 			// Check that the extrinsic was signed and get the signer.
 			let who = ensure_signed(origin)?;
 
+			Self::do_contribute(who, amount, None)?;
+
+			Ok(().into())
+		}
+
+		/// Allow an account to contribute to the common fund and lock the contribution
+		/// The locked amount cannot be withdrawn before the end of the lock period, in return the
+		/// account has a higher priority in the investor selection of the bidding
+		/// The origin must be signed
+		/// - 'amount': the amount deposited in the fund
+		/// - 'lock_period': the period the amount is locked for
+		/// Emits ContributeSucceeded and ContributionLocked events when successful
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::contribute_with_lock())]
+		#[transactional]
+		pub fn contribute_with_lock(
+			origin: OriginFor<T>,
+			amount: BalanceOf<T>,
+			lock_period: LockPeriod,
+		) -> DispatchResultWithPostInfo {
+			// Check that the extrinsic was signed and get the signer.
+			let who = ensure_signed(origin)?;
+
+			Self::do_contribute(who, amount, Some(lock_period))?;
+
+			Ok(().into())
+		}
+
+		/// Withdraw the account contribution from the fund
+		/// The withdrawal is queued if the fund doesn't have enough transferable
+		/// The origin must be signed
+		/// - amount : the amount to be withdrawn from the fund
+		/// Emits WithdrawalSucceeded event when successful, WithdrawalQueued when queued
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::withdraw_fund())]
+		#[transactional]
+		pub fn withdraw_fund(
+			origin: OriginFor<T>,
+			amount: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			// Check that the extrinsic was signed and get the signer.
+			let who = ensure_signed(origin)?;
+
+			// Check that the withdrawals are not paused
+			ensure!(!Self::is_paused(PausableOperation::Withdraw), Error::<T>::OperationIsPaused);
+
 			// Check that the account has the investor role
 			ensure!(
 				ROLES::Pallet::<T>::investors(who.clone()).is_some(),
 				Error::<T>::NotAnInvestor
 			);
 
-			// Check if it is the minimal contribution
-			ensure!(amount >= T::MinContribution::get(), Error::<T>::ContributionTooSmall);
+			// Get the contribution's account, aligned with its fund share tokens
+			let contribution = Self::sync_fund_tokens(&who).ok_or(Error::<T>::NotAContributor)?;
+
+			// Check that the amount is not superior to the available balance of the contributor
+			ensure!(amount <= contribution.available_balance, Error::<T>::NotEnoughFundToWithdraw);
 
-			// Check if account has enough to contribute
+			// Check that the amount is not locked
+			let block_number = <frame_system::Pallet<T>>::block_number();
 			ensure!(
-				T::LocalCurrency::free_balance(&who) >= amount,
-				Error::<T>::NotEnoughToContribute
+				amount <= contribution.withdrawable_amount(block_number),
+				Error::<T>::ContributionStillLocked
 			);
 
-			// Get the block number for timestamp
-			let block_number = <frame_system::Pallet<T>>::block_number();
+			// Check that the account has no withdrawal waiting in the queue
+			ensure!(
+				!WithdrawalRequests::<T>::contains_key(&who),
+				Error::<T>::WithdrawalAlreadyQueued
+			);
 
-			let contribution_log = ContributionLog { amount, block_number };
+			// The withdrawal waits behind the queued ones, or until the fund has enough
+			// transferable above the threshold
+			let fund = FundBalance::<T>::get();
+			if !WithdrawalQueue::<T>::get().is_empty() || !fund.can_take_off(amount) {
+				Self::queue_withdrawal(&who, amount)?;
+				return Ok(().into())
+			}
 
-			// Get the fund balance
-			let mut fund = FundBalance::<T>::get();
+			Self::do_withdraw(&who, amount)?;
 
-			if !Contributions::<T>::contains_key(&who) {
-				let contribution = Contribution {
-					account_id: who.clone(),
-					available_balance: amount,
-					reserved_balance: Self::u64_to_balance_option(0).unwrap(),
-					contributed_balance: Self::u64_to_balance_option(0).unwrap(),
-					has_withdrawn: false,
-					block_number,
-					contributions: vec![contribution_log],
-					withdraws: Vec::new(),
-				};
+			Ok(().into())
+		}
 
-				Contributions::<T>::insert(&who, contribution);
-			} else {
-				Contributions::<T>::mutate(&who, |val| {
-					let old_contrib = val.clone().unwrap();
-					let mut contribution_logs = old_contrib.contributions.clone();
-					// update the contributions history
-					contribution_logs.push(contribution_log.clone());
-
-					let new_contrib = Contribution {
-						account_id: who.clone(),
-						available_balance: old_contrib.available_balance + amount,
-						block_number,
-						contributions: contribution_logs,
-						..old_contrib
-					};
-					*val = Some(new_contrib);
-				});
-			}
+		/// Cancel the withdrawal of the account waiting in the queue
+		/// The origin must be signed
+		/// Emits WithdrawalCancelled event when successful
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::cancel_withdrawal())]
+		#[transactional]
+		pub fn cancel_withdrawal(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			// Check that the extrinsic was signed and get the signer.
+			let who = ensure_signed(origin)?;
 
-			// Update fund with new transferable amount
-			fund.contribute_transferable(amount);
-			FundBalance::<T>::mutate(|val| {
-				*val = fund.clone();
-			});
+			let request =
+				WithdrawalRequests::<T>::take(&who).ok_or(Error::<T>::NoWithdrawalQueued)?;
+			WithdrawalQueue::<T>::mutate(|queue| queue.retain(|account_id| account_id != &who));
 
-			// The amount is transferred to the treasurery
-			T::LocalCurrency::transfer(
-				&who,
-				&Pallet::<T>::fund_account_id(),
-				amount,
-				ExistenceRequirement::AllowDeath,
-			)?;
+			// Get the block number for timestamp
+			let block_number = <frame_system::Pallet<T>>::block_number();
 
 			// Emit an event.
-			Self::deposit_event(Event::ContributeSucceeded(who, amount, block_number));
+			Self::deposit_event(Event::WithdrawalCancelled(who, request.amount, block_number));
 
 			Ok(().into())
 		}
 
-		/// Withdraw the account contribution from the fund
+		/// Set the investment preferences of the account
 		/// The origin must be signed
-		/// - amount : the amount to be withdrawn from the fund
-		/// Emits WithdrawalSucceeded event when successful
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::withdraw_fund())]
-		#[transactional]
-		pub fn withdraw_fund(
+		/// - collections : collections the contribution can be used for, any if empty
+		/// - max_price : maximum price of an asset the contribution can be used for
+		/// - max_exposure : maximum amount of the contribution used for a single asset
+		/// - excluded_sellers : sellers the contribution cannot be used to buy from
+		/// Emits InvestorPreferencesSet event when successful
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_investor_preferences())]
+		pub fn set_investor_preferences(
 			origin: OriginFor<T>,
-			amount: BalanceOf<T>,
+			collections: PreferredCollectionsOf<T>,
+			max_price: Option<BalanceOf<T>>,
+			max_exposure: Option<BalanceOf<T>>,
+			excluded_sellers: ExcludedSellersOf<T>,
 		) -> DispatchResultWithPostInfo {
 			// Check that the extrinsic was signed and get the signer.
 			let who = ensure_signed(origin)?;
@@ -269,52 +494,68 @@ pub mod pallet {
 				Error::<T>::NotAnInvestor
 			);
 
-			// Check if the account has contributed to the fund
-			ensure!(Contributions::<T>::contains_key(&who), Error::<T>::NotAContributor);
+			let mut collection_ids = PreferredCollectionsOf::<T>::default();
+			for collection_id in collections {
+				if !collection_ids.contains(&collection_id) {
+					// The deduplicated collections fit in the bound of the given ones
+					collection_ids.try_push(collection_id).ok();
+				}
+			}
 
-			// Get the contribution's account
-			let contribution = Contributions::<T>::get(who.clone()).unwrap();
+			Preferences::<T>::insert(
+				&who,
+				InvestorPreferences {
+					collections: collection_ids,
+					max_price,
+					max_exposure,
+					excluded_sellers,
+				},
+			);
+
+			// Get the block number for timestamp
+			let block_number = <frame_system::Pallet<T>>::block_number();
 
-			// Retrieve the balance of the account
-			let contribution_amount = contribution.get_total_balance();
+			// Emit an event.
+			Self::deposit_event(Event::InvestorPreferencesSet(who, block_number));
 
-			// Check that the amount is not superior to the total balance of the contributor
-			ensure!(amount <= contribution_amount, Error::<T>::NotEnoughFundToWithdraw);
+			Ok(().into())
+		}
+
+		/// Pay an income to the fund, it is shared among the contributors pro-rata to their
+		/// available balance
+		/// The origin must be signed
+		/// - amount : the amount paid to the fund
+		/// Emits IncomeDistributed event when successful
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::deposit_income())]
+		#[transactional]
+		pub fn deposit_income(
+			origin: OriginFor<T>,
+			amount: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
 
-			// Get the fund balance
-			let mut fund = FundBalance::<T>::get();
+			Self::distribute_income(&who, amount)?;
 
-			// Check that the fund has enough transferable for the withdraw
-			ensure!(fund.can_take_off(amount), Error::<T>::NotEnoughInTransferableForWithdraw);
+			Ok(().into())
+		}
 
-			// Get the block number for timestamp
-			let block_number = <frame_system::Pallet<T>>::block_number();
+		/// Claim the rewards of the account from the fund income
+		/// The origin must be signed
+		/// Emits RewardsClaimed event when successful
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::claim_rewards())]
+		#[transactional]
+		pub fn claim_rewards(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let available_balance = Self::sync_fund_tokens(&who)
+				.map_or_else(Zero::zero, |contribution| contribution.available_balance);
+			Self::update_rewards(&who, available_balance, available_balance);
 
-			let withdraw_log = ContributionLog { amount, block_number };
-
-			Contributions::<T>::mutate(&who, |val| {
-				let old_contrib = val.clone().unwrap();
-				let mut withdraw_logs = old_contrib.withdraws.clone();
-				// update the withdraws history
-				withdraw_logs.push(withdraw_log.clone());
-
-				let new_contrib = Contribution {
-					available_balance: old_contrib.available_balance - amount,
-					has_withdrawn: true,
-					block_number,
-					withdraws: withdraw_logs.clone(),
-					..old_contrib
-				};
-				*val = Some(new_contrib);
-			});
-
-			// Update fund with new transferable amount
-			fund.withdraw_transferable(amount);
-			FundBalance::<T>::mutate(|val| {
-				*val = fund.clone();
-			});
-
-			// The amount is transferred from the treasury to the account
+			let amount = Rewards::<T>::get(&who).unclaimed;
+			ensure!(!amount.is_zero(), Error::<T>::NoRewardsToClaim);
+			Rewards::<T>::mutate(&who, |rewards| rewards.unclaimed = Zero::zero());
+
+			// The rewards are transferred from the fund to the account
 			T::LocalCurrency::transfer(
 				&Pallet::<T>::fund_account_id(),
 				&who,
@@ -322,15 +563,80 @@ pub mod pallet {
 				ExistenceRequirement::AllowDeath,
 			)?;
 
+			// Get the block number for timestamp
+			let block_number = <frame_system::Pallet<T>>::block_number();
+
 			// Emit an event.
-			Self::deposit_event(Event::WithdrawalSucceeded(
-				who,
-				amount,
-				structs::WithdrawalReason::NotDefined,
+			Self::deposit_event(Event::RewardsClaimed(who, amount, block_number));
+
+			Ok(().into())
+		}
+
+		/// Update the available balance of a contribution from the fund share tokens of the
+		/// account, after the tokens were transferred
+		/// The origin must be signed
+		/// - account_id : the account holding the fund share tokens
+		/// Emits ContributionSynced event when successful
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::sync_contribution())]
+		#[transactional]
+		pub fn sync_contribution(
+			origin: OriginFor<T>,
+			account_id: AccountIdOf<T>,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+
+			// A contribution is only created for an account with the investor role
+			ensure!(
+				Contributions::<T>::contains_key(&account_id) ||
+					ROLES::Pallet::<T>::investors(account_id.clone()).is_some(),
+				Error::<T>::NotAnInvestor
+			);
+
+			let contribution =
+				Self::sync_fund_tokens(&account_id).ok_or(Error::<T>::NotAContributor)?;
+
+			// Get the block number for timestamp
+			let block_number = <frame_system::Pallet<T>>::block_number();
+
+			// Emit an event.
+			Self::deposit_event(Event::ContributionSynced(
+				account_id,
+				contribution.available_balance,
 				block_number,
 			));
 
 			Ok(().into())
 		}
+
+		/// Pause or resume an operation of the fund
+		/// The origin must be T::PauseOrigin
+		/// - operation : the operation to pause or resume
+		/// - paused : whether the operation is paused
+		/// Emits OperationPaused or OperationResumed event when the state changes
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_operation_paused())]
+		pub fn set_operation_paused(
+			origin: OriginFor<T>,
+			operation: PausableOperation,
+			paused: bool,
+		) -> DispatchResultWithPostInfo {
+			T::PauseOrigin::ensure_origin(origin)?;
+
+			if Self::is_paused(operation) == paused {
+				return Ok(().into())
+			}
+			PausedOperations::<T>::insert(operation, paused);
+
+			// Get the block number for timestamp
+			let block_number = <frame_system::Pallet<T>>::block_number();
+
+			// Emit an event.
+			if paused {
+				Self::deposit_event(Event::OperationPaused(operation, block_number));
+			} else {
+				Self::deposit_event(Event::OperationResumed(operation, block_number));
+			}
+
+			Ok(().into())
+		}
 	}
 }
@@ -0,0 +1,130 @@
+pub use super::*;
+use frame_support::traits::{tokens::fungibles::Mutate, GetStorageVersion, StorageVersion};
+
+/// Apply the migrations from the on chain storage version to the current one
+pub fn migrate<T: Config>() -> Weight {
+	let onchain_version = Pallet::<T>::on_chain_storage_version();
+	let mut weight = T::DbWeight::get().reads(1);
+	if onchain_version >= 3 {
+		return weight
+	}
+
+	// The layout of the contributions is updated before minting their tokens
+	if onchain_version < 2 {
+		weight = weight.saturating_add(v2::migrate::<T>());
+	}
+	if onchain_version < 1 {
+		weight = weight.saturating_add(v1::migrate::<T>());
+	}
+	weight = weight.saturating_add(v3::migrate::<T>());
+
+	StorageVersion::new(3).put::<Pallet<T>>();
+	weight.saturating_add(T::DbWeight::get().writes(1))
+}
+
+pub mod v1 {
+	use super::*;
+
+	/// Mint the fund share tokens of the existing contributions
+	/// The tokens of a contribution are its available and reserved balances, a contribution whose
+	/// tokens can't be minted doesn't share the fund income
+	pub fn migrate<T: Config>() -> Weight {
+		let mut reads: u64 = 1;
+		let mut writes: u64 = 2;
+		let asset_id = T::FundAssetId::get();
+		if Pallet::<T>::create_fund_asset().is_err() {
+			frame_support::log::error!(
+				target: "runtime::housing_fund",
+				"Error: Unable to create the fund share token, no token is minted!"
+			);
+			return T::DbWeight::get().reads_writes(reads, writes)
+		}
+
+		let mut total_stake: BalanceOf<T> = Zero::zero();
+		for (account_id, contribution) in Contributions::<T>::iter() {
+			reads += 1;
+			let tokens = contribution.get_total_balance();
+			if !tokens.is_zero() {
+				reads += 1;
+				writes += 2;
+				if T::FundAssets::mint_into(asset_id, &account_id, tokens).is_err() {
+					frame_support::log::warn!(
+						target: "runtime::housing_fund",
+						"Warning: Unable to mint the fund share tokens of a contribution!"
+					);
+					continue
+				}
+			}
+			total_stake = total_stake.saturating_add(contribution.available_balance);
+		}
+		TotalStake::<T>::put(total_stake);
+
+		T::DbWeight::get().reads_writes(reads, writes)
+	}
+}
+
+pub mod v2 {
+	use super::*;
+
+	#[derive(Decode)]
+	pub struct OldContributionLog<T: Config> {
+		pub amount: BalanceOf<T>,
+		pub block_number: BlockNumberOf<T>,
+	}
+
+	#[derive(Decode)]
+	pub struct OldContribution<T: Config> {
+		pub account_id: AccountIdOf<T>,
+		pub available_balance: BalanceOf<T>,
+		pub reserved_balance: BalanceOf<T>,
+		pub contributed_balance: BalanceOf<T>,
+		pub has_withdrawn: bool,
+		pub block_number: BlockNumberOf<T>,
+		pub contributions: Vec<OldContributionLog<T>>,
+		pub withdraws: Vec<OldContributionLog<T>>,
+	}
+
+	fn migrate_logs<T: Config>(logs: Vec<OldContributionLog<T>>) -> Vec<ContributionLog<T>> {
+		logs.into_iter()
+			.map(|log| ContributionLog {
+				amount: log.amount,
+				block_number: log.block_number,
+				lock: None,
+			})
+			.collect()
+	}
+
+	/// Add the lock schedule to the contribution logs, the existing contributions are not locked
+	pub fn migrate<T: Config>() -> Weight {
+		let mut translated: u64 = 0;
+		Contributions::<T>::translate::<OldContribution<T>, _>(|_, contribution| {
+			translated += 1;
+			Some(Contribution {
+				account_id: contribution.account_id,
+				available_balance: contribution.available_balance,
+				reserved_balance: contribution.reserved_balance,
+				contributed_balance: contribution.contributed_balance,
+				has_withdrawn: contribution.has_withdrawn,
+				block_number: contribution.block_number,
+				contributions: migrate_logs::<T>(contribution.contributions),
+				withdraws: migrate_logs::<T>(contribution.withdraws),
+			})
+		});
+
+		T::DbWeight::get().reads_writes(translated, translated)
+	}
+}
+
+pub mod v3 {
+	use super::*;
+
+	/// Count the existing contributors and reservations
+	pub fn migrate<T: Config>() -> Weight {
+		let contributors = Contributions::<T>::iter_keys().count() as u32;
+		let reservations = Reservations::<T>::iter_keys().count() as u32;
+		ContributorCount::<T>::put(contributors);
+		ReservationCount::<T>::put(reservations);
+
+		T::DbWeight::get().reads_writes(contributors as u64 + reservations as u64, 2)
+	}
+}
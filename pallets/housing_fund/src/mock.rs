@@ -19,6 +19,8 @@ pub(crate) type Balance = u128;
 type AccountId = u64;
 pub type CollectionId = u32;
 pub type ItemId = u32;
+pub const HOUSES: CollectionId = 0;
+pub const OFFICES: CollectionId = 1;
 
 // Configure a mock runtime to test the pallet.
 frame_support::construct_runtime!(
@@ -29,6 +31,7 @@ frame_support::construct_runtime!(
 		{
 			System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
 			HousingFundModule: pallet_housing_fund::{Pallet, Call, Storage, Event<T>},
+			FundAssets: pallet_assets::<Instance1>::{Pallet, Storage, Event<T>},
 			Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
 			RoleModule: pallet_roles::{Pallet, Call, Storage, Event<T>},
 			Uniques: pallet_uniques::{Pallet, Call, Storage, Event<T>},
@@ -111,6 +114,8 @@ impl pallet_uniques::Config for Test {
 
 parameter_types! {
 	pub ReserveCollectionIdUpTo: u32 = 3;
+	pub const MaxMetadataRevisions: u32 = 3;
+	pub const MaxAttributesPerItem: u32 = 5;
 }
 impl pallet_nft::Config for Test {
 	type Event = Event;
@@ -120,6 +125,10 @@ impl pallet_nft::Config for Test {
 	type ProtocolOrigin = EnsureRoot<AccountId>;
 	type Permissions = NftPermissions;
 	type ReserveCollectionIdUpTo = ReserveCollectionIdUpTo;
+	type ItemStages = ();
+	type TokenFreezer = ();
+	type MaxMetadataRevisions = MaxMetadataRevisions;
+	type MaxAttributesPerItem = MaxAttributesPerItem;
 }
 
 parameter_types! {
@@ -128,6 +137,8 @@ parameter_types! {
 	pub const MaxFundContribution: u128 = 200;
 	pub const HousingFundPalletId: PalletId = PalletId(*b"housfund");
 	pub const MaxInvestorPerHouse: u32 = 2;
+	pub const MaxExcludedSellers: u32 = 5;
+	pub const MaxPreferredCollections: u32 = 3;
 }
 
 impl pallet_housing_fund::Config for Test {
@@ -139,6 +150,38 @@ impl pallet_housing_fund::Config for Test {
 	type WeightInfo = ();
 	type PalletId = HousingFundPalletId;
 	type MaxInvestorPerHouse = MaxInvestorPerHouse;
+	type MaxExcludedSellers = MaxExcludedSellers;
+	type MaxPreferredCollections = MaxPreferredCollections;
+	type FundAssets = FundAssets;
+	type FundAssetId = FundAssetId;
+	type MaxWithdrawalsProcessedPerBlock = MaxWithdrawalsProcessedPerBlock;
+	type MaxQueuedWithdrawals = MaxQueuedWithdrawals;
+	type BlocksPerMonth = BlocksPerMonth;
+	type PauseOrigin = EnsureRoot<AccountId>;
+}
+
+parameter_types! {
+	pub const FundAssetId: u32 = 0;
+	pub const MaxWithdrawalsProcessedPerBlock: u32 = 2;
+	pub const MaxQueuedWithdrawals: u32 = 3;
+	pub const BlocksPerMonth: u64 = 10;
+}
+
+impl pallet_assets::Config<pallet_assets::Instance1> for Test {
+	type Event = Event;
+	type Balance = Balance;
+	type AssetId = u32;
+	type Currency = Balances;
+	type ForceOrigin = EnsureRoot<AccountId>;
+	type AssetDeposit = ();
+	type AssetAccountDeposit = ();
+	type MetadataDepositBase = ();
+	type MetadataDepositPerByte = ();
+	type ApprovalDeposit = ();
+	type StringLimit = frame_support::traits::ConstU32<50>;
+	type Freezer = HousingFundModule;
+	type Extra = ();
+	type WeightInfo = ();
 }
 
 parameter_types! {
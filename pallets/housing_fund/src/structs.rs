@@ -5,8 +5,13 @@ pub use frame_support::{
 	dispatch::DispatchResult,
 	inherent::Vec,
 	pallet_prelude::*,
-	sp_runtime::traits::{AccountIdConversion, Zero},
-	traits::{Currency, ExistenceRequirement, Get, ReservableCurrency, WithdrawReasons},
+	sp_runtime::{
+		traits::{AccountIdConversion, One, Saturating, UniqueSaturatedInto, Zero},
+		FixedPointNumber, FixedU128, Percent,
+	},
+	traits::{
+		tokens::fungibles, Currency, ExistenceRequirement, Get, ReservableCurrency, WithdrawReasons,
+	},
 	transactional, PalletId,
 };
 pub use frame_system::{ensure_signed, pallet_prelude::*};
@@ -18,6 +23,11 @@ pub type NftItemId<T> = <T as pallet_nft::Config>::NftItemId;
 pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 pub type BalanceOf<T> = <<T as Config>::LocalCurrency as Currency<AccountIdOf<T>>>::Balance;
 pub type BlockNumberOf<T> = <T as frame_system::Config>::BlockNumber;
+pub type FundAssetIdOf<T> =
+	<<T as Config>::FundAssets as fungibles::Inspect<AccountIdOf<T>>>::AssetId;
+pub type PreferredCollectionsOf<T> =
+	BoundedVec<NftCollectionId<T>, <T as Config>::MaxPreferredCollections>;
+pub type ExcludedSellersOf<T> = BoundedVec<AccountIdOf<T>, <T as Config>::MaxExcludedSellers>;
 
 #[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
 pub enum WithdrawalReason {
@@ -79,6 +89,57 @@ impl<T: Config> FundInfo<T> {
 	}
 }
 
+// Operation of the fund that can be paused by the PauseOrigin
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum PausableOperation {
+	Contribute,
+	Withdraw,
+	HouseBidding,
+}
+
+// Period a contribution is locked for
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum LockPeriod {
+	ThreeMonths,
+	SixMonths,
+	TwelveMonths,
+}
+impl LockPeriod {
+	pub fn months(&self) -> u32 {
+		match self {
+			LockPeriod::ThreeMonths => 3,
+			LockPeriod::SixMonths => 6,
+			LockPeriod::TwelveMonths => 12,
+		}
+	}
+
+	// Duration of the period in blocks
+	pub fn blocks<T: Config>(&self) -> BlockNumberOf<T> {
+		T::BlocksPerMonth::get().saturating_mul(self.months().into())
+	}
+
+	// Priority boost of the contributor in the investor selection
+	pub fn boost(&self) -> Percent {
+		match self {
+			LockPeriod::ThreeMonths => Percent::from_percent(10),
+			LockPeriod::SixMonths => Percent::from_percent(25),
+			LockPeriod::TwelveMonths => Percent::from_percent(50),
+		}
+	}
+}
+
+// Lock of a contribution
+#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct LockSchedule<T: Config> {
+	// Period the contribution is locked for
+	pub period: LockPeriod,
+	// Block number from which the contribution can be withdrawn
+	pub unlock_block: BlockNumberOf<T>,
+	// Part of the contribution still locked, decreased when the locked funds are reserved
+	pub amount: BalanceOf<T>,
+}
+
 // Contains amount and timestamp of an account
 #[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo)]
 #[scale_info(skip_type_params(T))]
@@ -87,6 +148,16 @@ pub struct ContributionLog<T: Config> {
 	pub amount: BalanceOf<T>,
 	// Block numer as timestamp
 	pub block_number: BlockNumberOf<T>,
+	// Lock of the amount, if any
+	pub lock: Option<LockSchedule<T>>,
+}
+impl<T: Config> ContributionLog<T> {
+	// Active lock of the amount at block `now`
+	pub fn active_lock(&self, now: BlockNumberOf<T>) -> Option<&LockSchedule<T>> {
+		self.lock
+			.as_ref()
+			.filter(|lock| now < lock.unlock_block && !lock.amount.is_zero())
+	}
 }
 
 // Contains the contributed amount of an account, ist share and his contributions history
@@ -119,12 +190,50 @@ impl<T: Config> Contribution<T> {
 		amount <= self.available_balance
 	}
 
-	pub fn reserve_amount(&mut self, amount: BalanceOf<T>) {
+	// Returns the amounts taken from the locks, indexed by contribution log
+	pub fn reserve_amount(&mut self, amount: BalanceOf<T>) -> Vec<(u32, BalanceOf<T>)> {
+		// The unlocked balance which isn't waiting for a withdrawal is reserved first, the rest is
+		// taken from the active locks
+		let now = <frame_system::Pallet<T>>::block_number();
+		let unlocked = self
+			.withdrawable_amount(now)
+			.saturating_sub(Pallet::<T>::queued_withdrawal(&self.account_id));
+		let mut from_locks = amount.saturating_sub(unlocked);
+		let mut taken_from_locks = Vec::new();
+		for (index, log) in self.contributions.iter_mut().enumerate() {
+			if from_locks.is_zero() {
+				break
+			}
+			if let Some(lock) = log.lock.as_mut().filter(|lock| now < lock.unlock_block) {
+				let taken = from_locks.min(lock.amount);
+				if !taken.is_zero() {
+					lock.amount -= taken;
+					from_locks -= taken;
+					taken_from_locks.push((index as u32, taken));
+				}
+			}
+		}
+
 		self.available_balance -= amount;
 		self.reserved_balance += amount;
+
+		taken_from_locks
 	}
 
-	pub fn unreserve_amount(&mut self, amount: BalanceOf<T>) {
+	// The amounts taken from the locks by the reservation are locked again
+	pub fn unreserve_amount(
+		&mut self,
+		amount: BalanceOf<T>,
+		taken_from_locks: Vec<(u32, BalanceOf<T>)>,
+	) {
+		for (index, taken) in taken_from_locks.into_iter() {
+			if let Some(lock) =
+				self.contributions.get_mut(index as usize).and_then(|log| log.lock.as_mut())
+			{
+				lock.amount = lock.amount.saturating_add(taken);
+			}
+		}
+
 		self.reserved_balance -= amount;
 		self.available_balance += amount;
 	}
@@ -133,6 +242,40 @@ impl<T: Config> Contribution<T> {
 		self.reserved_balance -= amount;
 		self.contributed_balance += amount;
 	}
+
+	// Part of the available balance still locked at block `now`
+	pub fn locked_amount(&self, now: BlockNumberOf<T>) -> BalanceOf<T> {
+		self.contributions
+			.iter()
+			.filter_map(|log| log.active_lock(now))
+			.fold(Zero::zero(), |locked: BalanceOf<T>, lock| locked.saturating_add(lock.amount))
+			.min(self.available_balance)
+	}
+
+	// Part of the available balance that can be withdrawn at block `now`
+	pub fn withdrawable_amount(&self, now: BlockNumberOf<T>) -> BalanceOf<T> {
+		self.available_balance.saturating_sub(self.locked_amount(now))
+	}
+
+	// Highest priority boost among the locks active at block `now`
+	pub fn priority_boost(&self, now: BlockNumberOf<T>) -> Percent {
+		self.contributions
+			.iter()
+			.filter_map(|log| log.active_lock(now))
+			.map(|lock| lock.period.boost())
+			.max()
+			.unwrap_or_default()
+	}
+}
+
+// Withdrawal of a contributor waiting for the fund to have enough transferable
+#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct WithdrawalRequest<T: Config> {
+	// Amount to withdraw
+	pub amount: BalanceOf<T>,
+	// Block number of the request
+	pub block_number: BlockNumberOf<T>,
 }
 
 // Contains the details of the operations that occured
@@ -159,3 +302,86 @@ pub struct ContributionShare<T: Config> {
 	// Share of the fund
 	pub share: u32,
 }
+
+// Investment preferences of a contributor, used to filter the assets it is assigned to
+#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct InvestorPreferences<T: Config> {
+	// Collections the investor wants to invest in, any collection if empty
+	pub collections: PreferredCollectionsOf<T>,
+	// Maximum price of an asset the investor wants to invest in
+	pub max_price: Option<BalanceOf<T>>,
+	// Maximum amount the investor wants to invest in a single asset
+	pub max_exposure: Option<BalanceOf<T>>,
+	// Sellers the investor does not want to buy assets from
+	pub excluded_sellers: ExcludedSellersOf<T>,
+}
+impl<T: Config> InvestorPreferences<T> {
+	// Check that an asset matches the preferences
+	pub fn accepts(
+		&self,
+		collection_id: NftCollectionId<T>,
+		price: BalanceOf<T>,
+		seller: Option<&AccountIdOf<T>>,
+	) -> bool {
+		(self.collections.is_empty() || self.collections.contains(&collection_id)) &&
+			self.max_price.map_or(true, |max_price| price <= max_price) &&
+			seller.map_or(true, |seller| !self.excluded_sellers.contains(seller))
+	}
+
+	// Cap the amount invested in a single asset to the maximum exposure
+	pub fn cap_exposure(&self, amount: BalanceOf<T>) -> BalanceOf<T> {
+		self.max_exposure.map_or(amount, |max_exposure| amount.min(max_exposure))
+	}
+}
+
+// Rewards of a contributor from the fund income
+#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct RewardInfo<T: Config> {
+	// Rewards already credited to the contributor and not claimed yet
+	pub unclaimed: BalanceOf<T>,
+	// Rewards of the current available balance accrued before the contributor held it
+	pub debt: BalanceOf<T>,
+}
+impl<T: Config> Default for RewardInfo<T> {
+	fn default() -> Self {
+		RewardInfo { unclaimed: Zero::zero(), debt: Zero::zero() }
+	}
+}
+impl<T: Config> RewardInfo<T> {
+	// Credit the rewards accrued by `stake` since the last update and reset the debt for the
+	// `new_stake`
+	pub fn accrue(
+		&mut self,
+		reward_per_share: FixedU128,
+		stake: BalanceOf<T>,
+		new_stake: BalanceOf<T>,
+	) {
+		self.unclaimed = self.pending(reward_per_share, stake);
+		// The debt is rounded up so that the rounding never credits more than the income
+		self.debt = mul_int_ceil::<T>(reward_per_share, new_stake);
+	}
+
+	// Rewards that can be claimed by a contributor with an available balance of `stake`
+	pub fn pending(&self, reward_per_share: FixedU128, stake: BalanceOf<T>) -> BalanceOf<T> {
+		let accrued = reward_per_share.saturating_mul_int(stake).saturating_sub(self.debt);
+		self.unclaimed.saturating_add(accrued)
+	}
+}
+
+// Multiply a balance by a reward per share, rounded up
+pub fn mul_int_ceil<T: Config>(reward_per_share: FixedU128, amount: BalanceOf<T>) -> BalanceOf<T> {
+	let floor = reward_per_share.saturating_mul_int(amount);
+	let amount: u128 = amount.unique_saturated_into();
+	let exact = reward_per_share
+		.frac()
+		.into_inner()
+		.checked_mul(amount)
+		.map_or(false, |fraction| fraction % FixedU128::DIV == 0);
+	if exact {
+		floor
+	} else {
+		floor.saturating_add(One::one())
+	}
+}
@@ -1,6 +1,9 @@
 use super::*;
 use crate::{mock::*, Error};
-use frame_support::{assert_err, assert_noop, assert_ok};
+use frame_support::{
+	assert_err, assert_noop, assert_ok,
+	traits::{tokens::fungibles, GetStorageVersion},
+};
 
 #[test]
 fn contribute_without_having_investor_role_should_fail() {
@@ -88,7 +91,8 @@ fn contribute_with_valid_values_should_succeed() {
 				block_number: 1,
 				contributions: vec![ContributionLog {
 					amount: HousingFundModule::u64_to_balance_option(25).unwrap(),
-					block_number: 1
+					block_number: 1,
+					lock: None
 				}],
 				withdraws: Vec::new()
 			})
@@ -148,11 +152,13 @@ fn contribute_update_contribution_should_succeed() {
 				contributions: vec![
 					ContributionLog {
 						amount: HousingFundModule::u64_to_balance_option(20).unwrap(),
-						block_number: 1
+						block_number: 1,
+						lock: None
 					},
 					ContributionLog {
 						amount: HousingFundModule::u64_to_balance_option(30).unwrap(),
-						block_number: 1
+						block_number: 1,
+						lock: None
 					}
 				],
 				withdraws: Vec::new()
@@ -201,7 +207,8 @@ fn contribute_with_valid_values_from_two_contributors_should_succeed() {
 				block_number: 1,
 				contributions: vec![ContributionLog {
 					amount: HousingFundModule::u64_to_balance_option(25).unwrap(),
-					block_number: 1
+					block_number: 1,
+					lock: None
 				}],
 				withdraws: Vec::new()
 			})
@@ -218,7 +225,8 @@ fn contribute_with_valid_values_from_two_contributors_should_succeed() {
 				block_number: 1,
 				contributions: vec![ContributionLog {
 					amount: HousingFundModule::u64_to_balance_option(25).unwrap(),
-					block_number: 1
+					block_number: 1,
+					lock: None
 				}],
 				withdraws: Vec::new()
 			})
@@ -324,7 +332,8 @@ fn withdraw_with_valid_values_should_succeed() {
 				block_number: 1,
 				contributions: vec![ContributionLog {
 					amount: HousingFundModule::u64_to_balance_option(25).unwrap(),
-					block_number: 1
+					block_number: 1,
+					lock: None
 				}],
 				withdraws: Vec::new()
 			})
@@ -354,11 +363,13 @@ fn withdraw_with_valid_values_should_succeed() {
 				block_number: 1,
 				contributions: vec![ContributionLog {
 					amount: HousingFundModule::u64_to_balance_option(25).unwrap(),
-					block_number: 1
+					block_number: 1,
+					lock: None
 				}],
 				withdraws: vec![ContributionLog {
 					amount: HousingFundModule::u64_to_balance_option(20).unwrap(),
-					block_number: 1
+					block_number: 1,
+					lock: None
 				}]
 			})
 		);
@@ -436,11 +447,13 @@ fn withdraw_with_valid_values_from_two_contributors_should_succeed() {
 				block_number: 1,
 				contributions: vec![ContributionLog {
 					amount: HousingFundModule::u64_to_balance_option(25).unwrap(),
-					block_number: 1
+					block_number: 1,
+					lock: None
 				}],
 				withdraws: vec![ContributionLog {
 					amount: HousingFundModule::u64_to_balance_option(20).unwrap(),
-					block_number: 1
+					block_number: 1,
+					lock: None
 				}]
 			})
 		);
@@ -456,11 +469,13 @@ fn withdraw_with_valid_values_from_two_contributors_should_succeed() {
 				block_number: 1,
 				contributions: vec![ContributionLog {
 					amount: HousingFundModule::u64_to_balance_option(25).unwrap(),
-					block_number: 1
+					block_number: 1,
+					lock: None
 				}],
 				withdraws: vec![ContributionLog {
 					amount: HousingFundModule::u64_to_balance_option(20).unwrap(),
-					block_number: 1
+					block_number: 1,
+					lock: None
 				}]
 			})
 		);
@@ -548,7 +563,8 @@ fn house_bidding_with_valid_values_should_succeed() {
 				block_number: 1,
 				contributions: vec![ContributionLog {
 					amount: HousingFundModule::u64_to_balance_option(40).unwrap(),
-					block_number: 1
+					block_number: 1,
+					lock: None
 				}],
 				withdraws: Vec::new()
 			})
@@ -565,7 +581,8 @@ fn house_bidding_with_valid_values_should_succeed() {
 				block_number: 1,
 				contributions: vec![ContributionLog {
 					amount: HousingFundModule::u64_to_balance_option(40).unwrap(),
-					block_number: 1
+					block_number: 1,
+					lock: None
 				}],
 				withdraws: Vec::new()
 			})
@@ -581,6 +598,8 @@ fn house_bidding_with_valid_values_should_succeed() {
 				contributions: vec![(1, 30), (2, 30)]
 			})
 		);
+		assert_eq!(HousingFundModule::reservation_count(), 1);
+		assert_eq!(HousingFundModule::contributor_count(), 2);
 
 		// Check the amount reserved for the account
 		assert_eq!(
@@ -626,6 +645,7 @@ fn cancel_house_bidding_with_valid_values_should_succeed() {
 		assert_ok!(HousingFundModule::house_bidding(1, 1, 60, vec![(1, 30), (2, 30)]));
 
 		assert_ok!(HousingFundModule::cancel_house_bidding(1, 1));
+		assert_eq!(HousingFundModule::reservation_count(), 0);
 
 		assert_eq!(
 			HousingFundModule::fund_balance(),
@@ -647,7 +667,8 @@ fn cancel_house_bidding_with_valid_values_should_succeed() {
 				block_number: 1,
 				contributions: vec![ContributionLog {
 					amount: HousingFundModule::u64_to_balance_option(40).unwrap(),
-					block_number: 1
+					block_number: 1,
+					lock: None
 				}],
 				withdraws: Vec::new()
 			})
@@ -664,7 +685,8 @@ fn cancel_house_bidding_with_valid_values_should_succeed() {
 				block_number: 1,
 				contributions: vec![ContributionLog {
 					amount: HousingFundModule::u64_to_balance_option(40).unwrap(),
-					block_number: 1
+					block_number: 1,
+					lock: None
 				}],
 				withdraws: Vec::new()
 			})
@@ -802,7 +824,7 @@ fn contribution_unreserve_amount_should_succeed() {
 		let mut contribution = HousingFundModule::contributions(1).unwrap();
 
 		contribution.reserve_amount(30);
-		contribution.unreserve_amount(20);
+		contribution.unreserve_amount(20, Vec::new());
 		// check that contribution balance is correctly set
 		assert_eq!(contribution.available_balance, 40);
 		assert_eq!(contribution.reserved_balance, 10);
@@ -892,7 +914,8 @@ fn get_contributions_with_contribution_should_succeed() {
 				block_number: 1,
 				contributions: vec![ContributionLog {
 					amount: HousingFundModule::u64_to_balance_option(25).unwrap(),
-					block_number: 1
+					block_number: 1,
+					lock: None
 				}],
 				withdraws: Vec::new()
 			}
@@ -1006,6 +1029,7 @@ fn validate_house_bidding_with_valid_values_should_succeed() {
 		assert_ok!(HousingFundModule::house_bidding(1, 1, 60, vec![(1, 30), (2, 30)]));
 
 		assert_ok!(HousingFundModule::validate_house_bidding(1, 1));
+		assert_eq!(HousingFundModule::reservation_count(), 0);
 
 		assert_eq!(
 			HousingFundModule::fund_balance(),
@@ -1027,7 +1051,8 @@ fn validate_house_bidding_with_valid_values_should_succeed() {
 				block_number: 1,
 				contributions: vec![ContributionLog {
 					amount: HousingFundModule::u64_to_balance_option(40).unwrap(),
-					block_number: 1
+					block_number: 1,
+					lock: None
 				}],
 				withdraws: Vec::new()
 			})
@@ -1044,7 +1069,8 @@ fn validate_house_bidding_with_valid_values_should_succeed() {
 				block_number: 1,
 				contributions: vec![ContributionLog {
 					amount: HousingFundModule::u64_to_balance_option(40).unwrap(),
-					block_number: 1
+					block_number: 1,
+					lock: None
 				}],
 				withdraws: Vec::new()
 			})
@@ -1064,3 +1090,790 @@ fn validate_house_bidding_with_valid_values_should_succeed() {
 		);
 	});
 }
+
+#[test]
+fn set_investor_preferences_without_being_investor_should_fail() {
+	new_test_ext().execute_with(|| {
+		let account_id: u64 = 1;
+		assert_noop!(
+			HousingFundModule::set_investor_preferences(
+				Origin::signed(account_id),
+				vec![HOUSES].try_into().unwrap(),
+				None,
+				None,
+				Default::default()
+			),
+			Error::<Test>::NotAnInvestor
+		);
+	});
+}
+
+#[test]
+fn set_investor_preferences_with_too_many_values_should_fail() {
+	new_test_ext().execute_with(|| {
+		let account_id: u64 = 1;
+		// Give the investor role to the account
+		assert_ok!(RoleModule::set_role(
+			Origin::signed(account_id),
+			account_id,
+			crate::ROLES::Accounts::INVESTOR
+		));
+		// MaxExcludedSellers is 5, a call with more excluded sellers cannot be decoded
+		let call = mock::Call::HousingFundModule(crate::Call::set_investor_preferences {
+			collections: Default::default(),
+			max_price: None,
+			max_exposure: None,
+			excluded_sellers: Default::default(),
+		});
+		let mut encoded = call.encode()[..2].to_vec();
+		(Vec::<CollectionId>::new(), None::<Balance>, None::<Balance>, vec![2u64, 3, 4, 5, 6, 7])
+			.encode_to(&mut encoded);
+		assert!(mock::Call::decode(&mut &encoded[..]).is_err());
+
+		// MaxPreferredCollections is 3
+		let mut encoded = call.encode()[..2].to_vec();
+		(
+			vec![HOUSES, OFFICES, HOUSES, OFFICES],
+			None::<Balance>,
+			None::<Balance>,
+			Vec::<u64>::new(),
+		)
+			.encode_to(&mut encoded);
+		assert!(mock::Call::decode(&mut &encoded[..]).is_err());
+	});
+}
+
+#[test]
+fn set_investor_preferences_with_valid_values_should_succeed() {
+	new_test_ext().execute_with(|| {
+		let account_id: u64 = 1;
+		// Give the investor role to the account
+		assert_ok!(RoleModule::set_role(
+			Origin::signed(account_id),
+			account_id,
+			crate::ROLES::Accounts::INVESTOR
+		));
+
+		assert_ok!(HousingFundModule::set_investor_preferences(
+			Origin::signed(account_id),
+			vec![HOUSES, OFFICES, HOUSES].try_into().unwrap(),
+			Some(100),
+			Some(30),
+			vec![2].try_into().unwrap()
+		));
+
+		// The duplicated collection is only stored once
+		let preferences = HousingFundModule::preferences(account_id).unwrap();
+		assert_eq!(
+			preferences,
+			InvestorPreferences {
+				collections: vec![HOUSES, OFFICES].try_into().unwrap(),
+				max_price: Some(100),
+				max_exposure: Some(30),
+				excluded_sellers: vec![2].try_into().unwrap(),
+			}
+		);
+
+		let event = <frame_system::Pallet<Test>>::events()
+			.pop()
+			.expect("Expected at least one EventRecord to be found")
+			.event;
+		assert_eq!(
+			event,
+			mock::Event::HousingFundModule(crate::Event::InvestorPreferencesSet(account_id, 1))
+		);
+	});
+}
+
+#[test]
+fn investor_preferences_accepts_should_succeed() {
+	new_test_ext().execute_with(|| {
+		let houses = HOUSES;
+		let offices = OFFICES;
+		let mut preferences: InvestorPreferences<Test> = InvestorPreferences {
+			collections: Default::default(),
+			max_price: None,
+			max_exposure: None,
+			excluded_sellers: Default::default(),
+		};
+
+		// No preferences accept any asset
+		assert!(preferences.accepts(offices, 1000, Some(&2)));
+		assert_eq!(preferences.cap_exposure(50), 50);
+
+		preferences.collections = vec![houses].try_into().unwrap();
+		preferences.max_price = Some(100);
+		preferences.max_exposure = Some(30);
+		preferences.excluded_sellers = vec![2].try_into().unwrap();
+
+		assert!(preferences.accepts(houses, 100, Some(&3)));
+		assert!(!preferences.accepts(offices, 100, Some(&3)));
+		assert!(!preferences.accepts(houses, 101, Some(&3)));
+		assert!(!preferences.accepts(houses, 100, Some(&2)));
+		assert_eq!(preferences.cap_exposure(50), 30);
+		assert_eq!(preferences.cap_exposure(20), 20);
+	});
+}
+
+fn set_investor(account_id: u64) {
+	assert_ok!(RoleModule::set_role(
+		Origin::signed(account_id),
+		account_id,
+		crate::ROLES::Accounts::INVESTOR
+	));
+}
+
+#[test]
+fn deposit_income_should_credit_contributors_pro_rata() {
+	new_test_ext().execute_with(|| {
+		let payer: u64 = 3;
+		Balances::make_free_balance_be(&payer, 1_000);
+		set_investor(1);
+		set_investor(2);
+		assert_ok!(HousingFundModule::contribute_to_fund(Origin::signed(1), 30));
+		assert_ok!(HousingFundModule::contribute_to_fund(Origin::signed(2), 10));
+
+		assert_ok!(HousingFundModule::deposit_income(Origin::signed(payer), 40));
+		assert_eq!(
+			HousingFundModule::reward_per_share(),
+			FixedU128::saturating_from_integer(1_u32)
+		);
+		assert_eq!(HousingFundModule::undistributed_income(), 0);
+		assert_eq!(Balances::free_balance(payer), 960);
+		assert_eq!(HousingFundModule::pending_rewards(&1), 30);
+		assert_eq!(HousingFundModule::pending_rewards(&2), 10);
+
+		// the rewards are paid by the fund without changing the contribution
+		assert_ok!(HousingFundModule::claim_rewards(Origin::signed(1)));
+		assert_eq!(Balances::free_balance(1), 100);
+		assert_eq!(HousingFundModule::contributions(1).unwrap().available_balance, 30);
+		assert_eq!(HousingFundModule::pending_rewards(&1), 0);
+		assert_eq!(
+			System::events().last().unwrap().event,
+			mock::Event::HousingFundModule(crate::Event::RewardsClaimed(1, 30, 1))
+		);
+
+		assert_noop!(
+			HousingFundModule::claim_rewards(Origin::signed(1)),
+			Error::<Test>::NoRewardsToClaim
+		);
+	});
+}
+
+#[test]
+fn rewards_should_follow_available_balance_over_time() {
+	new_test_ext().execute_with(|| {
+		let payer: u64 = 3;
+		Balances::make_free_balance_be(&payer, 1_000);
+		set_investor(1);
+		set_investor(2);
+
+		assert_ok!(HousingFundModule::contribute_to_fund(Origin::signed(1), 20));
+		assert_ok!(HousingFundModule::deposit_income(Origin::signed(payer), 20));
+
+		// the late contributor doesn't get the previous income
+		assert_ok!(HousingFundModule::contribute_to_fund(Origin::signed(2), 20));
+		assert_ok!(HousingFundModule::deposit_income(Origin::signed(payer), 40));
+		assert_eq!(HousingFundModule::pending_rewards(&1), 40);
+		assert_eq!(HousingFundModule::pending_rewards(&2), 20);
+
+		// the withdrawn amount stops earning rewards
+		assert_ok!(HousingFundModule::withdraw_fund(Origin::signed(1), 10));
+		assert_ok!(HousingFundModule::deposit_income(Origin::signed(payer), 30));
+		assert_eq!(HousingFundModule::pending_rewards(&1), 50);
+		assert_eq!(HousingFundModule::pending_rewards(&2), 40);
+
+		// the reserved amount stops earning rewards
+		assert_ok!(HousingFundModule::house_bidding(1, 1, 20, vec![(1, 10), (2, 10)]));
+		assert_ok!(HousingFundModule::deposit_income(Origin::signed(payer), 10));
+		assert_eq!(HousingFundModule::pending_rewards(&1), 50);
+		assert_eq!(HousingFundModule::pending_rewards(&2), 50);
+	});
+}
+
+#[test]
+fn distribute_income_should_not_leak_with_roundings() {
+	new_test_ext().execute_with(|| {
+		let payer: u64 = 10;
+		let contributors: Vec<(u64, u128)> = vec![(3, 10), (4, 13), (5, 17)];
+		let fund_account_id = HousingFundModule::fund_account_id();
+		let initial_fund_balance = Balances::free_balance(fund_account_id);
+		Balances::make_free_balance_be(&payer, 1_000);
+
+		// the income received before any contribution waits for the contributors
+		assert_ok!(HousingFundModule::deposit_income(Origin::signed(payer), 5));
+		assert_eq!(HousingFundModule::undistributed_income(), 5);
+
+		let mut income: u128 = 5;
+		for (account_id, amount) in contributors.iter() {
+			Balances::make_free_balance_be(account_id, 100);
+			set_investor(*account_id);
+			assert_ok!(HousingFundModule::contribute_to_fund(Origin::signed(*account_id), *amount));
+		}
+
+		for (round, amount) in [7_u128, 11, 13, 1, 29].into_iter().enumerate() {
+			assert_ok!(HousingFundModule::deposit_income(Origin::signed(payer), amount));
+			income += amount;
+
+			// the stakes change between the incomes
+			let (account_id, _) = contributors[round % contributors.len()];
+			assert_ok!(HousingFundModule::contribute_to_fund(Origin::signed(account_id), 10));
+
+			// the credited rewards never exceed the income
+			let credited = contributors
+				.iter()
+				.map(|(account_id, _)| HousingFundModule::pending_rewards(account_id))
+				.sum::<u128>() + HousingFundModule::undistributed_income();
+			assert!(credited <= income);
+			// the rounding dust is bounded by one unit per contributor and per income
+			assert!(income - credited <= (contributors.len() * (round + 1)) as u128);
+		}
+
+		// the fund can pay every claim and keeps the contributions
+		let mut claimed: u128 = 0;
+		for (account_id, _) in contributors.iter() {
+			let rewards = HousingFundModule::pending_rewards(account_id);
+			assert_ok!(HousingFundModule::claim_rewards(Origin::signed(*account_id)));
+			claimed += rewards;
+		}
+		assert_eq!(
+			Balances::free_balance(fund_account_id),
+			initial_fund_balance + HousingFundModule::fund_balance().transferable + income -
+				claimed
+		);
+		assert!(income - claimed >= HousingFundModule::undistributed_income());
+	});
+}
+
+fn fund_tokens(account_id: u64) -> u128 {
+	FundAssets::balance(FundAssetId::get(), account_id)
+}
+
+#[test]
+fn contribution_should_mint_fund_tokens_and_withdraw_should_burn_them() {
+	new_test_ext().execute_with(|| {
+		set_investor(1);
+		assert_ok!(HousingFundModule::contribute_to_fund(Origin::signed(1), 30));
+		assert_eq!(fund_tokens(1), 30);
+		assert_eq!(FundAssets::total_supply(FundAssetId::get()), 30);
+
+		assert_ok!(HousingFundModule::withdraw_fund(Origin::signed(1), 10));
+		assert_eq!(fund_tokens(1), 20);
+		assert_eq!(FundAssets::total_supply(FundAssetId::get()), 20);
+	});
+}
+
+#[test]
+fn reserved_fund_tokens_should_be_frozen() {
+	new_test_ext().execute_with(|| {
+		set_investor(1);
+		set_investor(2);
+		Balances::make_free_balance_be(&3, 100);
+		set_investor(3);
+		assert_ok!(HousingFundModule::contribute_to_fund(Origin::signed(1), 30));
+		assert_ok!(HousingFundModule::contribute_to_fund(Origin::signed(2), 30));
+		assert_ok!(HousingFundModule::house_bidding(1, 1, 40, vec![(1, 20), (2, 20)]));
+
+		// only the available tokens can be transferred
+		assert_noop!(
+			FundAssets::transfer(Origin::signed(1), FundAssetId::get(), 3, 20),
+			pallet_assets::Error::<Test, pallet_assets::Instance1>::BalanceLow
+		);
+		assert_ok!(FundAssets::transfer(Origin::signed(1), FundAssetId::get(), 3, 10));
+		assert_eq!(HousingFundModule::available_fund_tokens(&1), 0);
+
+		// the receiver becomes a contributor once its tokens are synced
+		assert_eq!(HousingFundModule::contributions(3), None);
+		assert_ok!(HousingFundModule::sync_contribution(Origin::signed(2), 3));
+		assert_eq!(HousingFundModule::contributions(3).unwrap().available_balance, 10);
+		assert_ok!(HousingFundModule::sync_contribution(Origin::signed(2), 1));
+		assert_eq!(HousingFundModule::contributions(1).unwrap().available_balance, 0);
+		assert_eq!(HousingFundModule::total_stake(), 20);
+		assert_noop!(
+			HousingFundModule::withdraw_fund(Origin::signed(1), 10),
+			Error::<Test>::NotEnoughFundToWithdraw
+		);
+
+		// the reserved tokens are burnt when the purchase is validated
+		assert_ok!(HousingFundModule::validate_house_bidding(1, 1));
+		assert_eq!(fund_tokens(1), 0);
+		assert_eq!(fund_tokens(2), 10);
+		assert_eq!(FundAssets::total_supply(FundAssetId::get()), 20);
+	});
+}
+
+#[test]
+fn migration_should_mint_fund_tokens_of_existing_contributions() {
+	new_test_ext().execute_with(|| {
+		let contribution = |account_id: u64, available_balance: u128, reserved_balance: u128| {
+			Contribution::<Test> {
+				account_id,
+				available_balance,
+				reserved_balance,
+				contributed_balance: 0,
+				has_withdrawn: false,
+				block_number: 1,
+				contributions: Vec::new(),
+				withdraws: Vec::new(),
+			}
+		};
+		Contributions::<Test>::insert(1, contribution(1, 30, 10));
+		Contributions::<Test>::insert(2, contribution(2, 15, 0));
+
+		crate::migrations::migrate::<Test>();
+		assert_eq!(HousingFundModule::on_chain_storage_version(), 3);
+		assert_eq!(HousingFundModule::contributor_count(), 2);
+		assert_eq!(fund_tokens(1), 40);
+		assert_eq!(fund_tokens(2), 15);
+		assert_eq!(HousingFundModule::total_stake(), 45);
+		assert_eq!(HousingFundModule::available_fund_tokens(&1), 30);
+
+		// the migration is only applied once
+		crate::migrations::migrate::<Test>();
+		assert_eq!(fund_tokens(1), 40);
+	});
+}
+
+#[test]
+fn migration_should_leave_out_the_contributions_whose_tokens_cannot_be_minted() {
+	new_test_ext().execute_with(|| {
+		let contribution = |account_id: u64, available_balance: u128| Contribution::<Test> {
+			account_id,
+			available_balance,
+			reserved_balance: 0,
+			contributed_balance: 0,
+			has_withdrawn: false,
+			block_number: 1,
+			contributions: Vec::new(),
+			withdraws: Vec::new(),
+		};
+		Contributions::<Test>::insert(1, contribution(1, 40));
+		Contributions::<Test>::insert(2, contribution(2, 5));
+		// the supply of the fund share token can only grow by 5 more tokens
+		assert_ok!(HousingFundModule::create_fund_asset());
+		assert_ok!(<FundAssets as fungibles::Mutate<u64>>::mint_into(
+			FundAssetId::get(),
+			&3,
+			u128::MAX - 10
+		));
+
+		crate::migrations::migrate::<Test>();
+		assert_eq!(fund_tokens(1), 0);
+		assert_eq!(fund_tokens(2), 5);
+		assert_eq!(HousingFundModule::total_stake(), 5);
+	});
+}
+
+#[test]
+fn withdrawal_should_be_queued_when_the_fund_is_below_threshold() {
+	new_test_ext().execute_with(|| {
+		set_investor(1);
+		set_investor(2);
+		assert_ok!(HousingFundModule::contribute_to_fund(Origin::signed(1), 30));
+
+		// the withdrawal would bring the fund below the threshold
+		assert_ok!(HousingFundModule::withdraw_fund(Origin::signed(1), 30));
+		assert_eq!(HousingFundModule::withdrawal_queue(), vec![1]);
+		assert_eq!(HousingFundModule::withdrawal_requests(1).unwrap().amount, 30);
+		assert_eq!(Balances::free_balance(1), 70);
+		assert_eq!(
+			System::events().last().unwrap().event,
+			mock::Event::HousingFundModule(crate::Event::WithdrawalQueued(1, 30, 1))
+		);
+
+		// the queued amount can neither be used for a bidding nor transferred
+		assert_eq!(HousingFundModule::available_fund_tokens(&1), 0);
+		assert_noop!(
+			HousingFundModule::house_bidding(1, 1, 10, vec![(1, 10)]),
+			Error::<Test>::NotEnoughAvailableBalance
+		);
+		assert_noop!(
+			FundAssets::transfer(Origin::signed(1), FundAssetId::get(), 2, 10),
+			pallet_assets::Error::<Test, pallet_assets::Instance1>::BalanceLow
+		);
+
+		// the withdrawal waits for the fund
+		HousingFundModule::process_withdrawal_queue(Weight::MAX);
+		assert_eq!(HousingFundModule::withdrawal_queue(), vec![1]);
+
+		assert_ok!(HousingFundModule::contribute_to_fund(Origin::signed(2), 20));
+		HousingFundModule::on_idle(1, Weight::MAX);
+		assert!(HousingFundModule::withdrawal_queue().is_empty());
+		assert_eq!(HousingFundModule::withdrawal_requests(1), None);
+		assert_eq!(Balances::free_balance(1), 100);
+		assert_eq!(fund_tokens(1), 0);
+		assert_eq!(HousingFundModule::fund_balance().transferable, 20);
+	});
+}
+
+#[test]
+fn queued_withdrawals_should_be_processed_in_order() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&3, 100);
+		set_investor(1);
+		set_investor(2);
+		set_investor(3);
+		assert_ok!(HousingFundModule::contribute_to_fund(Origin::signed(1), 30));
+		assert_ok!(HousingFundModule::withdraw_fund(Origin::signed(1), 30));
+		assert_noop!(
+			HousingFundModule::cancel_withdrawal(Origin::signed(2)),
+			Error::<Test>::NoWithdrawalQueued
+		);
+		assert_ok!(HousingFundModule::contribute_to_fund(Origin::signed(2), 20));
+		assert_ok!(HousingFundModule::contribute_to_fund(Origin::signed(3), 10));
+
+		// the next withdrawals wait behind the queued one
+		assert_ok!(HousingFundModule::withdraw_fund(Origin::signed(2), 5));
+		assert_noop!(
+			HousingFundModule::withdraw_fund(Origin::signed(2), 5),
+			Error::<Test>::WithdrawalAlreadyQueued
+		);
+		assert_ok!(HousingFundModule::withdraw_fund(Origin::signed(3), 10));
+		assert_eq!(HousingFundModule::withdrawal_queue(), vec![1, 2, 3]);
+
+		// a queued withdrawal can be cancelled
+		assert_ok!(HousingFundModule::cancel_withdrawal(Origin::signed(2)));
+		assert_eq!(HousingFundModule::withdrawal_queue(), vec![1, 3]);
+		assert_eq!(HousingFundModule::available_fund_tokens(&2), 20);
+		assert_ok!(HousingFundModule::withdraw_fund(Origin::signed(2), 5));
+		assert_eq!(HousingFundModule::withdrawal_queue(), vec![1, 3, 2]);
+
+		// at most MaxWithdrawalsProcessedPerBlock withdrawals are executed in a block
+		HousingFundModule::on_idle(1, Weight::MAX);
+		assert_eq!(HousingFundModule::withdrawal_queue(), vec![2]);
+		assert_eq!(Balances::free_balance(1), 100);
+		assert_eq!(Balances::free_balance(3), 100);
+
+		HousingFundModule::on_idle(2, Weight::MAX);
+		assert!(HousingFundModule::withdrawal_queue().is_empty());
+		assert_eq!(Balances::free_balance(2), 85);
+		assert_eq!(HousingFundModule::fund_balance().transferable, 15);
+	});
+}
+
+#[test]
+fn withdrawal_should_not_be_queued_when_the_queue_is_full() {
+	new_test_ext().execute_with(|| {
+		for account_id in 1..=4 {
+			Balances::make_free_balance_be(&account_id, 100);
+			set_investor(account_id);
+			assert_ok!(HousingFundModule::contribute_to_fund(Origin::signed(account_id), 10));
+			if account_id < 4 {
+				assert_ok!(HousingFundModule::withdraw_fund(Origin::signed(account_id), 10));
+			}
+		}
+		assert_eq!(HousingFundModule::withdrawal_queue(), vec![1, 2, 3]);
+
+		// at most MaxQueuedWithdrawals withdrawals wait in the queue
+		assert_noop!(
+			HousingFundModule::withdraw_fund(Origin::signed(4), 10),
+			Error::<Test>::WithdrawalQueueFull
+		);
+		assert_eq!(HousingFundModule::withdrawal_requests(4), None);
+	});
+}
+
+#[test]
+fn failed_queued_withdrawal_should_leave_the_queue() {
+	new_test_ext().execute_with(|| {
+		set_investor(1);
+		set_investor(2);
+		assert_ok!(HousingFundModule::contribute_to_fund(Origin::signed(1), 30));
+		assert_ok!(HousingFundModule::withdraw_fund(Origin::signed(1), 30));
+		assert_ok!(HousingFundModule::contribute_to_fund(Origin::signed(2), 20));
+
+		// the fund account can't pay the withdrawal
+		Balances::make_free_balance_be(&HousingFundModule::fund_account_id(), 0);
+		HousingFundModule::on_idle(1, Weight::MAX);
+
+		assert!(HousingFundModule::withdrawal_queue().is_empty());
+		assert_eq!(HousingFundModule::withdrawal_requests(1), None);
+		assert_eq!(Balances::free_balance(1), 70);
+		assert_eq!(fund_tokens(1), 30);
+		assert_eq!(
+			System::events().last().unwrap().event,
+			mock::Event::HousingFundModule(crate::Event::WithdrawalFailed(1, 30, 1))
+		);
+	});
+}
+
+#[test]
+fn locked_contributions_should_be_withdrawable_once_unlocked() {
+	new_test_ext().execute_with(|| {
+		set_investor(1);
+		set_investor(2);
+		assert_ok!(HousingFundModule::contribute_to_fund(Origin::signed(2), 50));
+		assert_ok!(HousingFundModule::contribute_with_lock(
+			Origin::signed(1),
+			20,
+			LockPeriod::ThreeMonths
+		));
+		assert_eq!(
+			System::events().last().unwrap().event,
+			mock::Event::HousingFundModule(crate::Event::ContributionLocked(1, 20, 31))
+		);
+		assert_ok!(HousingFundModule::contribute_with_lock(
+			Origin::signed(1),
+			30,
+			LockPeriod::SixMonths
+		));
+		assert_eq!(HousingFundModule::priority_boost(&1), Percent::from_percent(25));
+		assert_eq!(HousingFundModule::priority_boost(&2), Percent::zero());
+
+		// the whole contribution is locked
+		assert_noop!(
+			HousingFundModule::withdraw_fund(Origin::signed(1), 10),
+			Error::<Test>::ContributionStillLocked
+		);
+
+		// the first lock-up expires after three months
+		System::set_block_number(31);
+		assert_eq!(HousingFundModule::priority_boost(&1), Percent::from_percent(25));
+		assert_noop!(
+			HousingFundModule::withdraw_fund(Origin::signed(1), 21),
+			Error::<Test>::ContributionStillLocked
+		);
+		assert_ok!(HousingFundModule::withdraw_fund(Origin::signed(1), 20));
+		assert_eq!(Balances::free_balance(1), 70);
+		assert_noop!(
+			HousingFundModule::withdraw_fund(Origin::signed(1), 1),
+			Error::<Test>::ContributionStillLocked
+		);
+
+		// the second one after six months
+		System::set_block_number(61);
+		assert_eq!(HousingFundModule::priority_boost(&1), Percent::zero());
+		assert_ok!(HousingFundModule::withdraw_fund(Origin::signed(1), 30));
+		assert_eq!(Balances::free_balance(1), 100);
+		assert_eq!(fund_tokens(1), 0);
+	});
+}
+
+#[test]
+fn reserved_locked_contributions_should_leave_the_lock() {
+	new_test_ext().execute_with(|| {
+		set_investor(1);
+		assert_ok!(HousingFundModule::contribute_to_fund(Origin::signed(1), 10));
+		assert_ok!(HousingFundModule::contribute_with_lock(
+			Origin::signed(1),
+			20,
+			LockPeriod::TwelveMonths
+		));
+
+		// the unlocked balance is reserved first, the rest is taken from the lock
+		assert_ok!(HousingFundModule::house_bidding(1, 1, 25, vec![(1, 25)]));
+		let contribution = HousingFundModule::contributions(1).unwrap();
+		assert_eq!(contribution.locked_amount(1), 5);
+		assert_ok!(HousingFundModule::validate_house_bidding(1, 1));
+		assert!(HousingFundModule::reserved_locks((1, 1), 1).is_empty());
+
+		// the new contributions are not held by the consumed lock
+		assert_ok!(HousingFundModule::contribute_to_fund(Origin::signed(1), 20));
+		assert_noop!(
+			HousingFundModule::withdraw_fund(Origin::signed(1), 21),
+			Error::<Test>::ContributionStillLocked
+		);
+		assert_ok!(HousingFundModule::withdraw_fund(Origin::signed(1), 20));
+		assert_eq!(HousingFundModule::priority_boost(&1), Percent::from_percent(50));
+	});
+}
+
+#[test]
+fn cancelled_reservations_should_restore_the_lock() {
+	new_test_ext().execute_with(|| {
+		set_investor(1);
+		assert_ok!(HousingFundModule::contribute_with_lock(
+			Origin::signed(1),
+			20,
+			LockPeriod::TwelveMonths
+		));
+
+		// the reservation takes the locked funds
+		assert_ok!(HousingFundModule::house_bidding(1, 1, 15, vec![(1, 15)]));
+		assert_eq!(HousingFundModule::reserved_locks((1, 1), 1), vec![(0, 15)]);
+		assert_eq!(HousingFundModule::contributions(1).unwrap().locked_amount(1), 5);
+
+		// the cancellation locks them again
+		assert_ok!(HousingFundModule::cancel_house_bidding(1, 1));
+		assert!(HousingFundModule::reserved_locks((1, 1), 1).is_empty());
+		assert_eq!(HousingFundModule::contributions(1).unwrap().locked_amount(1), 20);
+		assert_noop!(
+			HousingFundModule::withdraw_fund(Origin::signed(1), 10),
+			Error::<Test>::ContributionStillLocked
+		);
+	});
+}
+
+#[test]
+fn locked_fund_tokens_should_be_frozen() {
+	new_test_ext().execute_with(|| {
+		set_investor(1);
+		assert_ok!(HousingFundModule::contribute_to_fund(Origin::signed(1), 10));
+		assert_ok!(HousingFundModule::contribute_with_lock(
+			Origin::signed(1),
+			20,
+			LockPeriod::TwelveMonths
+		));
+		assert_eq!(HousingFundModule::locked_fund_tokens(&1), 20);
+
+		assert_noop!(
+			FundAssets::transfer(Origin::signed(1), FundAssetId::get(), 2, 20),
+			pallet_assets::Error::<Test, pallet_assets::Instance1>::BalanceLow
+		);
+		assert_ok!(FundAssets::transfer(Origin::signed(1), FundAssetId::get(), 2, 10));
+
+		// the tokens are released when the lock-up expires
+		System::set_block_number(121);
+		assert_eq!(HousingFundModule::locked_fund_tokens(&1), 0);
+		assert_ok!(FundAssets::transfer(Origin::signed(1), FundAssetId::get(), 2, 20));
+	});
+}
+
+#[test]
+fn set_operation_paused_should_halt_the_operation() {
+	new_test_ext().execute_with(|| {
+		set_investor(1);
+		set_investor(2);
+		assert_ok!(HousingFundModule::contribute_to_fund(Origin::signed(1), 30));
+
+		assert_noop!(
+			HousingFundModule::set_operation_paused(
+				Origin::signed(1),
+				PausableOperation::Contribute,
+				true
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(HousingFundModule::set_operation_paused(
+			Origin::root(),
+			PausableOperation::Contribute,
+			true
+		));
+		assert!(HousingFundModule::paused_operations(PausableOperation::Contribute));
+		assert_eq!(
+			System::events().last().unwrap().event,
+			mock::Event::HousingFundModule(crate::Event::OperationPaused(
+				PausableOperation::Contribute,
+				1
+			))
+		);
+
+		// only the paused operation is halted
+		assert_noop!(
+			HousingFundModule::contribute_to_fund(Origin::signed(2), 30),
+			Error::<Test>::OperationIsPaused
+		);
+		assert_noop!(
+			HousingFundModule::contribute_with_lock(Origin::signed(2), 30, LockPeriod::ThreeMonths),
+			Error::<Test>::OperationIsPaused
+		);
+		assert_ok!(HousingFundModule::withdraw_fund(Origin::signed(1), 10));
+
+		// setting the same state again doesn't emit an event
+		let events = System::events().len();
+		assert_ok!(HousingFundModule::set_operation_paused(
+			Origin::root(),
+			PausableOperation::Contribute,
+			true
+		));
+		assert_eq!(System::events().len(), events);
+
+		assert_ok!(HousingFundModule::set_operation_paused(
+			Origin::root(),
+			PausableOperation::Contribute,
+			false
+		));
+		assert_eq!(
+			System::events().last().unwrap().event,
+			mock::Event::HousingFundModule(crate::Event::OperationResumed(
+				PausableOperation::Contribute,
+				1
+			))
+		);
+		assert_ok!(HousingFundModule::contribute_to_fund(Origin::signed(2), 30));
+	});
+}
+
+#[test]
+fn paused_withdrawals_should_stay_in_the_queue() {
+	new_test_ext().execute_with(|| {
+		set_investor(1);
+		set_investor(2);
+		assert_ok!(HousingFundModule::contribute_to_fund(Origin::signed(1), 30));
+		assert_ok!(HousingFundModule::withdraw_fund(Origin::signed(1), 30));
+		assert_ok!(HousingFundModule::contribute_to_fund(Origin::signed(2), 50));
+
+		assert_ok!(HousingFundModule::set_operation_paused(
+			Origin::root(),
+			PausableOperation::Withdraw,
+			true
+		));
+		assert_noop!(
+			HousingFundModule::withdraw_fund(Origin::signed(2), 10),
+			Error::<Test>::OperationIsPaused
+		);
+		HousingFundModule::on_idle(1, Weight::MAX);
+		assert_eq!(HousingFundModule::withdrawal_queue(), vec![1]);
+
+		// the queue is processed once the withdrawals are resumed
+		assert_ok!(HousingFundModule::set_operation_paused(
+			Origin::root(),
+			PausableOperation::Withdraw,
+			false
+		));
+		HousingFundModule::on_idle(1, Weight::MAX);
+		assert!(HousingFundModule::withdrawal_queue().is_empty());
+		assert_eq!(Balances::free_balance(1), 100);
+	});
+}
+
+#[test]
+fn paused_house_bidding_should_fail() {
+	new_test_ext().execute_with(|| {
+		set_investor(1);
+		set_investor(2);
+		assert_ok!(HousingFundModule::contribute_to_fund(Origin::signed(1), 30));
+		assert_ok!(HousingFundModule::contribute_to_fund(Origin::signed(2), 30));
+
+		assert_ok!(HousingFundModule::set_operation_paused(
+			Origin::root(),
+			PausableOperation::HouseBidding,
+			true
+		));
+		assert_noop!(
+			HousingFundModule::house_bidding(1, 1, 40, vec![(1, 20), (2, 20)]),
+			Error::<Test>::OperationIsPaused
+		);
+	});
+}
+
+#[test]
+fn paused_calls_should_be_filtered() {
+	new_test_ext().execute_with(|| {
+		let contribute = crate::Call::<Test>::contribute_to_fund { amount: 10 };
+		let contribute_with_lock = crate::Call::<Test>::contribute_with_lock {
+			amount: 10,
+			lock_period: LockPeriod::SixMonths,
+		};
+		let withdraw = crate::Call::<Test>::withdraw_fund { amount: 10 };
+		let cancel = crate::Call::<Test>::cancel_withdrawal {};
+
+		assert!(!HousingFundModule::is_call_paused(&contribute));
+		assert_ok!(HousingFundModule::set_operation_paused(
+			Origin::root(),
+			PausableOperation::Contribute,
+			true
+		));
+		assert!(HousingFundModule::is_call_paused(&contribute));
+		assert!(HousingFundModule::is_call_paused(&contribute_with_lock));
+		assert!(!HousingFundModule::is_call_paused(&withdraw));
+
+		assert_ok!(HousingFundModule::set_operation_paused(
+			Origin::root(),
+			PausableOperation::Withdraw,
+			true
+		));
+		assert!(HousingFundModule::is_call_paused(&withdraw));
+		assert!(!HousingFundModule::is_call_paused(&cancel));
+	});
+}
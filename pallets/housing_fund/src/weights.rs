@@ -55,6 +55,13 @@ pub trait WeightInfo {
 	fn contribute_to_fund() -> Weight;
 	fn withdraw_fund() -> Weight;
 	fn house_bidding() -> Weight;
+	fn set_investor_preferences() -> Weight;
+	fn deposit_income() -> Weight;
+	fn claim_rewards() -> Weight;
+	fn sync_contribution() -> Weight;
+	fn cancel_withdrawal() -> Weight;
+	fn contribute_with_lock() -> Weight;
+	fn set_operation_paused() -> Weight;
 }
 
 /// Weights for pallet_housing_fund using the Substrate node and recommended hardware.
@@ -87,6 +94,66 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(12_u64))
 			.saturating_add(T::DbWeight::get().writes(13_u64))
 	}
+	// Storage: RoleModule InvestorLog (r:1 w:0)
+	// Storage: HousingFundModule Preferences (r:0 w:1)
+	fn set_investor_preferences() -> Weight {
+		Weight::from_ref_time(32_410_000_u64)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: System Account (r:2 w:2)
+	// Storage: HousingFundModule FundBalance (r:1 w:0)
+	// Storage: HousingFundModule UndistributedIncome (r:1 w:1)
+	// Storage: HousingFundModule RewardPerShare (r:1 w:1)
+	fn deposit_income() -> Weight {
+		Weight::from_ref_time(52_130_000_u64)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	// Storage: HousingFundModule Contributions (r:1 w:0)
+	// Storage: HousingFundModule RewardPerShare (r:1 w:0)
+	// Storage: HousingFundModule Rewards (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn claim_rewards() -> Weight {
+		Weight::from_ref_time(58_420_000_u64)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	// Storage: HousingFundModule Contributions (r:1 w:1)
+	// Storage: RoleModule InvestorLog (r:1 w:0)
+	// Storage: Assets Account (r:1 w:0)
+	// Storage: HousingFundModule RewardPerShare (r:1 w:0)
+	// Storage: HousingFundModule Rewards (r:1 w:1)
+	// Storage: HousingFundModule TotalStake (r:1 w:1)
+	fn sync_contribution() -> Weight {
+		Weight::from_ref_time(47_380_000_u64)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	// Storage: HousingFundModule WithdrawalRequests (r:1 w:1)
+	// Storage: HousingFundModule WithdrawalQueue (r:1 w:1)
+	fn cancel_withdrawal() -> Weight {
+		Weight::from_ref_time(29_140_000_u64)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	// Storage: RoleModule InvestorLog (r:1 w:0)
+	// Storage: HousingFundModule FundBalance (r:1 w:1)
+	// Storage: HousingFundModule Contributions (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:1 w:1)
+	fn contribute_with_lock() -> Weight {
+		Weight::from_ref_time(78_512_000_u64)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	// Storage: HousingFundModule PausedOperations (r:1 w:1)
+	fn set_operation_paused() -> Weight {
+		Weight::from_ref_time(21_360_000_u64)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -118,4 +185,64 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(12_u64))
 			.saturating_add(RocksDbWeight::get().writes(13_u64))
 	}
+	// Storage: RoleModule InvestorLog (r:1 w:0)
+	// Storage: HousingFundModule Preferences (r:0 w:1)
+	fn set_investor_preferences() -> Weight {
+		Weight::from_ref_time(32_410_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: System Account (r:2 w:2)
+	// Storage: HousingFundModule FundBalance (r:1 w:0)
+	// Storage: HousingFundModule UndistributedIncome (r:1 w:1)
+	// Storage: HousingFundModule RewardPerShare (r:1 w:1)
+	fn deposit_income() -> Weight {
+		Weight::from_ref_time(52_130_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	// Storage: HousingFundModule Contributions (r:1 w:0)
+	// Storage: HousingFundModule RewardPerShare (r:1 w:0)
+	// Storage: HousingFundModule Rewards (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn claim_rewards() -> Weight {
+		Weight::from_ref_time(58_420_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	// Storage: HousingFundModule Contributions (r:1 w:1)
+	// Storage: RoleModule InvestorLog (r:1 w:0)
+	// Storage: Assets Account (r:1 w:0)
+	// Storage: HousingFundModule RewardPerShare (r:1 w:0)
+	// Storage: HousingFundModule Rewards (r:1 w:1)
+	// Storage: HousingFundModule TotalStake (r:1 w:1)
+	fn sync_contribution() -> Weight {
+		Weight::from_ref_time(47_380_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	// Storage: HousingFundModule WithdrawalRequests (r:1 w:1)
+	// Storage: HousingFundModule WithdrawalQueue (r:1 w:1)
+	fn cancel_withdrawal() -> Weight {
+		Weight::from_ref_time(29_140_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	// Storage: RoleModule InvestorLog (r:1 w:0)
+	// Storage: HousingFundModule FundBalance (r:1 w:1)
+	// Storage: HousingFundModule Contributions (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:1 w:1)
+	fn contribute_with_lock() -> Weight {
+		Weight::from_ref_time(78_512_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	// Storage: HousingFundModule PausedOperations (r:1 w:1)
+	fn set_operation_paused() -> Weight {
+		Weight::from_ref_time(21_360_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
\ No newline at end of file
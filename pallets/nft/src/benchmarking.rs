@@ -28,10 +28,11 @@ fn create_account<T: Config>(name: &'static str, index: u32) -> T::AccountId {
 fn do_create_collection<T: Config>(caller: T::AccountId) {
 	let metadata: BoundedVec<_, _> =
 		vec![0; <T as UNQ::Config>::StringLimit::get() as usize].try_into().unwrap();
-	let coll_id = PossibleCollections::HOUSESTEST;
+	let coll_id = T::NftCollectionId::from(COLLECTION_ID_0);
 	assert!(NFT::Pallet::<T>::create_collection(
 		RawOrigin::Signed(caller).into(),
 		coll_id,
+		CollectionClass::new(AssetClass::HOUSE, 3),
 		metadata
 	)
 	.is_ok());
@@ -40,7 +41,7 @@ fn do_create_collection<T: Config>(caller: T::AccountId) {
 fn do_mint<T: Config>(caller: T::AccountId) {
 	let metadata: BoundedVec<_, _> =
 		vec![0; <T as UNQ::Config>::StringLimit::get() as usize].try_into().unwrap();
-	let coll_id = PossibleCollections::HOUSESTEST;
+	let coll_id = T::NftCollectionId::from(COLLECTION_ID_0);
 	assert!(NFT::Pallet::<T>::mint(RawOrigin::Signed(caller).into(), coll_id, metadata).is_ok());
 }
 
@@ -58,7 +59,7 @@ benchmarks! {
 		Roles::Pallet::<T>::account_approval(key_signed,caller.clone()).ok();
 
 		let metadata: BoundedVec<_, _> = vec![0; <T as UNQ::Config>::StringLimit::get() as usize].try_into().unwrap();
-	}: _(RawOrigin::Signed(caller.clone()), PossibleCollections::HOUSESTEST, metadata)
+	}: _(RawOrigin::Signed(caller.clone()), T::NftCollectionId::from(COLLECTION_ID_0), CollectionClass::new(AssetClass::HOUSE, 3), metadata)
 	verify {
 		assert_eq!(UNQ::Pallet::<T>::collection_owner(T::NftCollectionId::from(COLLECTION_ID_0).into()), Some(caller));
 	}
@@ -87,7 +88,7 @@ benchmarks! {
 
 		do_create_collection::<T>(caller1.clone());
 		let metadata: BoundedVec<_, _> = vec![0; <T as UNQ::Config>::StringLimit::get() as usize].try_into().unwrap();
-	}: _(RawOrigin::Signed(caller.clone()), PossibleCollections::HOUSESTEST, metadata)
+	}: _(RawOrigin::Signed(caller.clone()), T::NftCollectionId::from(COLLECTION_ID_0), metadata)
 	verify {
 		assert_eq!(UNQ::Pallet::<T>::owner(T::NftCollectionId::from(COLLECTION_ID_0).into(), T::NftItemId::from(0u32).into()), Some(caller));
 	}
@@ -118,7 +119,7 @@ benchmarks! {
 		let caller2 = create_account::<T>("caller2", 1);
 		let caller2_lookup = T::Lookup::unlookup(caller2.clone());
 		do_mint::<T>(caller3.clone());
-	}: _(RawOrigin::Root, PossibleCollections::HOUSESTEST, 0u32.into(), caller2_lookup)
+	}: _(RawOrigin::Root, T::NftCollectionId::from(COLLECTION_ID_0), 0u32.into(), caller2_lookup)
 	verify {
 		assert_eq!(UNQ::Pallet::<T>::owner(T::NftCollectionId::from(COLLECTION_ID_0).into(), T::NftItemId::from(0u32).into()), Some(caller2));
 	}
@@ -136,7 +137,7 @@ benchmarks! {
 		Roles::Pallet::<T>::account_approval(key_signed,caller.clone()).ok();
 
 		do_create_collection::<T>(caller.clone());
-	}: _(RawOrigin::Signed(caller), PossibleCollections::HOUSESTEST)
+	}: _(RawOrigin::Signed(caller), T::NftCollectionId::from(COLLECTION_ID_0))
 	verify {
 		assert_eq!(UNQ::Pallet::<T>::collections().count(), 0);
 	}
@@ -164,11 +165,41 @@ benchmarks! {
 
 		do_create_collection::<T>(caller1.clone());
 		do_mint::<T>(caller3.clone());
-	}: _(RawOrigin::Signed(caller1.clone()), PossibleCollections::HOUSESTEST, 0u32.into())
+	}: _(RawOrigin::Signed(caller1.clone()), T::NftCollectionId::from(COLLECTION_ID_0), 0u32.into())
 	verify {
 		assert_eq!(UNQ::Pallet::<T>::owned(&caller3).count(), 0);
 	}
 
+	set_attribute {
+		let caller1 = create_account::<T>("caller", 0);
+		let caller1_signed = <T as frame_system::Config>::Origin::from(RawOrigin::Signed(caller1.clone()));
+		let _ = Roles::Pallet::<T>::set_role(
+			caller1_signed.clone(),
+			caller1.clone(),
+			Roles::Accounts::SERVICER
+		);
+
+		let caller3 = create_account::<T>("caller3", 2);
+		let caller3_signed = <T as frame_system::Config>::Origin::from(RawOrigin::Signed(caller3.clone()));
+		let _ = Roles::Pallet::<T>::set_role(
+			caller3_signed.clone(),
+			caller3.clone(),
+			Roles::Accounts::SELLER
+		);
+		let key_account:T::AccountId = SUDO::Pallet::<T>::key().unwrap();
+		let key_signed = <T as frame_system::Config>::Origin::from(RawOrigin::Signed(key_account.clone()));
+		Roles::Pallet::<T>::account_approval(key_signed.clone(),caller1.clone()).ok();
+		Roles::Pallet::<T>::account_approval(key_signed,caller3.clone()).ok();
+
+		do_create_collection::<T>(caller1.clone());
+		do_mint::<T>(caller3.clone());
+		let key: AttributeKeyOf<T> = vec![0; <T as UNQ::Config>::KeyLimit::get() as usize].try_into().unwrap();
+		let value: AttributeValueOf<T> = vec![0; <T as UNQ::Config>::ValueLimit::get() as usize].try_into().unwrap();
+	}: _(RawOrigin::Signed(caller3), T::NftCollectionId::from(COLLECTION_ID_0), 0u32.into(), key.clone(), Some(value.clone()))
+	verify {
+		assert_eq!(NFT::Pallet::<T>::attribute(T::NftCollectionId::from(COLLECTION_ID_0), 0u32.into(), &key), Some(value.to_vec()));
+	}
+
 }
 
 #[cfg(test)]
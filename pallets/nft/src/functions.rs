@@ -1,4 +1,5 @@
 pub use super::*;
+use frame_support::traits::Locker;
 pub use frame_system::{pallet_prelude::OriginFor, RawOrigin};
 
 pub trait CreateTypedCollection<AccountId, CollectionId>: Create<AccountId> {
@@ -64,20 +65,23 @@ impl<T: Config> Pallet<T> {
 			|_details| Ok(()),
 		)?;
 
-		Items::<T>::insert(collection_id, item_id, ItemInfo { metadata });
+		Items::<T>::insert(collection_id, item_id, ItemInfo { metadata: metadata.clone() });
+		Self::record_metadata_revision(owner.clone(), collection_id, item_id, metadata);
 
 		Self::deposit_event(Event::ItemMinted { owner, collection_id, item_id });
 
 		Ok(())
 	}
 
+	/// Set the metadata of an item on behalf of `author`, recording the revision in its history
 	pub fn set_metadata(
-		owner: T::AccountId,
+		author: T::AccountId,
 		collection_id: T::NftCollectionId,
 		item_id: T::NftItemId,
 		metadata: BoundedVecOfUnq<T>,
 	) -> DispatchResult {
 		ensure!(Collections::<T>::contains_key(collection_id), Error::<T>::CollectionUnknown);
+		let owner = Self::collection_owner(collection_id).ok_or(Error::<T>::CollectionUnknown)?;
 		let origin = RawOrigin::Signed(owner);
 
 		let res0 = pallet_uniques::Pallet::<T>::set_metadata(
@@ -91,21 +95,128 @@ impl<T: Config> Pallet<T> {
 
 		Items::<T>::mutate(collection_id, item_id, |val| {
 			let mut val0 = val.clone().unwrap();
-			val0.metadata = metadata;
+			val0.metadata = metadata.clone();
 			*val = Some(val0);
 		});
+		Self::record_metadata_revision(author, collection_id, item_id, metadata);
 
 		//Self::deposit_event(Event::ItemMinted { owner, collection_id, item_id });
 
 		Ok(())
 	}
 
+	/// Add a revision to the metadata history of an item, dropping the oldest one when the
+	/// history is full
+	pub fn record_metadata_revision(
+		author: T::AccountId,
+		collection_id: T::NftCollectionId,
+		item_id: T::NftItemId,
+		metadata: BoundedVecOfUnq<T>,
+	) {
+		let revision = MetadataRevision {
+			metadata,
+			author,
+			block_number: <frame_system::Pallet<T>>::block_number(),
+		};
+		MetadataHistory::<T>::mutate(collection_id, item_id, |history| {
+			if !history.is_empty() && history.len() as u32 >= T::MaxMetadataRevisions::get() {
+				history.remove(0);
+			}
+			history.try_push(revision).ok();
+		});
+	}
+
+	/// Set the attribute `key` of an item in `pallet_uniques`, or clear it if `value` is `None`
+	/// The deposit of the attribute is reserved from `depositor`, the deposit of the previous value
+	/// being released to the account which paid it
+	pub fn do_set_attribute(
+		depositor: &T::AccountId,
+		collection_id: T::NftCollectionId,
+		item_id: T::NftItemId,
+		key: AttributeKeyOf<T>,
+		value: Option<AttributeValueOf<T>>,
+	) -> DispatchResult {
+		ensure!(Collections::<T>::contains_key(collection_id), Error::<T>::CollectionUnknown);
+		let previous = AttributeDeposits::<T>::take((collection_id, item_id, &key));
+		if let Some((who, deposit)) = &previous {
+			<T as pallet_uniques::Config>::Currency::unreserve(who, *deposit);
+		}
+		// the attributes are forced in `pallet_uniques`, which does not charge any deposit
+		let origin = RawOrigin::<T::AccountId>::Root;
+
+		match value {
+			Some(value) => {
+				if previous.is_none() {
+					AttributesCount::<T>::try_mutate(collection_id, item_id, |count| {
+						ensure!(
+							*count < T::MaxAttributesPerItem::get(),
+							Error::<T>::TooManyAttributes
+						);
+						*count = count.saturating_add(1);
+						Ok::<(), Error<T>>(())
+					})?;
+				}
+				let deposit = <T as pallet_uniques::Config>::DepositPerByte::get()
+					.saturating_mul(((key.len() + value.len()) as u32).into())
+					.saturating_add(<T as pallet_uniques::Config>::AttributeDepositBase::get());
+				<T as pallet_uniques::Config>::Currency::reserve(depositor, deposit)?;
+				AttributeDeposits::<T>::insert(
+					(collection_id, item_id, &key),
+					(depositor.clone(), deposit),
+				);
+				pallet_uniques::Pallet::<T>::set_attribute(
+					origin.into(),
+					collection_id.into(),
+					Some(item_id.into()),
+					key,
+					value,
+				)
+			},
+			None => {
+				if previous.is_some() {
+					AttributesCount::<T>::mutate(collection_id, item_id, |count| {
+						*count = count.saturating_sub(1)
+					});
+				}
+				pallet_uniques::Pallet::<T>::clear_attribute(
+					origin.into(),
+					collection_id.into(),
+					Some(item_id.into()),
+					key,
+				)
+			},
+		}
+	}
+
+	/// Release the deposits of the attributes of an item
+	fn release_attribute_deposits(collection_id: T::NftCollectionId, item_id: T::NftItemId) {
+		for (_key, (who, deposit)) in AttributeDeposits::<T>::drain_prefix((collection_id, item_id))
+		{
+			<T as pallet_uniques::Config>::Currency::unreserve(&who, deposit);
+		}
+		AttributesCount::<T>::remove(collection_id, item_id);
+	}
+
+	/// Value of the attribute `key` of an item
+	pub fn attribute(
+		collection_id: T::NftCollectionId,
+		item_id: T::NftItemId,
+		key: &[u8],
+	) -> Option<Vec<u8>> {
+		<pallet_uniques::Pallet<T> as Inspect<T::AccountId>>::attribute(
+			&collection_id.into(),
+			&item_id.into(),
+			key,
+		)
+	}
+
 	pub fn do_transfer(
 		collection_id: T::NftCollectionId,
 		item_id: T::NftItemId,
 		from: T::AccountId,
 		to: T::AccountId,
 	) -> DispatchResult {
+		ensure!(!Self::is_locked(collection_id, item_id), Error::<T>::Locked);
 		if from == to {
 			return Ok(())
 		}
@@ -126,6 +237,7 @@ impl<T: Config> Pallet<T> {
 		collection_id: T::NftCollectionId,
 		item_id: T::NftItemId,
 	) -> DispatchResult {
+		ensure!(!Self::is_locked(collection_id, item_id), Error::<T>::Locked);
 		pallet_uniques::Pallet::<T>::do_burn(
 			collection_id.into(),
 			item_id.into(),
@@ -133,12 +245,64 @@ impl<T: Config> Pallet<T> {
 		)?;
 
 		Items::<T>::remove(collection_id, item_id);
+		Self::release_attribute_deposits(collection_id, item_id);
 
 		Self::deposit_event(Event::ItemBurned { owner, collection_id, item_id });
 
 		Ok(())
 	}
 
+	/// Place a lock on an item, which cannot be transferred or burned and whose ownership tokens
+	/// are frozen until all its locks are released
+	pub fn set_lock(
+		collection_id: T::NftCollectionId,
+		item_id: T::NftItemId,
+		reason: LockReason,
+	) -> DispatchResult {
+		ensure!(Items::<T>::contains_key(collection_id, item_id), Error::<T>::ItemUnknown);
+		let mut locks = Locks::<T>::get(collection_id, item_id);
+		if locks.contains(&reason) {
+			return Ok(())
+		}
+		if locks.is_empty() {
+			T::TokenFreezer::freeze(collection_id, item_id)?;
+		}
+		locks.push(reason);
+		Locks::<T>::insert(collection_id, item_id, locks);
+
+		Self::deposit_event(Event::ItemLocked { collection_id, item_id, reason });
+
+		Ok(())
+	}
+
+	/// Release a lock of an item, the item and its ownership tokens being unfrozen with its last
+	/// lock
+	pub fn remove_lock(
+		collection_id: T::NftCollectionId,
+		item_id: T::NftItemId,
+		reason: LockReason,
+	) -> DispatchResult {
+		let mut locks = Locks::<T>::get(collection_id, item_id);
+		if !locks.contains(&reason) {
+			return Ok(())
+		}
+		locks.retain(|lock| *lock != reason);
+		if locks.is_empty() {
+			T::TokenFreezer::thaw(collection_id, item_id)?;
+			Locks::<T>::remove(collection_id, item_id);
+		} else {
+			Locks::<T>::insert(collection_id, item_id, locks);
+		}
+
+		Self::deposit_event(Event::ItemUnlocked { collection_id, item_id, reason });
+
+		Ok(())
+	}
+
+	pub fn is_locked(collection_id: T::NftCollectionId, item_id: T::NftItemId) -> bool {
+		Locks::<T>::contains_key(collection_id, item_id)
+	}
+
 	pub fn do_destroy_collection(
 		owner: T::AccountId,
 		collection_id: T::NftCollectionId,
@@ -156,6 +320,7 @@ impl<T: Config> Pallet<T> {
 			Some(owner.clone()),
 		)?;
 		Collections::<T>::remove(collection_id);
+		CollectionClasses::<T>::remove(collection_id);
 
 		Self::deposit_event(Event::CollectionDestroyed { owner, collection_id });
 		Ok(())
@@ -173,6 +338,27 @@ impl<T: Config> Inspect<T::AccountId> for Pallet<T> {
 	fn collection_owner(collection: &Self::CollectionId) -> Option<T::AccountId> {
 		Self::collection_owner(*collection)
 	}
+
+	fn attribute(
+		collection: &Self::CollectionId,
+		item: &Self::ItemId,
+		key: &[u8],
+	) -> Option<Vec<u8>> {
+		Self::attribute(*collection, *item, key)
+	}
+}
+
+// The locked items cannot be transferred through pallet_uniques either
+impl<T: Config>
+	Locker<<T as pallet_uniques::Config>::CollectionId, <T as pallet_uniques::Config>::ItemId>
+	for Pallet<T>
+{
+	fn is_locked(
+		collection: <T as pallet_uniques::Config>::CollectionId,
+		item: <T as pallet_uniques::Config>::ItemId,
+	) -> bool {
+		Self::is_locked(collection.into(), item.into())
+	}
 }
 
 impl<T: Config> InspectEnumerable<T::AccountId> for Pallet<T> {
@@ -19,7 +19,10 @@
 //! ### Dispatchable Functions
 
 //! * `create_collection` - Restricted to Servicer role, this function
-//! creates an NFT Collection of the given collection and sets its metadata
+//! registers an NFT Collection with the class of its assets, and sets its metadata
+
+//! * `set_collection_class` - Restricted to `ProtocolOrigin`, this function changes the class of a
+//!   registered Collection
 
 //! * `mint` - Restricted to Seller role, this function mints a NFT in the
 //! specified collection, and sets its metadata
@@ -32,6 +35,23 @@
 //! * `destroy_collection` - Restricted to Servicer role, this function Removes a Collection from
 //!   existence
 
+//! * `set_attribute` - Sets or clears a key/value attribute of an Item, the roles allowed to write
+//!   a key depending on the stage of the asset (see `NftPermission::can_set_attribute`)
+//!
+//! The collections are identified by their `NftCollectionId`, and their class (HOUSE, OFFICE or
+//! APPARTMENT assets, default maximum number of tenants, roles allowed to mint) is stored in
+//! `CollectionClasses`. The items of a collection are numbered from 0, `NextItemId` storing the
+//! id of the next item minted in each collection.
+//!
+//! The attributes of the items are stored by `pallet_uniques`, their deposits being reserved from
+//! the accounts which set them (`AttributeDeposits`), up to `MaxAttributesPerItem` attributes per
+//! item. Every metadata change of an item is kept in its `MetadataHistory`, up to
+//! `MaxMetadataRevisions` revisions.
+//!
+//! The onboarding, finalizer and asset management pallets place typed locks (`LockReason`) on
+//! the items for the duration of their processes. A locked item cannot be transferred or burned,
+//! and its ownership tokens are frozen by `TokenFreezer` until its last lock is released.
+
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
 #![allow(clippy::upper_case_acronyms)]
@@ -40,7 +60,7 @@ use codec::HasCompact;
 use frame_support::{
 	dispatch::DispatchResult,
 	ensure,
-	traits::{tokens::nonfungibles::*, Get},
+	traits::{tokens::nonfungibles::*, Currency, Get, ReservableCurrency},
 	transactional, BoundedVec,
 };
 use frame_system::{ensure_root, ensure_signed};
@@ -49,7 +69,7 @@ use pallet_uniques::DestroyWitness;
 pub use functions::*;
 pub use pallet_roles as Roles;
 use sp_runtime::{
-	traits::{AtLeast32BitUnsigned, StaticLookup, Zero},
+	traits::{AtLeast32BitUnsigned, CheckedAdd, One, Saturating, StaticLookup, Zero},
 	DispatchError,
 };
 use sp_std::boxed::Box;
@@ -58,6 +78,7 @@ use weights::WeightInfo;
 
 mod benchmarking;
 pub mod functions;
+pub mod migrations;
 pub mod types;
 pub mod weights;
 
@@ -70,6 +91,16 @@ mod tests;
 pub type BoundedVecOfUnq<T> = BoundedVec<u8, <T as pallet_uniques::Config>::StringLimit>;
 type CollectionInfoOf<T> = CollectionInfo<BoundedVecOfUnq<T>>;
 pub type ItemInfoOf<T> = ItemInfo<BoundedVec<u8, <T as pallet_uniques::Config>::StringLimit>>;
+pub type MetadataRevisionOf<T> = MetadataRevision<
+	<T as frame_system::Config>::AccountId,
+	<T as frame_system::Config>::BlockNumber,
+	BoundedVecOfUnq<T>,
+>;
+pub type AttributeKeyOf<T> = BoundedVec<u8, <T as pallet_uniques::Config>::KeyLimit>;
+pub type AttributeValueOf<T> = BoundedVec<u8, <T as pallet_uniques::Config>::ValueLimit>;
+pub type DepositBalanceOf<T> = <<T as pallet_uniques::Config>::Currency as Currency<
+	<T as frame_system::Config>::AccountId,
+>>::Balance;
 pub type Acc = Roles::Accounts;
 
 // Re-export pallet items so that they can be accessed from the crate namespace.
@@ -83,7 +114,11 @@ pub mod pallet {
 	use frame_support::{pallet_prelude::*, traits::EnsureOrigin};
 	use frame_system::pallet_prelude::OriginFor;
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
@@ -109,10 +144,22 @@ pub mod pallet {
 			+ Into<Self::ItemId>
 			+ From<Self::ItemId>;
 		type Permissions: NftPermission<Acc>;
+		/// Lifecycle stage of the items, deciding who can write their attributes
+		type ItemStages: ItemStageOf<Self::NftCollectionId, Self::NftItemId>;
+		/// Freezes the ownership tokens of the locked items
+		type TokenFreezer: TokenFreezer<Self::NftCollectionId, Self::NftItemId>;
 
 		/// Collection IDs reserved for runtime up to the following constant
 		#[pallet::constant]
 		type ReserveCollectionIdUpTo: Get<Self::NftCollectionId>;
+
+		/// Maximum number of metadata revisions kept for an item
+		#[pallet::constant]
+		type MaxMetadataRevisions: Get<u32>;
+
+		/// Maximum number of attributes set on an item
+		#[pallet::constant]
+		type MaxAttributesPerItem: Get<u32>;
 	}
 
 	#[pallet::storage]
@@ -133,21 +180,75 @@ pub mod pallet {
 		ItemInfoOf<T>,
 	>;
 
-	#[pallet::type_value]
-	///Initializing function for the approval waiting list
-	pub fn InitDefault<T: Config>() -> Vec<u32> {
-		vec![0, 0, 0, 0, 0, 0, 0]
-	}
+	#[pallet::storage]
+	#[pallet::getter(fn collection_class)]
+	/// Stores the class of each registered Collection
+	pub type CollectionClasses<T: Config> =
+		StorageMap<_, Twox64Concat, T::NftCollectionId, CollectionClass>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn next_item_id)]
+	/// Stores the id of the next Item minted in each Collection
+	pub type NextItemId<T: Config> =
+		StorageMap<_, Twox64Concat, T::NftCollectionId, T::NftItemId, ValueQuery>;
 
 	#[pallet::storage]
-	#[pallet::getter(fn itemid)]
-	/// Update Item ID
-	pub type ItemsCount<T: Config> = StorageValue<_, Vec<u32>, ValueQuery, InitDefault<T>>;
+	#[pallet::getter(fn metadata_history)]
+	/// Stores the last metadata revisions of each Item, the oldest first
+	pub type MetadataHistory<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::NftCollectionId,
+		Twox64Concat,
+		T::NftItemId,
+		BoundedVec<MetadataRevisionOf<T>, T::MaxMetadataRevisions>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn locks)]
+	/// Stores the locks placed on each Item by the running processes
+	/// A locked Item cannot be transferred or burned, and its ownership tokens are frozen
+	pub type Locks<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::NftCollectionId,
+		Twox64Concat,
+		T::NftItemId,
+		Vec<LockReason>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn attribute_deposit)]
+	/// Stores the deposit reserved for each attribute of an Item, and the account which paid it
+	pub type AttributeDeposits<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Twox64Concat, T::NftCollectionId>,
+			NMapKey<Twox64Concat, T::NftItemId>,
+			NMapKey<Blake2_128Concat, AttributeKeyOf<T>>,
+		),
+		(T::AccountId, DepositBalanceOf<T>),
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn attributes_count)]
+	/// Stores the number of attributes set on each Item
+	pub type AttributesCount<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::NftCollectionId,
+		Twox64Concat,
+		T::NftItemId,
+		u32,
+		ValueQuery,
+	>;
 
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		pub owner: Option<T::AccountId>,
-		pub collection_id: Option<u32>,
+		pub collections: Vec<(CollectionId, CollectionClass)>,
 		pub created_by: Option<Acc>,
 		pub metadata: Option<BoundedVecOfUnq<T>>,
 	}
@@ -156,7 +257,7 @@ pub mod pallet {
 		fn default() -> Self {
 			Self {
 				owner: Default::default(),
-				collection_id: Default::default(),
+				collections: Default::default(),
 				created_by: Default::default(),
 				metadata: Default::default(),
 			}
@@ -166,26 +267,28 @@ pub mod pallet {
 	#[pallet::genesis_build]
 	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
 		fn build(&self) {
-			let index = self.collection_id.unwrap();
-			for n in 0..index {
+			for (collection_id, class) in self.collections.iter() {
+				let collection_id: T::NftCollectionId = (*collection_id).into();
 				crate::Pallet::<T>::do_create_collection(
 					self.owner.clone().unwrap(),
-					n.into(),
+					collection_id,
 					self.created_by.unwrap(),
 					self.metadata.clone().unwrap(),
 				)
 				.ok();
+				CollectionClasses::<T>::insert(collection_id, class);
 			}
 		}
 	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
-		/// Creates an NFT Collection of the given Collection
+		/// Creates an NFT Collection with the given identifier and class
 		/// and sets its metadata
 		///
 		/// Parameters:
 		/// - `collection_id`: Identifier of a Collection
+		/// - `class`: Class of the assets of the Collection
 		/// - `metadata`: Arbitrary data about a Collection, e.g. IPFS hash or name
 		///
 		/// Emits CollectionCreated event
@@ -193,19 +296,43 @@ pub mod pallet {
 		#[transactional]
 		pub fn create_collection(
 			origin: OriginFor<T>,
-			collection_id: PossibleCollections,
+			collection_id: T::NftCollectionId,
+			class: CollectionClass,
 			metadata: BoundedVecOfUnq<T>,
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
-			let coll_id: CollectionId = collection_id.value();
 
 			//ensure!(T::ReserveCollectionIdUpTo::get() != coll_id.clone().into(),
 			// Error::<T>::IdReserved);
-			ensure!(!Self::is_id_reserved(coll_id.into()), Error::<T>::IdReserved);
+			ensure!(!Self::is_id_reserved(collection_id), Error::<T>::IdReserved);
 			let created_by = Roles::Pallet::<T>::get_roles(&sender).unwrap();
 			ensure!(T::Permissions::can_create(&created_by), Error::<T>::NotPermitted);
 
-			Self::do_create_collection(sender, coll_id.into(), created_by, metadata)?;
+			Self::do_create_collection(sender, collection_id, created_by, metadata)?;
+			CollectionClasses::<T>::insert(collection_id, class);
+
+			Ok(())
+		}
+
+		/// Changes the class of a registered Collection, triggered by `ProtocolOrigin`
+		///
+		/// Parameters:
+		/// - `collection_id`: Identifier of the Collection
+		/// - `class`: New class of the assets of the Collection
+		///
+		/// Emits CollectionClassSet event
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::create_collection())]
+		pub fn set_collection_class(
+			origin: OriginFor<T>,
+			collection_id: T::NftCollectionId,
+			class: CollectionClass,
+		) -> DispatchResult {
+			T::ProtocolOrigin::ensure_origin(origin)?;
+			ensure!(Collections::<T>::contains_key(collection_id), Error::<T>::CollectionUnknown);
+
+			CollectionClasses::<T>::insert(collection_id, class.clone());
+
+			Self::deposit_event(Event::CollectionClassSet { collection_id, class });
 
 			Ok(())
 		}
@@ -221,21 +348,22 @@ pub mod pallet {
 		#[transactional]
 		pub fn mint(
 			origin: OriginFor<T>,
-			collection_id: PossibleCollections,
+			collection_id: T::NftCollectionId,
 			metadata: BoundedVecOfUnq<T>,
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
-			let coll_id: CollectionId = collection_id.clone().value();
-			let idx = collection_id.value() as usize;
 			let created_by = Roles::Pallet::<T>::get_roles(&sender).unwrap();
-			let item_id = Self::itemid()[idx];
+			let item_id = Self::next_item_id(collection_id);
 
 			ensure!(T::Permissions::can_mint(&created_by), Error::<T>::NotPermitted);
+			let class =
+				Self::collection_class(collection_id).ok_or(Error::<T>::CollectionUnknown)?;
+			ensure!(class.allowed_roles.contains(&created_by), Error::<T>::NotPermitted);
 
-			Self::do_mint(sender, coll_id.into(), item_id.into(), metadata)?;
-			ItemsCount::<T>::mutate(|x| {
-				x[idx] += 1;
-			});
+			Self::do_mint(sender, collection_id, item_id, metadata)?;
+			let next_item_id =
+				item_id.checked_add(&One::one()).ok_or(Error::<T>::NoAvailableItemId)?;
+			NextItemId::<T>::insert(collection_id, next_item_id);
 
 			Ok(())
 		}
@@ -250,7 +378,7 @@ pub mod pallet {
 		#[transactional]
 		pub fn transfer(
 			origin: OriginFor<T>,
-			collection_id: PossibleCollections,
+			collection_id: T::NftCollectionId,
 			item_id: T::NftItemId,
 			dest: <T::Lookup as StaticLookup>::Source,
 		) -> DispatchResult {
@@ -258,11 +386,10 @@ pub mod pallet {
 			ensure_root(origin)?;
 
 			//Nft transfered from old to new owner
-			let coll_id: CollectionId = collection_id.value();
 			let dest = T::Lookup::lookup(dest)?;
-			let owner = Self::owner(coll_id.into(), item_id).ok_or(Error::<T>::ItemUnknown)?;
+			let owner = Self::owner(collection_id, item_id).ok_or(Error::<T>::ItemUnknown)?;
 
-			Self::do_transfer(coll_id.into(), item_id, owner, dest)?;
+			Self::do_transfer(collection_id, item_id, owner, dest)?;
 
 			Ok(())
 		}
@@ -276,17 +403,16 @@ pub mod pallet {
 		#[transactional]
 		pub fn burn(
 			origin: OriginFor<T>,
-			collection_id: PossibleCollections,
+			collection_id: T::NftCollectionId,
 			item_id: T::NftItemId,
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
 			let triggered_by = Roles::Pallet::<T>::get_roles(&sender).unwrap();
 			ensure!(T::Permissions::can_burn(&triggered_by), Error::<T>::NotPermitted);
 
-			let coll_id: CollectionId = collection_id.value();
-			let owner = Self::owner(coll_id.into(), item_id).ok_or(Error::<T>::ItemUnknown)?;
+			let owner = Self::owner(collection_id, item_id).ok_or(Error::<T>::ItemUnknown)?;
 
-			Self::do_burn(owner, coll_id.into(), item_id)?;
+			Self::do_burn(owner, collection_id, item_id)?;
 
 			Ok(())
 		}
@@ -299,23 +425,72 @@ pub mod pallet {
 		#[transactional]
 		pub fn destroy_collection(
 			origin: OriginFor<T>,
-			collection_id: PossibleCollections,
+			collection_id: T::NftCollectionId,
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
-			let coll_id: CollectionId = collection_id.value();
 
 			let created_by = Roles::Pallet::<T>::get_roles(&sender).unwrap();
 
 			ensure!(T::Permissions::can_destroy(&created_by), Error::<T>::NotPermitted);
 
-			Self::do_destroy_collection(sender, coll_id.into())?;
+			Self::do_destroy_collection(sender, collection_id)?;
+
+			Ok(())
+		}
+
+		/// Sets or clears an attribute of an Item
+		///
+		/// The caller role must be allowed to write `key` at the current stage of the asset, and
+		/// a seller must own the Item. The deposit of the attribute is reserved from the caller,
+		/// and an Item holds at most `MaxAttributesPerItem` attributes
+		///
+		/// Parameters:
+		/// - `collection_id`: The Collection of the Item
+		/// - `item_id`: The Item
+		/// - `key`: Key of the attribute
+		/// - `value`: Value of the attribute, the attribute being cleared if `None`
+		///
+		/// Emits AttributeSet event
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_attribute())]
+		#[transactional]
+		pub fn set_attribute(
+			origin: OriginFor<T>,
+			collection_id: T::NftCollectionId,
+			item_id: T::NftItemId,
+			key: AttributeKeyOf<T>,
+			value: Option<AttributeValueOf<T>>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let set_by = Roles::Pallet::<T>::get_roles(&sender).ok_or(Error::<T>::NotPermitted)?;
+			let owner = Self::owner(collection_id, item_id).ok_or(Error::<T>::ItemUnknown)?;
+			ensure!(set_by != Acc::SELLER || owner == sender, Error::<T>::NotPermitted);
+
+			let stage = T::ItemStages::item_stage(collection_id, item_id);
+			ensure!(
+				T::Permissions::can_set_attribute(&set_by, &key, stage),
+				Error::<T>::NotPermitted
+			);
+
+			Self::do_set_attribute(&sender, collection_id, item_id, key.clone(), value.clone())?;
+
+			Self::deposit_event(Event::AttributeSet {
+				who: sender,
+				collection_id,
+				item_id,
+				key,
+				value,
+			});
 
 			Ok(())
 		}
 	}
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		fn on_runtime_upgrade() -> Weight {
+			migrations::migrate::<T>()
+		}
+	}
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
@@ -339,6 +514,24 @@ pub mod pallet {
 		ItemBurned { owner: T::AccountId, collection_id: T::NftCollectionId, item_id: T::NftItemId },
 		/// A Collection was destroyed
 		CollectionDestroyed { owner: T::AccountId, collection_id: T::NftCollectionId },
+		/// The class of a Collection was changed
+		CollectionClassSet { collection_id: T::NftCollectionId, class: CollectionClass },
+		/// An attribute of an Item was set, or cleared if the value is `None`
+		AttributeSet {
+			who: T::AccountId,
+			collection_id: T::NftCollectionId,
+			item_id: T::NftItemId,
+			key: AttributeKeyOf<T>,
+			value: Option<AttributeValueOf<T>>,
+		},
+		/// A lock was placed on an Item
+		ItemLocked { collection_id: T::NftCollectionId, item_id: T::NftItemId, reason: LockReason },
+		/// A lock of an Item was released
+		ItemUnlocked {
+			collection_id: T::NftCollectionId,
+			item_id: T::NftItemId,
+			reason: LockReason,
+		},
 	}
 
 	#[pallet::error]
@@ -357,5 +550,9 @@ pub mod pallet {
 		NotPermitted,
 		/// ID reserved for runtime
 		IdReserved,
+		/// Item is locked by a running process
+		Locked,
+		/// Item already holds the maximum number of attributes
+		TooManyAttributes,
 	}
 }
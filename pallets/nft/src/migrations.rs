@@ -0,0 +1,68 @@
+pub use super::*;
+use frame_support::{
+	traits::{GetStorageVersion, StorageVersion},
+	weights::Weight,
+};
+
+/// Apply the migrations from the on chain storage version to the current one
+pub fn migrate<T: Config>() -> Weight {
+	let onchain_version = Pallet::<T>::on_chain_storage_version();
+	let mut weight = T::DbWeight::get().reads(1);
+	if onchain_version >= 1 {
+		return weight
+	}
+
+	weight = weight.saturating_add(v1::migrate::<T>());
+
+	StorageVersion::new(1).put::<Pallet<T>>();
+	weight.saturating_add(T::DbWeight::get().writes(1))
+}
+
+pub mod v1 {
+	use super::*;
+	use frame_support::pallet_prelude::{StorageValue, ValueQuery};
+
+	/// Number of items minted in each of the former hard-coded collections
+	#[frame_support::storage_alias]
+	pub type ItemsCount<T: Config> = StorageValue<Pallet<T>, Vec<u32>, ValueQuery>;
+
+	/// Class of the former hard-coded collections, 3 being the unused NONEXISTING
+	pub fn old_collection_class(collection_id: CollectionId) -> Option<AssetClass> {
+		match collection_id {
+			0 | 4 => Some(AssetClass::HOUSE),
+			1 | 5 => Some(AssetClass::OFFICE),
+			2 | 6 => Some(AssetClass::APPARTMENT),
+			_ => None,
+		}
+	}
+
+	/// Register the class of the existing collections 0 to 6,
+	/// and move their items count to `NextItemId`
+	pub fn migrate<T: Config>() -> Weight {
+		let mut reads: u64 = 1;
+		let mut writes: u64 = 1;
+		let items_count = ItemsCount::<T>::take();
+
+		for collection_id in 0..7u32 {
+			let asset_class = match old_collection_class(collection_id) {
+				Some(asset_class) => asset_class,
+				None => continue,
+			};
+			let nft_collection_id: T::NftCollectionId = collection_id.into();
+			reads += 1;
+			if !Collections::<T>::contains_key(nft_collection_id) {
+				continue
+			}
+			CollectionClasses::<T>::insert(nft_collection_id, CollectionClass::new(asset_class, 3));
+			writes += 1;
+
+			if let Some(count) = items_count.get(collection_id as usize) {
+				let next_item_id: T::NftItemId = (*count).into();
+				NextItemId::<T>::insert(nft_collection_id, next_item_id);
+				writes += 1;
+			}
+		}
+
+		T::DbWeight::get().reads_writes(reads, writes)
+	}
+}
@@ -40,6 +40,8 @@ frame_support::construct_runtime!(
 
 parameter_types! {
 	pub ReserveCollectionIdUpTo: u32 = 45;
+	pub const MaxMetadataRevisions: u32 = 3;
+	pub const MaxAttributesPerItem: u32 = 5;
 }
 
 #[derive(Eq, Copy, PartialEq, Clone)]
@@ -65,6 +67,26 @@ impl NftPermission<Acc> for NftTestPermissions {
 	fn has_deposit(created_by: &Acc) -> bool {
 		matches!(*created_by, Acc::SERVICER)
 	}
+
+	fn can_set_attribute(set_by: &Acc, key: &[u8], stage: ItemStage) -> bool {
+		NftPermissions::can_set_attribute(set_by, key, stage)
+	}
+}
+
+parameter_types! {
+	pub static FreezeFails: bool = false;
+}
+
+pub struct TestTokenFreezer;
+impl TokenFreezer<CollectionId, ItemId> for TestTokenFreezer {
+	fn freeze(_collection_id: CollectionId, _item_id: ItemId) -> DispatchResult {
+		ensure!(!FreezeFails::get(), DispatchError::Other("Unable to freeze"));
+		Ok(())
+	}
+	fn thaw(_collection_id: CollectionId, _item_id: ItemId) -> DispatchResult {
+		ensure!(!FreezeFails::get(), DispatchError::Other("Unable to thaw"));
+		Ok(())
+	}
 }
 
 impl Config for Test {
@@ -75,6 +97,10 @@ impl Config for Test {
 	type ProtocolOrigin = EnsureRoot<AccountId>;
 	type Permissions = NftTestPermissions;
 	type ReserveCollectionIdUpTo = ReserveCollectionIdUpTo;
+	type ItemStages = ();
+	type TokenFreezer = TestTokenFreezer;
+	type MaxMetadataRevisions = MaxMetadataRevisions;
+	type MaxAttributesPerItem = MaxAttributesPerItem;
 }
 
 parameter_types! {
@@ -94,7 +120,7 @@ impl pallet_uniques::Config for Test {
 	type ItemId = ItemId;
 	type Currency = Balances;
 	type ForceOrigin = EnsureRoot<AccountId>;
-	type Locker = ();
+	type Locker = NFT;
 	type CollectionDeposit = CollectionDeposit;
 	type ItemDeposit = ItemDeposit;
 	type MetadataDepositBase = UniquesMetadataDepositBase;
@@ -179,9 +205,12 @@ pub const DAVE: AccountId = AccountId::new([6u8; 32]);
 pub const EVE: AccountId = AccountId::new([5u8; 32]);
 pub const ACCOUNT_WITH_NO_BALANCE0: AccountId = AccountId::new([4u8; 32]);
 pub const ACCOUNT_WITH_NO_BALANCE1: AccountId = AccountId::new([7u8; 32]);
+pub const NOTARY: AccountId = AccountId::new([8u8; 32]);
 pub const BSX: Balance = 100_000_000_000;
 pub const HOUSES: <Test as pallet_uniques::Config>::CollectionId = 0;
 pub const HOUSESTEST: <Test as pallet_uniques::Config>::CollectionId = 4;
+pub const OFFICESTEST: <Test as pallet_uniques::Config>::CollectionId = 5;
+pub const APPARTMENTSTEST: <Test as pallet_uniques::Config>::CollectionId = 6;
 pub const HOUSESRES: <Test as pallet_uniques::Config>::CollectionId = 3;
 pub const COLLECTION_ID_RESERVED: <Test as pallet_uniques::Config>::CollectionId = 42;
 pub const ITEM_ID_0: <Test as pallet_uniques::Config>::ItemId = 0;
@@ -207,6 +236,7 @@ impl ExtBuilder {
 				(CHARLIE, 200_000 * BSX),
 				(DAVE, 150_000 * BSX),
 				(EVE, 150_000 * BSX),
+				(NOTARY, 150_000 * BSX),
 			],
 		}
 		.assimilate_storage(&mut t)
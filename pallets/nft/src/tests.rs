@@ -1,4 +1,4 @@
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{assert_noop, assert_ok, traits::GetStorageVersion};
 
 use super::*;
 use mock::*;
@@ -27,6 +27,8 @@ pub fn prep_roles() {
 	)
 	.ok();
 	RoleModule::account_approval(Origin::signed(ALICE), ACCOUNT_WITH_NO_BALANCE1).ok();
+	RoleModule::set_role(Origin::signed(NOTARY), NOTARY, Acc::NOTARY).ok();
+	RoleModule::account_approval(Origin::signed(ALICE), NOTARY).ok();
 }
 
 #[test]
@@ -37,7 +39,8 @@ fn create_collection_works() {
 		prep_roles();
 		assert_ok!(NFTPallet::create_collection(
 			Origin::signed(CHARLIE),
-			PossibleCollections::HOUSESTEST,
+			HOUSESTEST,
+			CollectionClass::new(AssetClass::HOUSE, 3),
 			metadata.clone()
 		));
 		assert_eq!(
@@ -56,7 +59,8 @@ fn create_collection_works() {
 		assert_noop!(
 			NFTPallet::create_collection(
 				Origin::signed(BOB),
-				PossibleCollections::OFFICESTEST,
+				OFFICESTEST,
+				CollectionClass::new(AssetClass::OFFICE, 3),
 				metadata.clone()
 			),
 			Error::<Test>::NotPermitted
@@ -66,7 +70,8 @@ fn create_collection_works() {
 		assert_noop!(
 			NFTPallet::create_collection(
 				Origin::signed(CHARLIE),
-				PossibleCollections::HOUSESTEST,
+				HOUSESTEST,
+				CollectionClass::new(AssetClass::HOUSE, 3),
 				metadata
 			),
 			pallet_uniques::Error::<Test>::InUse
@@ -83,20 +88,18 @@ fn mint_works() {
 
 		assert_ok!(NFTPallet::create_collection(
 			Origin::signed(CHARLIE),
-			PossibleCollections::HOUSESTEST,
+			HOUSESTEST,
+			CollectionClass::new(AssetClass::HOUSE, 3),
 			metadata.clone()
 		));
 		assert_ok!(NFTPallet::create_collection(
 			Origin::signed(CHARLIE),
-			PossibleCollections::OFFICESTEST,
+			OFFICESTEST,
+			CollectionClass::new(AssetClass::OFFICE, 3),
 			metadata.clone()
 		));
 
-		assert_ok!(NFTPallet::mint(
-			Origin::signed(BOB),
-			PossibleCollections::HOUSESTEST,
-			metadata.clone()
-		));
+		assert_ok!(NFTPallet::mint(Origin::signed(BOB), HOUSESTEST, metadata.clone()));
 		assert_eq!(
 			NFTPallet::items(HOUSESTEST, ITEM_ID_0).unwrap(),
 			ItemInfo { metadata: metadata.clone() }
@@ -111,17 +114,13 @@ fn mint_works() {
 
 		// not allowed in Permissions
 		assert_noop!(
-			NFTPallet::mint(
-				Origin::signed(DAVE),
-				PossibleCollections::OFFICESTEST,
-				metadata.clone()
-			),
+			NFTPallet::mint(Origin::signed(DAVE), OFFICESTEST, metadata.clone()),
 			Error::<Test>::NotPermitted
 		);
 
 		// invalid collection ID
 		assert_noop!(
-			NFTPallet::mint(Origin::signed(BOB), PossibleCollections::NONEXISTING, metadata),
+			NFTPallet::mint(Origin::signed(BOB), HOUSESRES, metadata),
 			Error::<Test>::CollectionUnknown
 		);
 	});
@@ -135,47 +134,31 @@ fn transfer_works() {
 		prep_roles();
 		assert_ok!(NFTPallet::create_collection(
 			Origin::signed(CHARLIE),
-			PossibleCollections::HOUSESTEST,
+			HOUSESTEST,
+			CollectionClass::new(AssetClass::HOUSE, 3),
 			metadata.clone()
 		));
 		assert_ok!(NFTPallet::create_collection(
 			Origin::signed(CHARLIE),
-			PossibleCollections::OFFICESTEST,
+			OFFICESTEST,
+			CollectionClass::new(AssetClass::OFFICE, 3),
 			metadata.clone()
 		));
-		assert_ok!(NFTPallet::mint(
-			Origin::signed(BOB),
-			PossibleCollections::HOUSESTEST,
-			metadata.clone()
-		));
-		assert_ok!(NFTPallet::mint(
-			Origin::signed(BOB),
-			PossibleCollections::OFFICESTEST,
-			metadata
-		));
+		assert_ok!(NFTPallet::mint(Origin::signed(BOB), HOUSESTEST, metadata.clone()));
+		assert_ok!(NFTPallet::mint(Origin::signed(BOB), OFFICESTEST, metadata));
 
 		let origin: Origin = frame_system::RawOrigin::Root.into();
 
 		// not existing
 		assert_noop!(
-			NFTPallet::transfer(
-				origin.clone(),
-				PossibleCollections::APPARTMENTSTEST,
-				ITEM_ID_0,
-				BOB
-			),
+			NFTPallet::transfer(origin.clone(), APPARTMENTSTEST, ITEM_ID_0, BOB),
 			Error::<Test>::ItemUnknown
 		);
 
-		assert_ok!(NFTPallet::transfer(
-			origin.clone(),
-			PossibleCollections::HOUSESTEST,
-			ITEM_ID_0,
-			DAVE
-		));
+		assert_ok!(NFTPallet::transfer(origin.clone(), HOUSESTEST, ITEM_ID_0, DAVE));
 		assert_eq!(NFTPallet::owner(HOUSESTEST, ITEM_ID_0).unwrap(), DAVE);
 
-		assert_ok!(NFTPallet::transfer(origin, PossibleCollections::HOUSESTEST, ITEM_ID_0, BOB));
+		assert_ok!(NFTPallet::transfer(origin, HOUSESTEST, ITEM_ID_0, BOB));
 		assert_eq!(NFTPallet::owner(HOUSESTEST, ITEM_ID_0).unwrap(), BOB);
 
 		expect_events(vec![crate::Event::ItemTransferred {
@@ -197,41 +180,27 @@ fn burn_works() {
 
 		assert_ok!(NFTPallet::create_collection(
 			Origin::signed(CHARLIE),
-			PossibleCollections::HOUSESTEST,
+			HOUSESTEST,
+			CollectionClass::new(AssetClass::HOUSE, 3),
 			metadata.clone()
 		));
 		assert_ok!(NFTPallet::create_collection(
 			Origin::signed(CHARLIE),
-			PossibleCollections::OFFICESTEST,
+			OFFICESTEST,
+			CollectionClass::new(AssetClass::OFFICE, 3),
 			metadata.clone()
 		));
-		assert_ok!(NFTPallet::mint(
-			Origin::signed(BOB),
-			PossibleCollections::HOUSESTEST,
-			metadata.clone()
-		));
-		assert_ok!(NFTPallet::mint(
-			Origin::signed(BOB),
-			PossibleCollections::HOUSESTEST,
-			metadata.clone()
-		));
-		assert_ok!(NFTPallet::mint(
-			Origin::signed(BOB),
-			PossibleCollections::OFFICESTEST,
-			metadata
-		));
+		assert_ok!(NFTPallet::mint(Origin::signed(BOB), HOUSESTEST, metadata.clone()));
+		assert_ok!(NFTPallet::mint(Origin::signed(BOB), HOUSESTEST, metadata.clone()));
+		assert_ok!(NFTPallet::mint(Origin::signed(BOB), OFFICESTEST, metadata));
 
 		// not allowed in Permissions
 		assert_noop!(
-			NFTPallet::burn(Origin::signed(BOB), PossibleCollections::OFFICESTEST, ITEM_ID_0),
+			NFTPallet::burn(Origin::signed(BOB), OFFICESTEST, ITEM_ID_0),
 			Error::<Test>::NotPermitted
 		);
 
-		assert_ok!(NFTPallet::burn(
-			Origin::signed(CHARLIE),
-			PossibleCollections::HOUSESTEST,
-			ITEM_ID_0
-		));
+		assert_ok!(NFTPallet::burn(Origin::signed(CHARLIE), HOUSESTEST, ITEM_ID_0));
 		assert!(!<Items<Test>>::contains_key(HOUSESTEST, ITEM_ID_0));
 
 		expect_events(vec![crate::Event::ItemBurned {
@@ -243,7 +212,7 @@ fn burn_works() {
 
 		// not existing
 		assert_noop!(
-			NFTPallet::burn(Origin::signed(CHARLIE), PossibleCollections::HOUSESTEST, ITEM_ID_0),
+			NFTPallet::burn(Origin::signed(CHARLIE), HOUSESTEST, ITEM_ID_0),
 			Error::<Test>::ItemUnknown
 		);
 	});
@@ -257,37 +226,32 @@ fn destroy_collection_works() {
 		prep_roles();
 		assert_ok!(NFTPallet::create_collection(
 			Origin::signed(CHARLIE),
-			PossibleCollections::HOUSESTEST,
+			HOUSESTEST,
+			CollectionClass::new(AssetClass::HOUSE, 3),
 			metadata.clone()
 		));
 		assert_ok!(NFTPallet::create_collection(
 			Origin::signed(CHARLIE),
-			PossibleCollections::OFFICESTEST,
+			OFFICESTEST,
+			CollectionClass::new(AssetClass::OFFICE, 3),
 			metadata.clone()
 		));
-		assert_ok!(NFTPallet::mint(Origin::signed(BOB), PossibleCollections::HOUSESTEST, metadata));
+		assert_ok!(NFTPallet::mint(Origin::signed(BOB), HOUSESTEST, metadata));
 
 		// existing item
 		assert_noop!(
-			NFTPallet::destroy_collection(Origin::signed(CHARLIE), PossibleCollections::HOUSESTEST),
+			NFTPallet::destroy_collection(Origin::signed(CHARLIE), HOUSESTEST),
 			Error::<Test>::TokenCollectionNotEmpty
 		);
-		assert_ok!(NFTPallet::burn(
-			Origin::signed(CHARLIE),
-			PossibleCollections::HOUSESTEST,
-			ITEM_ID_0
-		));
+		assert_ok!(NFTPallet::burn(Origin::signed(CHARLIE), HOUSESTEST, ITEM_ID_0));
 
 		// not allowed in Permissions
 		assert_noop!(
-			NFTPallet::destroy_collection(Origin::signed(BOB), PossibleCollections::OFFICESTEST),
+			NFTPallet::destroy_collection(Origin::signed(BOB), OFFICESTEST),
 			Error::<Test>::NotPermitted
 		);
 
-		assert_ok!(NFTPallet::destroy_collection(
-			Origin::signed(CHARLIE),
-			PossibleCollections::HOUSESTEST
-		));
+		assert_ok!(NFTPallet::destroy_collection(Origin::signed(CHARLIE), HOUSESTEST));
 		assert_eq!(NFTPallet::collections(HOUSESTEST), None);
 
 		expect_events(vec![crate::Event::CollectionDestroyed {
@@ -298,7 +262,7 @@ fn destroy_collection_works() {
 
 		// not existing
 		assert_noop!(
-			NFTPallet::destroy_collection(Origin::signed(CHARLIE), PossibleCollections::HOUSESTEST),
+			NFTPallet::destroy_collection(Origin::signed(CHARLIE), HOUSESTEST),
 			Error::<Test>::CollectionUnknown
 		);
 	});
@@ -318,7 +282,8 @@ fn deposit_works() {
 
 		assert_ok!(NFTPallet::create_collection(
 			Origin::signed(CHARLIE),
-			PossibleCollections::HOUSESTEST,
+			HOUSESTEST,
+			CollectionClass::new(AssetClass::HOUSE, 3),
 			metadata.clone()
 		));
 		assert_eq!(
@@ -330,10 +295,7 @@ fn deposit_works() {
 			collection_deposit
 		);
 
-		assert_ok!(NFTPallet::destroy_collection(
-			Origin::signed(CHARLIE),
-			PossibleCollections::HOUSESTEST
-		));
+		assert_ok!(NFTPallet::destroy_collection(Origin::signed(CHARLIE), HOUSESTEST));
 		assert_eq!(
 			<Test as pallet_uniques::Config>::Currency::free_balance(&CHARLIE),
 			initial_balance
@@ -343,18 +305,15 @@ fn deposit_works() {
 		// no deposit
 		assert_ok!(NFTPallet::create_collection(
 			Origin::signed(CHARLIE),
-			PossibleCollections::HOUSESTEST,
+			HOUSESTEST,
+			CollectionClass::new(AssetClass::HOUSE, 3),
 			metadata.clone()
 		));
-		assert_ok!(NFTPallet::mint(Origin::signed(BOB), PossibleCollections::HOUSESTEST, metadata));
+		assert_ok!(NFTPallet::mint(Origin::signed(BOB), HOUSESTEST, metadata));
 		assert_eq!(<Test as pallet_uniques::Config>::Currency::free_balance(&BOB), initial_balance);
 		assert_eq!(<Test as pallet_uniques::Config>::Currency::reserved_balance(&BOB), 0);
 
-		assert_ok!(NFTPallet::burn(
-			Origin::signed(CHARLIE),
-			PossibleCollections::HOUSESTEST,
-			ITEM_ID_0
-		));
+		assert_ok!(NFTPallet::burn(Origin::signed(CHARLIE), HOUSESTEST, ITEM_ID_0));
 		assert_eq!(<Test as pallet_uniques::Config>::Currency::free_balance(&BOB), initial_balance);
 		assert_eq!(<Test as pallet_uniques::Config>::Currency::reserved_balance(&BOB), 0);
 	})
@@ -406,3 +365,583 @@ fn is_id_reserved_should_return_true_when_id_is_from_reserved_range() {
 		"num == ReserveCollectionIdUpTo should be part of reserved CollectionId range"
 	);
 }
+
+#[test]
+fn mint_should_number_the_items_of_each_collection() {
+	ExtBuilder::default().build().execute_with(|| {
+		let metadata: BoundedVec<u8, <Test as pallet_uniques::Config>::StringLimit> =
+			b"metadata".to_vec().try_into().unwrap();
+		prep_roles();
+		assert_ok!(NFTPallet::create_collection(
+			Origin::signed(CHARLIE),
+			HOUSESTEST,
+			CollectionClass::new(AssetClass::HOUSE, 3),
+			metadata.clone()
+		));
+		assert_ok!(NFTPallet::create_collection(
+			Origin::signed(CHARLIE),
+			OFFICESTEST,
+			CollectionClass::new(AssetClass::OFFICE, 3),
+			metadata.clone()
+		));
+		assert_eq!(
+			NFTPallet::collection_class(HOUSESTEST),
+			Some(CollectionClass {
+				asset_class: AssetClass::HOUSE,
+				max_tenants: 3,
+				allowed_roles: vec![Acc::SELLER].try_into().unwrap()
+			})
+		);
+
+		assert_ok!(NFTPallet::mint(Origin::signed(BOB), HOUSESTEST, metadata.clone()));
+		assert_ok!(NFTPallet::mint(Origin::signed(BOB), HOUSESTEST, metadata.clone()));
+		assert_ok!(NFTPallet::mint(Origin::signed(BOB), OFFICESTEST, metadata));
+		assert_eq!(NFTPallet::owner(HOUSESTEST, ITEM_ID_1), Some(BOB));
+		assert_eq!(NFTPallet::owner(OFFICESTEST, ITEM_ID_0), Some(BOB));
+		assert_eq!(NFTPallet::next_item_id(HOUSESTEST), 2);
+		assert_eq!(NFTPallet::next_item_id(OFFICESTEST), 1);
+
+		// the items are not numbered again when the collection is created again
+		assert_ok!(NFTPallet::destroy_collection(Origin::signed(CHARLIE), OFFICESTEST));
+		assert_eq!(NFTPallet::collection_class(OFFICESTEST), None);
+		assert_eq!(NFTPallet::next_item_id(OFFICESTEST), 1);
+	});
+}
+
+#[test]
+fn set_collection_class_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let metadata: BoundedVec<u8, <Test as pallet_uniques::Config>::StringLimit> =
+			b"metadata".to_vec().try_into().unwrap();
+		prep_roles();
+		assert_ok!(NFTPallet::create_collection(
+			Origin::signed(CHARLIE),
+			HOUSESTEST,
+			CollectionClass::new(AssetClass::HOUSE, 3),
+			metadata.clone()
+		));
+
+		let class = CollectionClass {
+			asset_class: AssetClass::APPARTMENT,
+			max_tenants: 5,
+			allowed_roles: vec![Acc::SERVICER].try_into().unwrap(),
+		};
+		assert_ok!(NFTPallet::set_collection_class(Origin::root(), HOUSESTEST, class.clone()));
+		assert_eq!(NFTPallet::collection_class(HOUSESTEST), Some(class.clone()));
+		expect_events(vec![
+			crate::Event::CollectionClassSet { collection_id: HOUSESTEST, class }.into()
+		]);
+
+		// sellers are no longer allowed to mint in the collection
+		assert_noop!(
+			NFTPallet::mint(Origin::signed(BOB), HOUSESTEST, metadata),
+			Error::<Test>::NotPermitted
+		);
+	});
+}
+
+#[test]
+fn set_collection_class_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		prep_roles();
+		let class = CollectionClass::new(AssetClass::HOUSE, 3);
+		assert_noop!(
+			NFTPallet::set_collection_class(Origin::signed(CHARLIE), HOUSESTEST, class.clone()),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_noop!(
+			NFTPallet::set_collection_class(Origin::root(), HOUSESTEST, class),
+			Error::<Test>::CollectionUnknown
+		);
+	});
+}
+
+#[test]
+fn migration_should_register_the_class_of_existing_collections() {
+	ExtBuilder::default().build().execute_with(|| {
+		let metadata: BoundedVec<u8, <Test as pallet_uniques::Config>::StringLimit> =
+			b"metadata".to_vec().try_into().unwrap();
+		for collection_id in [HOUSES, HOUSESTEST, OFFICESTEST] {
+			assert_ok!(NFTPallet::do_create_collection(
+				CHARLIE,
+				collection_id,
+				Acc::SERVICER,
+				metadata.clone()
+			));
+		}
+		crate::migrations::v1::ItemsCount::<Test>::put(vec![2, 0, 0, 0, 3, 1, 0]);
+
+		crate::migrations::migrate::<Test>();
+		assert_eq!(NFTPallet::on_chain_storage_version(), 1);
+		assert_eq!(
+			NFTPallet::collection_class(HOUSES),
+			Some(CollectionClass::new(AssetClass::HOUSE, 3))
+		);
+		assert_eq!(
+			NFTPallet::collection_class(OFFICESTEST),
+			Some(CollectionClass::new(AssetClass::OFFICE, 3))
+		);
+		assert_eq!(NFTPallet::collection_class(APPARTMENTSTEST), None);
+		assert_eq!(NFTPallet::next_item_id(HOUSES), 2);
+		assert_eq!(NFTPallet::next_item_id(HOUSESTEST), 3);
+		assert_eq!(NFTPallet::next_item_id(OFFICESTEST), 1);
+		assert!(!crate::migrations::v1::ItemsCount::<Test>::exists());
+
+		// the migration is only applied once
+		NextItemId::<Test>::insert(HOUSES, 5);
+		crate::migrations::migrate::<Test>();
+		assert_eq!(NFTPallet::next_item_id(HOUSES), 5);
+	});
+}
+
+#[test]
+fn set_attribute_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let metadata: BoundedVec<u8, <Test as pallet_uniques::Config>::StringLimit> =
+			b"metadata".to_vec().try_into().unwrap();
+		let key: AttributeKeyOf<Test> = b"surface".to_vec().try_into().unwrap();
+		let value: AttributeValueOf<Test> = b"120m2".to_vec().try_into().unwrap();
+		let legal_key: AttributeKeyOf<Test> = b"legal:deed".to_vec().try_into().unwrap();
+		prep_roles();
+		assert_ok!(NFTPallet::create_collection(
+			Origin::signed(CHARLIE),
+			HOUSESTEST,
+			CollectionClass::new(AssetClass::HOUSE, 3),
+			metadata.clone()
+		));
+		assert_ok!(NFTPallet::mint(Origin::signed(BOB), HOUSESTEST, metadata));
+
+		assert_ok!(NFTPallet::set_attribute(
+			Origin::signed(BOB),
+			HOUSESTEST,
+			ITEM_ID_0,
+			key.clone(),
+			Some(value.clone())
+		));
+		assert_eq!(NFTPallet::attribute(HOUSESTEST, ITEM_ID_0, b"surface"), Some(value.to_vec()));
+		expect_events(vec![crate::Event::AttributeSet {
+			who: BOB,
+			collection_id: HOUSESTEST,
+			item_id: ITEM_ID_0,
+			key: key.clone(),
+			value: Some(value.clone()),
+		}
+		.into()]);
+
+		// legal attributes are written by notaries
+		assert_ok!(NFTPallet::set_attribute(
+			Origin::signed(NOTARY),
+			HOUSESTEST,
+			ITEM_ID_0,
+			legal_key,
+			Some(value)
+		));
+		assert_eq!(
+			NFTPallet::attribute(HOUSESTEST, ITEM_ID_0, b"legal:deed"),
+			Some(b"120m2".to_vec())
+		);
+
+		// the servicer clears the attribute
+		assert_ok!(NFTPallet::set_attribute(
+			Origin::signed(CHARLIE),
+			HOUSESTEST,
+			ITEM_ID_0,
+			key.clone(),
+			None
+		));
+		assert_eq!(NFTPallet::attribute(HOUSESTEST, ITEM_ID_0, b"surface"), None);
+		expect_events(vec![crate::Event::AttributeSet {
+			who: CHARLIE,
+			collection_id: HOUSESTEST,
+			item_id: ITEM_ID_0,
+			key,
+			value: None,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn set_attribute_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		let metadata: BoundedVec<u8, <Test as pallet_uniques::Config>::StringLimit> =
+			b"metadata".to_vec().try_into().unwrap();
+		let key: AttributeKeyOf<Test> = b"surface".to_vec().try_into().unwrap();
+		let value: AttributeValueOf<Test> = b"120m2".to_vec().try_into().unwrap();
+		let legal_key: AttributeKeyOf<Test> = b"legal:deed".to_vec().try_into().unwrap();
+		prep_roles();
+		assert_ok!(NFTPallet::create_collection(
+			Origin::signed(CHARLIE),
+			HOUSESTEST,
+			CollectionClass::new(AssetClass::HOUSE, 3),
+			metadata.clone()
+		));
+		assert_ok!(NFTPallet::mint(Origin::signed(BOB), HOUSESTEST, metadata));
+
+		// unknown item
+		assert_noop!(
+			NFTPallet::set_attribute(
+				Origin::signed(BOB),
+				HOUSESTEST,
+				ITEM_ID_1,
+				key.clone(),
+				Some(value.clone())
+			),
+			Error::<Test>::ItemUnknown
+		);
+
+		// the seller does not own the item
+		assert_noop!(
+			NFTPallet::set_attribute(
+				Origin::signed(ACCOUNT_WITH_NO_BALANCE1),
+				HOUSESTEST,
+				ITEM_ID_0,
+				key.clone(),
+				Some(value.clone())
+			),
+			Error::<Test>::NotPermitted
+		);
+
+		// investors cannot write attributes
+		assert_noop!(
+			NFTPallet::set_attribute(
+				Origin::signed(DAVE),
+				HOUSESTEST,
+				ITEM_ID_0,
+				key,
+				Some(value.clone())
+			),
+			Error::<Test>::NotPermitted
+		);
+
+		// legal attributes are reserved to notaries
+		assert_noop!(
+			NFTPallet::set_attribute(
+				Origin::signed(BOB),
+				HOUSESTEST,
+				ITEM_ID_0,
+				legal_key,
+				Some(value)
+			),
+			Error::<Test>::NotPermitted
+		);
+	});
+}
+
+#[test]
+fn attribute_deposit_should_be_reserved_from_the_caller() {
+	ExtBuilder::default().build().execute_with(|| {
+		let metadata: BoundedVec<u8, <Test as pallet_uniques::Config>::StringLimit> =
+			b"metadata".to_vec().try_into().unwrap();
+		let key: AttributeKeyOf<Test> = b"surface".to_vec().try_into().unwrap();
+		let value: AttributeValueOf<Test> = b"120m2".to_vec().try_into().unwrap();
+		prep_roles();
+		assert_ok!(NFTPallet::create_collection(
+			Origin::signed(CHARLIE),
+			HOUSESTEST,
+			CollectionClass::new(AssetClass::HOUSE, 3),
+			metadata.clone()
+		));
+		assert_ok!(NFTPallet::mint(Origin::signed(BOB), HOUSESTEST, metadata));
+		let bob_reserved = Balances::reserved_balance(BOB);
+		let charlie_reserved = Balances::reserved_balance(CHARLIE);
+		let deposit = AttributeDepositBase::get() + DepositPerByte::get() * 12;
+
+		// the seller pays the deposit of the attribute, not the collection owner
+		assert_ok!(NFTPallet::set_attribute(
+			Origin::signed(BOB),
+			HOUSESTEST,
+			ITEM_ID_0,
+			key.clone(),
+			Some(value.clone())
+		));
+		assert_eq!(Balances::reserved_balance(BOB), bob_reserved + deposit);
+		assert_eq!(Balances::reserved_balance(CHARLIE), charlie_reserved);
+		assert_eq!(
+			NFTPallet::attribute_deposit((HOUSESTEST, ITEM_ID_0, &key)),
+			Some((BOB, deposit))
+		);
+		assert_eq!(NFTPallet::attributes_count(HOUSESTEST, ITEM_ID_0), 1);
+
+		// the deposit of an overwritten value is released
+		assert_ok!(NFTPallet::set_attribute(
+			Origin::signed(CHARLIE),
+			HOUSESTEST,
+			ITEM_ID_0,
+			key.clone(),
+			Some(value)
+		));
+		assert_eq!(Balances::reserved_balance(BOB), bob_reserved);
+		assert_eq!(Balances::reserved_balance(CHARLIE), charlie_reserved + deposit);
+		assert_eq!(NFTPallet::attributes_count(HOUSESTEST, ITEM_ID_0), 1);
+
+		// and so is the deposit of a cleared attribute
+		assert_ok!(NFTPallet::set_attribute(
+			Origin::signed(CHARLIE),
+			HOUSESTEST,
+			ITEM_ID_0,
+			key.clone(),
+			None
+		));
+		assert_eq!(Balances::reserved_balance(CHARLIE), charlie_reserved);
+		assert_eq!(NFTPallet::attribute_deposit((HOUSESTEST, ITEM_ID_0, &key)), None);
+		assert_eq!(NFTPallet::attributes_count(HOUSESTEST, ITEM_ID_0), 0);
+	});
+}
+
+#[test]
+fn attributes_of_an_item_should_be_capped() {
+	ExtBuilder::default().build().execute_with(|| {
+		let metadata: BoundedVec<u8, <Test as pallet_uniques::Config>::StringLimit> =
+			b"metadata".to_vec().try_into().unwrap();
+		let key = |i: u32| -> AttributeKeyOf<Test> { vec![i as u8].try_into().unwrap() };
+		let value: AttributeValueOf<Test> = b"value".to_vec().try_into().unwrap();
+		prep_roles();
+		assert_ok!(NFTPallet::create_collection(
+			Origin::signed(CHARLIE),
+			HOUSESTEST,
+			CollectionClass::new(AssetClass::HOUSE, 3),
+			metadata.clone()
+		));
+		assert_ok!(NFTPallet::mint(Origin::signed(BOB), HOUSESTEST, metadata));
+		let bob_reserved = Balances::reserved_balance(BOB);
+
+		let max = MaxAttributesPerItem::get();
+		for i in 0..max {
+			assert_ok!(NFTPallet::set_attribute(
+				Origin::signed(BOB),
+				HOUSESTEST,
+				ITEM_ID_0,
+				key(i),
+				Some(value.clone())
+			));
+		}
+		assert_noop!(
+			NFTPallet::set_attribute(
+				Origin::signed(BOB),
+				HOUSESTEST,
+				ITEM_ID_0,
+				key(max),
+				Some(value.clone())
+			),
+			Error::<Test>::TooManyAttributes
+		);
+
+		// an existing attribute can still be overwritten, or cleared to make room for another
+		assert_ok!(NFTPallet::set_attribute(
+			Origin::signed(BOB),
+			HOUSESTEST,
+			ITEM_ID_0,
+			key(0),
+			Some(value.clone())
+		));
+		assert_ok!(NFTPallet::set_attribute(
+			Origin::signed(BOB),
+			HOUSESTEST,
+			ITEM_ID_0,
+			key(0),
+			None
+		));
+		assert_ok!(NFTPallet::set_attribute(
+			Origin::signed(BOB),
+			HOUSESTEST,
+			ITEM_ID_0,
+			key(max),
+			Some(value)
+		));
+		assert_eq!(NFTPallet::attributes_count(HOUSESTEST, ITEM_ID_0), max);
+
+		// burning the item releases the deposits of its attributes
+		assert_ok!(NFTPallet::burn(Origin::signed(CHARLIE), HOUSESTEST, ITEM_ID_0));
+		assert_eq!(Balances::reserved_balance(BOB), bob_reserved);
+		assert_eq!(NFTPallet::attributes_count(HOUSESTEST, ITEM_ID_0), 0);
+	});
+}
+
+#[test]
+fn can_set_attribute_should_depend_on_the_stage_of_the_item() {
+	let key = b"surface".as_slice();
+	let legal_key = b"legal:deed".as_slice();
+	for stage in [ItemStage::Editing, ItemStage::Listed, ItemStage::Closed] {
+		assert!(NftPermissions::can_set_attribute(&Acc::NOTARY, legal_key, stage));
+		assert!(!NftPermissions::can_set_attribute(&Acc::SERVICER, legal_key, stage));
+		assert!(!NftPermissions::can_set_attribute(&Acc::NOTARY, key, stage));
+		assert!(!NftPermissions::can_set_attribute(&Acc::INVESTOR, key, stage));
+	}
+	assert!(NftPermissions::can_set_attribute(&Acc::SELLER, key, ItemStage::Editing));
+	assert!(NftPermissions::can_set_attribute(&Acc::SERVICER, key, ItemStage::Editing));
+	assert!(!NftPermissions::can_set_attribute(&Acc::SELLER, key, ItemStage::Listed));
+	assert!(NftPermissions::can_set_attribute(&Acc::SERVICER, key, ItemStage::Listed));
+	assert!(!NftPermissions::can_set_attribute(&Acc::SERVICER, key, ItemStage::Closed));
+}
+
+#[test]
+fn metadata_history_should_keep_the_last_revisions() {
+	ExtBuilder::default().build().execute_with(|| {
+		let metadata = |m: &[u8]| -> BoundedVecOfUnq<Test> { m.to_vec().try_into().unwrap() };
+		prep_roles();
+		assert_ok!(NFTPallet::create_collection(
+			Origin::signed(CHARLIE),
+			HOUSESTEST,
+			CollectionClass::new(AssetClass::HOUSE, 3),
+			metadata(b"collection")
+		));
+		assert_ok!(NFTPallet::mint(Origin::signed(BOB), HOUSESTEST, metadata(b"v0")));
+		assert_eq!(
+			NFTPallet::metadata_history(HOUSESTEST, ITEM_ID_0).to_vec(),
+			vec![MetadataRevision { metadata: metadata(b"v0"), author: BOB, block_number: 1 }]
+		);
+
+		System::set_block_number(2);
+		assert_ok!(NFTPallet::set_metadata(BOB, HOUSESTEST, ITEM_ID_0, metadata(b"v1")));
+		System::set_block_number(3);
+		assert_ok!(NFTPallet::set_metadata(CHARLIE, HOUSESTEST, ITEM_ID_0, metadata(b"v2")));
+		System::set_block_number(4);
+		assert_ok!(NFTPallet::set_metadata(BOB, HOUSESTEST, ITEM_ID_0, metadata(b"v3")));
+
+		// only the last MaxMetadataRevisions revisions are kept
+		assert_eq!(
+			NFTPallet::metadata_history(HOUSESTEST, ITEM_ID_0).to_vec(),
+			vec![
+				MetadataRevision { metadata: metadata(b"v1"), author: BOB, block_number: 2 },
+				MetadataRevision { metadata: metadata(b"v2"), author: CHARLIE, block_number: 3 },
+				MetadataRevision { metadata: metadata(b"v3"), author: BOB, block_number: 4 },
+			]
+		);
+		assert_eq!(NFTPallet::items(HOUSESTEST, ITEM_ID_0).unwrap().metadata, metadata(b"v3"));
+	});
+}
+
+#[test]
+fn locked_item_should_not_be_transferred_or_burned() {
+	ExtBuilder::default().build().execute_with(|| {
+		let metadata: BoundedVec<u8, <Test as pallet_uniques::Config>::StringLimit> =
+			b"metadata".to_vec().try_into().unwrap();
+		prep_roles();
+		assert_ok!(NFTPallet::create_collection(
+			Origin::signed(CHARLIE),
+			HOUSESTEST,
+			CollectionClass::new(AssetClass::HOUSE, 3),
+			metadata.clone()
+		));
+		assert_ok!(NFTPallet::mint(Origin::signed(BOB), HOUSESTEST, metadata));
+
+		for reason in [LockReason::Onboarding, LockReason::Finalising, LockReason::Referendum(0)] {
+			assert_ok!(NFTPallet::set_lock(HOUSESTEST, ITEM_ID_0, reason));
+			assert_eq!(NFTPallet::locks(HOUSESTEST, ITEM_ID_0), vec![reason]);
+			expect_events(vec![crate::Event::ItemLocked {
+				collection_id: HOUSESTEST,
+				item_id: ITEM_ID_0,
+				reason,
+			}
+			.into()]);
+
+			assert_noop!(
+				NFTPallet::transfer(Origin::root(), HOUSESTEST, ITEM_ID_0, DAVE),
+				Error::<Test>::Locked
+			);
+			assert_noop!(
+				NFTPallet::burn(Origin::signed(CHARLIE), HOUSESTEST, ITEM_ID_0),
+				Error::<Test>::Locked
+			);
+			// the item cannot be transferred through pallet_uniques either
+			assert_noop!(
+				pallet_uniques::Pallet::<Test>::do_transfer(
+					HOUSESTEST,
+					ITEM_ID_0,
+					DAVE,
+					|_, _| Ok(())
+				),
+				pallet_uniques::Error::<Test>::Locked
+			);
+
+			assert_ok!(NFTPallet::remove_lock(HOUSESTEST, ITEM_ID_0, reason));
+			assert!(!NFTPallet::is_locked(HOUSESTEST, ITEM_ID_0));
+			expect_events(vec![crate::Event::ItemUnlocked {
+				collection_id: HOUSESTEST,
+				item_id: ITEM_ID_0,
+				reason,
+			}
+			.into()]);
+		}
+
+		assert_ok!(NFTPallet::transfer(Origin::root(), HOUSESTEST, ITEM_ID_0, DAVE));
+		assert_ok!(NFTPallet::burn(Origin::signed(CHARLIE), HOUSESTEST, ITEM_ID_0));
+	});
+}
+
+#[test]
+fn item_should_stay_locked_until_its_last_lock_is_released() {
+	ExtBuilder::default().build().execute_with(|| {
+		let metadata: BoundedVec<u8, <Test as pallet_uniques::Config>::StringLimit> =
+			b"metadata".to_vec().try_into().unwrap();
+		prep_roles();
+		assert_ok!(NFTPallet::create_collection(
+			Origin::signed(CHARLIE),
+			HOUSESTEST,
+			CollectionClass::new(AssetClass::HOUSE, 3),
+			metadata.clone()
+		));
+		assert_ok!(NFTPallet::mint(Origin::signed(BOB), HOUSESTEST, metadata));
+
+		// unknown item
+		assert_noop!(
+			NFTPallet::set_lock(HOUSESTEST, ITEM_ID_1, LockReason::Onboarding),
+			Error::<Test>::ItemUnknown
+		);
+
+		assert_ok!(NFTPallet::set_lock(HOUSESTEST, ITEM_ID_0, LockReason::Referendum(0)));
+		assert_ok!(NFTPallet::set_lock(HOUSESTEST, ITEM_ID_0, LockReason::Referendum(1)));
+		assert_ok!(NFTPallet::set_lock(HOUSESTEST, ITEM_ID_0, LockReason::Referendum(1)));
+		assert_eq!(
+			NFTPallet::locks(HOUSESTEST, ITEM_ID_0),
+			vec![LockReason::Referendum(0), LockReason::Referendum(1)]
+		);
+
+		// releasing a lock which is not placed has no effect
+		assert_ok!(NFTPallet::remove_lock(HOUSESTEST, ITEM_ID_0, LockReason::Onboarding));
+		assert_ok!(NFTPallet::remove_lock(HOUSESTEST, ITEM_ID_0, LockReason::Referendum(0)));
+		assert_eq!(NFTPallet::locks(HOUSESTEST, ITEM_ID_0), vec![LockReason::Referendum(1)]);
+		assert_noop!(
+			NFTPallet::transfer(Origin::root(), HOUSESTEST, ITEM_ID_0, DAVE),
+			Error::<Test>::Locked
+		);
+
+		assert_ok!(NFTPallet::remove_lock(HOUSESTEST, ITEM_ID_0, LockReason::Referendum(1)));
+		assert!(!Locks::<Test>::contains_key(HOUSESTEST, ITEM_ID_0));
+		assert_ok!(NFTPallet::transfer(Origin::root(), HOUSESTEST, ITEM_ID_0, DAVE));
+	});
+}
+
+#[test]
+fn locks_should_not_change_when_the_tokens_cannot_be_frozen() {
+	ExtBuilder::default().build().execute_with(|| {
+		let metadata: BoundedVec<u8, <Test as pallet_uniques::Config>::StringLimit> =
+			b"metadata".to_vec().try_into().unwrap();
+		prep_roles();
+		assert_ok!(NFTPallet::create_collection(
+			Origin::signed(CHARLIE),
+			HOUSESTEST,
+			CollectionClass::new(AssetClass::HOUSE, 3),
+			metadata.clone()
+		));
+		assert_ok!(NFTPallet::mint(Origin::signed(BOB), HOUSESTEST, metadata));
+
+		FreezeFails::set(true);
+		assert_noop!(
+			NFTPallet::set_lock(HOUSESTEST, ITEM_ID_0, LockReason::Onboarding),
+			DispatchError::Other("Unable to freeze")
+		);
+		assert!(!NFTPallet::is_locked(HOUSESTEST, ITEM_ID_0));
+
+		FreezeFails::set(false);
+		assert_ok!(NFTPallet::set_lock(HOUSESTEST, ITEM_ID_0, LockReason::Onboarding));
+
+		FreezeFails::set(true);
+		assert_noop!(
+			NFTPallet::remove_lock(HOUSESTEST, ITEM_ID_0, LockReason::Onboarding),
+			DispatchError::Other("Unable to thaw")
+		);
+		assert_eq!(NFTPallet::locks(HOUSESTEST, ITEM_ID_0), vec![LockReason::Onboarding]);
+	});
+}
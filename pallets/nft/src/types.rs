@@ -1,5 +1,4 @@
 pub use super::*;
-use enum_iterator::Sequence;
 pub use frame_support::inherent::Vec;
 use frame_support::pallet_prelude::*;
 #[cfg(feature = "std")]
@@ -9,28 +8,38 @@ pub use scale_info::{prelude::vec, TypeInfo};
 
 /// NFT Collection ID
 pub type CollectionId = u32;
-#[derive(Clone, Encode, Decode, PartialEq, Eq, TypeInfo, Copy, Sequence)]
+
+/// Class of the assets of a collection
+#[derive(Clone, Encode, Decode, PartialEq, Eq, TypeInfo, Copy)]
 #[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
-pub enum PossibleCollections {
-	HOUSES,
-	OFFICES,
-	APPARTMENTS,
-	HOUSESTEST,
-	OFFICESTEST,
-	APPARTMENTSTEST,
-	NONEXISTING,
+pub enum AssetClass {
+	HOUSE,
+	OFFICE,
+	APPARTMENT,
 }
 
-impl PossibleCollections {
-	pub fn value(&self) -> CollectionId {
-		match *self {
-			PossibleCollections::HOUSES => 0,
-			PossibleCollections::OFFICES => 1,
-			PossibleCollections::APPARTMENTS => 2,
-			PossibleCollections::HOUSESTEST => 4,
-			PossibleCollections::OFFICESTEST => 5,
-			PossibleCollections::APPARTMENTSTEST => 6,
-			PossibleCollections::NONEXISTING => 3,
+/// Maximum number of roles allowed to mint in a collection, each role being listed once
+pub type MaxAllowedRoles = ConstU32<7>;
+
+/// Typed metadata of a collection, set when it is registered
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct CollectionClass {
+	/// Class of the assets of the collection
+	pub asset_class: AssetClass,
+	/// Maximum number of tenants of an asset of the collection, unless set by its seller
+	pub max_tenants: u8,
+	/// Roles allowed to mint in the collection
+	pub allowed_roles: BoundedVec<Acc, MaxAllowedRoles>,
+}
+
+impl CollectionClass {
+	/// Class of a collection of `asset_class` assets minted by sellers
+	pub fn new(asset_class: AssetClass, max_tenants: u8) -> Self {
+		CollectionClass {
+			asset_class,
+			max_tenants,
+			allowed_roles: BoundedVec::truncate_from(vec![Acc::SELLER]),
 		}
 	}
 }
@@ -52,12 +61,78 @@ pub struct ItemInfo<BoundedVec> {
 	pub metadata: BoundedVec,
 }
 
+/// A revision of the metadata of an item
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct MetadataRevision<AccountId, BlockNumber, BoundedVec> {
+	/// Metadata set by the revision
+	pub metadata: BoundedVec,
+	/// Account which set the metadata
+	pub author: AccountId,
+	/// Block at which the metadata was set
+	pub block_number: BlockNumber,
+}
+
+/// Stage of an item in the lifecycle of its asset
+#[derive(Clone, Encode, Decode, PartialEq, Eq, TypeInfo, Copy)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+pub enum ItemStage {
+	/// The asset is edited by its seller
+	Editing,
+	/// The asset is reviewed, voted on or sold
+	Listed,
+	/// The asset was sold or withdrawn
+	Closed,
+}
+
+/// Provides the lifecycle stage of an item
+pub trait ItemStageOf<CollectionId, ItemId> {
+	fn item_stage(collection_id: CollectionId, item_id: ItemId) -> ItemStage;
+}
+
+impl<CollectionId, ItemId> ItemStageOf<CollectionId, ItemId> for () {
+	fn item_stage(_collection_id: CollectionId, _item_id: ItemId) -> ItemStage {
+		ItemStage::Editing
+	}
+}
+
+/// Process holding a lock on an item
+#[derive(Clone, Encode, Decode, PartialEq, Eq, TypeInfo, Copy)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+pub enum LockReason {
+	/// The asset is listed by the onboarding pallet, from its appraisal to its sale
+	Onboarding,
+	/// The purchase of the asset is checked by a notary in the finalizer pallet
+	Finalising,
+	/// A referendum of the asset owners is running in the asset management pallet
+	Referendum(u32),
+}
+
+/// Freezes the ownership tokens of the locked items
+pub trait TokenFreezer<CollectionId, ItemId> {
+	fn freeze(collection_id: CollectionId, item_id: ItemId) -> DispatchResult;
+	fn thaw(collection_id: CollectionId, item_id: ItemId) -> DispatchResult;
+}
+
+impl<CollectionId, ItemId> TokenFreezer<CollectionId, ItemId> for () {
+	fn freeze(_collection_id: CollectionId, _item_id: ItemId) -> DispatchResult {
+		Ok(())
+	}
+	fn thaw(_collection_id: CollectionId, _item_id: ItemId) -> DispatchResult {
+		Ok(())
+	}
+}
+
+/// Prefix of the attribute keys reserved to notaries, e.g. `legal:deed`
+pub const LEGAL_KEY_PREFIX: &[u8] = b"legal:";
+
 pub trait NftPermission<Acc> {
 	fn can_create(created_by: &Acc) -> bool;
 	fn can_mint(created_by: &Acc) -> bool;
 	fn can_burn(created_by: &Acc) -> bool;
 	fn can_destroy(created_by: &Acc) -> bool;
 	fn has_deposit(created_by: &Acc) -> bool;
+	fn can_set_attribute(set_by: &Acc, key: &[u8], stage: ItemStage) -> bool;
 }
 
 #[derive(Encode, Decode, Eq, Copy, PartialEq, Clone, RuntimeDebug, TypeInfo)]
@@ -84,4 +159,17 @@ impl NftPermission<Acc> for NftPermissions {
 	fn has_deposit(created_by: &Acc) -> bool {
 		matches!(*created_by, Acc::SERVICER)
 	}
+
+	/// Legal attributes are written by notaries at any stage, the other attributes by the seller
+	/// and the servicer while the asset is edited, and by the servicer only once it is listed
+	fn can_set_attribute(set_by: &Acc, key: &[u8], stage: ItemStage) -> bool {
+		if key.starts_with(LEGAL_KEY_PREFIX) {
+			return matches!(*set_by, Acc::NOTARY)
+		}
+		match stage {
+			ItemStage::Editing => matches!(*set_by, Acc::SELLER | Acc::SERVICER),
+			ItemStage::Listed => matches!(*set_by, Acc::SERVICER),
+			ItemStage::Closed => false,
+		}
+	}
 }
@@ -57,6 +57,7 @@ pub trait WeightInfo {
 	fn transfer() -> Weight;
 	fn destroy_collection() -> Weight;
 	fn burn() -> Weight;
+	fn set_attribute() -> Weight;
 }
 
 /// Weights for pallet_nft using the Substrate node and recommended hardware.
@@ -67,13 +68,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: System Account (r:1 w:1)
 	// Storage: Uniques ClassAccount (r:0 w:1)
 	// Storage: NftModule Collections (r:0 w:1)
+	// Storage: NftModule CollectionClasses (r:0 w:1)
 	fn create_collection() -> Weight {
 		Weight::from_ref_time(64_040_000_u64)
 			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	// Storage: RoleModule AccountsRolesLog (r:1 w:0)
-	// Storage: NftModule ItemsCount (r:1 w:1)
+	// Storage: NftModule NextItemId (r:1 w:1)
+	// Storage: NftModule CollectionClasses (r:1 w:0)
 	// Storage: NftModule Collections (r:1 w:0)
 	// Storage: Uniques Asset (r:1 w:1)
 	// Storage: Uniques Class (r:1 w:1)
@@ -81,18 +84,20 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: System Account (r:1 w:1)
 	// Storage: Uniques Account (r:0 w:1)
 	// Storage: NftModule Items (r:0 w:1)
+	// Storage: NftModule MetadataHistory (r:1 w:1)
 	fn mint() -> Weight {
 		Weight::from_ref_time(97_272_000_u64)
 			.saturating_add(T::DbWeight::get().reads(7_u64))
 			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
+	// Storage: NftModule Locks (r:1 w:0)
 	// Storage: Uniques Asset (r:1 w:1)
 	// Storage: Uniques Class (r:1 w:0)
 	// Storage: Uniques Account (r:0 w:2)
 	// Storage: Uniques ItemPriceOf (r:0 w:1)
 	fn transfer() -> Weight {
 		Weight::from_ref_time(58_109_000_u64)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	// Storage: RoleModule AccountsRolesLog (r:1 w:0)
@@ -103,12 +108,14 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Uniques ClassMetadataOf (r:0 w:1)
 	// Storage: Uniques CollectionMaxSupply (r:0 w:1)
 	// Storage: NftModule Collections (r:0 w:1)
+	// Storage: NftModule CollectionClasses (r:0 w:1)
 	fn destroy_collection() -> Weight {
 		Weight::from_ref_time(92_182_000_u64)
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	// Storage: RoleModule AccountsRolesLog (r:1 w:0)
+	// Storage: NftModule Locks (r:1 w:0)
 	// Storage: Uniques Asset (r:1 w:1)
 	// Storage: Uniques Class (r:1 w:1)
 	// Storage: System Account (r:1 w:1)
@@ -117,7 +124,20 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: NftModule Items (r:0 w:1)
 	fn burn() -> Weight {
 		Weight::from_ref_time(78_597_000_u64)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	// Storage: RoleModule AccountsRolesLog (r:1 w:0)
+	// Storage: Uniques Asset (r:1 w:0)
+	// Storage: Uniques Class (r:1 w:1)
+	// Storage: Uniques Attribute (r:1 w:1)
+	// Storage: NftModule Collections (r:1 w:0)
+	// Storage: NftModule AttributeDeposits (r:1 w:1)
+	// Storage: NftModule AttributesCount (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn set_attribute() -> Weight {
+		Weight::from_ref_time(61_350_000_u64)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
 			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 }
@@ -129,13 +149,15 @@ impl WeightInfo for () {
 	// Storage: System Account (r:1 w:1)
 	// Storage: Uniques ClassAccount (r:0 w:1)
 	// Storage: NftModule Collections (r:0 w:1)
+	// Storage: NftModule CollectionClasses (r:0 w:1)
 	fn create_collection() -> Weight {
 		Weight::from_ref_time(64_040_000_u64)
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	// Storage: RoleModule AccountsRolesLog (r:1 w:0)
-	// Storage: NftModule ItemsCount (r:1 w:1)
+	// Storage: NftModule NextItemId (r:1 w:1)
+	// Storage: NftModule CollectionClasses (r:1 w:0)
 	// Storage: NftModule Collections (r:1 w:0)
 	// Storage: Uniques Asset (r:1 w:1)
 	// Storage: Uniques Class (r:1 w:1)
@@ -143,18 +165,20 @@ impl WeightInfo for () {
 	// Storage: System Account (r:1 w:1)
 	// Storage: Uniques Account (r:0 w:1)
 	// Storage: NftModule Items (r:0 w:1)
+	// Storage: NftModule MetadataHistory (r:1 w:1)
 	fn mint() -> Weight {
 		Weight::from_ref_time(97_272_000_u64)
 			.saturating_add(RocksDbWeight::get().reads(7_u64))
 			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
+	// Storage: NftModule Locks (r:1 w:0)
 	// Storage: Uniques Asset (r:1 w:1)
 	// Storage: Uniques Class (r:1 w:0)
 	// Storage: Uniques Account (r:0 w:2)
 	// Storage: Uniques ItemPriceOf (r:0 w:1)
 	fn transfer() -> Weight {
 		Weight::from_ref_time(58_109_000_u64)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	// Storage: RoleModule AccountsRolesLog (r:1 w:0)
@@ -165,12 +189,14 @@ impl WeightInfo for () {
 	// Storage: Uniques ClassMetadataOf (r:0 w:1)
 	// Storage: Uniques CollectionMaxSupply (r:0 w:1)
 	// Storage: NftModule Collections (r:0 w:1)
+	// Storage: NftModule CollectionClasses (r:0 w:1)
 	fn destroy_collection() -> Weight {
 		Weight::from_ref_time(92_182_000_u64)
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	// Storage: RoleModule AccountsRolesLog (r:1 w:0)
+	// Storage: NftModule Locks (r:1 w:0)
 	// Storage: Uniques Asset (r:1 w:1)
 	// Storage: Uniques Class (r:1 w:1)
 	// Storage: System Account (r:1 w:1)
@@ -179,7 +205,20 @@ impl WeightInfo for () {
 	// Storage: NftModule Items (r:0 w:1)
 	fn burn() -> Weight {
 		Weight::from_ref_time(78_597_000_u64)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	// Storage: RoleModule AccountsRolesLog (r:1 w:0)
+	// Storage: Uniques Asset (r:1 w:0)
+	// Storage: Uniques Class (r:1 w:1)
+	// Storage: Uniques Attribute (r:1 w:1)
+	// Storage: NftModule Collections (r:1 w:0)
+	// Storage: NftModule AttributeDeposits (r:1 w:1)
+	// Storage: NftModule AttributesCount (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn set_attribute() -> Weight {
+		Weight::from_ref_time(61_350_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
 			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 }
\ No newline at end of file
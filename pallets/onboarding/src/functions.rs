@@ -8,15 +8,20 @@ pub use frame_support::{
 	ensure,
 	inherent::Vec,
 	traits::{
-		tokens::nonfungibles::*, BalanceStatus, Currency, ExistenceRequirement, Get,
+		tokens::nonfungibles::*, BalanceStatus, Currency, EnsureOrigin, ExistenceRequirement, Get,
 		ReservableCurrency,
 	},
-	transactional, BoundedVec,
+	transactional,
+	weights::Weight,
+	BoundedVec,
 };
-pub use frame_system::{ensure_signed, pallet_prelude::*, RawOrigin};
+pub use frame_system::{ensure_root, ensure_signed, pallet_prelude::*, RawOrigin};
 
 pub use sp_runtime::{
-	traits::{AccountIdConversion, AtLeast32BitUnsigned, Saturating, StaticLookup, Zero},
+	traits::{
+		AccountIdConversion, AtLeast32BitUnsigned, Saturating, StaticLookup, UniqueSaturatedInto,
+		Zero,
+	},
 	DispatchError, Percent,
 };
 pub use sp_std::boxed::Box;
@@ -24,42 +29,153 @@ pub use sp_std::boxed::Box;
 impl<T: Config> Pallet<T> {
 	pub fn create_asset(
 		origin: OriginFor<T>,
-		collection: NftCollectionOf,
+		collection_id: T::NftCollectionId,
 		metadata: Nft::BoundedVecOfUnq<T>,
 		new_price: Option<BalanceOf<T>>,
 		item_id: T::NftItemId,
 		max_tenants: u8,
 	) -> DispatchResult {
-		let coll_id: T::NftCollectionId = collection.clone().value().into();
 		// Mint nft
-		Nft::Pallet::<T>::mint(origin.clone(), collection, metadata).ok();
+		Nft::Pallet::<T>::mint(origin.clone(), collection_id, metadata)?;
 
-		let infos = Nft::Items::<T>::get(coll_id, item_id).unwrap();
+		let infos = Nft::Items::<T>::get(collection_id, item_id)
+			.ok_or(Error::<T>::CollectionOrItemUnknown)?;
 		// Set asset price
-		Self::price(origin, collection, item_id, new_price).ok();
+		Self::price(origin, collection_id, item_id, new_price).ok();
 		// Create Asset
-		Asset::<T>::new(coll_id, item_id, infos, new_price,max_tenants).ok();
+		Asset::<T>::new(collection_id, item_id, infos, new_price,max_tenants).ok();
 
 		Ok(())
 	}
 
-	pub fn status(collection: NftCollectionOf, item_id: T::NftItemId, status: AssetStatus) {
-		let collection_id: T::NftCollectionId = collection.clone().value().into();
-		Houses::<T>::mutate(collection_id, item_id, |val| {
-			let mut asset = val.clone().unwrap();
-			asset.status = status;
-			*val = Some(asset);
+	/// Move an asset to `status` on behalf of `origin`, following the lifecycle transitions
+	/// The transition is recorded in the status history of the asset
+	pub fn do_change_status(
+		collection_id: T::NftCollectionId,
+		item_id: T::NftItemId,
+		status: AssetStatus,
+		origin: TransitionOrigin,
+	) -> DispatchResult {
+		let mut asset =
+			Houses::<T>::get(collection_id, item_id).ok_or(Error::<T>::CollectionOrItemUnknown)?;
+		ensure!(
+			asset.status.transition_origin(status) == Some(origin),
+			Error::<T>::InvalidTransition
+		);
+		if status == AssetStatus::VOTING {
+			let appraisal = asset.appraisal.as_ref().ok_or(Error::<T>::AppraisalNeeded)?;
+			let price = Prices::<T>::get(collection_id, item_id).ok_or(Error::<T>::NotForSale)?;
+			ensure!(
+				appraisal.within_band(price, T::AppraisalBand::get()),
+				Error::<T>::PriceOutsideAppraisalBand
+			);
+		}
+
+		let block_number = <frame_system::Pallet<T>>::block_number();
+		let change = StatusChange::<T> { from: asset.status, to: status, origin, block_number };
+		StatusHistory::<T>::mutate(collection_id, item_id, |history| {
+			if !history.is_empty() && history.len() as u32 >= T::MaxStatusHistory::get() {
+				history.remove(0);
+			}
+			history.try_push(change).ok();
 		});
+		asset.status = status;
+		Houses::<T>::insert(collection_id, item_id, asset);
+		// The NFT is locked from the appraisal of the asset to its sale
+		if matches!(
+			status,
+			AssetStatus::APPRAISING |
+				AssetStatus::REVIEWING |
+				AssetStatus::VOTING |
+				AssetStatus::ONBOARDED
+		) {
+			Nft::Pallet::<T>::set_lock(collection_id, item_id, Nft::LockReason::Onboarding)?;
+		} else {
+			Nft::Pallet::<T>::remove_lock(collection_id, item_id, Nft::LockReason::Onboarding)?;
+		}
+		T::OnStatusChange::on_status_change(collection_id, item_id, status)?;
+
+		Self::deposit_event(Event::AssetStatusChanged {
+			changed_to: status,
+			collection: collection_id,
+			item: item_id,
+		});
+
+		Ok(())
+	}
+
+	/// Move an asset through `statuses`, each transition being made on behalf of its origin
+	/// The asset is appraised at its price when it leaves APPRAISING
+	#[cfg(any(test, feature = "std", feature = "runtime-benchmarks"))]
+	pub fn move_asset(
+		collection_id: T::NftCollectionId,
+		item_id: T::NftItemId,
+		statuses: &[AssetStatus],
+	) -> DispatchResult {
+		for status in statuses {
+			let mut asset = Houses::<T>::get(collection_id, item_id)
+				.ok_or(Error::<T>::CollectionOrItemUnknown)?;
+			let origin =
+				asset.status.transition_origin(*status).ok_or(Error::<T>::InvalidTransition)?;
+			if origin == TransitionOrigin::Appraiser {
+				asset.appraisal = Some(Appraisal {
+					appraiser: Self::account_id(),
+					value: asset.price.unwrap_or_default(),
+					report_hash: Default::default(),
+					fee: Zero::zero(),
+					block_number: <frame_system::Pallet<T>>::block_number(),
+				});
+				Houses::<T>::insert(collection_id, item_id, asset);
+			}
+			Self::do_change_status(collection_id, item_id, *status, origin)?;
+		}
+		Ok(())
+	}
+
+	/// Check that the details of the asset are set and valid for the class of its collection
+	pub fn ensure_valid_details(
+		collection_id: T::NftCollectionId,
+		item_id: T::NftItemId,
+	) -> DispatchResult {
+		let details =
+			Details::<T>::get(collection_id, item_id).ok_or(Error::<T>::MissingPropertyDetail)?;
+		let class = Nft::Pallet::<T>::collection_class(collection_id)
+			.ok_or(Error::<T>::CollectionOrItemUnknown)?;
+		details.validate(class.asset_class)
+	}
+
+	/// Check that `origin` can act as `transition_origin` through `change_status`
+	pub fn ensure_transition_origin(
+		origin: OriginFor<T>,
+		transition_origin: TransitionOrigin,
+	) -> DispatchResult {
+		match transition_origin {
+			TransitionOrigin::Council => {
+				T::CouncilOrigin::ensure_origin(origin)?;
+			},
+			TransitionOrigin::Democracy => {
+				T::DemocracyOrigin::ensure_origin(origin)?;
+			},
+			// The seller transitions go through `submit_awaiting` and the finalizer pallet, the
+			// notary ones through the finalizer pallet, the bidding ones through the bidding
+			// pallet, the appraisal ones through `submit_appraisal` and the expiry ones through
+			// `expire_listings`
+			TransitionOrigin::Seller |
+			TransitionOrigin::Notary |
+			TransitionOrigin::Bidding |
+			TransitionOrigin::Appraiser |
+			TransitionOrigin::Expiry => return Err(DispatchError::BadOrigin),
+		}
+		Ok(())
 	}
 
 	pub fn price(
 		origin: OriginFor<T>,
-		collection: NftCollectionOf,
+		collection_id: T::NftCollectionId,
 		item_id: T::NftItemId,
 		new_price: Option<BalanceOf<T>>,
 	) -> DispatchResult {
 		let sender = ensure_signed(origin)?;
-		let collection_id: T::NftCollectionId = collection.clone().value().into();
 
 		ensure!(
 			pallet_nft::Pallet::<T>::owner(collection_id, item_id) == Some(sender.clone()),
@@ -78,16 +194,14 @@ impl<T: Config> Pallet<T> {
 	}
 
 	///Execute the buy/sell transaction
-
+	#[transactional]
 	pub fn do_buy(
-		collection: NftCollectionOf,
+		collection_id: T::NftCollectionId,
 		item_id: T::NftItemId,
 		buyer: T::AccountId,
 		_infos: Asset<T>,
 	) -> DispatchResult {
-		let collection_id: T::NftCollectionId = collection.clone().value().into();
 		let origin_root: OriginFor<T> = frame_system::RawOrigin::Root.into();
-		let origin_buyer: OriginFor<T> = frame_system::RawOrigin::Signed(buyer.clone()).into();
 
 		//Check that the house item exists and has the correct status
 		ensure!(
@@ -102,8 +216,6 @@ impl<T: Config> Pallet<T> {
 		let owner = Nft::Pallet::<T>::owner(collection_id, item_id)
 			.ok_or(Error::<T>::CollectionOrItemUnknown)?;
 		ensure!(buyer != owner, Error::<T>::BuyFromSelf);
-		let balance = <T as Config>::Currency::reserved_balance(&owner);
-		let _returned = <T as Config>::Currency::unreserve(&owner, balance);
 
 		// The reserved funds in Housing Fund from the house bidding are unreserved for the transfer
 		// transaction
@@ -119,7 +231,18 @@ impl<T: Config> Pallet<T> {
 			ExistenceRequirement::KeepAlive,
 		)?;
 		let to = T::Lookup::unlookup(buyer.clone());
-		Nft::Pallet::<T>::transfer(origin_root, collection, item_id, to)?;
+		Nft::Pallet::<T>::transfer(origin_root, collection_id, item_id, to)?;
+		// The proposal fee is released, minus the sale fee
+		let fee = Self::slash_proposal_deposit(&owner, collection_id, item_id, T::SaleFee::get());
+		let released = Self::release_proposal_deposit(&owner, collection_id, item_id);
+		Self::deposit_event(Event::ProposalFeeSettled {
+			who: owner.clone(),
+			collection: collection_id,
+			item: item_id,
+			released,
+			fee,
+		});
+
 		Self::deposit_event(Event::TokenSold {
 			owner,
 			buyer,
@@ -129,7 +252,102 @@ impl<T: Config> Pallet<T> {
 		});
 
 		//change status
-		Self::change_status(origin_buyer, collection, item_id, AssetStatus::PURCHASED).ok();
+		Self::do_change_status(
+			collection_id,
+			item_id,
+			AssetStatus::PURCHASED,
+			TransitionOrigin::Bidding,
+		)?;
+
+		Ok(())
+	}
+
+	/// Send the `slash` part of the proposal fee of an asset to the fees account, the rest of it
+	/// stays reserved
+	/// Returns the slashed amount
+	pub fn slash_proposal_deposit(
+		seller: &T::AccountId,
+		collection_id: T::NftCollectionId,
+		item_id: T::NftItemId,
+		slash: Percent,
+	) -> BalanceOf<T> {
+		let deposit = ProposalDeposits::<T>::get(collection_id, item_id);
+		let slashed = slash.mul_floor(deposit);
+		let res = <T as Config>::Currency::repatriate_reserved(
+			seller,
+			&Self::account_id(),
+			slashed,
+			BalanceStatus::Free,
+		);
+		debug_assert!(res.is_ok());
+		ProposalDeposits::<T>::insert(collection_id, item_id, deposit.saturating_sub(slashed));
+
+		// A part of the fees is credited to the housing fund contributors
+		let income = T::FundFeeShare::get().mul_floor(slashed);
+		if !income.is_zero() {
+			let income: u128 = income.unique_saturated_into();
+			let res = HousingFund::Pallet::<T>::distribute_income(
+				&Self::account_id(),
+				income.unique_saturated_into(),
+			);
+			debug_assert!(res.is_ok());
+		}
+
+		slashed
+	}
+
+	/// Release the proposal fee of an asset to its seller
+	/// Returns the released amount
+	pub fn release_proposal_deposit(
+		seller: &T::AccountId,
+		collection_id: T::NftCollectionId,
+		item_id: T::NftItemId,
+	) -> BalanceOf<T> {
+		let deposit = ProposalDeposits::<T>::take(collection_id, item_id);
+		<T as Config>::Currency::unreserve(seller, deposit);
+
+		deposit
+	}
+
+	/// Withdraw the listing of an asset, sending the `slash` part of its proposal fee to the fees
+	/// account and refunding the rest to its seller
+	/// The NFT is burned and the running proposal of the asset is cancelled
+	pub fn do_withdraw(
+		seller: T::AccountId,
+		collection_id: T::NftCollectionId,
+		item_id: T::NftItemId,
+		slash: Percent,
+	) -> DispatchResult {
+		let asset =
+			Houses::<T>::get(collection_id, item_id).ok_or(Error::<T>::CollectionOrItemUnknown)?;
+		if (asset.status == AssetStatus::REVIEWING || asset.status == AssetStatus::VOTING) &&
+			Votes::Pallet::<T>::voting_proposals(asset.proposal_hash).is_some()
+		{
+			Votes::Pallet::<T>::cancel_proposal(asset.proposal_hash)?;
+		}
+
+		let slashed = Self::slash_proposal_deposit(&seller, collection_id, item_id, slash);
+		let refunded = Self::release_proposal_deposit(&seller, collection_id, item_id);
+
+		Nft::Pallet::<T>::remove_lock(collection_id, item_id, Nft::LockReason::Onboarding)?;
+		Nft::Pallet::<T>::do_burn(seller.clone(), collection_id, item_id)?;
+		Houses::<T>::remove(collection_id, item_id);
+		Prices::<T>::remove(collection_id, item_id);
+		Vcalls::<T>::remove(collection_id, item_id);
+		Details::<T>::remove(collection_id, item_id);
+		StatusHistory::<T>::remove(collection_id, item_id);
+		let offers = CounterOffers::<T>::take(collection_id, item_id);
+		if let Some(offer) = offers.last().filter(|offer| offer.status == OfferStatus::Pending) {
+			CounterOfferExpiries::<T>::remove(offer.expires_at(), (collection_id, item_id));
+		}
+
+		Self::deposit_event(Event::ListingWithdrawn {
+			who: seller,
+			collection: collection_id,
+			item: item_id,
+			refunded,
+			slashed,
+		});
 
 		Ok(())
 	}
@@ -172,6 +390,17 @@ impl<T: Config> Pallet<T> {
 		Self::get_houses_by_status(types::AssetStatus::ONBOARDED)
 	}
 
+	/// Assets which details match `filter`
+	pub fn assets_by_attributes(filter: PropertyFilter) -> Vec<(T::NftCollectionId, T::NftItemId)> {
+		Details::<T>::iter()
+			.filter(|(collection_id, item_id, details)| {
+				Houses::<T>::get(collection_id, item_id)
+					.map_or(false, |asset| filter.matches(asset.status, details))
+			})
+			.map(|(collection_id, item_id, _)| (collection_id, item_id))
+			.collect()
+	}
+
 	pub fn get_finalised_houses() -> Vec<(
 		<T as pallet_nft::Config>::NftCollectionId,
 		<T as pallet_nft::Config>::NftItemId,
@@ -188,16 +417,14 @@ impl<T: Config> Pallet<T> {
 		Self::get_houses_by_status(types::AssetStatus::FINALISING)
 	}
 
+	/// Send the proposal of an appraised asset to the voting pallet, on behalf of its seller
 	pub fn do_submit_proposal(
-		origin: OriginFor<T>,
-		collection: NftCollectionOf,
+		seller: T::AccountId,
+		collection_id: T::NftCollectionId,
 		item_id: T::NftItemId,
 	) {
-		//Change asset status to REVIEWING
-		Self::change_status(origin.clone(), collection, item_id, AssetStatus::REVIEWING).ok();
 		//Send Proposal struct to voting pallet
 		//get the needed call and convert them to pallet_voting format
-		let collection_id: T::NftCollectionId = collection.clone().value().into();
 		let out_call = Vcalls::<T>::get(collection_id, item_id).unwrap();
 
 		let w_status0 =
@@ -218,7 +445,221 @@ impl<T: Config> Pallet<T> {
 		});
 
 		//Send Calls struct to voting pallet
-		Votes::Pallet::<T>::submit_proposal(origin, w_status1, w_status0, w_r_destroy, w_r_edit)
+		Votes::Pallet::<T>::do_submit_proposal(seller, w_status1, w_status0, w_r_destroy, w_r_edit)
 			.ok();
 	}
+
+	/// Record the answer of the seller owning an asset to the pending counter-offer on it
+	/// Returns the offered price
+	pub fn answer_counter_offer(
+		seller: &T::AccountId,
+		collection_id: T::NftCollectionId,
+		item_id: T::NftItemId,
+		accepted: bool,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		ensure!(Roles::Pallet::<T>::sellers(seller).is_some(), Error::<T>::ReservedToSeller);
+		let asset =
+			Houses::<T>::get(collection_id, item_id).ok_or(Error::<T>::CollectionOrItemUnknown)?;
+		ensure!(
+			Nft::Pallet::<T>::owner(collection_id, item_id).as_ref() == Some(seller),
+			Error::<T>::NotTheTokenOwner
+		);
+		ensure!(asset.status == AssetStatus::REVIEWING, Error::<T>::NotReviewing);
+
+		let now = <frame_system::Pallet<T>>::block_number();
+		CounterOffers::<T>::try_mutate(collection_id, item_id, |offers| {
+			let offer = offers
+				.iter_mut()
+				.last()
+				.filter(|offer| offer.status == OfferStatus::Pending)
+				.ok_or(Error::<T>::NoPendingCounterOffer)?;
+			ensure!(offer.is_pending(now), Error::<T>::CounterOfferExpired);
+			offer.status = if accepted { OfferStatus::Accepted } else { OfferStatus::Declined };
+			CounterOfferExpiries::<T>::remove(offer.expires_at(), (collection_id, item_id));
+
+			Ok(offer.price)
+		})
+	}
+
+	/// Send an asset which counter-offer was accepted to the investors vote, on behalf of its
+	/// seller
+	pub fn do_submit_investor_proposal(
+		seller: T::AccountId,
+		collection_id: T::NftCollectionId,
+		item_id: T::NftItemId,
+	) -> DispatchResult {
+		let out_call =
+			Vcalls::<T>::get(collection_id, item_id).ok_or(Error::<T>::CollectionOrItemUnknown)?;
+
+		let proposal = Box::new(
+			Self::get_formatted_collective_proposal(*out_call.after_vote_status)
+				.ok_or(Error::<T>::InvestorProposalFailed)?,
+		);
+		let w_r_edit = Box::new(
+			Self::get_formatted_collective_proposal(*out_call.reject_edit)
+				.ok_or(Error::<T>::InvestorProposalFailed)?,
+		);
+
+		let proposal_hash = T::Hashing::hash_of(&proposal);
+		Houses::<T>::mutate(collection_id, item_id, |asset| {
+			if let Some(asset) = asset {
+				asset.proposal_hash = proposal_hash;
+			}
+		});
+
+		Votes::Pallet::<T>::submit_investor_proposal(seller, proposal, w_r_edit)
+	}
+
+	/// Check up to T::ExpiryScanLimit assets for an expired listing, starting after the last asset
+	/// checked by the previous call, and send the expired ones back to EDITING
+	pub fn expire_listings(now: BlockNumberOf<T>) -> Weight {
+		let db_weight = T::DbWeight::get();
+		if ListingLifetimes::<T>::iter().next().is_none() {
+			return db_weight.reads(1)
+		}
+
+		let limit = T::ExpiryScanLimit::get() as usize;
+		let houses = match ExpiryCursor::<T>::get() {
+			Some(cursor) => Houses::<T>::iter_from(cursor),
+			None => Houses::<T>::iter(),
+		};
+		let checked: Vec<_> = houses.take(limit).collect();
+
+		// The next check starts over once all the assets were checked
+		match checked.last() {
+			Some((collection_id, item_id, _)) if checked.len() == limit =>
+				ExpiryCursor::<T>::put(Houses::<T>::hashed_key_for(collection_id, item_id)),
+			_ => ExpiryCursor::<T>::kill(),
+		}
+
+		let mut expired: u64 = 0;
+		for (collection_id, item_id, asset) in checked.iter() {
+			let lifetime = match Self::listing_lifetimes(asset.status) {
+				Some(lifetime) => lifetime,
+				None => continue,
+			};
+			let since = Self::status_history(collection_id, item_id)
+				.last()
+				.map_or(asset.created, |change| change.block_number);
+			if now >= since.saturating_add(lifetime) &&
+				Self::do_expire(*collection_id, *item_id).is_ok()
+			{
+				expired += 1;
+			}
+		}
+
+		let checked = checked.len() as u64;
+		db_weight.reads_writes(checked.saturating_mul(3).saturating_add(2), expired * 8 + 1)
+	}
+
+	/// Restore the council vote on the assets which counter-offer expires at block `now`
+	pub fn expire_counter_offers(now: BlockNumberOf<T>) -> Weight {
+		let expired: Vec<_> =
+			CounterOfferExpiries::<T>::drain_prefix(now).map(|(asset, _)| asset).collect();
+		let mut reads: u64 = 1;
+		let mut writes: u64 = 0;
+		for (collection_id, item_id) in expired {
+			reads += 3;
+			writes += 1;
+			if Self::do_expire_counter_offer(collection_id, item_id).is_ok() {
+				// the voting and collective proposals, and the proposal hash of the asset
+				reads += 5;
+				writes += 8;
+			}
+		}
+
+		T::DbWeight::get().reads_writes(reads, writes)
+	}
+
+	/// Mark the unanswered counter-offer on an asset as expired, and submit the asset to the
+	/// council vote again at its price
+	#[transactional]
+	pub fn do_expire_counter_offer(
+		collection_id: T::NftCollectionId,
+		item_id: T::NftItemId,
+	) -> DispatchResult {
+		let asset =
+			Houses::<T>::get(collection_id, item_id).ok_or(Error::<T>::CollectionOrItemUnknown)?;
+		ensure!(asset.status == AssetStatus::REVIEWING, Error::<T>::NotReviewing);
+		let seller = Nft::Pallet::<T>::owner(collection_id, item_id)
+			.ok_or(Error::<T>::CollectionOrItemUnknown)?;
+		let price = CounterOffers::<T>::try_mutate(collection_id, item_id, |offers| {
+			let offer = offers
+				.iter_mut()
+				.last()
+				.filter(|offer| offer.status == OfferStatus::Pending)
+				.ok_or(Error::<T>::NoPendingCounterOffer)?;
+			offer.status = OfferStatus::Expired;
+
+			Ok::<_, Error<T>>(offer.price)
+		})?;
+
+		Self::do_submit_proposal(seller, collection_id, item_id);
+
+		Self::deposit_event(Event::CounterOfferExpired {
+			collection: collection_id,
+			item: item_id,
+			price,
+		});
+
+		Ok(())
+	}
+
+	/// Send an asset which listing expired back to EDITING, cancelling its running proposal and
+	/// releasing its proposal fee
+	#[transactional]
+	pub fn do_expire(collection_id: T::NftCollectionId, item_id: T::NftItemId) -> DispatchResult {
+		let asset =
+			Houses::<T>::get(collection_id, item_id).ok_or(Error::<T>::CollectionOrItemUnknown)?;
+		let seller = Nft::Pallet::<T>::owner(collection_id, item_id)
+			.ok_or(Error::<T>::CollectionOrItemUnknown)?;
+
+		if (asset.status == AssetStatus::REVIEWING || asset.status == AssetStatus::VOTING) &&
+			Votes::Pallet::<T>::voting_proposals(asset.proposal_hash).is_some()
+		{
+			Votes::Pallet::<T>::cancel_proposal(asset.proposal_hash)?;
+		}
+		CounterOffers::<T>::mutate(collection_id, item_id, |offers| {
+			if let Some(offer) =
+				offers.iter_mut().last().filter(|o| o.status == OfferStatus::Pending)
+			{
+				offer.status = OfferStatus::Expired;
+			}
+		});
+
+		Self::do_change_status(
+			collection_id,
+			item_id,
+			AssetStatus::EDITING,
+			TransitionOrigin::Expiry,
+		)?;
+		let released = Self::release_proposal_deposit(&seller, collection_id, item_id);
+
+		Self::deposit_event(Event::ListingExpired {
+			who: seller,
+			collection: collection_id,
+			item: item_id,
+			status: asset.status,
+			released,
+		});
+
+		Ok(())
+	}
+}
+
+impl<T: Config> Nft::ItemStageOf<T::NftCollectionId, T::NftItemId> for Pallet<T> {
+	/// The assets are edited by their seller until they are submitted, and closed once sold,
+	/// cancelled or slashed. NFTs without asset are considered edited
+	fn item_stage(collection_id: T::NftCollectionId, item_id: T::NftItemId) -> Nft::ItemStage {
+		match Self::houses(collection_id, item_id).map(|asset| asset.status) {
+			None |
+			Some(AssetStatus::EDITING) |
+			Some(AssetStatus::REJECTED) |
+			Some(AssetStatus::REPRICING) => Nft::ItemStage::Editing,
+			Some(AssetStatus::PURCHASED) |
+			Some(AssetStatus::CANCELLED) |
+			Some(AssetStatus::SLASH) => Nft::ItemStage::Closed,
+			Some(_) => Nft::ItemStage::Listed,
+		}
+	}
 }
@@ -17,16 +17,61 @@
 //!   possible)
 //! - Reject a proposal for destruction (NFT is burned, asset is marked as SLASH)
 //!
+//! An onboarded asset for which the bidding pallet repeatedly fails to assemble investors or to
+//! bid is sent back to the seller with the status REPRICING, so it can be edited and re-submitted.
+//!
+//! ### Asset lifecycle
+//!
+//! The status of an asset only follows the transitions of `AssetStatus::transition_origin`, each
+//! one being reserved to an origin:
+//! - EDITING, REJECTED or REPRICING -> APPRAISING : the seller owning the asset, through
+//!   `submit_awaiting`
+//! - APPRAISING -> REVIEWING or REJECTED : an appraiser, through `submit_appraisal`
+//! - REVIEWING -> VOTING, REJECTED or SLASH : the council (`CouncilOrigin`)
+//! - VOTING -> ONBOARDED, REJECTED or SLASH : the investors referendum (`DemocracyOrigin`)
+//! - ONBOARDED -> FINALISING or REPRICING : the bidding pallet
+//! - FINALISING -> FINALISED or REJECTED : a notary, through the finalizer pallet
+//! - FINALISED -> PURCHASED : the bidding pallet
+//! - FINALISED -> CANCELLED : the seller owning the asset, through the finalizer pallet
+//! - APPRAISING, REVIEWING, VOTING or ONBOARDED -> EDITING : the expiry of the listing
+//!
+//! Every transition is recorded in the status history of the asset.
+//!
+//! A listing expires when its asset stays in a status longer than the lifetime set for it with
+//! `set_listing_lifetime`. The assets are checked at the beginning of each block, up to
+//! `ExpiryScanLimit` of them, each check starting after the last asset checked by the previous
+//! one. An expired asset goes back to EDITING, its running proposal is cancelled and its proposal
+//! fee is released, so that its seller reprices it and submits it again.
+//!
+//! An asset cannot move to VOTING if its price deviates from its appraised value by more than
+//! `AppraisalBand`.
+//!
 //! ### Dispatchable Functions
 //! #### Role setting
 //!
 //! * `do_something` - Used in a Call to initialize the fields of the VotingCalls struct.
-//!  
-//! * `set_price` - Modify the price of an Existing proposal with the status EDIT or REJECTED
+//!
+//! * `change_status` - Move an asset to the next status of its lifecycle, for the transitions of
+//!   the council and of the investors referendum.
+//!
+//! * `set_price` - Modify the price of an Existing proposal with the status EDIT, REJECTED or
+//!   REPRICING
 //! Proposal price is the only part that can be edited
 //!
+//! * `set_property_details` - Set the structured details of a property (address, surface, rooms,
+//!   energy rating, documents...) while it can be edited. The required details depend on the type
+//!   of the property.
+//!
+//! * `submit_appraisal` - An appraiser submits the valuation of a submitted asset, with the hash of
+//!   its report and its fee paid by the seller. The asset is sent to the council review if its
+//!   price is close enough to the valuation, and rejected for repricing otherwise. The appraisal is
+//!   stored on the asset.
+//!
 //! * `do_buy` - Execute the buy/sell transaction.
-//! Funds reserved during proposal creation are unreserved.
+//! The proposal fee reserved during proposal creation is released, except for the `SaleFee` part
+//! of it which goes to the fees account.
+//! The `FundFeeShare` part of the fees and slashes sent to the fees account is credited to the
+//! housing fund contributors.
 //! Sent to the voting pallet as a Call.
 //!
 //! * `reject_edit` - Reject a submitted proposal for price editing,
@@ -41,16 +86,40 @@
 //! A struct containing Calls for the voting pallet is also created and stored.
 //! the proposal submission is optionnal, and can be disabled through the value
 //! of the boolean `submit`. A defined amount that will be slashed in case of
-//! proposal rejection is also reserved.
+//! proposal rejection is also reserved. The property details can be given at creation.
 //!
-//! * `submit_awaiting` - Submit/edit an awaiting proposal for review.
+//! * `submit_awaiting` - Submit/edit an awaiting proposal for appraisal and review.
+//! The valid property details of the asset are required to submit it.
 //! This is also used for re-submission of rejected proposals.
+//!
+//! * `withdraw_listing` - The seller withdraws the listing of an asset which is not onboarded yet.
+//! The NFT is burned, and the proposal fee is refunded, partially if the proposal was already
+//! submitted.
+//!
+//! * `propose_counter_offer` - The council offers another price for an asset under review, within
+//!   the appraisal band, and the running council vote is cancelled. The offer is a decision of the
+//!   House Council, submitted with `pallet_voting::council_motion`. The seller has
+//!   `CounterOfferPeriod` blocks to answer, after which the asset goes back to the council vote at
+//!   its price.
+//!
+//! * `accept_counter_offer` - The seller accepts the offered price, which replaces the price of the
+//!   asset, and the asset goes to the investors vote.
+//!
+//! * `decline_counter_offer` - The seller declines the offered price, and the listing is withdrawn
+//!   without slashing the proposal fee.
+//!
+//! The last `MaxCounterOffers` counter-offers made on an asset are kept in its `CounterOffers` log,
+//! which is removed with the listing.
+//!
+//! * `set_listing_lifetime` - Root sets the number of blocks an asset can stay in a status before
+//!   its listing expires.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
 #![allow(clippy::upper_case_acronyms)]
 
 mod functions;
+pub mod migrations;
 mod types;
 
 pub use functions::*;
@@ -64,6 +133,9 @@ pub use pallet_voting as Votes;
 
 pub use pallet::*;
 
+pub mod runtime_api;
+pub use runtime_api::OnboardingApi;
+
 #[cfg(test)]
 mod mock;
 
@@ -111,10 +183,61 @@ pub mod pallet {
 
 		#[pallet::constant]
 		type Slash: Get<Percent>;
+
+		/// Part of the proposal fee sent to the fees account when the asset is sold, the rest is
+		/// released to the seller
+		#[pallet::constant]
+		type SaleFee: Get<Percent>;
+
+		/// Part of the fees and slashes sent to the fees account which is credited to the housing
+		/// fund contributors
+		#[pallet::constant]
+		type FundFeeShare: Get<Percent>;
+
+		/// Maximum number of inspection reports in the details of a property
+		#[pallet::constant]
+		type MaxInspectionReports: Get<u32>;
+
+		/// Maximum deviation of the price of an asset from its appraised value, for the asset to
+		/// be voted on
+		#[pallet::constant]
+		type AppraisalBand: Get<Percent>;
+
+		/// Maximum appraisal fee, as a part of the asset price
+		#[pallet::constant]
+		type MaxAppraisalFee: Get<Percent>;
+
+		/// Number of blocks given to a seller to answer a counter-offer of the council
+		#[pallet::constant]
+		type CounterOfferPeriod: Get<BlockNumberOf<Self>>;
+
+		/// Maximum number of counter-offers kept in the log of an asset
+		#[pallet::constant]
+		type MaxCounterOffers: Get<u32>;
+
+		/// Maximum number of assets checked for an expired listing in a block
+		#[pallet::constant]
+		type ExpiryScanLimit: Get<u32>;
+
+		/// Handler notified when the status of an asset changes
+		type OnStatusChange: OnAssetStatusChange<Self>;
+
+		/// Maximum number of transitions kept in the status history of an asset
+		#[pallet::constant]
+		type MaxStatusHistory: Get<u32>;
+
+		/// Origin of the council decisions on the submitted assets
+		type CouncilOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Origin of the investors referendum on the reviewed assets
+		type DemocracyOrigin: EnsureOrigin<Self::Origin>;
 	}
 
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
@@ -165,6 +288,83 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn property_details)]
+	/// Stores the structured details of the assets
+	pub type Details<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::NftCollectionId,
+		Blake2_128Concat,
+		T::NftItemId,
+		PropertyDetails<T>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn proposal_deposits)]
+	/// Stores the proposal fee reserved from the seller of each asset
+	pub type ProposalDeposits<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::NftCollectionId,
+		Blake2_128Concat,
+		T::NftItemId,
+		BalanceOf<T>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn status_history)]
+	/// Stores the latest status transitions of the assets
+	pub type StatusHistory<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::NftCollectionId,
+		Blake2_128Concat,
+		T::NftItemId,
+		StatusHistoryOf<T>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn counter_offers)]
+	/// Stores the counter-offers of the council on each asset
+	pub type CounterOffers<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::NftCollectionId,
+		Blake2_128Concat,
+		T::NftItemId,
+		CounterOffersOf<T>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn listing_lifetimes)]
+	/// Stores the number of blocks an asset can stay in a status before its listing expires
+	pub type ListingLifetimes<T: Config> =
+		StorageMap<_, Blake2_128Concat, AssetStatus, BlockNumberOf<T>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn counter_offer_expiries)]
+	/// Stores the assets which pending counter-offer expires at each block
+	pub type CounterOfferExpiries<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		BlockNumberOf<T>,
+		Blake2_128Concat,
+		(T::NftCollectionId, T::NftItemId),
+		(),
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn expiry_cursor)]
+	/// Stores the key of the last asset checked for an expired listing, the next check starting
+	/// after it
+	pub type ExpiryCursor<T: Config> = StorageValue<_, Vec<u8>, OptionQuery>;
+
 	// Pallets use events to inform users when important changes are made.
 	// https://docs.substrate.io/v3/runtime/events-and-errors
 	#[pallet::event]
@@ -227,6 +427,72 @@ pub mod pallet {
 			collection: T::NftCollectionId,
 			item: T::NftItemId,
 		},
+		/// Proposal fee of a sold asset settled, `fee` going to the fees account
+		ProposalFeeSettled {
+			who: T::AccountId,
+			collection: T::NftCollectionId,
+			item: T::NftItemId,
+			released: BalanceOf<T>,
+			fee: BalanceOf<T>,
+		},
+		/// Details of a property set by its seller
+		PropertyDetailsSet { who: T::AccountId, collection: T::NftCollectionId, item: T::NftItemId },
+		/// Appraisal of an asset submitted
+		AppraisalSubmitted {
+			who: T::AccountId,
+			collection: T::NftCollectionId,
+			item: T::NftItemId,
+			value: BalanceOf<T>,
+			report_hash: T::Hash,
+			fee: BalanceOf<T>,
+		},
+		/// Listing withdrawn by its seller
+		ListingWithdrawn {
+			who: T::AccountId,
+			collection: T::NftCollectionId,
+			item: T::NftItemId,
+			refunded: BalanceOf<T>,
+			slashed: BalanceOf<T>,
+		},
+		/// Price offered by the council to the seller of an asset
+		CounterOfferProposed {
+			collection: T::NftCollectionId,
+			item: T::NftItemId,
+			price: BalanceOf<T>,
+			deadline: BlockNumberOf<T>,
+		},
+		/// Counter-offer accepted by the seller, the asset goes to the investors vote
+		CounterOfferAccepted {
+			who: T::AccountId,
+			collection: T::NftCollectionId,
+			item: T::NftItemId,
+			price: BalanceOf<T>,
+		},
+		/// Counter-offer declined by the seller, the listing is withdrawn
+		CounterOfferDeclined {
+			who: T::AccountId,
+			collection: T::NftCollectionId,
+			item: T::NftItemId,
+			price: BalanceOf<T>,
+		},
+		/// Listing lifetime of a status set, None if the listings don't expire in this status
+		ListingLifetimeSet { status: AssetStatus, lifetime: Option<BlockNumberOf<T>> },
+		/// Listing expired in `status`, the asset is back to EDITING and its seller is asked to
+		/// reprice it before submitting it again
+		ListingExpired {
+			who: T::AccountId,
+			collection: T::NftCollectionId,
+			item: T::NftItemId,
+			status: AssetStatus,
+			released: BalanceOf<T>,
+		},
+		/// Counter-offer not answered by the seller before its deadline, the asset goes back to
+		/// the council vote
+		CounterOfferExpired {
+			collection: T::NftCollectionId,
+			item: T::NftItemId,
+			price: BalanceOf<T>,
+		},
 	}
 
 	// Errors inform users that something went wrong.
@@ -260,6 +526,50 @@ pub mod pallet {
 		ReservedToSeller,
 		/// Failed to unreserved fund in Housing fund
 		HousingFundUnreserveFundFailed,
+		/// The asset cannot move from its status to the requested one
+		InvalidTransition,
+		/// The listing cannot be withdrawn once the asset is onboarded
+		CannotWithdrawItem,
+		/// A property detail is malformed
+		InvalidPropertyDetail,
+		/// A property detail required for the type of the property is missing
+		MissingPropertyDetail,
+		/// Action reserved to Appraiser role
+		ReservedToAppraiser,
+		/// The asset is not waiting for an appraisal
+		NotAppraising,
+		/// The appraiser cannot own the appraised asset
+		AppraiserIsOwner,
+		/// The appraisal fee exceeds the maximum allowed
+		AppraisalFeeTooHigh,
+		/// The asset has not been appraised
+		AppraisalNeeded,
+		/// The price of the asset deviates too much from its appraised value
+		PriceOutsideAppraisalBand,
+		/// The asset is not under review by the council
+		NotReviewing,
+		/// A counter-offer is already waiting for the answer of the seller
+		CounterOfferPending,
+		/// No counter-offer is waiting for the answer of the seller
+		NoPendingCounterOffer,
+		/// The deadline of the counter-offer has passed
+		CounterOfferExpired,
+		/// The counter-offer could not be sent to the investors vote
+		InvestorProposalFailed,
+		/// The listings don't expire in this status
+		StatusCannotExpire,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		/// Weight: see `expire_listings` and `expire_counter_offers`
+		fn on_initialize(n: T::BlockNumber) -> Weight {
+			Self::expire_listings(n).saturating_add(Self::expire_counter_offers(n))
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			migrations::migrate::<T>()
+		}
 	}
 
 	// Dispatchable functions allows users to interact with the pallet and invoke state changes.
@@ -286,24 +596,25 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Move an asset to the next status of its lifecycle
+		/// The origin must be T::CouncilOrigin during the review or T::DemocracyOrigin during the
+		/// investors vote. The other transitions go through the calls making their own checks
+		/// Emits AssetStatusChanged when successful
 		#[pallet::weight(10_000)]
 		#[transactional]
 		pub fn change_status(
 			origin: OriginFor<T>,
-			collection: NftCollectionOf,
+			collection_id: T::NftCollectionId,
 			item_id: T::NftItemId,
 			status: AssetStatus,
 		) -> DispatchResult {
-			let _caller = ensure_signed(origin.clone()).unwrap();
-			let coll_id: T::NftCollectionId = collection.clone().value().into();
-			Self::status(collection, item_id, status);
-			Self::deposit_event(Event::AssetStatusChanged {
-				changed_to: status,
-				collection: coll_id,
-				item: item_id,
-			});
+			let asset =
+				Self::houses(collection_id, item_id).ok_or(Error::<T>::CollectionOrItemUnknown)?;
+			let transition_origin =
+				asset.status.transition_origin(status).ok_or(Error::<T>::InvalidTransition)?;
+			Self::ensure_transition_origin(origin, transition_origin)?;
 
-			Ok(())
+			Self::do_change_status(collection_id, item_id, status, transition_origin)
 		}
 
 		/// Modify the price of an Existing proposal
@@ -311,12 +622,11 @@ pub mod pallet {
 		#[transactional]
 		pub fn set_price(
 			origin: OriginFor<T>,
-			collection: NftCollectionOf,
+			collection_id: T::NftCollectionId,
 			item_id: T::NftItemId,
 			new_price: Option<BalanceOf<T>>,
 		) -> DispatchResult {
 			let caller = ensure_signed(origin.clone()).unwrap();
-			let collection_id: T::NftCollectionId = collection.clone().value().into();
 			ensure!(
 				Houses::<T>::contains_key(collection_id, item_id),
 				Error::<T>::CollectionOrItemUnknown
@@ -331,11 +641,13 @@ pub mod pallet {
 			let asset = Self::houses(collection_id, item_id).unwrap();
 			let status = asset.status;
 			ensure!(
-				status == AssetStatus::EDITING || status == AssetStatus::REJECTED,
+				status == AssetStatus::EDITING ||
+					status == AssetStatus::REJECTED ||
+					status == AssetStatus::REPRICING,
 				Error::<T>::CannotEditItem
 			);
 
-			Self::price(origin, collection, item_id, new_price).ok();
+			Self::price(origin, collection_id, item_id, new_price).ok();
 
 			Self::deposit_event(Event::TokenPriceUpdated {
 				who: caller,
@@ -347,15 +659,58 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Set the structured details of a property
+		/// The origin must be the seller owning the asset, and the asset must have the status
+		/// EDITING, REJECTED or REPRICING
+		/// The details are validated against the class of the collection
 		#[pallet::weight(10_000)]
+		#[transactional]
+		pub fn set_property_details(
+			origin: OriginFor<T>,
+			collection_id: T::NftCollectionId,
+			item_id: T::NftItemId,
+			details: PropertyDetails<T>,
+		) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+			ensure!(Roles::Pallet::<T>::sellers(&caller).is_some(), Error::<T>::ReservedToSeller);
+			let asset =
+				Self::houses(collection_id, item_id).ok_or(Error::<T>::CollectionOrItemUnknown)?;
+			ensure!(
+				Nft::Pallet::<T>::owner(collection_id, item_id) == Some(caller.clone()),
+				Error::<T>::NotTheTokenOwner
+			);
+			ensure!(
+				asset.status == AssetStatus::EDITING ||
+					asset.status == AssetStatus::REJECTED ||
+					asset.status == AssetStatus::REPRICING,
+				Error::<T>::CannotEditItem
+			);
+			let class = Nft::Pallet::<T>::collection_class(collection_id)
+				.ok_or(Error::<T>::CollectionOrItemUnknown)?;
+			details.validate(class.asset_class)?;
+
+			Details::<T>::insert(collection_id, item_id, details);
+
+			Self::deposit_event(Event::PropertyDetailsSet {
+				who: caller,
+				collection: collection_id,
+				item: item_id,
+			});
+
+			Ok(())
+		}
+
+		/// Reject a submitted proposal for price editing
+		/// The origin must be T::CouncilOrigin during the review, T::DemocracyOrigin during the
+		/// investors vote
+		#[pallet::weight(10_000)]
+		#[transactional]
 		pub fn reject_edit(
 			origin: OriginFor<T>,
-			collection: NftCollectionOf,
+			collection_id: T::NftCollectionId,
 			item_id: T::NftItemId,
 			_infos: Asset<T>,
 		) -> DispatchResult {
-			let caller = ensure_signed(origin.clone()).unwrap();
-			let collection_id: T::NftCollectionId = collection.clone().value().into();
 			ensure!(
 				Houses::<T>::contains_key(collection_id, item_id),
 				Error::<T>::CollectionOrItemUnknown
@@ -365,44 +720,34 @@ pub mod pallet {
 				house.status == AssetStatus::REVIEWING || house.status == AssetStatus::VOTING,
 				Error::<T>::CannotSubmitItem
 			);
-			Self::change_status(origin, collection, item_id, AssetStatus::REJECTED).ok();
+			Self::change_status(origin, collection_id, item_id, AssetStatus::REJECTED)?;
 
 			let owner = Nft::Pallet::<T>::owner(collection_id, item_id).unwrap();
-			let balance = <T as Config>::Currency::reserved_balance(&owner);
-			let fees = <T as Config>::Slash::get().mul_floor(balance);
-			let remain = balance.saturating_sub(fees);
-			<T as pallet::Config>::Currency::unreserve(&owner, fees);
-			let res = <T as pallet::Config>::Currency::transfer(
-				&owner,
-				&Self::account_id(),
-				fees,
-				ExistenceRequirement::AllowDeath,
-			);
-			debug_assert!(res.is_ok());
-
-			let res1 = <T as pallet::Config>::Currency::reserve(&owner, remain);
-			debug_assert!(res1.is_ok());
+			let fees =
+				Self::slash_proposal_deposit(&owner, collection_id, item_id, T::Slash::get());
 
 			Self::deposit_event(Event::RejectedForEditing {
-				by_who: caller.clone(),
+				by_who: owner.clone(),
 				collection: collection_id,
 				item: item_id,
 			});
 
-			Self::deposit_event(Event::SlashedFunds { from_who: caller, amount: Some(fees) });
+			Self::deposit_event(Event::SlashedFunds { from_who: owner, amount: Some(fees) });
 
 			Ok(())
 		}
 
+		/// Reject a submitted proposal for destruction
+		/// The origin must be T::CouncilOrigin during the review, T::DemocracyOrigin during the
+		/// investors vote
 		#[pallet::weight(10_000)]
+		#[transactional]
 		pub fn reject_destroy(
 			origin: OriginFor<T>,
-			collection: NftCollectionOf,
+			collection_id: T::NftCollectionId,
 			item_id: T::NftItemId,
 			_infos: Asset<T>,
 		) -> DispatchResult {
-			let caller = ensure_signed(origin.clone()).unwrap();
-			let collection_id: T::NftCollectionId = collection.clone().value().into();
 			ensure!(
 				Houses::<T>::contains_key(collection_id, item_id),
 				Error::<T>::CollectionOrItemUnknown
@@ -412,106 +757,115 @@ pub mod pallet {
 				house.status == AssetStatus::REVIEWING || house.status == AssetStatus::VOTING,
 				Error::<T>::CannotSubmitItem
 			);
-			Self::change_status(origin.clone(), collection, item_id, AssetStatus::SLASH).ok();
+			Self::change_status(origin, collection_id, item_id, AssetStatus::SLASH)?;
 			let owner = Nft::Pallet::<T>::owner(collection_id, item_id).unwrap();
-			Nft::Pallet::<T>::burn(origin, collection, item_id).ok();
-			let balance = <T as Config>::Currency::reserved_balance(&owner);
-			ensure!(balance > Zero::zero(), Error::<T>::NoneValue);
-			<T as pallet::Config>::Currency::unreserve(&owner, balance);
-			let res = <T as pallet::Config>::Currency::transfer(
+			Nft::Pallet::<T>::do_burn(owner.clone(), collection_id, item_id)?;
+			let balance = Self::slash_proposal_deposit(
 				&owner,
-				&Self::account_id(),
-				balance,
-				ExistenceRequirement::AllowDeath,
+				collection_id,
+				item_id,
+				Percent::from_percent(100),
 			);
-			debug_assert!(res.is_ok());
+			ProposalDeposits::<T>::remove(collection_id, item_id);
 
 			Self::deposit_event(Event::RejectedForDestruction {
-				by_who: caller.clone(),
+				by_who: owner.clone(),
 				collection: collection_id,
 				item: item_id,
 			});
 
-			Self::deposit_event(Event::SlashedFunds { from_who: caller, amount: Some(balance) });
+			Self::deposit_event(Event::SlashedFunds { from_who: owner, amount: Some(balance) });
 
 			Ok(())
 		}
 
+		/// Withdraw the listing of an asset
+		/// The origin must be the seller owning the asset
+		/// The proposal fee is fully refunded while the asset is edited, and T::Slash of it goes to
+		/// the fees account during the appraisal, the review and the investors vote. The listing
+		/// cannot be withdrawn once the asset is onboarded.
+		/// The NFT is burned and the pending proposal of the asset is cancelled
+		#[pallet::weight(10_000)]
+		#[transactional]
+		pub fn withdraw_listing(
+			origin: OriginFor<T>,
+			collection_id: T::NftCollectionId,
+			item_id: T::NftItemId,
+		) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+			ensure!(Roles::Pallet::<T>::sellers(&caller).is_some(), Error::<T>::ReservedToSeller);
+			let asset =
+				Self::houses(collection_id, item_id).ok_or(Error::<T>::CollectionOrItemUnknown)?;
+			ensure!(
+				Nft::Pallet::<T>::owner(collection_id, item_id) == Some(caller.clone()),
+				Error::<T>::NotTheTokenOwner
+			);
+
+			let slash = match asset.status {
+				AssetStatus::EDITING | AssetStatus::REJECTED | AssetStatus::REPRICING =>
+					Percent::zero(),
+				AssetStatus::APPRAISING | AssetStatus::REVIEWING | AssetStatus::VOTING =>
+					T::Slash::get(),
+				_ => return Err(Error::<T>::CannotWithdrawItem.into()),
+			};
+
+			Self::do_withdraw(caller, collection_id, item_id, slash)
+		}
+
 		/// `create_and_submit_proposal` - Creation and submission of a proposal.
 		/// the proposal submission is optionnal, and can be disabled through the value
 		/// of the boolean `submit`.
+		/// `max_tenants` defaults to the maximum number of tenants of the collection class.
+		/// The property `details` are required to submit the proposal.
 		#[pallet::weight(10_000)]
 		#[transactional]
 		pub fn create_and_submit_proposal(
 			origin: OriginFor<T>,
-			collection: NftCollectionOf,
+			collection_id: T::NftCollectionId,
 			price: Option<BalanceOf<T>>,
 			metadata: Nft::BoundedVecOfUnq<T>,
 			submit: bool,
-			max_tenants:u8,
+			max_tenants: Option<u8>,
+			details: Option<PropertyDetails<T>>,
 		) -> DispatchResult {
 			let caller = ensure_signed(origin.clone()).unwrap();
 			ensure!(Roles::Pallet::<T>::sellers(&caller).is_some(), Error::<T>::ReservedToSeller);
-			let idx = collection.clone().value() as usize;
+			let class = Nft::Pallet::<T>::collection_class(collection_id)
+				.ok_or(Error::<T>::CollectionOrItemUnknown)?;
+			let max_tenants = max_tenants.unwrap_or(class.max_tenants);
+			if let Some(details) = &details {
+				details.validate(class.asset_class)?;
+			}
 
 			// Get itemId and infos from minted nft
-			let item_id: T::NftItemId = Nft::ItemsCount::<T>::get()[idx].into();
+			let item_id: T::NftItemId = Nft::Pallet::<T>::next_item_id(collection_id);
 
 			//Create asset
 			let balance1 = <T as Config>::Currency::free_balance(&caller);
 			let balance0 = T::ProposalFee::get().mul_floor(price.unwrap());
 			ensure!(balance1 > balance0, Error::<T>::InsufficientBalance);
 
-			<T as Config>::Currency::reserve(&caller, balance0).ok();
-			Self::create_asset(origin.clone(), collection, metadata, price, item_id,max_tenants).ok();
-
-			let collection_id: T::NftCollectionId = collection.clone().value().into();
-
-			let house = Self::houses(collection_id, item_id).unwrap();
-
-			let _new_call = VotingCalls::<T>::new(collection_id, item_id).ok();
-
-			//Create Call for collective-to-democracy status change
-			let call1: T::Prop =
-				Call::<T>::change_status { collection, item_id, status: AssetStatus::VOTING }
-					.into();
-			let call1_wrap = Box::new(call1);
-			Vcalls::<T>::mutate(collection_id, item_id, |val| {
-				let mut v0 = val.clone().unwrap();
-				v0.democracy_status = call1_wrap;
-				*val = Some(v0);
-			});
+			<T as Config>::Currency::reserve(&caller, balance0)?;
+			Self::create_asset(
+				origin.clone(),
+				collection_id,
+				metadata,
+				price,
+				item_id,
+				max_tenants,
+			)?;
 
-			//Create Call for proposal reject_edit
-			let call2: T::Prop =
-				Call::<T>::reject_edit { collection, item_id, infos: house.clone() }.into();
-			let call2_wrap = Box::new(call2);
-			Vcalls::<T>::mutate(collection_id, item_id, |val| {
-				let mut v0 = val.clone().unwrap();
-				v0.reject_edit = call2_wrap;
-				*val = Some(v0);
-			});
+			ProposalDeposits::<T>::insert(collection_id, item_id, balance0);
 
-			//Create Call for proposal reject_destroy
-			let call3: T::Prop =
-				Call::<T>::reject_destroy { collection, item_id, infos: house }.into();
-			let call3_wrap = Box::new(call3);
-			Vcalls::<T>::mutate(collection_id, item_id, |val| {
-				let mut v0 = val.clone().unwrap();
-				v0.reject_destroy = call3_wrap;
-				*val = Some(v0);
-			});
+			let house =
+				Self::houses(collection_id, item_id).ok_or(Error::<T>::CollectionOrItemUnknown)?;
 
-			//Create Call for asset status change after Investor's vote
-			let call4: T::Prop =
-				Call::<T>::change_status { collection, item_id, status: AssetStatus::ONBOARDED }
-					.into();
-			let call4_wrap = Box::new(call4);
-			Vcalls::<T>::mutate(collection_id, item_id, |val| {
-				let mut v0 = val.clone().unwrap();
-				v0.after_vote_status = call4_wrap;
-				*val = Some(v0);
-			});
+			//Create the calls dispatched by the voting pallet at each step of the proposal
+			Vcalls::<T>::insert(
+				collection_id,
+				item_id,
+				VotingCalls::<T>::new(collection_id, item_id, house),
+			);
 
 			Self::deposit_event(Event::ProposalCreated {
 				who: caller.clone(),
@@ -525,8 +879,23 @@ pub mod pallet {
 				amount: Some(balance0),
 			});
 
+			if let Some(details) = details {
+				Details::<T>::insert(collection_id, item_id, details);
+				Self::deposit_event(Event::PropertyDetailsSet {
+					who: caller.clone(),
+					collection: collection_id,
+					item: item_id,
+				});
+			}
+
 			if submit {
-				Self::do_submit_proposal(origin, collection, item_id);
+				Self::ensure_valid_details(collection_id, item_id)?;
+				Self::do_change_status(
+					collection_id,
+					item_id,
+					AssetStatus::APPRAISING,
+					TransitionOrigin::Seller,
+				)?;
 
 				Self::deposit_event(Event::ProposalSubmitted {
 					who: caller,
@@ -544,7 +913,7 @@ pub mod pallet {
 		#[transactional]
 		pub fn submit_awaiting(
 			origin: OriginFor<T>,
-			collection: NftCollectionOf,
+			collection_id: T::NftCollectionId,
 			item_id: T::NftItemId,
 			price: Option<BalanceOf<T>>,
 			data: Option<Nft::BoundedVecOfUnq<T>>,
@@ -552,26 +921,31 @@ pub mod pallet {
 			let caller = ensure_signed(origin.clone()).unwrap();
 			ensure!(Roles::Pallet::<T>::sellers(&caller).is_some(), Error::<T>::ReservedToSeller);
 
-			let collection_id: T::NftCollectionId = collection.clone().value().into();
 			ensure!(
 				Houses::<T>::contains_key(collection_id, item_id),
 				Error::<T>::CollectionOrItemUnknown
 			);
+			ensure!(
+				Nft::Pallet::<T>::owner(collection_id, item_id) == Some(caller.clone()),
+				Error::<T>::NotTheTokenOwner
+			);
 			let house = Self::houses(collection_id, item_id).unwrap();
 			ensure!(
-				house.status == AssetStatus::EDITING || house.status == AssetStatus::REJECTED,
+				house.status == AssetStatus::EDITING ||
+					house.status == AssetStatus::REJECTED ||
+					house.status == AssetStatus::REPRICING,
 				Error::<T>::CannotSubmitItem
 			);
+			Self::ensure_valid_details(collection_id, item_id)?;
 
 			//Edit asset price
 			let price0 = Prices::<T>::get(collection_id, item_id).unwrap();
 
 			let data0 = Nft::Pallet::<T>::items(collection_id, item_id).unwrap().metadata;
 			let data1 = data.unwrap_or_else(|| data0.clone());
-			let collection_owner = Nft::Pallet::<T>::collection_owner(collection_id).unwrap();
 			if data1 != data0 {
 				let res =
-					Nft::Pallet::<T>::set_metadata(collection_owner, collection_id, item_id, data1);
+					Nft::Pallet::<T>::set_metadata(caller.clone(), collection_id, item_id, data1);
 				debug_assert!(res.is_ok());
 			}
 
@@ -579,10 +953,26 @@ pub mod pallet {
 			if b == Zero::zero() {
 				b = price0;
 			} else {
-				Self::set_price(origin.clone(), collection, item_id, Some(b)).ok();
+				Self::set_price(origin.clone(), collection_id, item_id, Some(b)).ok();
 			}
 
-			Self::do_submit_proposal(origin, collection, item_id);
+			// The proposal fee of an expired listing was released, and is reserved again
+			if Self::proposal_deposits(collection_id, item_id).is_zero() {
+				let fee = T::ProposalFee::get().mul_floor(b);
+				<T as Config>::Currency::reserve(&caller, fee)?;
+				ProposalDeposits::<T>::insert(collection_id, item_id, fee);
+				Self::deposit_event(Event::FundsReserved {
+					from_who: caller.clone(),
+					amount: Some(fee),
+				});
+			}
+
+			Self::do_change_status(
+				collection_id,
+				item_id,
+				AssetStatus::APPRAISING,
+				TransitionOrigin::Seller,
+			)?;
 
 			Self::deposit_event(Event::ProposalSubmitted {
 				who: caller,
@@ -593,5 +983,230 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Submit the appraisal of an asset
+		/// The origin must have the appraiser role and must not own the asset, which must have the
+		/// status APPRAISING
+		/// The seller pays `fee` to the appraiser, up to T::MaxAppraisalFee of the asset price
+		/// The asset is sent to the council review if its price is within T::AppraisalBand of
+		/// `value`, and is rejected for repricing otherwise
+		#[pallet::weight(10_000)]
+		#[transactional]
+		pub fn submit_appraisal(
+			origin: OriginFor<T>,
+			collection_id: T::NftCollectionId,
+			item_id: T::NftItemId,
+			value: BalanceOf<T>,
+			report_hash: T::Hash,
+			fee: BalanceOf<T>,
+		) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+			ensure!(
+				Roles::Pallet::<T>::appraisers(&caller).is_some(),
+				Error::<T>::ReservedToAppraiser
+			);
+			let mut asset =
+				Self::houses(collection_id, item_id).ok_or(Error::<T>::CollectionOrItemUnknown)?;
+			ensure!(asset.status == AssetStatus::APPRAISING, Error::<T>::NotAppraising);
+			let owner = Nft::Pallet::<T>::owner(collection_id, item_id)
+				.ok_or(Error::<T>::CollectionOrItemUnknown)?;
+			ensure!(owner != caller, Error::<T>::AppraiserIsOwner);
+			let price = Prices::<T>::get(collection_id, item_id).ok_or(Error::<T>::NotForSale)?;
+			ensure!(
+				fee <= T::MaxAppraisalFee::get().mul_floor(price),
+				Error::<T>::AppraisalFeeTooHigh
+			);
+
+			<T as Config>::Currency::transfer(
+				&owner,
+				&caller,
+				fee,
+				ExistenceRequirement::KeepAlive,
+			)?;
+
+			let appraisal = Appraisal::<T> {
+				appraiser: caller.clone(),
+				value,
+				report_hash,
+				fee,
+				block_number: <frame_system::Pallet<T>>::block_number(),
+			};
+			let within_band = appraisal.within_band(price, T::AppraisalBand::get());
+			asset.appraisal = Some(appraisal);
+			Houses::<T>::insert(collection_id, item_id, asset);
+
+			Self::deposit_event(Event::AppraisalSubmitted {
+				who: caller,
+				collection: collection_id,
+				item: item_id,
+				value,
+				report_hash,
+				fee,
+			});
+
+			if within_band {
+				Self::do_change_status(
+					collection_id,
+					item_id,
+					AssetStatus::REVIEWING,
+					TransitionOrigin::Appraiser,
+				)?;
+				Self::do_submit_proposal(owner, collection_id, item_id);
+			} else {
+				Self::do_change_status(
+					collection_id,
+					item_id,
+					AssetStatus::REJECTED,
+					TransitionOrigin::Appraiser,
+				)?;
+			}
+
+			Ok(())
+		}
+
+		/// Offer another price to the seller of an asset under review
+		/// The origin must be T::CouncilOrigin, a council motion of `pallet_voting`, the asset must
+		/// have the status REVIEWING and the offered price must be within T::AppraisalBand of the
+		/// appraised value
+		/// The running council vote on the asset is cancelled, and the seller has
+		/// T::CounterOfferPeriod blocks to answer before the council vote is restored
+		#[pallet::weight(10_000)]
+		#[transactional]
+		pub fn propose_counter_offer(
+			origin: OriginFor<T>,
+			collection_id: T::NftCollectionId,
+			item_id: T::NftItemId,
+			price: BalanceOf<T>,
+		) -> DispatchResult {
+			T::CouncilOrigin::ensure_origin(origin)?;
+			let asset =
+				Self::houses(collection_id, item_id).ok_or(Error::<T>::CollectionOrItemUnknown)?;
+			ensure!(asset.status == AssetStatus::REVIEWING, Error::<T>::NotReviewing);
+			let appraisal = asset.appraisal.as_ref().ok_or(Error::<T>::AppraisalNeeded)?;
+			ensure!(
+				appraisal.within_band(price, T::AppraisalBand::get()),
+				Error::<T>::PriceOutsideAppraisalBand
+			);
+
+			let now = <frame_system::Pallet<T>>::block_number();
+			let mut offers = Self::counter_offers(collection_id, item_id);
+			if let Some(last) = offers.iter_mut().last() {
+				ensure!(!last.is_pending(now), Error::<T>::CounterOfferPending);
+				if last.status == OfferStatus::Pending {
+					last.status = OfferStatus::Expired;
+				}
+			}
+
+			if Votes::Pallet::<T>::voting_proposals(asset.proposal_hash).is_some() {
+				Votes::Pallet::<T>::cancel_proposal(asset.proposal_hash)?;
+			}
+
+			let deadline = now.saturating_add(T::CounterOfferPeriod::get());
+			let offer = CounterOffer::<T> {
+				price,
+				proposed_at: now,
+				deadline,
+				status: OfferStatus::Pending,
+			};
+			CounterOfferExpiries::<T>::insert(offer.expires_at(), (collection_id, item_id), ());
+			if !offers.is_empty() && offers.len() as u32 >= T::MaxCounterOffers::get() {
+				offers.remove(0);
+			}
+			offers.try_push(offer).ok();
+			CounterOffers::<T>::insert(collection_id, item_id, offers);
+
+			Self::deposit_event(Event::CounterOfferProposed {
+				collection: collection_id,
+				item: item_id,
+				price,
+				deadline,
+			});
+
+			Ok(())
+		}
+
+		/// Accept the pending counter-offer of the council on an asset
+		/// The origin must be the seller owning the asset, before the deadline of the offer
+		/// The offered price replaces the price of the asset, which goes to the investors vote
+		#[pallet::weight(10_000)]
+		#[transactional]
+		pub fn accept_counter_offer(
+			origin: OriginFor<T>,
+			collection_id: T::NftCollectionId,
+			item_id: T::NftItemId,
+		) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+			let price = Self::answer_counter_offer(&caller, collection_id, item_id, true)?;
+
+			Prices::<T>::insert(collection_id, item_id, price);
+			Houses::<T>::mutate(collection_id, item_id, |asset| {
+				if let Some(asset) = asset {
+					asset.price = Some(price);
+				}
+			});
+			Self::do_change_status(
+				collection_id,
+				item_id,
+				AssetStatus::VOTING,
+				TransitionOrigin::Council,
+			)?;
+			Self::do_submit_investor_proposal(caller.clone(), collection_id, item_id)?;
+
+			Self::deposit_event(Event::CounterOfferAccepted {
+				who: caller,
+				collection: collection_id,
+				item: item_id,
+				price,
+			});
+
+			Ok(())
+		}
+
+		/// Decline the pending counter-offer of the council on an asset
+		/// The origin must be the seller owning the asset, before the deadline of the offer
+		/// The listing is withdrawn and the proposal fee is fully refunded
+		#[pallet::weight(10_000)]
+		#[transactional]
+		pub fn decline_counter_offer(
+			origin: OriginFor<T>,
+			collection_id: T::NftCollectionId,
+			item_id: T::NftItemId,
+		) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+			let price = Self::answer_counter_offer(&caller, collection_id, item_id, false)?;
+
+			Self::do_withdraw(caller.clone(), collection_id, item_id, Percent::zero())?;
+
+			Self::deposit_event(Event::CounterOfferDeclined {
+				who: caller,
+				collection: collection_id,
+				item: item_id,
+				price,
+			});
+
+			Ok(())
+		}
+
+		/// Set the number of blocks an asset can stay in `status` before its listing expires, or
+		/// None for the listings not to expire in this status
+		/// The origin must be root, and the asset must be able to go back to EDITING from `status`
+		#[pallet::weight(10_000)]
+		pub fn set_listing_lifetime(
+			origin: OriginFor<T>,
+			status: AssetStatus,
+			lifetime: Option<BlockNumberOf<T>>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			ensure!(
+				status.transition_origin(AssetStatus::EDITING) == Some(TransitionOrigin::Expiry),
+				Error::<T>::StatusCannotExpire
+			);
+
+			ListingLifetimes::<T>::set(status, lifetime);
+
+			Self::deposit_event(Event::ListingLifetimeSet { status, lifetime });
+
+			Ok(())
+		}
 	}
 }
@@ -0,0 +1,175 @@
+pub use super::*;
+use frame_support::traits::{GetStorageVersion, StorageVersion};
+
+/// Apply the migrations from the on chain storage version to the current one
+pub fn migrate<T: Config>() -> Weight {
+	let onchain_version = Pallet::<T>::on_chain_storage_version();
+	let mut weight = T::DbWeight::get().reads(1);
+	if onchain_version >= 3 {
+		return weight
+	}
+
+	if onchain_version < 1 {
+		weight = weight.saturating_add(v1::migrate::<T>());
+	}
+	if onchain_version < 2 {
+		weight = weight.saturating_add(v2::migrate::<T>());
+	}
+	weight = weight.saturating_add(v3::migrate::<T>());
+
+	StorageVersion::new(3).put::<Pallet<T>>();
+	weight.saturating_add(T::DbWeight::get().writes(1))
+}
+
+pub mod v1 {
+	use super::*;
+
+	#[derive(Encode, Decode)]
+	pub struct OldAsset<T: Config> {
+		pub status: AssetStatus,
+		pub created: BlockNumberOf<T>,
+		pub infos: ItemInfoOf<T>,
+		pub price: Option<BalanceOf<T>>,
+		pub representative: Option<T::AccountId>,
+		pub tenants: Vec<T::AccountId>,
+		pub proposal_hash: T::Hash,
+		pub max_tenants: u8,
+	}
+
+	/// Add the appraisal to the existing assets, none of them being appraised.
+	/// The assets in review or in vote were accepted at their price before the appraisals: they
+	/// are appraised at this price on behalf of the pallet, so that they can still be voted.
+	pub fn migrate<T: Config>() -> Weight {
+		let mut translated: u64 = 0;
+		let block_number = <frame_system::Pallet<T>>::block_number();
+		Houses::<T>::translate::<OldAsset<T>, _>(|_, _, asset| {
+			translated += 1;
+			let appraisal = match asset.status {
+				AssetStatus::REVIEWING | AssetStatus::VOTING =>
+					asset.price.map(|value| Appraisal {
+						appraiser: Pallet::<T>::account_id(),
+						value,
+						report_hash: Default::default(),
+						fee: Zero::zero(),
+						block_number,
+					}),
+				_ => None,
+			};
+			Some(Asset {
+				status: asset.status,
+				created: asset.created,
+				infos: asset.infos,
+				price: asset.price,
+				representative: asset.representative,
+				tenants: asset.tenants,
+				proposal_hash: asset.proposal_hash,
+				max_tenants: asset.max_tenants,
+				appraisal,
+			})
+		});
+
+		T::DbWeight::get().reads_writes(translated.saturating_add(1), translated)
+	}
+}
+
+pub mod v2 {
+	use super::*;
+
+	/// The voting calls embed the collection, formerly given as a `NftCollectionOf`, and the
+	/// asset, formerly without appraisal: rebuild them from the stored assets.
+	/// The pending proposals embed these calls as well: they are removed from the voting
+	/// pallet and submitted again to the council.
+	pub fn migrate<T: Config>() -> Weight {
+		let keys: Vec<_> = Vcalls::<T>::iter_keys().collect();
+		let weight = Votes::Pallet::<T>::disapprove_undecodable_motions();
+		let mut reads = keys.len() as u64;
+		let mut writes: u64 = 0;
+
+		for (collection_id, item_id) in keys {
+			reads += 1;
+			let asset = match Houses::<T>::get(collection_id, item_id) {
+				Some(asset) => asset,
+				None => {
+					Vcalls::<T>::remove(collection_id, item_id);
+					writes += 1;
+					continue
+				},
+			};
+			Vcalls::<T>::insert(
+				collection_id,
+				item_id,
+				VotingCalls::<T>::new(collection_id, item_id, asset.clone()),
+			);
+			writes += 1;
+
+			let pending =
+				asset.status == AssetStatus::REVIEWING || asset.status == AssetStatus::VOTING;
+			reads += 1;
+			if !pending ||
+				!Votes::VotingProposals::<T>::contains_key(asset.proposal_hash) ||
+				Votes::Pallet::<T>::voting_proposals(asset.proposal_hash).is_some()
+			{
+				continue
+			}
+
+			Votes::Pallet::<T>::remove_stale_proposal(asset.proposal_hash);
+			Houses::<T>::mutate(collection_id, item_id, |asset| {
+				if let Some(asset) = asset {
+					asset.status = AssetStatus::REVIEWING;
+				}
+			});
+			writes += 4;
+			if let Some(owner) = Nft::Pallet::<T>::owner(collection_id, item_id) {
+				// the voting and collective proposals, and the proposal hash of the asset
+				Pallet::<T>::do_submit_proposal(owner, collection_id, item_id);
+				reads += 5;
+				writes += 8;
+			}
+		}
+
+		weight.saturating_add(T::DbWeight::get().reads_writes(reads, writes))
+	}
+}
+
+pub mod v3 {
+	use super::*;
+	use sp_std::collections::btree_map::BTreeMap;
+
+	/// The proposal fee was reserved from the sellers without being recorded: record it in
+	/// `ProposalDeposits` for the assets which are still listed, the fee of a rejected asset
+	/// having been slashed. The recorded deposits of a seller are capped by its reserved balance.
+	pub fn migrate<T: Config>() -> Weight {
+		let mut reserved: BTreeMap<T::AccountId, BalanceOf<T>> = BTreeMap::new();
+		let mut reads: u64 = 0;
+		let mut writes: u64 = 0;
+
+		for (collection_id, item_id, asset) in Houses::<T>::iter() {
+			reads += 1;
+			let fee = T::ProposalFee::get().mul_floor(asset.price.unwrap_or_default());
+			let fee = match asset.status {
+				AssetStatus::PURCHASED | AssetStatus::SLASH | AssetStatus::CANCELLED => continue,
+				AssetStatus::REJECTED => fee.saturating_sub(T::Slash::get().mul_floor(fee)),
+				_ => fee,
+			};
+			if fee.is_zero() || ProposalDeposits::<T>::contains_key(collection_id, item_id) {
+				continue
+			}
+			let owner = match Nft::Pallet::<T>::owner(collection_id, item_id) {
+				Some(owner) => owner,
+				None => continue,
+			};
+			reads += 3;
+			let available = reserved
+				.entry(owner.clone())
+				.or_insert_with(|| <T as Config>::Currency::reserved_balance(&owner));
+			let deposit = fee.min(*available);
+			*available = available.saturating_sub(deposit);
+			if !deposit.is_zero() {
+				ProposalDeposits::<T>::insert(collection_id, item_id, deposit);
+				writes += 1;
+			}
+		}
+
+		T::DbWeight::get().reads_writes(reads, writes)
+	}
+}
@@ -2,12 +2,14 @@ use super::*;
 use crate as pallet_onboarding;
 use frame_support::{
 	parameter_types,
-	traits::{AsEnsureOriginWithArg, ConstU16, ConstU32, ConstU64, EqualPrivilegeOnly},
+	traits::{
+		AsEnsureOriginWithArg, ConstU16, ConstU32, ConstU64, EitherOfDiverse, EqualPrivilegeOnly,
+	},
 	weights::Weight,
 	PalletId,
 };
 
-use crate::Nft::NftPermissions;
+pub use crate::Nft::{AssetClass, CollectionClass, NftPermissions};
 use frame_system::{EnsureRoot, EnsureSigned};
 use pallet_collective::{Instance1, PrimeDefaultVote};
 use pallet_roles::GenesisBuild;
@@ -29,7 +31,7 @@ pub type MaxProposals = u32;
 pub type BlockNumber = u64;
 pub type CollectionId = u32;
 pub type ItemId = u32;
-pub type NftColl = Nft::PossibleCollections;
+pub const OFFICESTEST: CollectionId = 5;
 
 // Configure a mock runtime to test the pallet.
 frame_support::construct_runtime!(
@@ -40,7 +42,7 @@ frame_support::construct_runtime!(
 	{
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
 		OnboardingModule: pallet_onboarding::{Pallet, Call, Storage, Event<T>},
-		VotingModule: pallet_voting::{Pallet, Call, Storage, Event<T>},
+		VotingModule: pallet_voting::{Pallet, Call, Storage, Event<T>, Origin<T>},
 		RoleModule: pallet_roles::{Pallet, Call, Storage, Event<T>},
 		Sudo: pallet_sudo::{Pallet, Call, Storage, Event<T>},
 		NftModule: pallet_nft::{Pallet, Call, Storage, Event<T>},
@@ -50,6 +52,7 @@ frame_support::construct_runtime!(
 		Collective: pallet_collective::<Instance1>::{Pallet, Call, Event<T>, Origin<T>, Config<T>},
 		Democracy: pallet_democracy::{Pallet, Call, Storage, Config<T>, Event<T>},
 		HousingFund: pallet_housing_fund::{Pallet, Call, Storage, Event<T>},
+		FundAssets: pallet_assets::<Instance1>::{Pallet, Storage, Event<T>},
 	}
 );
 
@@ -205,6 +208,8 @@ impl pallet_balances::Config for Test {
 
 parameter_types! {
 	pub ReserveCollectionIdUpTo: u32 = 3;
+	pub const MaxMetadataRevisions: u32 = 3;
+	pub const MaxAttributesPerItem: u32 = 5;
 }
 impl pallet_nft::Config for Test {
 	type Event = Event;
@@ -214,6 +219,10 @@ impl pallet_nft::Config for Test {
 	type ProtocolOrigin = EnsureRoot<AccountId>;
 	type Permissions = NftPermissions;
 	type ReserveCollectionIdUpTo = ReserveCollectionIdUpTo;
+	type ItemStages = OnboardingModule;
+	type TokenFreezer = ();
+	type MaxMetadataRevisions = MaxMetadataRevisions;
+	type MaxAttributesPerItem = MaxAttributesPerItem;
 }
 
 parameter_types! {
@@ -235,11 +244,18 @@ impl pallet_voting::Config for Test {
 		pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 1, 2>;
 	type MinimumDepositVote = MinimumDeposit;
 	type CheckPeriod = CheckPeriod;
+	type VotingOrigin = Origin;
 }
 
 parameter_types! {
 	pub const ProposalFee:Percent = Percent::from_percent(5);
 	pub const SlashedFee: Percent = Percent::from_percent(10);
+	pub const SaleFee: Percent = Percent::from_percent(20);
+	pub static FundFeeShare: Percent = Percent::from_percent(0);
+	pub const AppraisalBand: Percent = Percent::from_percent(10);
+	pub const MaxAppraisalFee: Percent = Percent::from_percent(1);
+	pub const CounterOfferPeriod: BlockNumber = 10;
+	pub const ExpiryScanLimit: u32 = 2;
 	pub const FeesAccount: PalletId = PalletId(*b"feeslash");
 }
 
@@ -249,8 +265,22 @@ impl pallet_onboarding::Config for Test {
 	type Prop = Call;
 	type ProposalFee = ProposalFee;
 	type Slash = SlashedFee;
+	type SaleFee = SaleFee;
+	type FundFeeShare = FundFeeShare;
+	type MaxInspectionReports = frame_support::traits::ConstU32<5>;
+	type AppraisalBand = AppraisalBand;
+	type MaxAppraisalFee = MaxAppraisalFee;
+	type CounterOfferPeriod = CounterOfferPeriod;
+	type MaxCounterOffers = frame_support::traits::ConstU32<2>;
+	type ExpiryScanLimit = ExpiryScanLimit;
 	type WeightInfo = ();
 	type FeesAccount = FeesAccount;
+	type OnStatusChange = ();
+	type MaxStatusHistory = frame_support::traits::ConstU32<5>;
+	type CouncilOrigin =
+		EitherOfDiverse<EnsureRoot<AccountId>, pallet_voting::EnsureCouncilDecision<Test>>;
+	type DemocracyOrigin =
+		EitherOfDiverse<EnsureRoot<AccountId>, pallet_voting::EnsureInvestorsDecision<Test>>;
 }
 
 //---implementing pallet sudo---------
@@ -260,7 +290,7 @@ impl pallet_sudo::Config for Test {
 }
 
 parameter_types! {
-	pub const MaxMembers:u32 =7;
+	pub const MaxMembers:u32 =8;
 }
 impl pallet_roles::Config for Test {
 	type Event = Event;
@@ -273,6 +303,8 @@ parameter_types! {
 	pub const FundThreshold: u64 = 100;
 	pub const MaxFundContribution: u64 = 20;
 	pub const MaxInvestorPerHouse: u32 = 10;
+	pub const MaxExcludedSellers: u32 = 5;
+	pub const MaxPreferredCollections: u32 = 10;
 	pub const HousingFundPalletId: PalletId = PalletId(*b"housfund");
 }
 
@@ -286,6 +318,38 @@ impl pallet_housing_fund::Config for Test {
 	type WeightInfo = ();
 	type PalletId = HousingFundPalletId;
 	type MaxInvestorPerHouse = MaxInvestorPerHouse;
+	type MaxExcludedSellers = MaxExcludedSellers;
+	type MaxPreferredCollections = MaxPreferredCollections;
+	type FundAssets = FundAssets;
+	type FundAssetId = FundAssetId;
+	type MaxWithdrawalsProcessedPerBlock = MaxWithdrawalsProcessedPerBlock;
+	type MaxQueuedWithdrawals = MaxQueuedWithdrawals;
+	type BlocksPerMonth = BlocksPerMonth;
+	type PauseOrigin = EnsureRoot<AccountId>;
+}
+
+parameter_types! {
+	pub const FundAssetId: u32 = 0;
+	pub const MaxWithdrawalsProcessedPerBlock: u32 = 2;
+	pub const MaxQueuedWithdrawals: u32 = 10;
+	pub const BlocksPerMonth: u64 = 10;
+}
+
+impl pallet_assets::Config<pallet_assets::Instance1> for Test {
+	type Event = Event;
+	type Balance = Balance;
+	type AssetId = u32;
+	type Currency = Balances;
+	type ForceOrigin = EnsureRoot<AccountId>;
+	type AssetDeposit = ();
+	type AssetAccountDeposit = ();
+	type MetadataDepositBase = ();
+	type MetadataDepositPerByte = ();
+	type ApprovalDeposit = ();
+	type StringLimit = frame_support::traits::ConstU32<50>;
+	type Freezer = HousingFund;
+	type Extra = ();
+	type WeightInfo = ();
 }
 
 pub const ALICE: AccountId = AccountId::new([1u8; 32]);
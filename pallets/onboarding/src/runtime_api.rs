@@ -0,0 +1,22 @@
+//! Runtime API definition for the onboarding pallet.
+//! It gives access to the structured details of the properties, and to the
+//! assets matching some of their attributes.
+#![allow(clippy::unnecessary_mut_passed)]
+
+use crate::PropertyFilter;
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	pub trait OnboardingApi<CollectionId, ItemId, Details> where
+		CollectionId: Codec,
+		ItemId: Codec,
+		Details: Codec,
+	{
+		/// Structured details of the asset `item_id` of the collection `collection_id`
+		fn property_details(collection_id: CollectionId, item_id: ItemId) -> Option<Details>;
+
+		/// Assets which details match every attribute given in `filter`
+		fn assets_by_attributes(filter: PropertyFilter) -> Vec<(CollectionId, ItemId)>;
+	}
+}
@@ -1,6 +1,10 @@
 use super::*;
-use crate::mock::*;
-use frame_support::assert_ok;
+use crate::{mock::*, Roles::Hash};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{GetStorageVersion, StorageVersion},
+};
+use AssetStatus::*;
 
 pub fn prep_roles() {
 	RoleModule::set_role(Origin::signed(CHARLIE), CHARLIE, Acc::SERVICER).ok();
@@ -17,10 +21,153 @@ pub fn prep_roles() {
 	)
 	.ok();
 	RoleModule::account_approval(Origin::signed(ALICE), ACCOUNT_WITH_NO_BALANCE0).ok();
+	RoleModule::set_role(Origin::signed(NOTARY), NOTARY, Acc::NOTARY).ok();
+	RoleModule::account_approval(Origin::signed(ALICE), NOTARY).ok();
+	RoleModule::set_role(Origin::signed(APPRAISER), APPRAISER, Acc::APPRAISER).ok();
+	RoleModule::account_approval(Origin::signed(ALICE), APPRAISER).ok();
 }
 
 pub const MAX_TENANTS:u8 = 3;
 
+pub const NOTARY: AccountId = AccountId::new([7u8; 32]);
+
+pub const OTHER_SELLER: AccountId = AccountId::new([8u8; 32]);
+
+pub const APPRAISER: AccountId = AccountId::new([9u8; 32]);
+
+pub const ALL_STATUSES: [AssetStatus; 12] = [
+	EDITING, APPRAISING, REVIEWING, VOTING, ONBOARDED, FINALISING, FINALISED, PURCHASED, REJECTED,
+	SLASH, CANCELLED, REPRICING,
+];
+
+pub const ALL_ORIGINS: [TransitionOrigin; 7] = [
+	TransitionOrigin::Seller,
+	TransitionOrigin::Council,
+	TransitionOrigin::Democracy,
+	TransitionOrigin::Bidding,
+	TransitionOrigin::Notary,
+	TransitionOrigin::Appraiser,
+	TransitionOrigin::Expiry,
+];
+
+/// Store an appraisal of the asset at `value`, without going through `submit_appraisal`
+pub fn set_appraisal(collection_id: CollectionId, item_id: ItemId, value: u64) {
+	Houses::<Test>::mutate(collection_id, item_id, |asset| {
+		asset.as_mut().unwrap().appraisal = Some(Appraisal {
+			appraiser: APPRAISER,
+			value,
+			report_hash: Default::default(),
+			fee: 0,
+			block_number: System::block_number(),
+		});
+	});
+}
+
+/// Appraise the asset at `value` on behalf of APPRAISER, without fee
+pub fn appraise(item_id: ItemId, value: u64) -> DispatchResult {
+	OnboardingModule::submit_appraisal(
+		Origin::signed(APPRAISER),
+		OFFICESTEST,
+		item_id,
+		value,
+		Default::default(),
+		0,
+	)
+}
+
+/// Move an asset through the given statuses, each transition with its allowed origin
+/// The asset is appraised at its price when it leaves APPRAISING
+pub fn move_to(collection_id: CollectionId, item_id: ItemId, statuses: Vec<AssetStatus>) {
+	for status in statuses {
+		let asset = Houses::<Test>::get(collection_id, item_id).unwrap();
+		let origin = asset.status.transition_origin(status).unwrap();
+		if origin == TransitionOrigin::Appraiser {
+			set_appraisal(collection_id, item_id, asset.price.unwrap());
+		}
+		assert_ok!(OnboardingModule::do_change_status(collection_id, item_id, status, origin));
+	}
+}
+
+/// Valid details of an office
+pub fn office_details() -> PropertyDetails<Test> {
+	PropertyDetails {
+		address: b"1 Dam Square, Amsterdam".to_vec().try_into().unwrap(),
+		geohash: Some(b"u173zq".to_vec().try_into().unwrap()),
+		surface: 120,
+		rooms: 0,
+		year_built: None,
+		energy_rating: Some(EnergyRating::B),
+		deed_hash: Some(sp_core::H256::repeat_byte(1)),
+		inspection_reports: vec![sp_core::H256::repeat_byte(2)].try_into().unwrap(),
+	}
+}
+
+/// Create a collection and a proposal of BOB, without submitting it
+pub fn create_proposal_of_bob() -> (CollectionId, ItemId) {
+	let metadata: BoundedVec<u8, <Test as pallet_uniques::Config>::StringLimit> =
+		b"metadata".to_vec().try_into().unwrap();
+	prep_roles();
+	NftModule::create_collection(
+		Origin::signed(CHARLIE),
+		OFFICESTEST,
+		CollectionClass::new(AssetClass::OFFICE, MAX_TENANTS),
+		metadata.clone(),
+	)
+	.ok();
+	assert_ok!(OnboardingModule::create_and_submit_proposal(
+		Origin::signed(BOB),
+		OFFICESTEST,
+		Some(100_000_000),
+		metadata,
+		false,
+		Some(MAX_TENANTS),
+		Some(office_details())
+	));
+	let collection_id = OFFICESTEST;
+	let item_id = NftModule::next_item_id(collection_id) - 1;
+	(collection_id, item_id)
+}
+
+/// Force the status of an asset, bypassing the lifecycle
+pub fn force_status(collection_id: CollectionId, item_id: ItemId, status: AssetStatus) {
+	Houses::<Test>::mutate(collection_id, item_id, |asset| {
+		asset.as_mut().unwrap().status = status;
+	});
+}
+
+#[test]
+fn create_proposal_in_a_collection_closed_to_sellers_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		let metadata: BoundedVec<u8, <Test as pallet_uniques::Config>::StringLimit> =
+			b"metadata".to_vec().try_into().unwrap();
+		prep_roles();
+		let class = CollectionClass {
+			allowed_roles: vec![Acc::SERVICER].try_into().unwrap(),
+			..CollectionClass::new(AssetClass::OFFICE, MAX_TENANTS)
+		};
+		assert_ok!(NftModule::create_collection(
+			Origin::signed(CHARLIE),
+			OFFICESTEST,
+			class,
+			metadata.clone(),
+		));
+
+		// the mint error is returned, and the proposal fee is not reserved
+		assert_noop!(
+			OnboardingModule::create_and_submit_proposal(
+				Origin::signed(BOB),
+				OFFICESTEST,
+				Some(100_000_000),
+				metadata,
+				false,
+				Some(MAX_TENANTS),
+				None
+			),
+			pallet_nft::Error::<Test>::NotPermitted
+		);
+	});
+}
+
 #[test]
 fn create_proposal() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -32,22 +179,24 @@ fn create_proposal() {
 		//Charlie creates a collection
 		assert_ok!(NftModule::create_collection(
 			Origin::signed(CHARLIE),
-			NftColl::OFFICESTEST,
+			OFFICESTEST,
+			CollectionClass::new(AssetClass::OFFICE, MAX_TENANTS),
 			metadata0.clone(),
 		));
 		// Bob creates a proposal without submiting for review
 		let price = 100_000_000;
 		assert_ok!(OnboardingModule::create_and_submit_proposal(
 			Origin::signed(BOB),
-			NftColl::OFFICESTEST,
+			OFFICESTEST,
 			Some(price),
 			metadata1,
 			false,
-			MAX_TENANTS
+			Some(MAX_TENANTS),
+			Some(office_details())
 		));
 
-		let coll_id = NftColl::OFFICESTEST.value();
-		let item_id = pallet_nft::ItemsCount::<Test>::get()[coll_id as usize] - 1;
+		let coll_id = OFFICESTEST;
+		let item_id = NftModule::next_item_id(coll_id) - 1;
 		let status: AssetStatus = Houses::<Test>::get(coll_id, item_id).unwrap().status;
 
 		assert!(Houses::<Test>::get(coll_id, item_id).unwrap().representative.is_none());
@@ -61,6 +210,8 @@ fn create_proposal() {
 			}
 			.into(),
 			crate::Event::FundsReserved { from_who: BOB, amount: Some(5_000_000) }.into(),
+			crate::Event::PropertyDetailsSet { who: BOB, collection: coll_id, item: item_id }
+				.into(),
 		]);
 
 		assert_eq!(status, AssetStatus::EDITING);
@@ -69,7 +220,7 @@ fn create_proposal() {
 		let new_price = 150_000_000;
 		assert_ok!(OnboardingModule::set_price(
 			Origin::signed(BOB),
-			NftColl::OFFICESTEST,
+			OFFICESTEST,
 			item_id,
 			Some(new_price)
 		));
@@ -89,7 +240,7 @@ fn create_proposal() {
 		//Bob finally submit the proposal without changing the price a second time
 		assert_ok!(OnboardingModule::submit_awaiting(
 			Origin::signed(BOB),
-			NftColl::OFFICESTEST,
+			OFFICESTEST,
 			item_id,
 			None,
 			Some(metadata0.clone()),
@@ -98,11 +249,62 @@ fn create_proposal() {
 		let house_price = Houses::<Test>::get(coll_id, item_id).unwrap().price;
 		assert_eq!(house_price, Some(150_000_000));
 		let status: AssetStatus = Houses::<Test>::get(coll_id, item_id).unwrap().status;
-		assert_eq!(status, AssetStatus::REVIEWING);
+		assert_eq!(status, AssetStatus::APPRAISING);
 		assert_eq!(Nft::Pallet::<Test>::items(coll_id, item_id).unwrap().metadata, metadata0);
 	});
 }
 
+#[test]
+fn proposal_should_use_the_class_of_its_collection() {
+	ExtBuilder::default().build().execute_with(|| {
+		let metadata: BoundedVec<u8, <Test as pallet_uniques::Config>::StringLimit> =
+			b"metadata".to_vec().try_into().unwrap();
+		prep_roles();
+		// the collection is not registered
+		assert_noop!(
+			OnboardingModule::create_and_submit_proposal(
+				Origin::signed(BOB),
+				OFFICESTEST,
+				Some(100_000_000),
+				metadata.clone(),
+				false,
+				None,
+				None
+			),
+			Error::<Test>::CollectionOrItemUnknown
+		);
+
+		assert_ok!(NftModule::create_collection(
+			Origin::signed(CHARLIE),
+			OFFICESTEST,
+			CollectionClass::new(AssetClass::OFFICE, 5),
+			metadata.clone(),
+		));
+		assert_ok!(OnboardingModule::create_and_submit_proposal(
+			Origin::signed(BOB),
+			OFFICESTEST,
+			Some(100_000_000),
+			metadata.clone(),
+			false,
+			None,
+			None
+		));
+		assert_ok!(OnboardingModule::create_and_submit_proposal(
+			Origin::signed(BOB),
+			OFFICESTEST,
+			Some(100_000_000),
+			metadata,
+			false,
+			Some(MAX_TENANTS),
+			None
+		));
+		assert_eq!(NftModule::next_item_id(OFFICESTEST), 2);
+		// the maximum number of tenants defaults to the one of the collection class
+		assert_eq!(Houses::<Test>::get(OFFICESTEST, 0).unwrap().max_tenants, 5);
+		assert_eq!(Houses::<Test>::get(OFFICESTEST, 1).unwrap().max_tenants, MAX_TENANTS);
+	});
+}
+
 #[test]
 fn create_proposal_2() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -114,31 +316,42 @@ fn create_proposal_2() {
 		//Charlie creates a collection
 		assert_ok!(NftModule::create_collection(
 			Origin::signed(CHARLIE),
-			NftColl::OFFICESTEST,
+			OFFICESTEST,
+			CollectionClass::new(AssetClass::OFFICE, MAX_TENANTS),
 			metadata0
 		));
 		// Bob creates a proposal and submit it for review
 		let price = 100_000_000;
 		assert_ok!(OnboardingModule::create_and_submit_proposal(
 			Origin::signed(BOB),
-			NftColl::OFFICESTEST,
+			OFFICESTEST,
 			Some(price),
 			metadata1,
 			true,
-			MAX_TENANTS
+			Some(MAX_TENANTS),
+			Some(office_details())
 		));
 
-		let coll_id = NftColl::OFFICESTEST.value();
-		let item_id = pallet_nft::ItemsCount::<Test>::get()[coll_id as usize] - 1;
+		let coll_id = OFFICESTEST;
+		let item_id = NftModule::next_item_id(coll_id) - 1;
+		assert_eq!(Houses::<Test>::get(coll_id, item_id).unwrap().status, AssetStatus::APPRAISING);
+		assert_ok!(appraise(item_id, price));
 
 		let status: AssetStatus = Houses::<Test>::get(coll_id, item_id).unwrap().status;
 
 		let out_call = OnboardingModule::voting_calls(coll_id, item_id).unwrap();
+		let w_status0 = Box::new(
+			OnboardingModule::get_formatted_collective_proposal(*out_call.democracy_status)
+				.unwrap(),
+		);
 		let w_status1 = Box::new(
 			OnboardingModule::get_formatted_collective_proposal(*out_call.after_vote_status)
 				.unwrap(),
 		);
-		assert_ok!(w_status1.dispatch(Origin::signed(ALICE)));
+		// the status calls are dispatched on behalf of the council and of the referendum
+		assert_ok!(w_status0.dispatch(Origin::root()));
+		assert!(w_status1.clone().dispatch(Origin::signed(ALICE)).is_err());
+		assert_ok!(w_status1.dispatch(Origin::root()));
 
 		let status_bis: AssetStatus = Houses::<Test>::get(coll_id, item_id).unwrap().status;
 		assert_ne!(status.clone(), status_bis.clone());
@@ -183,7 +396,8 @@ fn proposal_rejections() {
 		//Charlie creates a collection
 		assert_ok!(NftModule::create_collection(
 			Origin::signed(CHARLIE),
-			NftColl::OFFICESTEST,
+			OFFICESTEST,
+			CollectionClass::new(AssetClass::OFFICE, MAX_TENANTS),
 			metadata0
 		));
 
@@ -192,16 +406,18 @@ fn proposal_rejections() {
 		let price1 = 150_000_000;
 		assert_ok!(OnboardingModule::create_and_submit_proposal(
 			Origin::signed(BOB),
-			NftColl::OFFICESTEST,
+			OFFICESTEST,
 			Some(price0),
 			metadata1,
 			true,
-			MAX_TENANTS
+			Some(MAX_TENANTS),
+			Some(office_details())
 		));
-		let coll_id = NftColl::OFFICESTEST.value();
-		let item_id0 = pallet_nft::ItemsCount::<Test>::get()[coll_id as usize] - 1;
+		let coll_id = OFFICESTEST;
+		let item_id0 = NftModule::next_item_id(coll_id) - 1;
 		let status_0: AssetStatus = Houses::<Test>::get(coll_id, item_id0).unwrap().status;
-		assert_eq!(status_0, AssetStatus::REVIEWING);
+		assert_eq!(status_0, AssetStatus::APPRAISING);
+		assert_ok!(appraise(item_id0, price0));
 		let initial_balance = <Test as pallet_uniques::Config>::Currency::free_balance(&BOB);
 		let fees_balance0 = <Test as pallet_uniques::Config>::Currency::total_balance(
 			&OnboardingModule::account_id(),
@@ -209,29 +425,35 @@ fn proposal_rejections() {
 
 		assert_ok!(OnboardingModule::create_and_submit_proposal(
 			Origin::signed(BOB),
-			NftColl::OFFICESTEST,
+			OFFICESTEST,
 			Some(price1),
 			metadata2,
 			true,
-			MAX_TENANTS
+			Some(MAX_TENANTS),
+			Some(office_details())
 		));
-		let item_id1 = pallet_nft::ItemsCount::<Test>::get()[coll_id as usize] - 1;
+		let item_id1 = NftModule::next_item_id(coll_id) - 1;
 		let status_1: AssetStatus = Houses::<Test>::get(coll_id, item_id0).unwrap().status;
 		let balance0 = <Test as pallet_uniques::Config>::Currency::free_balance(&BOB);
+		assert_ok!(appraise(item_id1, price1));
 
 		assert_eq!(status_1, AssetStatus::REVIEWING);
 
-		//Chalie Reject_Edit first proposal
+		//The council Reject_Edit first proposal
 		let house0 = Houses::<Test>::get(coll_id, item_id0).unwrap();
-		assert_ok!(OnboardingModule::reject_edit(
-			Origin::signed(CHARLIE),
-			NftColl::OFFICESTEST,
-			item_id0,
-			house0
-		));
+		assert_noop!(
+			OnboardingModule::reject_edit(
+				Origin::signed(CHARLIE),
+				OFFICESTEST,
+				item_id0,
+				house0.clone()
+			),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(OnboardingModule::reject_edit(Origin::root(), OFFICESTEST, item_id0, house0));
 
 		expect_events(vec![crate::Event::RejectedForEditing {
-			by_who: CHARLIE,
+			by_who: BOB,
 			collection: coll_id,
 			item: item_id0,
 		}
@@ -245,17 +467,12 @@ fn proposal_rejections() {
 		);
 		assert_ne!(fees_balance1, fees_balance0);
 
-		//Charlie Reject_Destroy second proposal
+		//The council Reject_Destroy second proposal
 		let house1 = Houses::<Test>::get(coll_id, item_id1).unwrap();
-		assert_ok!(OnboardingModule::reject_destroy(
-			Origin::signed(CHARLIE),
-			NftColl::OFFICESTEST,
-			item_id1,
-			house1
-		));
+		assert_ok!(OnboardingModule::reject_destroy(Origin::root(), OFFICESTEST, item_id1, house1));
 
 		expect_events(vec![crate::Event::RejectedForDestruction {
-			by_who: CHARLIE,
+			by_who: BOB,
 			collection: coll_id,
 			item: item_id1,
 		}
@@ -291,17 +508,19 @@ fn get_onboarded_houses_no_onboarded_houses() {
 		//Charlie creates a collection
 		assert_ok!(NftModule::create_collection(
 			Origin::signed(CHARLIE),
-			NftColl::OFFICESTEST,
+			OFFICESTEST,
+			CollectionClass::new(AssetClass::OFFICE, MAX_TENANTS),
 			metadata0
 		));
 		// Bob creates a proposal without submiting for review
 		assert_ok!(OnboardingModule::create_and_submit_proposal(
 			Origin::signed(BOB),
-			NftColl::OFFICESTEST,
+			OFFICESTEST,
 			Some(100_000_000),
 			metadata1,
 			false,
-			MAX_TENANTS
+			Some(MAX_TENANTS),
+			None
 		));
 
 		let onboarded_houses = OnboardingModule::get_onboarded_houses();
@@ -322,40 +541,38 @@ fn get_onboarded_houses_with_onboarded_houses() {
 		//Charlie creates a collection
 		assert_ok!(NftModule::create_collection(
 			Origin::signed(CHARLIE),
-			NftColl::OFFICESTEST,
+			OFFICESTEST,
+			CollectionClass::new(AssetClass::OFFICE, MAX_TENANTS),
 			metadata0
 		));
 		// Bob creates a proposal without submiting for review
 		let price = 100_000_000;
 		assert_ok!(OnboardingModule::create_and_submit_proposal(
 			Origin::signed(BOB),
-			NftColl::OFFICESTEST,
+			OFFICESTEST,
 			Some(price),
 			metadata1,
 			false,
-			MAX_TENANTS
+			Some(MAX_TENANTS),
+			None
 		));
 
-		let collection_id = NftColl::OFFICESTEST.value();
-		let item_id = pallet_nft::ItemsCount::<Test>::get()[collection_id as usize] - 1;
+		let collection_id = OFFICESTEST;
+		let item_id = NftModule::next_item_id(collection_id) - 1;
 
 		// we simulate for the the presence of an onboarded house by changing its status
-		assert_ok!(OnboardingModule::change_status(
-			Origin::signed(BOB),
-			NftColl::OFFICESTEST,
-			item_id,
-			AssetStatus::ONBOARDED,
-		));
+		move_to(collection_id, item_id, vec![APPRAISING, REVIEWING, VOTING, ONBOARDED]);
 
 		let price2 = 200_000_000;
 		// we add a new asset that won't have the ONBOARDED status
 		assert_ok!(OnboardingModule::create_and_submit_proposal(
 			Origin::signed(BOB),
-			NftColl::OFFICESTEST,
+			OFFICESTEST,
 			Some(price2),
 			metadata2,
 			false,
-			MAX_TENANTS
+			Some(MAX_TENANTS),
+			None
 		));
 
 		// we check that the onboarded house is correctly retrieved
@@ -381,17 +598,19 @@ fn get_finalised_houses_no_finalised_houses() {
 		//Charlie creates a collection
 		assert_ok!(NftModule::create_collection(
 			Origin::signed(CHARLIE),
-			NftColl::OFFICESTEST,
+			OFFICESTEST,
+			CollectionClass::new(AssetClass::OFFICE, MAX_TENANTS),
 			metadata0
 		));
 		// Bob creates a proposal without submiting for review
 		assert_ok!(OnboardingModule::create_and_submit_proposal(
 			Origin::signed(BOB),
-			NftColl::OFFICESTEST,
+			OFFICESTEST,
 			Some(100_000_000),
 			metadata1,
 			false,
-			MAX_TENANTS
+			Some(MAX_TENANTS),
+			None
 		));
 
 		let finalised_houses = OnboardingModule::get_finalised_houses();
@@ -412,40 +631,42 @@ fn get_finalised_houses_with_finalised_houses() {
 		//Charlie creates a collection
 		assert_ok!(NftModule::create_collection(
 			Origin::signed(CHARLIE),
-			NftColl::OFFICESTEST,
+			OFFICESTEST,
+			CollectionClass::new(AssetClass::OFFICE, MAX_TENANTS),
 			metadata0
 		));
 		// Bob creates a proposal without submiting for review
 		let price = 100_000_000;
 		assert_ok!(OnboardingModule::create_and_submit_proposal(
 			Origin::signed(BOB),
-			NftColl::OFFICESTEST,
+			OFFICESTEST,
 			Some(price),
 			metadata1,
 			false,
-			MAX_TENANTS
+			Some(MAX_TENANTS),
+			None
 		));
 
-		let collection_id = NftColl::OFFICESTEST.value();
-		let item_id = pallet_nft::ItemsCount::<Test>::get()[collection_id as usize] - 1;
+		let collection_id = OFFICESTEST;
+		let item_id = NftModule::next_item_id(collection_id) - 1;
 
 		// we simulate for the the presence of a finalised house by changing its status
-		assert_ok!(OnboardingModule::change_status(
-			Origin::signed(BOB),
-			NftColl::OFFICESTEST,
+		move_to(
+			collection_id,
 			item_id,
-			AssetStatus::FINALISED
-		));
+			vec![APPRAISING, REVIEWING, VOTING, ONBOARDED, FINALISING, FINALISED],
+		);
 
 		let price2 = 200_000_000;
 		// we add a new asset that won't have the FINALISED status
 		assert_ok!(OnboardingModule::create_and_submit_proposal(
 			Origin::signed(BOB),
-			NftColl::OFFICESTEST,
+			OFFICESTEST,
 			Some(price2),
 			metadata2,
 			false,
-			MAX_TENANTS
+			Some(MAX_TENANTS),
+			None
 		));
 
 		// we check that the finalised house is correctly retrieved
@@ -471,17 +692,19 @@ fn get_finalising_houses_no_finalising_houses() {
 		//Charlie creates a collection
 		assert_ok!(NftModule::create_collection(
 			Origin::signed(CHARLIE),
-			NftColl::OFFICESTEST,
+			OFFICESTEST,
+			CollectionClass::new(AssetClass::OFFICE, MAX_TENANTS),
 			metadata0
 		));
 		// Bob creates a proposal without submiting for review
 		assert_ok!(OnboardingModule::create_and_submit_proposal(
 			Origin::signed(BOB),
-			NftColl::OFFICESTEST,
+			OFFICESTEST,
 			Some(100_000_000),
 			metadata1,
 			false,
-			MAX_TENANTS
+			Some(MAX_TENANTS),
+			None
 		));
 
 		let finalising_houses = OnboardingModule::get_finalising_houses();
@@ -502,40 +725,38 @@ fn get_finalising_houses_with_finalising_houses() {
 		//Charlie creates a collection
 		assert_ok!(NftModule::create_collection(
 			Origin::signed(CHARLIE),
-			NftColl::OFFICESTEST,
+			OFFICESTEST,
+			CollectionClass::new(AssetClass::OFFICE, MAX_TENANTS),
 			metadata0
 		));
 		// Bob creates a proposal without submiting for review
 		let price = 100_000_000;
 		assert_ok!(OnboardingModule::create_and_submit_proposal(
 			Origin::signed(BOB),
-			NftColl::OFFICESTEST,
+			OFFICESTEST,
 			Some(price),
 			metadata1,
 			false,
-			MAX_TENANTS
+			Some(MAX_TENANTS),
+			None
 		));
 
-		let collection_id = NftColl::OFFICESTEST.value();
-		let item_id = pallet_nft::ItemsCount::<Test>::get()[collection_id as usize] - 1;
+		let collection_id = OFFICESTEST;
+		let item_id = NftModule::next_item_id(collection_id) - 1;
 
 		// we simulate for the the presence of a finalising house by changing its status
-		assert_ok!(OnboardingModule::change_status(
-			Origin::signed(BOB),
-			NftColl::OFFICESTEST,
-			item_id,
-			AssetStatus::FINALISING
-		));
+		move_to(collection_id, item_id, vec![APPRAISING, REVIEWING, VOTING, ONBOARDED, FINALISING]);
 
 		let price2 = 200_000_000;
 		// we add a new asset that won't have the FINALISING status
 		assert_ok!(OnboardingModule::create_and_submit_proposal(
 			Origin::signed(BOB),
-			NftColl::OFFICESTEST,
+			OFFICESTEST,
 			Some(price2),
 			metadata2,
 			false,
-			MAX_TENANTS
+			Some(MAX_TENANTS),
+			None
 		));
 
 		// we check that the finalising house is correctly retrieved
@@ -547,3 +768,1523 @@ fn get_finalising_houses_with_finalising_houses() {
 		assert_eq!(house.2.price, Some(price));
 	});
 }
+
+#[test]
+fn lifecycle_should_only_allow_its_transitions() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, item_id) = create_proposal_of_bob();
+		set_appraisal(collection_id, item_id, 100_000_000);
+
+		let mut allowed = Vec::new();
+		for from in ALL_STATUSES {
+			for to in ALL_STATUSES {
+				for origin in ALL_ORIGINS {
+					force_status(collection_id, item_id, from);
+					if from.transition_origin(to) == Some(origin) {
+						assert_ok!(OnboardingModule::do_change_status(
+							collection_id,
+							item_id,
+							to,
+							origin
+						));
+						assert_eq!(Houses::<Test>::get(collection_id, item_id).unwrap().status, to);
+						allowed.push((from, to, origin));
+					} else {
+						assert_noop!(
+							OnboardingModule::do_change_status(collection_id, item_id, to, origin),
+							Error::<Test>::InvalidTransition
+						);
+					}
+				}
+			}
+		}
+
+		assert_eq!(
+			allowed,
+			vec![
+				(EDITING, APPRAISING, TransitionOrigin::Seller),
+				(APPRAISING, EDITING, TransitionOrigin::Expiry),
+				(APPRAISING, REVIEWING, TransitionOrigin::Appraiser),
+				(APPRAISING, REJECTED, TransitionOrigin::Appraiser),
+				(REVIEWING, EDITING, TransitionOrigin::Expiry),
+				(REVIEWING, VOTING, TransitionOrigin::Council),
+				(REVIEWING, REJECTED, TransitionOrigin::Council),
+				(REVIEWING, SLASH, TransitionOrigin::Council),
+				(VOTING, EDITING, TransitionOrigin::Expiry),
+				(VOTING, ONBOARDED, TransitionOrigin::Democracy),
+				(VOTING, REJECTED, TransitionOrigin::Democracy),
+				(VOTING, SLASH, TransitionOrigin::Democracy),
+				(ONBOARDED, EDITING, TransitionOrigin::Expiry),
+				(ONBOARDED, FINALISING, TransitionOrigin::Bidding),
+				(ONBOARDED, REPRICING, TransitionOrigin::Bidding),
+				(FINALISING, FINALISED, TransitionOrigin::Notary),
+				(FINALISING, REJECTED, TransitionOrigin::Notary),
+				(FINALISED, PURCHASED, TransitionOrigin::Bidding),
+				(FINALISED, CANCELLED, TransitionOrigin::Seller),
+				(REJECTED, APPRAISING, TransitionOrigin::Seller),
+				(REPRICING, APPRAISING, TransitionOrigin::Seller),
+			]
+		);
+	});
+}
+
+#[test]
+fn change_status_should_require_the_origin_of_the_transition() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, item_id) = create_proposal_of_bob();
+		set_appraisal(collection_id, item_id, 100_000_000);
+		// the seller owning the asset, the council or the referendum, a notary, an investor
+		let origins =
+			[Origin::signed(BOB), Origin::root(), Origin::signed(NOTARY), Origin::signed(DAVE)];
+
+		for from in ALL_STATUSES {
+			for to in ALL_STATUSES {
+				for (index, origin) in origins.iter().enumerate() {
+					force_status(collection_id, item_id, from);
+					let expected: DispatchResult = match (from.transition_origin(to), index) {
+						(None, _) => Err(Error::<Test>::InvalidTransition.into()),
+						(Some(TransitionOrigin::Council | TransitionOrigin::Democracy), 1) =>
+							Ok(()),
+						// the other transitions cannot be triggered through `change_status`
+						_ => Err(DispatchError::BadOrigin),
+					};
+					assert_eq!(
+						OnboardingModule::change_status(origin.clone(), OFFICESTEST, item_id, to),
+						expected,
+						"{from:?} -> {to:?} by origin {index}"
+					);
+				}
+			}
+		}
+	});
+}
+
+#[test]
+fn seller_transitions_should_be_reserved_to_the_owner() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, item_id) = create_proposal_of_bob();
+		RoleModule::set_role(Origin::signed(OTHER_SELLER), OTHER_SELLER, Acc::SELLER).ok();
+		RoleModule::account_approval(Origin::signed(ALICE), OTHER_SELLER).ok();
+
+		assert_noop!(
+			OnboardingModule::submit_awaiting(
+				Origin::signed(OTHER_SELLER),
+				OFFICESTEST,
+				item_id,
+				None,
+				None
+			),
+			Error::<Test>::NotTheTokenOwner
+		);
+		assert_eq!(Houses::<Test>::get(collection_id, item_id).unwrap().status, EDITING);
+	});
+}
+
+#[test]
+fn seller_and_notary_transitions_should_not_go_through_change_status() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, item_id) = create_proposal_of_bob();
+		let change_status =
+			|origin, status| OnboardingModule::change_status(origin, OFFICESTEST, item_id, status);
+
+		// the submission goes through `submit_awaiting`
+		assert_noop!(change_status(Origin::signed(BOB), APPRAISING), DispatchError::BadOrigin);
+		force_status(collection_id, item_id, REJECTED);
+		assert_noop!(change_status(Origin::signed(BOB), APPRAISING), DispatchError::BadOrigin);
+
+		// the decisions on the purchase go through the finalizer pallet
+		force_status(collection_id, item_id, FINALISING);
+		assert_noop!(change_status(Origin::signed(NOTARY), REJECTED), DispatchError::BadOrigin);
+		assert_noop!(change_status(Origin::signed(NOTARY), FINALISED), DispatchError::BadOrigin);
+		force_status(collection_id, item_id, FINALISED);
+		assert_noop!(change_status(Origin::signed(BOB), CANCELLED), DispatchError::BadOrigin);
+	});
+}
+
+#[test]
+fn status_history_should_keep_the_latest_transitions() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, item_id) = create_proposal_of_bob();
+		move_to(
+			collection_id,
+			item_id,
+			vec![APPRAISING, REVIEWING, VOTING, ONBOARDED, FINALISING, FINALISED],
+		);
+
+		// the oldest transition is dropped once MaxStatusHistory transitions are recorded
+		let history = OnboardingModule::status_history(collection_id, item_id);
+		assert_eq!(history.len(), 5);
+		assert_eq!((history[0].from, history[0].to), (APPRAISING, REVIEWING));
+		assert_eq!((history[4].from, history[4].to), (FINALISING, FINALISED));
+	});
+}
+
+#[test]
+fn status_history_should_record_the_transitions() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, item_id) = create_proposal_of_bob();
+		assert!(OnboardingModule::status_history(collection_id, item_id).is_empty());
+
+		let block_number = System::block_number();
+		assert_ok!(OnboardingModule::submit_awaiting(
+			Origin::signed(BOB),
+			OFFICESTEST,
+			item_id,
+			None,
+			None
+		));
+		assert_ok!(appraise(item_id, 100_000_000));
+		System::set_block_number(block_number + 1);
+		assert_ok!(OnboardingModule::change_status(Origin::root(), OFFICESTEST, item_id, VOTING));
+
+		assert_eq!(
+			OnboardingModule::status_history(collection_id, item_id),
+			vec![
+				StatusChange::<Test> {
+					from: EDITING,
+					to: APPRAISING,
+					origin: TransitionOrigin::Seller,
+					block_number,
+				},
+				StatusChange::<Test> {
+					from: APPRAISING,
+					to: REVIEWING,
+					origin: TransitionOrigin::Appraiser,
+					block_number,
+				},
+				StatusChange::<Test> {
+					from: REVIEWING,
+					to: VOTING,
+					origin: TransitionOrigin::Council,
+					block_number: block_number + 1,
+				},
+			]
+		);
+	});
+}
+
+/// Check that the listing of Bob was withdrawn, with `fee` released and `slashed` sent to the fees
+/// account
+fn assert_withdrawn(
+	collection_id: CollectionId,
+	item_id: ItemId,
+	reserved: u64,
+	fees_balance: u64,
+	fee: u64,
+	slashed: u64,
+) {
+	assert_eq!(Balances::reserved_balance(&BOB), reserved - fee);
+	assert_eq!(Balances::free_balance(&OnboardingModule::account_id()), fees_balance + slashed);
+	assert!(OnboardingModule::houses(collection_id, item_id).is_none());
+	assert!(OnboardingModule::prices(collection_id, item_id).is_none());
+	assert!(OnboardingModule::voting_calls(collection_id, item_id).is_none());
+	assert!(NftModule::owner(collection_id, item_id).is_none());
+	assert_eq!(OnboardingModule::proposal_deposits(collection_id, item_id), 0);
+	assert!(OnboardingModule::property_details(collection_id, item_id).is_none());
+	expect_events(vec![mock::Event::OnboardingModule(crate::Event::ListingWithdrawn {
+		who: BOB,
+		collection: collection_id,
+		item: item_id,
+		refunded: fee - slashed,
+		slashed,
+	})]);
+}
+
+#[test]
+fn withdraw_listing_while_editing_should_refund_the_fee() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, item_id) = create_proposal_of_bob();
+		let reserved = Balances::reserved_balance(&BOB);
+		let fees_balance = Balances::free_balance(&OnboardingModule::account_id());
+
+		assert_ok!(OnboardingModule::withdraw_listing(Origin::signed(BOB), OFFICESTEST, item_id));
+
+		// 5% of the price was reserved
+		assert_withdrawn(collection_id, item_id, reserved, fees_balance, 5_000_000, 0);
+	});
+}
+
+#[test]
+fn withdraw_listing_during_appraisal_should_slash_the_fee() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, item_id) = create_proposal_of_bob();
+		assert_ok!(OnboardingModule::submit_awaiting(
+			Origin::signed(BOB),
+			OFFICESTEST,
+			item_id,
+			None,
+			None
+		));
+		let reserved = Balances::reserved_balance(&BOB);
+		let fees_balance = Balances::free_balance(&OnboardingModule::account_id());
+
+		assert_ok!(OnboardingModule::withdraw_listing(Origin::signed(BOB), OFFICESTEST, item_id));
+
+		// 10% of the fee is slashed
+		assert_withdrawn(collection_id, item_id, reserved, fees_balance, 5_000_000, 500_000);
+	});
+}
+
+#[test]
+fn slashed_fee_should_be_shared_with_the_housing_fund() {
+	ExtBuilder::default().build().execute_with(|| {
+		FundFeeShare::set(&Percent::from_percent(50));
+		let (collection_id, item_id) = create_proposal_of_bob();
+		assert_ok!(OnboardingModule::submit_awaiting(
+			Origin::signed(BOB),
+			OFFICESTEST,
+			item_id,
+			None,
+			None
+		));
+		let fund_account = HousingFund::fund_account_id();
+		let fund_balance = Balances::free_balance(&fund_account);
+		let fees_balance = Balances::free_balance(&OnboardingModule::account_id());
+
+		assert_ok!(OnboardingModule::withdraw_listing(Origin::signed(BOB), OFFICESTEST, item_id));
+
+		// half of the slashed fee is credited to the housing fund contributors
+		assert_eq!(OnboardingModule::proposal_deposits(collection_id, item_id), 0);
+		assert_eq!(Balances::free_balance(&fund_account), fund_balance + 250_000);
+		assert_eq!(Balances::free_balance(&OnboardingModule::account_id()), fees_balance + 250_000);
+		assert!(System::events().iter().any(|record| record.event ==
+			mock::Event::HousingFund(pallet_housing_fund::Event::IncomeDistributed(
+				OnboardingModule::account_id(),
+				250_000,
+				System::block_number()
+			))));
+	});
+}
+
+#[test]
+fn withdraw_listing_during_review_should_slash_the_fee() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, item_id) = create_proposal_of_bob();
+		assert_ok!(OnboardingModule::submit_awaiting(
+			Origin::signed(BOB),
+			OFFICESTEST,
+			item_id,
+			None,
+			None
+		));
+		assert_ok!(appraise(item_id, 100_000_000));
+		let proposal_hash = OnboardingModule::houses(collection_id, item_id).unwrap().proposal_hash;
+		let collective_hash =
+			VotingModule::voting_proposals(proposal_hash).unwrap().collective_hash;
+		let reserved = Balances::reserved_balance(&BOB);
+		let fees_balance = Balances::free_balance(&OnboardingModule::account_id());
+
+		assert_ok!(OnboardingModule::withdraw_listing(Origin::signed(BOB), OFFICESTEST, item_id));
+
+		// 10% of the fee is slashed
+		assert_withdrawn(collection_id, item_id, reserved, fees_balance, 5_000_000, 500_000);
+		assert!(VotingModule::voting_proposals(proposal_hash).is_none());
+		assert!(Collective::proposal_of(collective_hash).is_none());
+	});
+}
+
+#[test]
+fn withdraw_listing_during_investors_vote_should_slash_the_fee() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, item_id) = create_proposal_of_bob();
+		assert_ok!(OnboardingModule::submit_awaiting(
+			Origin::signed(BOB),
+			OFFICESTEST,
+			item_id,
+			None,
+			None
+		));
+		assert_ok!(appraise(item_id, 100_000_000));
+		let proposal_hash = OnboardingModule::houses(collection_id, item_id).unwrap().proposal_hash;
+
+		// the council approves the proposal
+		for member in [ALICE, BOB, CHARLIE] {
+			assert_ok!(VotingModule::council_vote(Origin::signed(member), proposal_hash, true));
+		}
+		System::set_block_number(System::block_number() + 3);
+		assert_ok!(VotingModule::council_close_vote(Origin::signed(ALICE), proposal_hash));
+		assert_eq!(OnboardingModule::houses(collection_id, item_id).unwrap().status, VOTING);
+
+		let referendum_index = VotingModule::voting_proposals(proposal_hash)
+			.unwrap()
+			.democracy_referendum_index;
+		assert!(Democracy::referendum_info(referendum_index).is_some());
+		let reserved = Balances::reserved_balance(&BOB);
+		let fees_balance = Balances::free_balance(&OnboardingModule::account_id());
+
+		assert_ok!(OnboardingModule::withdraw_listing(Origin::signed(BOB), OFFICESTEST, item_id));
+
+		assert_withdrawn(collection_id, item_id, reserved, fees_balance, 5_000_000, 500_000);
+		assert!(VotingModule::voting_proposals(proposal_hash).is_none());
+		assert!(Democracy::referendum_info(referendum_index).is_none());
+	});
+}
+
+#[test]
+fn withdraw_listing_after_rejection_should_refund_the_remaining_fee() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, item_id) = create_proposal_of_bob();
+		move_to(collection_id, item_id, vec![APPRAISING, REVIEWING, REJECTED]);
+		let reserved = Balances::reserved_balance(&BOB);
+		let fees_balance = Balances::free_balance(&OnboardingModule::account_id());
+
+		assert_ok!(OnboardingModule::withdraw_listing(Origin::signed(BOB), OFFICESTEST, item_id));
+
+		assert_withdrawn(collection_id, item_id, reserved, fees_balance, 5_000_000, 0);
+	});
+}
+
+#[test]
+fn withdraw_listing_once_onboarded_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, item_id) = create_proposal_of_bob();
+
+		for status in [ONBOARDED, FINALISING, FINALISED, PURCHASED, CANCELLED, SLASH] {
+			force_status(collection_id, item_id, status);
+			assert_noop!(
+				OnboardingModule::withdraw_listing(Origin::signed(BOB), OFFICESTEST, item_id),
+				Error::<Test>::CannotWithdrawItem
+			);
+		}
+	});
+}
+
+#[test]
+fn withdraw_listing_should_be_reserved_to_the_owner() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (_, item_id) = create_proposal_of_bob();
+		RoleModule::set_role(Origin::signed(OTHER_SELLER), OTHER_SELLER, Acc::SELLER).ok();
+		RoleModule::account_approval(Origin::signed(ALICE), OTHER_SELLER).ok();
+
+		assert_noop!(
+			OnboardingModule::withdraw_listing(Origin::signed(OTHER_SELLER), OFFICESTEST, item_id),
+			Error::<Test>::NotTheTokenOwner
+		);
+		assert_noop!(
+			OnboardingModule::withdraw_listing(Origin::signed(DAVE), OFFICESTEST, item_id),
+			Error::<Test>::ReservedToSeller
+		);
+	});
+}
+
+#[test]
+fn proposal_fee_should_be_tracked_per_asset() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, item_id0) = create_proposal_of_bob();
+		let (_, item_id1) = create_proposal_of_bob();
+		assert_eq!(OnboardingModule::proposal_deposits(collection_id, item_id0), 5_000_000);
+		assert_eq!(OnboardingModule::proposal_deposits(collection_id, item_id1), 5_000_000);
+
+		// Bob holds a reserve unrelated to his listings
+		assert_ok!(Balances::reserve(&BOB, 1_000));
+		let reserved = Balances::reserved_balance(&BOB);
+		let fees_balance = Balances::free_balance(&OnboardingModule::account_id());
+
+		move_to(collection_id, item_id0, vec![APPRAISING, REVIEWING]);
+		assert_ok!(OnboardingModule::reject_edit(
+			Origin::root(),
+			OFFICESTEST,
+			item_id0,
+			OnboardingModule::houses(collection_id, item_id0).unwrap()
+		));
+
+		// only 10% of the fee of the rejected asset is slashed
+		assert_eq!(OnboardingModule::proposal_deposits(collection_id, item_id0), 4_500_000);
+		assert_eq!(OnboardingModule::proposal_deposits(collection_id, item_id1), 5_000_000);
+		assert_eq!(Balances::reserved_balance(&BOB), reserved - 500_000);
+		assert_eq!(Balances::free_balance(&OnboardingModule::account_id()), fees_balance + 500_000);
+	});
+}
+
+#[test]
+fn proposal_fee_should_be_settled_on_sale() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, item_id0) = create_proposal_of_bob();
+		let (_, item_id1) = create_proposal_of_bob();
+		assert_ok!(Balances::reserve(&BOB, 1_000));
+		Balances::make_free_balance_be(
+			&pallet_housing_fund::Pallet::<Test>::fund_account_id(),
+			200_000_000,
+		);
+		let reserved = Balances::reserved_balance(&BOB);
+		let fees_balance = Balances::free_balance(&OnboardingModule::account_id());
+
+		move_to(
+			collection_id,
+			item_id0,
+			vec![APPRAISING, REVIEWING, VOTING, ONBOARDED, FINALISING, FINALISED],
+		);
+		let asset = OnboardingModule::houses(collection_id, item_id0).unwrap();
+		assert_ok!(OnboardingModule::do_buy(OFFICESTEST, item_id0, DAVE, asset));
+
+		// 20% of the fee goes to the fees account, the rest is released to the seller and the
+		// other reserves of the seller are untouched
+		assert_eq!(OnboardingModule::proposal_deposits(collection_id, item_id0), 0);
+		assert_eq!(OnboardingModule::proposal_deposits(collection_id, item_id1), 5_000_000);
+		assert_eq!(Balances::reserved_balance(&BOB), reserved - 5_000_000);
+		assert_eq!(
+			Balances::free_balance(&OnboardingModule::account_id()),
+			fees_balance + 1_000_000
+		);
+		assert_eq!(NftModule::owner(collection_id, item_id0), Some(DAVE));
+		expect_events(vec![mock::Event::OnboardingModule(crate::Event::ProposalFeeSettled {
+			who: BOB,
+			collection: collection_id,
+			item: item_id0,
+			released: 4_000_000,
+			fee: 1_000_000,
+		})]);
+	});
+}
+
+#[test]
+fn set_property_details_should_succeed() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, item_id) = create_proposal_of_bob();
+		assert_eq!(
+			OnboardingModule::property_details(collection_id, item_id),
+			Some(office_details())
+		);
+
+		let details = PropertyDetails { rooms: 4, ..office_details() };
+		assert_ok!(OnboardingModule::set_property_details(
+			Origin::signed(BOB),
+			OFFICESTEST,
+			item_id,
+			details.clone()
+		));
+
+		assert_eq!(OnboardingModule::property_details(collection_id, item_id), Some(details));
+		expect_events(vec![mock::Event::OnboardingModule(crate::Event::PropertyDetailsSet {
+			who: BOB,
+			collection: collection_id,
+			item: item_id,
+		})]);
+
+		// the details can be amended after a rejection
+		move_to(collection_id, item_id, vec![APPRAISING, REVIEWING, REJECTED]);
+		let details = PropertyDetails { surface: 150, ..office_details() };
+		assert_ok!(OnboardingModule::set_property_details(
+			Origin::signed(BOB),
+			OFFICESTEST,
+			item_id,
+			details.clone()
+		));
+		assert_eq!(OnboardingModule::property_details(collection_id, item_id), Some(details));
+	});
+}
+
+#[test]
+fn set_property_details_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, item_id) = create_proposal_of_bob();
+		let set_details = |origin, details| {
+			OnboardingModule::set_property_details(origin, OFFICESTEST, item_id, details)
+		};
+
+		assert_noop!(
+			set_details(Origin::signed(DAVE), office_details()),
+			Error::<Test>::ReservedToSeller
+		);
+		RoleModule::set_role(Origin::signed(OTHER_SELLER), OTHER_SELLER, Acc::SELLER).ok();
+		RoleModule::account_approval(Origin::signed(ALICE), OTHER_SELLER).ok();
+		assert_noop!(
+			set_details(Origin::signed(OTHER_SELLER), office_details()),
+			Error::<Test>::NotTheTokenOwner
+		);
+
+		// malformed details
+		let no_address = PropertyDetails { address: Default::default(), ..office_details() };
+		assert_noop!(
+			set_details(Origin::signed(BOB), no_address),
+			Error::<Test>::InvalidPropertyDetail
+		);
+		let no_surface = PropertyDetails { surface: 0, ..office_details() };
+		assert_noop!(
+			set_details(Origin::signed(BOB), no_surface),
+			Error::<Test>::InvalidPropertyDetail
+		);
+		let bad_geohash = PropertyDetails {
+			geohash: Some(b"u17a".to_vec().try_into().unwrap()),
+			..office_details()
+		};
+		assert_noop!(
+			set_details(Origin::signed(BOB), bad_geohash),
+			Error::<Test>::InvalidPropertyDetail
+		);
+
+		// an office needs an energy rating and a deed
+		let no_rating = PropertyDetails { energy_rating: None, ..office_details() };
+		assert_noop!(
+			set_details(Origin::signed(BOB), no_rating),
+			Error::<Test>::MissingPropertyDetail
+		);
+		let no_deed = PropertyDetails { deed_hash: None, ..office_details() };
+		assert_noop!(
+			set_details(Origin::signed(BOB), no_deed),
+			Error::<Test>::MissingPropertyDetail
+		);
+
+		// the details cannot be amended once the asset is submitted
+		move_to(collection_id, item_id, vec![APPRAISING, REVIEWING]);
+		assert_noop!(
+			set_details(Origin::signed(BOB), office_details()),
+			Error::<Test>::CannotEditItem
+		);
+	});
+}
+
+#[test]
+fn submitted_proposal_should_have_valid_property_details() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_proposal_of_bob();
+		let metadata: BoundedVec<u8, <Test as pallet_uniques::Config>::StringLimit> =
+			b"metadata".to_vec().try_into().unwrap();
+		let create = |submit, details| {
+			OnboardingModule::create_and_submit_proposal(
+				Origin::signed(BOB),
+				OFFICESTEST,
+				Some(100_000_000),
+				metadata.clone(),
+				submit,
+				Some(MAX_TENANTS),
+				details,
+			)
+		};
+
+		assert_noop!(create(true, None), Error::<Test>::MissingPropertyDetail);
+		let no_deed = PropertyDetails { deed_hash: None, ..office_details() };
+		assert_noop!(create(false, Some(no_deed)), Error::<Test>::MissingPropertyDetail);
+		let no_surface = PropertyDetails { surface: 0, ..office_details() };
+		assert_noop!(create(false, Some(no_surface)), Error::<Test>::InvalidPropertyDetail);
+
+		// a proposal without details can be created, but not submitted
+		assert_ok!(create(false, None));
+		let item_id = NftModule::next_item_id(OFFICESTEST) - 1;
+		assert_noop!(
+			OnboardingModule::submit_awaiting(
+				Origin::signed(BOB),
+				OFFICESTEST,
+				item_id,
+				None,
+				None
+			),
+			Error::<Test>::MissingPropertyDetail
+		);
+
+		assert_ok!(OnboardingModule::set_property_details(
+			Origin::signed(BOB),
+			OFFICESTEST,
+			item_id,
+			office_details()
+		));
+		assert_ok!(OnboardingModule::submit_awaiting(
+			Origin::signed(BOB),
+			OFFICESTEST,
+			item_id,
+			None,
+			None
+		));
+		assert_eq!(OnboardingModule::houses(OFFICESTEST, item_id).unwrap().status, APPRAISING);
+	});
+}
+
+#[test]
+fn property_details_should_depend_on_the_type_of_property() {
+	ExtBuilder::default().build().execute_with(|| {
+		let office = office_details();
+		assert_ok!(office.validate(AssetClass::OFFICE));
+		// an apartment also needs rooms, and a house a year of construction
+		assert_noop!(office.validate(AssetClass::APPARTMENT), Error::<Test>::MissingPropertyDetail);
+		let apartment = PropertyDetails { rooms: 3, ..office_details() };
+		assert_ok!(apartment.validate(AssetClass::APPARTMENT));
+		assert_noop!(apartment.validate(AssetClass::HOUSE), Error::<Test>::MissingPropertyDetail);
+		let house = PropertyDetails { year_built: Some(1920), ..apartment };
+		assert_ok!(house.validate(AssetClass::HOUSE));
+	});
+}
+
+#[test]
+fn assets_by_attributes_should_filter_the_assets() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, item_id0) = create_proposal_of_bob();
+		let (_, item_id1) = create_proposal_of_bob();
+		// an asset without details is never listed
+		let (_, item_id2) = create_proposal_of_bob();
+		assert_ok!(OnboardingModule::set_property_details(
+			Origin::signed(BOB),
+			OFFICESTEST,
+			item_id0,
+			office_details()
+		));
+		let large_office = PropertyDetails {
+			surface: 400,
+			rooms: 10,
+			year_built: Some(2010),
+			energy_rating: Some(EnergyRating::D),
+			..office_details()
+		};
+		assert_ok!(OnboardingModule::set_property_details(
+			Origin::signed(BOB),
+			OFFICESTEST,
+			item_id1,
+			large_office
+		));
+		move_to(collection_id, item_id1, vec![APPRAISING, REVIEWING]);
+
+		let assets = |filter| {
+			let mut assets = OnboardingModule::assets_by_attributes(filter);
+			assets.sort();
+			assets
+		};
+		let both = vec![(collection_id, item_id0), (collection_id, item_id1)];
+		assert_eq!(assets(PropertyFilter::default()), both);
+		assert!(!assets(PropertyFilter::default()).contains(&(collection_id, item_id2)));
+		assert_eq!(
+			assets(PropertyFilter { min_surface: Some(200), ..Default::default() }),
+			vec![(collection_id, item_id1)]
+		);
+		assert_eq!(
+			assets(PropertyFilter { max_surface: Some(200), ..Default::default() }),
+			vec![(collection_id, item_id0)]
+		);
+		assert_eq!(
+			assets(PropertyFilter { min_rooms: Some(5), ..Default::default() }),
+			vec![(collection_id, item_id1)]
+		);
+		// an asset without year of construction does not match
+		assert_eq!(
+			assets(PropertyFilter { built_after: Some(1900), ..Default::default() }),
+			vec![(collection_id, item_id1)]
+		);
+		assert_eq!(
+			assets(PropertyFilter { energy_rating: Some(EnergyRating::C), ..Default::default() }),
+			vec![(collection_id, item_id0)]
+		);
+		assert_eq!(
+			assets(PropertyFilter { energy_rating: Some(EnergyRating::D), ..Default::default() }),
+			both
+		);
+		assert_eq!(
+			assets(PropertyFilter { status: Some(REVIEWING), ..Default::default() }),
+			vec![(collection_id, item_id1)]
+		);
+		assert_eq!(
+			assets(PropertyFilter {
+				status: Some(EDITING),
+				min_surface: Some(200),
+				..Default::default()
+			}),
+			vec![]
+		);
+	});
+}
+
+#[test]
+fn submit_appraisal_should_send_the_asset_to_review() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, item_id) = create_proposal_of_bob();
+		assert_ok!(OnboardingModule::submit_awaiting(
+			Origin::signed(BOB),
+			OFFICESTEST,
+			item_id,
+			None,
+			None
+		));
+		let report_hash = sp_core::H256::repeat_byte(1);
+		let bob_balance = Balances::free_balance(&BOB);
+
+		// the price is 5% above the appraised value, and the fee is the maximum allowed
+		assert_ok!(OnboardingModule::submit_appraisal(
+			Origin::signed(APPRAISER),
+			OFFICESTEST,
+			item_id,
+			95_238_096,
+			report_hash,
+			1_000_000
+		));
+
+		let asset = OnboardingModule::houses(collection_id, item_id).unwrap();
+		assert_eq!(asset.status, REVIEWING);
+		assert_eq!(
+			asset.appraisal,
+			Some(Appraisal {
+				appraiser: APPRAISER,
+				value: 95_238_096,
+				report_hash,
+				fee: 1_000_000,
+				block_number: System::block_number(),
+			})
+		);
+		assert_eq!(Balances::free_balance(&BOB), bob_balance - 1_000_000);
+		assert_eq!(Balances::free_balance(&APPRAISER), 1_000_000);
+		// the proposal is submitted to the council
+		assert!(VotingModule::voting_proposals(asset.proposal_hash).is_some());
+		expect_events(vec![mock::Event::OnboardingModule(crate::Event::AppraisalSubmitted {
+			who: APPRAISER,
+			collection: collection_id,
+			item: item_id,
+			value: 95_238_096,
+			report_hash,
+			fee: 1_000_000,
+		})]);
+	});
+}
+
+#[test]
+fn submit_appraisal_outside_the_band_should_reject_the_asset() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, item_id) = create_proposal_of_bob();
+		assert_ok!(OnboardingModule::submit_awaiting(
+			Origin::signed(BOB),
+			OFFICESTEST,
+			item_id,
+			None,
+			None
+		));
+
+		// the price is 25% above the appraised value
+		assert_ok!(appraise(item_id, 80_000_000));
+		let asset = OnboardingModule::houses(collection_id, item_id).unwrap();
+		assert_eq!(asset.status, REJECTED);
+		assert_eq!(asset.appraisal.unwrap().value, 80_000_000);
+		assert!(VotingModule::voting_proposals(asset.proposal_hash).is_none());
+		// the proposal fee is untouched, the seller only pays the preimage of the referendum
+		assert_eq!(OnboardingModule::proposal_deposits(collection_id, item_id), 5_000_000);
+		let preimage_deposit = match Democracy::preimages(proposal.democracy_hash) {
+			Some(pallet_democracy::PreimageStatus::Available { deposit, .. }) => deposit,
+			_ => 0,
+		};
+		assert_eq!(Balances::reserved_balance(&BOB), reserved + preimage_deposit);
+		assert!(OnboardingModule::counter_offer_expiries(12, (collection_id, item_id)).is_none());
+
+		// the seller reprices the asset, which is appraised again
+		assert_ok!(OnboardingModule::submit_awaiting(
+			Origin::signed(BOB),
+			OFFICESTEST,
+			item_id,
+			Some(88_000_000),
+			None
+		));
+		assert_eq!(OnboardingModule::houses(collection_id, item_id).unwrap().status, APPRAISING);
+		assert_ok!(appraise(item_id, 80_000_000));
+		assert_eq!(OnboardingModule::houses(collection_id, item_id).unwrap().status, REVIEWING);
+	});
+}
+
+#[test]
+fn submit_appraisal_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, item_id) = create_proposal_of_bob();
+		let appraisal = |origin: AccountId, fee: u64| {
+			OnboardingModule::submit_appraisal(
+				Origin::signed(origin),
+				OFFICESTEST,
+				item_id,
+				100_000_000,
+				Default::default(),
+				fee,
+			)
+		};
+
+		assert_noop!(appraisal(APPRAISER, 0), Error::<Test>::NotAppraising);
+		assert_ok!(OnboardingModule::submit_awaiting(
+			Origin::signed(BOB),
+			OFFICESTEST,
+			item_id,
+			None,
+			None
+		));
+		assert_noop!(appraisal(DAVE, 0), Error::<Test>::ReservedToAppraiser);
+		assert_noop!(appraisal(NOTARY, 0), Error::<Test>::ReservedToAppraiser);
+		// the fee is limited to 1% of the price
+		assert_noop!(appraisal(APPRAISER, 1_000_001), Error::<Test>::AppraisalFeeTooHigh);
+
+		// the appraiser cannot own the asset
+		assert_ok!(NftModule::transfer(Origin::root(), OFFICESTEST, item_id, APPRAISER));
+		assert_noop!(appraisal(APPRAISER, 0), Error::<Test>::AppraiserIsOwner);
+		assert_eq!(OnboardingModule::houses(collection_id, item_id).unwrap().status, APPRAISING);
+	});
+}
+
+#[test]
+fn voting_should_require_a_price_within_the_appraisal_band() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, item_id) = create_proposal_of_bob();
+		let to_voting =
+			|| OnboardingModule::change_status(Origin::root(), OFFICESTEST, item_id, VOTING);
+
+		force_status(collection_id, item_id, REVIEWING);
+		assert_noop!(to_voting(), Error::<Test>::AppraisalNeeded);
+
+		// the price is 10% above the appraised value, then more
+		set_appraisal(collection_id, item_id, 90_909_091);
+		Prices::<Test>::insert(collection_id, item_id, 100_000_001);
+		assert_noop!(to_voting(), Error::<Test>::PriceOutsideAppraisalBand);
+		Prices::<Test>::insert(collection_id, item_id, 100_000_000);
+		assert_ok!(to_voting());
+		assert_eq!(OnboardingModule::houses(collection_id, item_id).unwrap().status, VOTING);
+	});
+}
+
+/// Submit a proposal of BOB appraised at its price, which goes to the council review
+pub fn review_proposal_of_bob() -> (CollectionId, ItemId) {
+	let (collection_id, item_id) = create_proposal_of_bob();
+	assert_ok!(OnboardingModule::submit_awaiting(
+		Origin::signed(BOB),
+		OFFICESTEST,
+		item_id,
+		None,
+		None
+	));
+	assert_ok!(appraise(item_id, 100_000_000));
+	(collection_id, item_id)
+}
+
+/// The council offers `price` for the asset
+pub fn counter_offer(item_id: ItemId, price: u64) -> DispatchResult {
+	OnboardingModule::propose_counter_offer(Origin::root(), OFFICESTEST, item_id, price)
+}
+
+#[test]
+fn propose_counter_offer_should_cancel_the_council_vote() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, item_id) = review_proposal_of_bob();
+		let proposal_hash = OnboardingModule::houses(collection_id, item_id).unwrap().proposal_hash;
+		let collective_hash =
+			VotingModule::voting_proposals(proposal_hash).unwrap().collective_hash;
+
+		assert_ok!(counter_offer(item_id, 95_000_000));
+
+		assert!(VotingModule::voting_proposals(proposal_hash).is_none());
+		assert!(Collective::proposal_of(collective_hash).is_none());
+		assert_eq!(OnboardingModule::houses(collection_id, item_id).unwrap().status, REVIEWING);
+		assert_eq!(
+			OnboardingModule::counter_offers(collection_id, item_id),
+			vec![CounterOffer::<Test> {
+				price: 95_000_000,
+				proposed_at: 1,
+				deadline: 11,
+				status: OfferStatus::Pending,
+			}]
+		);
+		expect_events(vec![mock::Event::OnboardingModule(crate::Event::CounterOfferProposed {
+			collection: collection_id,
+			item: item_id,
+			price: 95_000_000,
+			deadline: 11,
+		})]);
+	});
+}
+
+#[test]
+fn accept_counter_offer_should_send_the_asset_to_the_investors_vote() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, item_id) = review_proposal_of_bob();
+		assert_ok!(counter_offer(item_id, 95_000_000));
+		System::set_block_number(11);
+		let reserved = Balances::reserved_balance(&BOB);
+
+		assert_ok!(OnboardingModule::accept_counter_offer(
+			Origin::signed(BOB),
+			OFFICESTEST,
+			item_id
+		));
+
+		let asset = OnboardingModule::houses(collection_id, item_id).unwrap();
+		assert_eq!(asset.status, VOTING);
+		assert_eq!(asset.price, Some(95_000_000));
+		assert_eq!(OnboardingModule::prices(collection_id, item_id), Some(95_000_000));
+		assert_eq!(
+			OnboardingModule::counter_offers(collection_id, item_id)[0].status,
+			OfferStatus::Accepted
+		);
+		// the proposal skips the council vote
+		let proposal = VotingModule::voting_proposals(asset.proposal_hash).unwrap();
+		assert!(proposal.collective_closed);
+		assert!(Democracy::referendum_info(proposal.democracy_referendum_index).is_some());
+		assert!(VotingModule::democracy_proposals(asset.proposal_hash).is_some());
+		// the proposal fee is untouched
+		assert_eq!(OnboardingModule::proposal_deposits(collection_id, item_id), 5_000_000);
+		expect_events(vec![mock::Event::OnboardingModule(crate::Event::CounterOfferAccepted {
+			who: BOB,
+			collection: collection_id,
+			item: item_id,
+			price: 95_000_000,
+		})]);
+
+		// the asset is rejected for editing when the investors don't approve it
+		let end_block = 11 + <Test as pallet_democracy::Config>::VotingPeriod::get();
+		System::set_block_number(end_block);
+		<VotingModule as frame_support::traits::Hooks<BlockNumber>>::on_initialize(end_block);
+		assert_eq!(OnboardingModule::houses(collection_id, item_id).unwrap().status, REJECTED);
+	});
+}
+
+#[test]
+fn counter_offer_should_be_a_council_motion() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, item_id) = review_proposal_of_bob();
+		let call = Box::new(mock::Call::OnboardingModule(crate::Call::propose_counter_offer {
+			collection_id,
+			item_id,
+			price: 95_000_000,
+		}));
+		let hash = <Test as frame_system::Config>::Hashing::hash_of(&call);
+
+		assert_ok!(VotingModule::council_motion(Origin::signed(ALICE), call));
+		assert!(OnboardingModule::counter_offers(collection_id, item_id).is_empty());
+
+		assert_ok!(VotingModule::council_vote(Origin::signed(ALICE), hash, true));
+		assert_ok!(VotingModule::council_vote(Origin::signed(CHARLIE), hash, true));
+		assert_ok!(VotingModule::council_close_vote(Origin::signed(ALICE), hash));
+
+		// the offer is made on behalf of the council
+		let offers = OnboardingModule::counter_offers(collection_id, item_id);
+		assert_eq!(offers.len(), 1);
+		assert_eq!(offers[0].price, 95_000_000);
+		assert_eq!(offers[0].status, OfferStatus::Pending);
+	});
+}
+
+#[test]
+fn unanswered_counter_offer_should_restore_the_council_vote() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, item_id) = review_proposal_of_bob();
+		assert_ok!(counter_offer(item_id, 95_000_000));
+		assert!(OnboardingModule::counter_offer_expiries(12, (collection_id, item_id)).is_some());
+
+		// the seller can answer until the deadline
+		run_to_block(11);
+		assert_eq!(
+			OnboardingModule::counter_offers(collection_id, item_id)[0].status,
+			OfferStatus::Pending
+		);
+
+		run_to_block(12);
+		assert_eq!(
+			OnboardingModule::counter_offers(collection_id, item_id)[0].status,
+			OfferStatus::Expired
+		);
+		assert!(OnboardingModule::counter_offer_expiries(12, (collection_id, item_id)).is_none());
+
+		// the asset is back to the council vote at its price
+		let asset = OnboardingModule::houses(collection_id, item_id).unwrap();
+		assert_eq!(asset.status, REVIEWING);
+		assert_eq!(asset.price, Some(100_000_000));
+		let proposal = VotingModule::voting_proposals(asset.proposal_hash).unwrap();
+		assert!(Collective::proposal_of(proposal.collective_hash).is_some());
+		expect_events(vec![mock::Event::OnboardingModule(crate::Event::CounterOfferExpired {
+			collection: collection_id,
+			item: item_id,
+			price: 95_000_000,
+		})]);
+
+		assert_noop!(
+			OnboardingModule::accept_counter_offer(Origin::signed(BOB), OFFICESTEST, item_id),
+			Error::<Test>::NoPendingCounterOffer
+		);
+	});
+}
+
+#[test]
+fn decline_counter_offer_should_withdraw_the_listing_without_slash() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, item_id) = review_proposal_of_bob();
+		assert_ok!(counter_offer(item_id, 105_000_000));
+		let reserved = Balances::reserved_balance(&BOB);
+		let fees_balance = Balances::free_balance(&OnboardingModule::account_id());
+
+		assert_ok!(OnboardingModule::decline_counter_offer(
+			Origin::signed(BOB),
+			OFFICESTEST,
+			item_id
+		));
+
+		assert_withdrawn(collection_id, item_id, reserved, fees_balance, 5_000_000, 0);
+		// the offers are removed with the listing
+		assert!(OnboardingModule::counter_offers(collection_id, item_id).is_empty());
+		expect_events(vec![mock::Event::OnboardingModule(crate::Event::CounterOfferDeclined {
+			who: BOB,
+			collection: collection_id,
+			item: item_id,
+			price: 105_000_000,
+		})]);
+	});
+}
+
+#[test]
+fn counter_offer_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, item_id) = create_proposal_of_bob();
+		let answer = |origin: AccountId, accept: bool| {
+			if accept {
+				OnboardingModule::accept_counter_offer(Origin::signed(origin), OFFICESTEST, item_id)
+			} else {
+				OnboardingModule::decline_counter_offer(
+					Origin::signed(origin),
+					OFFICESTEST,
+					item_id,
+				)
+			}
+		};
+
+		assert_ok!(OnboardingModule::submit_awaiting(
+			Origin::signed(BOB),
+			OFFICESTEST,
+			item_id,
+			None,
+			None
+		));
+		assert_noop!(counter_offer(item_id, 100_000_000), Error::<Test>::NotReviewing);
+		assert_ok!(appraise(item_id, 100_000_000));
+
+		assert_noop!(
+			OnboardingModule::propose_counter_offer(
+				Origin::signed(ALICE),
+				OFFICESTEST,
+				item_id,
+				100_000_000
+			),
+			DispatchError::BadOrigin
+		);
+		// the offer must be within 10% of the appraised value
+		assert_noop!(counter_offer(item_id, 89_999_999), Error::<Test>::PriceOutsideAppraisalBand);
+		assert_noop!(answer(BOB, true), Error::<Test>::NoPendingCounterOffer);
+		assert_noop!(answer(BOB, false), Error::<Test>::NoPendingCounterOffer);
+
+		assert_ok!(counter_offer(item_id, 90_000_000));
+		assert_noop!(counter_offer(item_id, 95_000_000), Error::<Test>::CounterOfferPending);
+
+		// only the seller owning the asset can answer
+		RoleModule::set_role(Origin::signed(OTHER_SELLER), OTHER_SELLER, Acc::SELLER).ok();
+		RoleModule::account_approval(Origin::signed(ALICE), OTHER_SELLER).ok();
+		assert_noop!(answer(DAVE, true), Error::<Test>::ReservedToSeller);
+		assert_noop!(answer(OTHER_SELLER, true), Error::<Test>::NotTheTokenOwner);
+		assert_noop!(answer(OTHER_SELLER, false), Error::<Test>::NotTheTokenOwner);
+
+		// the seller did not answer before the deadline
+		System::set_block_number(12);
+		assert_noop!(answer(BOB, true), Error::<Test>::CounterOfferExpired);
+		assert_noop!(answer(BOB, false), Error::<Test>::CounterOfferExpired);
+
+		// the council can make another offer
+		assert_ok!(counter_offer(item_id, 95_000_000));
+		let statuses: Vec<OfferStatus> = OnboardingModule::counter_offers(collection_id, item_id)
+			.into_iter()
+			.map(|offer| offer.status)
+			.collect();
+		assert_eq!(statuses, vec![OfferStatus::Expired, OfferStatus::Pending]);
+		assert_ok!(answer(BOB, true));
+		assert_eq!(OnboardingModule::houses(collection_id, item_id).unwrap().status, VOTING);
+		assert_noop!(counter_offer(item_id, 95_000_000), Error::<Test>::NotReviewing);
+	});
+}
+
+#[test]
+fn counter_offers_should_keep_the_latest_ones() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, item_id) = review_proposal_of_bob();
+		assert_ok!(counter_offer(item_id, 90_000_000));
+		run_to_block(12);
+		assert_ok!(counter_offer(item_id, 95_000_000));
+		run_to_block(23);
+
+		// the oldest offer is dropped once MaxCounterOffers offers are logged
+		assert_ok!(counter_offer(item_id, 105_000_000));
+		let offers: Vec<(u64, OfferStatus)> =
+			OnboardingModule::counter_offers(collection_id, item_id)
+				.into_iter()
+				.map(|offer| (offer.price, offer.status))
+				.collect();
+		assert_eq!(
+			offers,
+			vec![(95_000_000, OfferStatus::Expired), (105_000_000, OfferStatus::Pending)]
+		);
+	});
+}
+
+#[test]
+fn withdraw_listing_should_remove_the_counter_offers() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, item_id) = review_proposal_of_bob();
+		assert_ok!(counter_offer(item_id, 95_000_000));
+
+		assert_ok!(OnboardingModule::withdraw_listing(Origin::signed(BOB), OFFICESTEST, item_id));
+		assert!(OnboardingModule::counter_offers(collection_id, item_id).is_empty());
+		assert!(OnboardingModule::counter_offer_expiries(12, (collection_id, item_id)).is_none());
+	});
+}
+
+/// Go to block `n` and check the listings for expiry
+pub fn run_to_block(n: BlockNumber) {
+	System::set_block_number(n);
+	<OnboardingModule as frame_support::traits::Hooks<BlockNumber>>::on_initialize(n);
+}
+
+#[test]
+fn set_listing_lifetime_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(OnboardingModule::set_listing_lifetime(Origin::root(), ONBOARDED, Some(20)));
+		assert_eq!(OnboardingModule::listing_lifetimes(ONBOARDED), Some(20));
+		expect_events(vec![mock::Event::OnboardingModule(crate::Event::ListingLifetimeSet {
+			status: ONBOARDED,
+			lifetime: Some(20),
+		})]);
+
+		assert_ok!(OnboardingModule::set_listing_lifetime(Origin::root(), ONBOARDED, None));
+		assert_eq!(OnboardingModule::listing_lifetimes(ONBOARDED), None);
+	});
+}
+
+#[test]
+fn set_listing_lifetime_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			OnboardingModule::set_listing_lifetime(Origin::signed(BOB), ONBOARDED, Some(20)),
+			DispatchError::BadOrigin
+		);
+		for status in [EDITING, FINALISING, FINALISED, PURCHASED, REJECTED, SLASH, REPRICING] {
+			assert_noop!(
+				OnboardingModule::set_listing_lifetime(Origin::root(), status, Some(20)),
+				Error::<Test>::StatusCannotExpire
+			);
+		}
+	});
+}
+
+#[test]
+fn expired_listing_should_go_back_to_editing() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, item_id) = create_proposal_of_bob();
+		move_to(collection_id, item_id, vec![APPRAISING, REVIEWING, VOTING, ONBOARDED]);
+		assert_ok!(OnboardingModule::set_listing_lifetime(Origin::root(), ONBOARDED, Some(10)));
+		let reserved = Balances::reserved_balance(&BOB);
+
+		// the asset was onboarded at block 1
+		run_to_block(10);
+		assert_eq!(OnboardingModule::houses(collection_id, item_id).unwrap().status, ONBOARDED);
+
+		run_to_block(11);
+		assert_eq!(OnboardingModule::houses(collection_id, item_id).unwrap().status, EDITING);
+		assert_eq!(
+			OnboardingModule::status_history(collection_id, item_id).last(),
+			Some(&StatusChange::<Test> {
+				from: ONBOARDED,
+				to: EDITING,
+				origin: TransitionOrigin::Expiry,
+				block_number: 11,
+			})
+		);
+		// the proposal fee is released
+		assert_eq!(Balances::reserved_balance(&BOB), reserved - 5_000_000);
+		assert_eq!(OnboardingModule::proposal_deposits(collection_id, item_id), 0);
+		expect_events(vec![mock::Event::OnboardingModule(crate::Event::ListingExpired {
+			who: BOB,
+			collection: collection_id,
+			item: item_id,
+			status: ONBOARDED,
+			released: 5_000_000,
+		})]);
+
+		// the seller reprices the asset, and the proposal fee is reserved again
+		assert_ok!(OnboardingModule::submit_awaiting(
+			Origin::signed(BOB),
+			OFFICESTEST,
+			item_id,
+			Some(80_000_000),
+			None
+		));
+		assert_eq!(OnboardingModule::houses(collection_id, item_id).unwrap().status, APPRAISING);
+		assert_eq!(OnboardingModule::proposal_deposits(collection_id, item_id), 4_000_000);
+		assert_eq!(Balances::reserved_balance(&BOB), reserved - 1_000_000);
+	});
+}
+
+#[test]
+fn expired_listing_during_review_should_cancel_the_council_vote() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, item_id) = review_proposal_of_bob();
+		assert_ok!(counter_offer(item_id, 95_000_000));
+		let proposal_hash = OnboardingModule::houses(collection_id, item_id).unwrap().proposal_hash;
+		assert_ok!(OnboardingModule::set_listing_lifetime(Origin::root(), REVIEWING, Some(5)));
+
+		run_to_block(6);
+
+		assert_eq!(OnboardingModule::houses(collection_id, item_id).unwrap().status, EDITING);
+		assert!(VotingModule::voting_proposals(proposal_hash).is_none());
+		assert_eq!(
+			OnboardingModule::counter_offers(collection_id, item_id)[0].status,
+			OfferStatus::Expired
+		);
+	});
+}
+
+#[test]
+fn listing_expiry_should_check_a_limited_number_of_assets_per_block() {
+	ExtBuilder::default().build().execute_with(|| {
+		let assets: Vec<(CollectionId, ItemId)> = (0..5)
+			.map(|_| {
+				let (collection_id, item_id) = create_proposal_of_bob();
+				move_to(collection_id, item_id, vec![APPRAISING, REVIEWING, VOTING, ONBOARDED]);
+				(collection_id, item_id)
+			})
+			.collect();
+		let expired_count = || {
+			assets
+				.iter()
+				.filter(|(collection_id, item_id)| {
+					OnboardingModule::houses(collection_id, item_id).unwrap().status == EDITING
+				})
+				.count()
+		};
+		assert_ok!(OnboardingModule::set_listing_lifetime(Origin::root(), ONBOARDED, Some(10)));
+
+		run_to_block(10);
+		assert_eq!(expired_count(), 0);
+		assert!(OnboardingModule::expiry_cursor().is_some());
+
+		// 2 assets are checked in each block, the check of block 11 starting after the assets
+		// checked in block 10
+		run_to_block(11);
+		assert_eq!(expired_count(), 2);
+		run_to_block(12);
+		assert_eq!(expired_count(), 3);
+		assert!(OnboardingModule::expiry_cursor().is_none());
+		run_to_block(13);
+		assert_eq!(expired_count(), 5);
+	});
+}
+
+#[test]
+fn attributes_should_be_frozen_for_the_seller_once_the_asset_is_submitted() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, item_id) = create_proposal_of_bob();
+		let key: Nft::AttributeKeyOf<Test> = b"surface".to_vec().try_into().unwrap();
+		let value: Nft::AttributeValueOf<Test> = b"120m2".to_vec().try_into().unwrap();
+
+		assert_ok!(NftModule::set_attribute(
+			Origin::signed(BOB),
+			collection_id,
+			item_id,
+			key.clone(),
+			Some(value.clone())
+		));
+
+		move_to(collection_id, item_id, vec![APPRAISING]);
+		assert_noop!(
+			NftModule::set_attribute(
+				Origin::signed(BOB),
+				collection_id,
+				item_id,
+				key.clone(),
+				None
+			),
+			Nft::Error::<Test>::NotPermitted
+		);
+		// the servicer can still correct the attributes of a listed asset
+		assert_ok!(NftModule::set_attribute(
+			Origin::signed(CHARLIE),
+			collection_id,
+			item_id,
+			key,
+			None
+		));
+		assert_eq!(NftModule::attribute(collection_id, item_id, b"surface"), None);
+
+		for status in ALL_STATUSES {
+			Houses::<Test>::mutate(collection_id, item_id, |asset| {
+				asset.as_mut().unwrap().status = status;
+			});
+			let stage = match status {
+				EDITING | REJECTED | REPRICING => Nft::ItemStage::Editing,
+				PURCHASED | CANCELLED | SLASH => Nft::ItemStage::Closed,
+				_ => Nft::ItemStage::Listed,
+			};
+			assert_eq!(
+				<OnboardingModule as Nft::ItemStageOf<_, _>>::item_stage(collection_id, item_id),
+				stage
+			);
+		}
+	});
+}
+
+#[test]
+fn listed_asset_should_be_locked_until_its_sale() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, item_id) = create_proposal_of_bob();
+		assert!(!NftModule::is_locked(collection_id, item_id));
+
+		for status in [APPRAISING, REVIEWING, VOTING, ONBOARDED] {
+			move_to(collection_id, item_id, vec![status]);
+			assert_eq!(NftModule::locks(collection_id, item_id), vec![Nft::LockReason::Onboarding]);
+			assert_noop!(
+				NftModule::transfer(Origin::root(), collection_id, item_id, DAVE),
+				Nft::Error::<Test>::Locked
+			);
+		}
+
+		// the lock is released when the asset is sold or goes back to its seller
+		move_to(collection_id, item_id, vec![FINALISING]);
+		assert!(!NftModule::is_locked(collection_id, item_id));
+		move_to(collection_id, item_id, vec![REJECTED]);
+		assert!(!NftModule::is_locked(collection_id, item_id));
+
+		let (collection_id, item_id) = create_proposal_of_bob();
+		move_to(collection_id, item_id, vec![APPRAISING, REVIEWING, VOTING, ONBOARDED, REPRICING]);
+		assert!(!NftModule::is_locked(collection_id, item_id));
+	});
+}
+
+#[test]
+fn withdrawn_or_destroyed_asset_should_be_unlocked_before_burning_its_nft() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, item_id) = create_proposal_of_bob();
+		move_to(collection_id, item_id, vec![APPRAISING, REVIEWING, VOTING]);
+		assert!(NftModule::is_locked(collection_id, item_id));
+
+		assert_ok!(OnboardingModule::withdraw_listing(Origin::signed(BOB), collection_id, item_id));
+		assert!(!NftModule::is_locked(collection_id, item_id));
+		assert_eq!(NftModule::owner(collection_id, item_id), None);
+
+		let (collection_id, item_id) = create_proposal_of_bob();
+		move_to(collection_id, item_id, vec![APPRAISING, REVIEWING]);
+		let asset = OnboardingModule::houses(collection_id, item_id).unwrap();
+		assert_ok!(OnboardingModule::reject_destroy(Origin::root(), collection_id, item_id, asset));
+		assert!(!NftModule::is_locked(collection_id, item_id));
+		assert_eq!(NftModule::owner(collection_id, item_id), None);
+	});
+}
+
+#[test]
+fn migration_should_add_the_appraisal_to_existing_assets() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, item_id) = create_proposal_of_bob();
+		let asset = OnboardingModule::houses(collection_id, item_id).unwrap();
+
+		// the asset is stored with the layout prior to the appraisals
+		let old_asset = crate::migrations::v1::OldAsset::<Test> {
+			status: REVIEWING,
+			created: asset.created,
+			infos: asset.infos.clone(),
+			price: asset.price,
+			representative: None,
+			tenants: Vec::new(),
+			proposal_hash: asset.proposal_hash,
+			max_tenants: asset.max_tenants,
+		};
+		frame_support::storage::unhashed::put(
+			&Houses::<Test>::hashed_key_for(collection_id, item_id),
+			&old_asset,
+		);
+		StorageVersion::new(0).put::<OnboardingModule>();
+
+		crate::migrations::migrate::<Test>();
+		assert_eq!(OnboardingModule::on_chain_storage_version(), 3);
+		let migrated = OnboardingModule::houses(collection_id, item_id).unwrap();
+		// the asset in review is appraised at its price
+		let appraisal = Appraisal::<Test> {
+			appraiser: OnboardingModule::account_id(),
+			value: asset.price.unwrap(),
+			report_hash: Default::default(),
+			fee: 0,
+			block_number: System::block_number(),
+		};
+		assert_eq!(
+			migrated,
+			Asset { status: REVIEWING, appraisal: Some(appraisal), ..asset.clone() }
+		);
+
+		// the migration is only applied once
+		crate::migrations::migrate::<Test>();
+		assert_eq!(OnboardingModule::houses(collection_id, item_id).unwrap(), migrated);
+
+		// the asset can still go to the investors vote
+		assert_ok!(OnboardingModule::do_change_status(
+			collection_id,
+			item_id,
+			VOTING,
+			TransitionOrigin::Council
+		));
+	});
+}
+
+#[test]
+fn migration_should_rebuild_the_voting_calls_and_resubmit_pending_proposals() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, item_id) = create_proposal_of_bob();
+		assert_ok!(OnboardingModule::submit_awaiting(
+			Origin::signed(BOB),
+			OFFICESTEST,
+			item_id,
+			None,
+			None
+		));
+		assert_ok!(appraise(item_id, 100_000_000));
+		let asset = OnboardingModule::houses(collection_id, item_id).unwrap();
+		let voting_calls = Vcalls::<Test>::get(collection_id, item_id).unwrap();
+		let collective_hash =
+			VotingModule::voting_proposals(asset.proposal_hash).unwrap().collective_hash;
+
+		// the stored calls were encoded with the former collection argument
+		let undecodable = vec![0xffu8];
+		frame_support::storage::unhashed::put_raw(
+			&Vcalls::<Test>::hashed_key_for(collection_id, item_id),
+			&undecodable,
+		);
+		frame_support::storage::unhashed::put_raw(
+			&pallet_voting::VotingProposals::<Test>::hashed_key_for(asset.proposal_hash),
+			&undecodable,
+		);
+		frame_support::storage::unhashed::put_raw(
+			&pallet_collective::ProposalOf::<Test, pallet_collective::Instance1>::hashed_key_for(
+				collective_hash,
+			),
+			&undecodable,
+		);
+		StorageVersion::new(1).put::<OnboardingModule>();
+
+		crate::migrations::migrate::<Test>();
+		assert_eq!(OnboardingModule::on_chain_storage_version(), 3);
+		assert_eq!(Vcalls::<Test>::get(collection_id, item_id), Some(voting_calls));
+
+		// the proposal is submitted again to the council
+		let migrated = OnboardingModule::houses(collection_id, item_id).unwrap();
+		assert_eq!(migrated.status, REVIEWING);
+		let proposal = VotingModule::voting_proposals(migrated.proposal_hash).unwrap();
+		assert!(Collective::proposal_of(proposal.collective_hash).is_some());
+		assert_eq!(Collective::proposals().len(), 1);
+	});
+}
+
+#[test]
+fn migration_should_record_the_proposal_fees_of_listed_assets() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, listed) = create_proposal_of_bob();
+		let (_, rejected) = create_proposal_of_bob();
+		let (_, purchased) = create_proposal_of_bob();
+		let fee = ProposalFee::get().mul_floor(100_000_000);
+		force_status(collection_id, rejected, REJECTED);
+		force_status(collection_id, purchased, PURCHASED);
+
+		// the fees were reserved without being recorded
+		for item_id in [listed, rejected, purchased] {
+			ProposalDeposits::<Test>::remove(collection_id, item_id);
+		}
+		StorageVersion::new(2).put::<OnboardingModule>();
+
+		crate::migrations::migrate::<Test>();
+		assert_eq!(OnboardingModule::on_chain_storage_version(), 3);
+		assert_eq!(OnboardingModule::proposal_deposits(collection_id, listed), fee);
+		assert_eq!(
+			OnboardingModule::proposal_deposits(collection_id, rejected),
+			fee - SlashedFee::get().mul_floor(fee)
+		);
+		assert!(!ProposalDeposits::<Test>::contains_key(collection_id, purchased));
+
+		// the migration is only applied once
+		ProposalDeposits::<Test>::remove(collection_id, listed);
+		crate::migrations::migrate::<Test>();
+		assert!(!ProposalDeposits::<Test>::contains_key(collection_id, listed));
+	});
+}
@@ -6,7 +6,6 @@ use serde::{Deserialize, Serialize};
 
 use scale_info::TypeInfo;
 
-pub type NftCollectionOf = Nft::PossibleCollections;
 pub use Nft::ItemInfoOf;
 
 #[derive(Clone, Encode, Decode, PartialEq, Eq, TypeInfo, Copy)]
@@ -22,8 +21,72 @@ pub enum AssetStatus {
 	REJECTED,
 	SLASH,
 	CANCELLED,
+	REPRICING,
+	APPRAISING,
 }
 
+/// Origin allowed to trigger a transition of the asset lifecycle
+#[derive(Clone, Encode, Decode, PartialEq, Eq, TypeInfo, Copy)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+pub enum TransitionOrigin {
+	/// The owner of the asset, with the seller role
+	Seller,
+	/// The council, through `CouncilOrigin`
+	Council,
+	/// The investors referendum, through `DemocracyOrigin`
+	Democracy,
+	/// The bidding pallet, which can't be reached through an extrinsic
+	Bidding,
+	/// An account with the notary role
+	Notary,
+	/// An account with the appraiser role, through `submit_appraisal`
+	Appraiser,
+	/// The expiry of the listing, once the asset stayed longer than its lifetime in its status
+	Expiry,
+}
+
+impl AssetStatus {
+	/// Origin allowed to move an asset from this status to `to`, None if the transition is not
+	/// part of the lifecycle
+	pub fn transition_origin(&self, to: AssetStatus) -> Option<TransitionOrigin> {
+		use AssetStatus::*;
+		match (self, to) {
+			(EDITING | REJECTED | REPRICING, APPRAISING) => Some(TransitionOrigin::Seller),
+			(APPRAISING, REVIEWING | REJECTED) => Some(TransitionOrigin::Appraiser),
+			(REVIEWING, VOTING | REJECTED | SLASH) => Some(TransitionOrigin::Council),
+			(VOTING, ONBOARDED | REJECTED | SLASH) => Some(TransitionOrigin::Democracy),
+			(ONBOARDED, FINALISING | REPRICING) => Some(TransitionOrigin::Bidding),
+			(FINALISING, FINALISED | REJECTED) => Some(TransitionOrigin::Notary),
+			(FINALISED, PURCHASED) => Some(TransitionOrigin::Bidding),
+			(FINALISED, CANCELLED) => Some(TransitionOrigin::Seller),
+			(APPRAISING | REVIEWING | VOTING | ONBOARDED, EDITING) =>
+				Some(TransitionOrigin::Expiry),
+			_ => None,
+		}
+	}
+}
+
+/// Transition of an asset recorded in its status history
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebugNoBound, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct StatusChange<T: Config> {
+	/// Status before the transition
+	pub from: AssetStatus,
+	/// Status after the transition
+	pub to: AssetStatus,
+	/// Origin of the transition
+	pub origin: TransitionOrigin,
+	/// Block of the transition
+	pub block_number: BlockNumberOf<T>,
+}
+
+/// Latest transitions of an asset, the oldest ones being dropped once `MaxStatusHistory` is reached
+pub type StatusHistoryOf<T> = BoundedVec<StatusChange<T>, <T as Config>::MaxStatusHistory>;
+
+/// Latest counter-offers on an asset, the oldest ones being dropped once `MaxCounterOffers` is
+/// reached
+pub type CounterOffersOf<T> = BoundedVec<CounterOffer<T>, <T as Config>::MaxCounterOffers>;
+
 #[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebugNoBound, TypeInfo)]
 #[scale_info(skip_type_params(T))]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
@@ -44,6 +107,8 @@ pub struct Asset<T: Config> {
 	pub proposal_hash: T::Hash,
 	/// Maximum number of tenants for this asset
 	pub max_tenants: u8,
+	/// Last appraisal of the asset
+	pub appraisal: Option<Appraisal<T>>,
 }
 
 impl<T: Config> Asset<T> {
@@ -65,6 +130,7 @@ impl<T: Config> Asset<T> {
 			tenants: Default::default(),
 			proposal_hash: Default::default(),
 			max_tenants,
+			appraisal: None,
 		};
 		Houses::<T>::insert(collection, item, house);
 
@@ -72,6 +138,172 @@ impl<T: Config> Asset<T> {
 	}
 }
 
+/// Valuation of an asset by an appraiser
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebugNoBound, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct Appraisal<T: Config> {
+	/// Account of the appraiser
+	pub appraiser: T::AccountId,
+	/// Appraised value of the asset
+	pub value: BalanceOf<T>,
+	/// Hash of the appraisal report
+	pub report_hash: T::Hash,
+	/// Fee paid by the seller to the appraiser
+	pub fee: BalanceOf<T>,
+	/// Block of the appraisal
+	pub block_number: BlockNumberOf<T>,
+}
+
+impl<T: Config> Appraisal<T> {
+	/// Whether `price` deviates from the appraised value by at most `band` of it
+	pub fn within_band(&self, price: BalanceOf<T>, band: Percent) -> bool {
+		let deviation = if price > self.value { price - self.value } else { self.value - price };
+		deviation <= band.mul_floor(self.value)
+	}
+}
+
+/// Status of a counter-offer of the council
+#[derive(Clone, Encode, Decode, PartialEq, Eq, TypeInfo, Copy)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+pub enum OfferStatus {
+	/// Waiting for the answer of the seller
+	Pending,
+	/// Accepted by the seller, the asset went to the investors vote at the offered price
+	Accepted,
+	/// Declined by the seller, the listing was withdrawn
+	Declined,
+	/// Not answered by the seller before its deadline
+	Expired,
+}
+
+/// Price offered by the council to the seller of a reviewed asset
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebugNoBound, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct CounterOffer<T: Config> {
+	/// Offered price
+	pub price: BalanceOf<T>,
+	/// Block of the offer
+	pub proposed_at: BlockNumberOf<T>,
+	/// Last block for the seller to answer
+	pub deadline: BlockNumberOf<T>,
+	/// Status of the offer
+	pub status: OfferStatus,
+}
+
+impl<T: Config> CounterOffer<T> {
+	/// Whether the offer is still waiting for an answer at block `now`
+	pub fn is_pending(&self, now: BlockNumberOf<T>) -> bool {
+		self.status == OfferStatus::Pending && now <= self.deadline
+	}
+
+	/// Block at which the offer expires if the seller did not answer it
+	pub fn expires_at(&self) -> BlockNumberOf<T> {
+		self.deadline.saturating_add(1u32.into())
+	}
+}
+
+/// Geohash of a property location, up to 12 characters
+pub type Geohash = BoundedVec<u8, ConstU32<12>>;
+
+/// Characters of the geohash base32 alphabet
+const GEOHASH_ALPHABET: &[u8] = b"0123456789bcdefghjkmnpqrstuvwxyz";
+
+/// Energy performance rating of a property, from the most efficient to the least efficient
+#[derive(Clone, Encode, Decode, PartialEq, Eq, PartialOrd, Ord, TypeInfo, Copy)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+pub enum EnergyRating {
+	A,
+	B,
+	C,
+	D,
+	E,
+	F,
+	G,
+}
+
+/// Structured details of a property
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebugNoBound, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct PropertyDetails<T: Config> {
+	/// Postal address
+	pub address: Nft::BoundedVecOfUnq<T>,
+	/// Geohash of the location
+	pub geohash: Option<Geohash>,
+	/// Surface in square meters
+	pub surface: u32,
+	/// Number of rooms
+	pub rooms: u8,
+	/// Year of construction
+	pub year_built: Option<u16>,
+	/// Energy performance rating
+	pub energy_rating: Option<EnergyRating>,
+	/// Hash of the deed
+	pub deed_hash: Option<T::Hash>,
+	/// Hashes of the inspection reports
+	pub inspection_reports: BoundedVec<T::Hash, T::MaxInspectionReports>,
+}
+
+impl<T: Config> PropertyDetails<T> {
+	/// Check the details of a property of class `asset_class`
+	/// Every property needs an address and a surface, the rooms, the year of construction, the
+	/// energy rating and the deed being required depending on the class of the property
+	pub fn validate(&self, asset_class: Nft::AssetClass) -> DispatchResult {
+		ensure!(!self.address.is_empty() && self.surface > 0, Error::<T>::InvalidPropertyDetail);
+		if let Some(geohash) = &self.geohash {
+			ensure!(
+				!geohash.is_empty() && geohash.iter().all(|c| GEOHASH_ALPHABET.contains(c)),
+				Error::<T>::InvalidPropertyDetail
+			);
+		}
+
+		let (rooms, year_built, energy_rating, deed) = match asset_class {
+			Nft::AssetClass::HOUSE => (true, true, true, true),
+			Nft::AssetClass::APPARTMENT => (true, false, true, true),
+			Nft::AssetClass::OFFICE => (false, false, true, true),
+		};
+		ensure!(!rooms || self.rooms > 0, Error::<T>::MissingPropertyDetail);
+		ensure!(!year_built || self.year_built.is_some(), Error::<T>::MissingPropertyDetail);
+		ensure!(!energy_rating || self.energy_rating.is_some(), Error::<T>::MissingPropertyDetail);
+		ensure!(!deed || self.deed_hash.is_some(), Error::<T>::MissingPropertyDetail);
+
+		Ok(())
+	}
+}
+
+/// Attributes used to filter the assets, every given attribute must match
+#[derive(Clone, Encode, Decode, PartialEq, Eq, Default, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+pub struct PropertyFilter {
+	/// Status of the asset
+	pub status: Option<AssetStatus>,
+	/// Minimal surface in square meters
+	pub min_surface: Option<u32>,
+	/// Maximal surface in square meters
+	pub max_surface: Option<u32>,
+	/// Minimal number of rooms
+	pub min_rooms: Option<u8>,
+	/// Minimal year of construction
+	pub built_after: Option<u16>,
+	/// Least efficient energy rating accepted
+	pub energy_rating: Option<EnergyRating>,
+}
+
+impl PropertyFilter {
+	/// Check whether an asset with `status` and `details` matches the filter
+	pub fn matches<T: Config>(&self, status: AssetStatus, details: &PropertyDetails<T>) -> bool {
+		self.status.map_or(true, |s| s == status) &&
+			self.min_surface.map_or(true, |surface| details.surface >= surface) &&
+			self.max_surface.map_or(true, |surface| details.surface <= surface) &&
+			self.min_rooms.map_or(true, |rooms| details.rooms >= rooms) &&
+			self.built_after
+				.map_or(true, |year| details.year_built.map_or(false, |built| built >= year)) &&
+			self.energy_rating.map_or(true, |rating| {
+				details.energy_rating.map_or(false, |energy_rating| energy_rating <= rating)
+			})
+	}
+}
+
 #[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebugNoBound, TypeInfo)]
 #[scale_info(skip_type_params(T))]
 //#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
@@ -87,17 +319,57 @@ pub struct VotingCalls<T: Config> {
 }
 
 impl<T: Config> VotingCalls<T> {
-	pub fn new(collection: T::NftCollectionId, item: T::NftItemId) -> DispatchResult {
-		let nbr: u32 = 0;
-		let call: T::Prop = Call::<T>::do_something { something: nbr }.into();
-
-		let calls = VotingCalls::<T> {
-			reject_edit: Box::new(call.clone()),
-			reject_destroy: Box::new(call.clone()),
-			democracy_status: Box::new(call.clone()),
-			after_vote_status: Box::new(call),
-		};
-		Vcalls::<T>::insert(collection, item, calls);
+	/// Calls of the proposal of an asset: collective-to-democracy status change, rejections
+	/// of the council and status change after a positive investors vote
+	pub fn new(collection_id: T::NftCollectionId, item_id: T::NftItemId, house: Asset<T>) -> Self {
+		let democracy_status: T::Prop =
+			Call::<T>::change_status { collection_id, item_id, status: AssetStatus::VOTING }.into();
+		let reject_edit: T::Prop =
+			Call::<T>::reject_edit { collection_id, item_id, infos: house.clone() }.into();
+		let reject_destroy: T::Prop =
+			Call::<T>::reject_destroy { collection_id, item_id, infos: house }.into();
+		let after_vote_status: T::Prop =
+			Call::<T>::change_status { collection_id, item_id, status: AssetStatus::ONBOARDED }
+				.into();
+
+		VotingCalls::<T> {
+			reject_edit: Box::new(reject_edit),
+			reject_destroy: Box::new(reject_destroy),
+			democracy_status: Box::new(democracy_status),
+			after_vote_status: Box::new(after_vote_status),
+		}
+	}
+}
+
+/// Handler notified when the status of an asset changes, so that the pallets processing the
+/// assets can track them without iterating over all the houses
+pub trait OnAssetStatusChange<T: Config> {
+	fn on_status_change(
+		collection_id: T::NftCollectionId,
+		item_id: T::NftItemId,
+		status: AssetStatus,
+	) -> DispatchResult;
+}
+
+impl<T: Config> OnAssetStatusChange<T> for () {
+	fn on_status_change(
+		_collection_id: T::NftCollectionId,
+		_item_id: T::NftItemId,
+		_status: AssetStatus,
+	) -> DispatchResult {
 		Ok(())
 	}
 }
+
+impl<T: Config, A: OnAssetStatusChange<T>, B: OnAssetStatusChange<T>> OnAssetStatusChange<T>
+	for (A, B)
+{
+	fn on_status_change(
+		collection_id: T::NftCollectionId,
+		item_id: T::NftItemId,
+		status: AssetStatus,
+	) -> DispatchResult {
+		A::on_status_change(collection_id, item_id, status)?;
+		B::on_status_change(collection_id, item_id, status)
+	}
+}
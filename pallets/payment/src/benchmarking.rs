@@ -0,0 +1,94 @@
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as PaymentModule;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_system::RawOrigin;
+
+fn funded_account<T: Config>(name: &'static str, index: u32) -> T::AccountId {
+	let who: T::AccountId = account(name, index, 0);
+	T::Currency::make_free_balance_be(&who, 1_000u32.into());
+	who
+}
+
+benchmarks! {
+	pay {
+		let x in 1..T::MaxRemarkLength::get();
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, 10_000_000u32.into());
+		let recipient = funded_account::<T>("recipient", 0);
+		let remark: BoundedDataOf<T> = sp_std::vec![1u8; x as usize].try_into().unwrap();
+	}: _(RawOrigin::Signed(caller), recipient, PaymentAsset::Native, 1_000u32.into(), Some(remark))
+
+	create_recurring {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, 10_000_000u32.into());
+		let recipient = funded_account::<T>("recipient", 0);
+	}: _(RawOrigin::Signed(caller), recipient, PaymentAsset::Native, 1_000u32.into(), 10u32.into(), 12, None)
+
+	cancel_recurring {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, 10_000_000u32.into());
+		let recipient = funded_account::<T>("recipient", 0);
+		let mandate_id = PaymentModule::<T>::next_payment_id();
+		PaymentModule::<T>::create_recurring(
+			RawOrigin::Signed(caller.clone()).into(),
+			recipient.clone(),
+			PaymentAsset::Native,
+			1_000u32.into(),
+			10u32.into(),
+			12,
+			None,
+		)?;
+	}: _(RawOrigin::Signed(caller), recipient, mandate_id)
+
+	pay_batch {
+		let x in 1..T::MaxBatchSize::get();
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, 10_000_000u32.into());
+		let payments: BatchPaymentsOf<T> = (0..x)
+			.map(|i| (funded_account::<T>("recipient", i), 1_000u32.into()))
+			.collect::<Vec<_>>()
+			.try_into()
+			.unwrap();
+	}: _(RawOrigin::Signed(caller), PaymentAsset::Native, payments, None)
+
+	process_recurring {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, 10_000_000u32.into());
+		let recipient = funded_account::<T>("recipient", 0);
+		let mandate_id = PaymentModule::<T>::next_payment_id();
+		PaymentModule::<T>::create_recurring(
+			RawOrigin::Signed(caller.clone()).into(),
+			recipient.clone(),
+			PaymentAsset::Native,
+			1_000u32.into(),
+			10u32.into(),
+			12,
+			None,
+		)?;
+		let now = PaymentModule::<T>::recurring_payments((&caller, &recipient, mandate_id))
+			.unwrap()
+			.next_payment_at;
+		let mandate_key = (caller, recipient, mandate_id);
+	}: {
+		PaymentModule::<T>::process_recurring(mandate_key.clone(), now)
+	}
+	verify {
+		let (from, to, mandate_id) = mandate_key;
+		let mandate = PaymentModule::<T>::recurring_payments((&from, &to, mandate_id)).unwrap();
+		assert_eq!(mandate.remaining, 10);
+	}
+
+	set_asset_fee {
+		let origin = T::FeeOrigin::successful_origin();
+		let fee = Percent::from_percent(1);
+	}: _<T::Origin>(origin, PaymentAsset::Native, fee)
+	verify {
+		assert_eq!(PaymentModule::<T>::asset_fee(PaymentAsset::Native), fee);
+	}
+
+	impl_benchmark_test_suite!(PaymentModule, crate::mock::new_test_ext(), crate::mock::Test);
+}
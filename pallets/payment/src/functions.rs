@@ -1,4 +1,5 @@
 pub use super::*;
+use pallet_assets::FrozenBalance;
 
 impl<T: Config> Pallet<T> {
 	pub fn balance_to_u128_option(input: BalanceOf<T>) -> Option<u128> {
@@ -17,52 +18,69 @@ impl<T: Config> PaymentHandler<T> for Pallet<T> {
 	fn create_payment(
 		from: &T::AccountId,
 		recipient: &T::AccountId,
+		asset: PaymentAssetOf<T>,
 		amount: BalanceOf<T>,
 		payment_state: PaymentState<T>,
 		incentive_percentage: Percent,
 		remark: Option<&[u8]>,
-	) -> Result<PaymentDetail<T>, sp_runtime::DispatchError> {
-		Payment::<T>::try_mutate(
-			from,
-			recipient,
-			|maybe_payment| -> Result<PaymentDetail<T>, sp_runtime::DispatchError> {
-				// only payment requests can be overwritten
-				if let Some(payment) = maybe_payment {
-					ensure!(
-						payment.state == PaymentState::PaymentRequested,
-						Error::<T>::PaymentAlreadyInProcess
-					);
-				}
+	) -> Result<(PaymentId, PaymentDetail<T>), sp_runtime::DispatchError> {
+		let payment_id = NextPaymentId::<T>::get();
+		let next_id = payment_id.checked_add(1).ok_or(Error::<T>::MathError)?;
 
-				// Calculate incentive amount - this is to insentivise the user to release
-				// the funds once a transaction has been completed
-				let incentive_amount = incentive_percentage.mul_floor(amount);
+		let bounded_remark: Option<BoundedDataOf<T>> = match remark {
+			Some(r) => Some(r.to_vec().try_into().map_err(|_| Error::<T>::RemarkTooLong)?),
+			None => None,
+		};
 
-				let mut new_payment = PaymentDetail {
-					amount,
-					incentive_amount,
-					state: payment_state,
-					resolver_account: T::DisputeResolver::get_resolver_account(),
-					fee_detail: None,
-				};
+		// Calculate incentive amount - this is to insentivise the user to release
+		// the funds once a transaction has been completed
+		let incentive_amount = incentive_percentage.mul_floor(amount);
 
-				// Calculate fee amount - this will be implemented based on the custom
-				// implementation of the fee provider
-				let (fee_recipient, fee_percent) =
-					T::FeeHandler::apply_fees(from, recipient, &new_payment, remark);
-				let fee_amount = fee_percent.mul_floor(amount);
-				new_payment.fee_detail = Some((fee_recipient, fee_amount));
+		// requests and payments are removed once expired
+		let now = <frame_system::Pallet<T>>::block_number();
+		let expiry = match payment_state {
+			PaymentState::PaymentRequested => T::PaymentRequestExpiry::get(),
+			_ => T::PaymentExpiry::get(),
+		};
+		let expires_at = now.saturating_add(expiry);
+		let resolver_account = T::DisputeResolver::get_resolver_account(from, recipient)
+			.ok_or(Error::<T>::NoDisputeResolver)?;
 
-				*maybe_payment = Some(new_payment.clone());
+		let mut new_payment = PaymentDetail {
+			asset,
+			amount,
+			incentive_amount,
+			state: payment_state,
+			resolver_account,
+			fee_detail: None,
+			remark: bounded_remark,
+			created_at: now,
+			expires_at,
+		};
 
-				Ok(new_payment)
-			},
-		)
+		// Calculate fee amount - this will be implemented based on the custom
+		// implementation of the fee provider
+		let (fee_recipient, fee_percent) =
+			T::FeeHandler::apply_fees(from, recipient, &new_payment, remark);
+		let fee_amount = fee_percent.mul_floor(amount);
+		new_payment.fee_detail = Some((fee_recipient, fee_amount));
+
+		Payment::<T>::insert((from, recipient, payment_id), new_payment.clone());
+		NextPaymentId::<T>::put(next_id);
+		Self::schedule_task(
+			(from.clone(), recipient.clone(), payment_id),
+			Task::Expire,
+			expires_at,
+		);
+
+		Ok((payment_id, new_payment))
 	}
 
 	/// The function will reserve the fees+transfer amount from the `from`
 	/// account. After reserving the payment.amount will be transferred to
 	/// the recipient but will stay in Reserve state.
+	/// Payments in a fungible asset cannot be reserved, the total amount is
+	/// transferred to the pallet account instead.
 	#[require_transactional]
 	fn reserve_payment_amount(
 		from: &T::AccountId,
@@ -74,10 +92,22 @@ impl<T: Config> PaymentHandler<T> for Pallet<T> {
 		let total_fee_amount = payment.incentive_amount.saturating_add(fee_amount);
 		let total_amount = total_fee_amount.saturating_add(payment.amount);
 
-		// reserve the total amount from payment creator
-		T::Currency::reserve(from, total_amount)?;
-		// transfer payment amount to recipient -- keeping reserve status
-		T::Currency::repatriate_reserved(from, to, payment.amount, BalanceStatus::Reserved)?;
+		match payment.asset {
+			PaymentAsset::Native => {
+				// reserve the total amount from payment creator
+				T::Currency::reserve(from, total_amount)?;
+				// transfer payment amount to recipient -- keeping reserve status
+				T::Currency::repatriate_reserved(
+					from,
+					to,
+					payment.amount,
+					BalanceStatus::Reserved,
+				)?;
+			},
+			PaymentAsset::Fungible(_) => {
+				Self::transfer_asset(&payment.asset, from, &Self::account_id(), total_amount)?;
+			},
+		}
 		Ok(())
 	}
 
@@ -91,105 +121,538 @@ impl<T: Config> PaymentHandler<T> for Pallet<T> {
 	fn settle_payment(
 		from: &T::AccountId,
 		to: &T::AccountId,
+		payment_id: PaymentId,
 		recipient_share: Percent,
+		outcome: PaymentOutcome,
 	) -> DispatchResult {
-		Payment::<T>::try_mutate(from, to, |maybe_payment| -> DispatchResult {
+		Payment::<T>::try_mutate((from, to, payment_id), |maybe_payment| -> DispatchResult {
 			let payment = maybe_payment.take().ok_or(Error::<T>::InvalidPayment)?;
 
-			// unreserve the incentive amount and fees from the owner account
-			match payment.fee_detail {
-				Some((fee_recipient, fee_amount)) => {
-					T::Currency::unreserve(from, payment.incentive_amount + fee_amount);
-					// transfer fee to marketplace if operation is not cancel
-					if recipient_share != Percent::zero() {
-						T::Currency::transfer(
-							from,           // fee is paid by payment creator
-							&fee_recipient, // account of fee recipient
-							fee_amount,     // amount of fee
-							AllowDeath,
-						)?;
-					}
+			let amount_to_recipient = recipient_share.mul_floor(payment.amount);
+			let amount_to_sender = payment.amount.saturating_sub(amount_to_recipient);
+
+			let fee_paid = match payment.asset {
+				PaymentAsset::Native => {
+					// unreserve the incentive amount and fees from the owner account
+					let fee_paid = match payment.fee_detail {
+						Some((fee_recipient, fee_amount)) => {
+							T::Currency::unreserve(from, payment.incentive_amount + fee_amount);
+							// transfer fee to marketplace if operation is not cancel
+							if recipient_share != Percent::zero() {
+								T::Currency::transfer(
+									from,           // fee is paid by payment creator
+									&fee_recipient, // account of fee recipient
+									fee_amount,     // amount of fee
+									AllowDeath,
+								)?;
+								fee_amount
+							} else {
+								Zero::zero()
+							}
+						},
+						None => {
+							T::Currency::unreserve(from, payment.incentive_amount);
+							Zero::zero()
+						},
+					};
+
+					// Unreserve the transfer amount
+					T::Currency::unreserve(to, payment.amount);
+
+					// send share to recipient
+					T::Currency::transfer(to, from, amount_to_sender, AllowDeath)?;
+					fee_paid
 				},
-				None => {
-					T::Currency::unreserve(from, payment.incentive_amount);
+				PaymentAsset::Fungible(_) => {
+					// everything is held by the pallet account
+					let pallet_account = Self::account_id();
+					let mut creator_refund =
+						payment.incentive_amount.saturating_add(amount_to_sender);
+					let fee_paid = match payment.fee_detail {
+						// transfer fee to marketplace if operation is not cancel
+						Some((fee_recipient, fee_amount)) =>
+							if recipient_share != Percent::zero() {
+								Self::transfer_asset(
+									&payment.asset,
+									&pallet_account,
+									&fee_recipient,
+									fee_amount,
+								)?;
+								fee_amount
+							} else {
+								creator_refund = creator_refund.saturating_add(fee_amount);
+								Zero::zero()
+							},
+						None => Zero::zero(),
+					};
+
+					Self::transfer_asset(&payment.asset, &pallet_account, to, amount_to_recipient)?;
+					Self::transfer_asset(&payment.asset, &pallet_account, from, creator_refund)?;
+					fee_paid
 				},
 			};
 
-			// Unreserve the transfer amount
-			T::Currency::unreserve(to, payment.amount);
-
-			let amount_to_recipient = recipient_share.mul_floor(payment.amount);
-			let amount_to_sender = payment.amount.saturating_sub(amount_to_recipient);
-			// send share to recipient
-			T::Currency::transfer(to, from, amount_to_sender, AllowDeath)?;
+			Self::store_receipt(PaymentReceipt {
+				payment_id,
+				from: from.clone(),
+				to: to.clone(),
+				asset: payment.asset,
+				amount: payment.amount,
+				recipient_amount: amount_to_recipient,
+				fee_amount: fee_paid,
+				outcome,
+				remark: payment.remark,
+				created_at: payment.created_at,
+				settled_at: <frame_system::Pallet<T>>::block_number(),
+			});
 
 			Ok(())
 		})?;
+		// the scheduled task of a settled payment is obsolete
+		Self::unschedule_task(&(from.clone(), to.clone(), payment_id));
+		Ok(())
+	}
+
+	fn get_payment_details(
+		from: &T::AccountId,
+		to: &T::AccountId,
+		payment_id: PaymentId,
+	) -> Option<PaymentDetail<T>> {
+		Payment::<T>::get((from, to, payment_id))
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Account holding the fungible assets of the active payments
+	pub fn account_id() -> T::AccountId {
+		T::PalletId::get().into_account_truncating()
+	}
+
+	/// Transfer `amount` of `asset` from `source` to `dest`
+	pub fn transfer_asset(
+		asset: &PaymentAssetOf<T>,
+		source: &T::AccountId,
+		dest: &T::AccountId,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		if amount.is_zero() {
+			return Ok(())
+		}
+		match asset {
+			PaymentAsset::Native => T::Currency::transfer(source, dest, amount, AllowDeath),
+			PaymentAsset::Fungible(asset_id) => {
+				let asset_amount = Self::to_asset_balance(amount)?;
+				<T::Assets as fungibles::Transfer<T::AccountId>>::transfer(
+					*asset_id,
+					source,
+					dest,
+					asset_amount,
+					false,
+				)?;
+				Ok(())
+			},
+		}
+	}
+
+	/// Balance of `asset` that `who` can use to pay
+	pub fn free_balance(asset: &PaymentAssetOf<T>, who: &T::AccountId) -> BalanceOf<T> {
+		match asset {
+			PaymentAsset::Native => T::Currency::free_balance(who),
+			PaymentAsset::Fungible(asset_id) => {
+				let balance: AssetBalanceOf<T> =
+					<T::Assets as fungibles::Inspect<T::AccountId>>::reducible_balance(
+						*asset_id, who, false,
+					);
+				balance
+					.try_into()
+					.ok()
+					.and_then(Self::u128_to_balance_option)
+					.unwrap_or_else(Zero::zero)
+			},
+		}
+	}
+
+	/// Lock `amount` of `asset` in the account of `who`: the native currency is
+	/// reserved, a fungible asset is frozen
+	pub fn reserve_asset(
+		asset: &PaymentAssetOf<T>,
+		who: &T::AccountId,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		match asset {
+			PaymentAsset::Native => T::Currency::reserve(who, amount),
+			PaymentAsset::Fungible(asset_id) => {
+				ensure!(
+					Self::free_balance(asset, who) >= amount,
+					Error::<T>::InsufficientAssetBalance
+				);
+				let asset_amount = Self::to_asset_balance(amount)?;
+				HeldAssets::<T>::mutate(asset_id, who, |held| {
+					*held = held.saturating_add(asset_amount)
+				});
+				Ok(())
+			},
+		}
+	}
+
+	/// Unlock up to `amount` of `asset` in the account of `who`
+	pub fn unreserve_asset(
+		asset: &PaymentAssetOf<T>,
+		who: &T::AccountId,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		match asset {
+			PaymentAsset::Native => {
+				T::Currency::unreserve(who, amount);
+			},
+			PaymentAsset::Fungible(asset_id) => {
+				let asset_amount = Self::to_asset_balance(amount)?;
+				HeldAssets::<T>::mutate_exists(asset_id, who, |held| {
+					*held = held
+						.map(|held| held.saturating_sub(asset_amount))
+						.filter(|held| !held.is_zero())
+				});
+			},
+		}
 		Ok(())
 	}
 
-	fn get_payment_details(from: &T::AccountId, to: &T::AccountId) -> Option<PaymentDetail<T>> {
-		Payment::<T>::get(from, to)
+	fn to_asset_balance(amount: BalanceOf<T>) -> Result<AssetBalanceOf<T>, Error<T>> {
+		Self::balance_to_u128_option(amount)
+			.and_then(|amount| amount.try_into().ok())
+			.ok_or(Error::<T>::MathError)
+	}
+
+	/// Add the receipt of a settled payment to the history of the creator and
+	/// of the recipient. When the history of an account is full, its oldest
+	/// receipt is dropped.
+	pub fn store_receipt(receipt: PaymentReceipt<T>) {
+		for who in [&receipt.from, &receipt.to] {
+			PaymentHistory::<T>::mutate(who, |history| {
+				if !history.is_empty() && history.len() as u32 >= T::MaxPaymentHistory::get() {
+					history.remove(0);
+				}
+				history.try_push(receipt.clone()).ok();
+			});
+		}
+	}
+
+	/// Receipts of the payments of `who` settled between the blocks `start`
+	/// and `end` (both included)
+	pub fn payments_by_period(
+		who: &T::AccountId,
+		start: T::BlockNumber,
+		end: T::BlockNumber,
+	) -> Vec<PaymentReceipt<T>> {
+		PaymentHistory::<T>::get(who)
+			.into_iter()
+			.filter(|receipt| receipt.settled_at >= start && receipt.settled_at <= end)
+			.collect()
 	}
 }
 
 impl<T: Config> Pallet<T> {
+	/// Create an escrowed payment of `amount` of `asset` from `from` to
+	/// `recipient` and reserve its funds
+	pub fn do_pay(
+		from: &T::AccountId,
+		recipient: &T::AccountId,
+		asset: PaymentAssetOf<T>,
+		amount: BalanceOf<T>,
+		remark: Option<BoundedDataOf<T>>,
+	) -> Result<PaymentId, sp_runtime::DispatchError> {
+		// create PaymentDetail and add to storage
+		let (payment_id, payment_detail) = <Self as PaymentHandler<T>>::create_payment(
+			from,
+			recipient,
+			asset,
+			amount,
+			PaymentState::Created,
+			T::IncentivePercentage::get(),
+			remark.as_ref().map(|x| x.as_slice()),
+		)?;
+		// reserve funds for payment
+		<Self as PaymentHandler<T>>::reserve_payment_amount(from, recipient, payment_detail)?;
+		// emit paymentcreated event
+		Self::deposit_event(Event::PaymentCreated {
+			from: from.clone(),
+			to: recipient.clone(),
+			payment_id,
+			asset,
+			amount,
+			remark,
+		});
+		Ok(payment_id)
+	}
+
+	/// Create the next payment of a recurring payment. Returns the block of
+	/// the following payment, or None when the recurring payment is over.
+	pub fn execute_recurring(
+		mandate_key: &PaymentKeyOf<T>,
+		now: T::BlockNumber,
+	) -> Result<Option<T::BlockNumber>, sp_runtime::DispatchError> {
+		let (from, to, mandate_id) = mandate_key;
+		RecurringPayments::<T>::try_mutate_exists(
+			(from, to, *mandate_id),
+			|maybe_mandate| -> Result<Option<T::BlockNumber>, sp_runtime::DispatchError> {
+				let mandate = maybe_mandate.as_mut().ok_or(Error::<T>::NoRecurringPayment)?;
+				let payment_id =
+					Self::do_pay(from, to, mandate.asset, mandate.amount, mandate.remark.clone())?;
+				mandate.remaining = mandate.remaining.saturating_sub(1);
+
+				Self::deposit_event(Event::RecurringPaymentExecuted {
+					from: from.clone(),
+					to: to.clone(),
+					mandate_id: *mandate_id,
+					payment_id,
+					remaining: mandate.remaining,
+				});
+
+				if mandate.remaining == 0 {
+					*maybe_mandate = None;
+					return Ok(None)
+				}
+				mandate.next_payment_at =
+					now.checked_add(&mandate.period).ok_or(Error::<T>::MathError)?;
+				Ok(Some(mandate.next_payment_at))
+			},
+		)
+	}
+
+	/// Move the dispute of a payment to a new stage ending at `deadline`
+	pub fn update_dispute(
+		payment_key: &PaymentKeyOf<T>,
+		stage: DisputeStage<T::AccountId>,
+		deadline: T::BlockNumber,
+	) -> DispatchResult {
+		Disputes::<T>::try_mutate(
+			(&payment_key.0, &payment_key.1, payment_key.2),
+			|maybe_dispute| -> DispatchResult {
+				let dispute = maybe_dispute.as_mut().ok_or(Error::<T>::NoDispute)?;
+				dispute.stage = stage;
+				dispute.deadline = deadline;
+				Ok(())
+			},
+		)?;
+		Self::schedule_task(payment_key.clone(), Task::DisputeDeadline, deadline);
+		Ok(())
+	}
+
+	/// Settle a disputed payment with the given `recipient_share` and remove
+	/// its dispute
+	pub fn settle_dispute(
+		payment_key: &PaymentKeyOf<T>,
+		recipient_share: Percent,
+	) -> DispatchResult {
+		<Self as PaymentHandler<T>>::settle_payment(
+			&payment_key.0,
+			&payment_key.1,
+			payment_key.2,
+			recipient_share,
+			PaymentOutcome::Resolved { recipient_share },
+		)?;
+		Disputes::<T>::remove((&payment_key.0, &payment_key.1, payment_key.2));
+		Ok(())
+	}
+
+	/// Apply the default outcome of a dispute whose deadline has passed:
+	/// `DefaultRecipientShare` if the resolver did not decide, the decision of
+	/// the resolver otherwise
+	fn apply_dispute_default(payment_key: &PaymentKeyOf<T>) -> DispatchResult {
+		let dispute = Disputes::<T>::get((&payment_key.0, &payment_key.1, payment_key.2))
+			.ok_or(Error::<T>::NoDispute)?;
+		let recipient_share = match dispute.stage {
+			DisputeStage::AwaitingResolver => T::DefaultRecipientShare::get(),
+			DisputeStage::Resolved { recipient_share } |
+			DisputeStage::Appealed { recipient_share, .. } => recipient_share,
+		};
+		Self::settle_dispute(payment_key, recipient_share)?;
+		Self::deposit_event(Event::DisputeDeadlinePassed {
+			from: payment_key.0.clone(),
+			to: payment_key.1.clone(),
+			payment_id: payment_key.2,
+			recipient_share,
+		});
+		Ok(())
+	}
+
+	/// Execute the tasks which are due at `now`, oldest first, in the limit of
+	/// `remaining_weight`. Returns the consumed weight.
 	pub fn check_task(now: T::BlockNumber, remaining_weight: Weight) -> Weight {
-		const MAX_TASKS_TO_PROCESS: usize = 5;
-		// reduce the weight used to read the task list
-		remaining_weight.saturating_sub(T::WeightInfo::remove_task());
-		let cancel_weight = T::WeightInfo::cancel();
-
-		// calculate count of tasks that can be processed with remaining weight
-		let possible_task_count: usize = remaining_weight
-			.ref_time()
-			.saturating_div(cancel_weight.ref_time())
-			.try_into()
-			.unwrap_or(MAX_TASKS_TO_PROCESS);
-
-		ScheduledTasks::<T>::mutate(|tasks| {
-			let mut task_list: Vec<_> = tasks
-				.clone()
-				.into_iter()
-				.take(possible_task_count)
-				// leave out tasks in the future
-				.filter(|(_, ScheduledTask { when, task })| {
-					when <= &now && matches!(task, Task::Cancel)
-				})
-				.collect();
-
-			// order by oldest task to process
-			task_list.sort_by(|(_, t), (_, x)| x.when.cmp(&t.when));
-
-			while !task_list.is_empty() && remaining_weight >= cancel_weight {
-				if let Some((account_pair, _)) = task_list.pop() {
-					remaining_weight.saturating_sub(cancel_weight);
-					// remove the task form the tasks storage
-					tasks.remove(&account_pair);
-
-					// process the cancel payment
-					if <Self as PaymentHandler<T>>::settle_payment(
-						&account_pair.0,
-						&account_pair.1,
-						Percent::from_percent(0),
-					)
-					.is_err()
-					{
-						// log the payment refund failure
-						log::warn!(
-							target: "runtime::payments",
-							"Warning: Unable to process payment refund!"
-						);
-					} else {
-						// emit the cancel event if the refund was successful
-						Self::deposit_event(Event::PaymentCancelled {
-							from: account_pair.0,
-							to: account_pair.1,
-						});
-					}
+		// reading and removing the next key of the queue
+		let read_weight = T::WeightInfo::remove_task();
+		let mut used_weight = Weight::zero();
+		let mut block = NextTaskBlock::<T>::get();
+
+		while block <= now && used_weight.saturating_add(read_weight) <= remaining_weight {
+			let payment_key = match TaskQueue::<T>::iter_key_prefix(block).next() {
+				Some(payment_key) => payment_key,
+				// every task of this block was executed
+				None => {
+					used_weight = used_weight.saturating_add(read_weight);
+					block = block.saturating_add(One::one());
+					// tasks scheduled from now on go to this block at the earliest
+					NextTaskBlock::<T>::put(block);
+					continue
+				},
+			};
+			let task_weight = ScheduledTasks::<T>::get(&payment_key)
+				.map_or(Weight::zero(), |scheduled| Self::task_weight(&scheduled.task));
+			if used_weight.saturating_add(read_weight).saturating_add(task_weight) >
+				remaining_weight
+			{
+				break
+			}
+			used_weight = used_weight.saturating_add(read_weight);
+			TaskQueue::<T>::remove(block, &payment_key);
+			if let Some(ScheduledTask { task, .. }) = ScheduledTasks::<T>::take(&payment_key) {
+				used_weight = used_weight.saturating_add(task_weight);
+				Self::execute_task(payment_key, task, now);
+			}
+		}
+		used_weight
+	}
+
+	/// Weight of the execution of a scheduled task
+	fn task_weight(task: &Task) -> Weight {
+		match task {
+			Task::Recurring => T::WeightInfo::process_recurring(),
+			_ => T::WeightInfo::cancel(),
+		}
+	}
+
+	fn execute_task(payment_key: PaymentKeyOf<T>, task: Task, now: T::BlockNumber) {
+		match task {
+			// create the next payment of a recurring payment
+			Task::Recurring => Self::process_recurring(payment_key, now),
+			// apply the default outcome of the dispute
+			Task::DisputeDeadline =>
+				if Self::apply_dispute_default(&payment_key).is_err() {
+					log::warn!(
+						target: "runtime::payments",
+						"Warning: Unable to apply the default outcome of a dispute!"
+					);
+				},
+			// refund the expired payment
+			Task::Expire =>
+				if Self::expire_payment(&payment_key).is_err() {
+					log::warn!(
+						target: "runtime::payments",
+						"Warning: Unable to process payment expiry!"
+					);
+				},
+			// process the cancel payment
+			Task::Cancel => {
+				if <Self as PaymentHandler<T>>::settle_payment(
+					&payment_key.0,
+					&payment_key.1,
+					payment_key.2,
+					Percent::from_percent(0),
+					PaymentOutcome::Refunded,
+				)
+				.is_err()
+				{
+					// log the payment refund failure
+					log::warn!(
+						target: "runtime::payments",
+						"Warning: Unable to process payment refund!"
+					);
+				} else {
+					// emit the cancel event if the refund was successful
+					Self::deposit_event(Event::PaymentCancelled {
+						from: payment_key.0,
+						to: payment_key.1,
+						payment_id: payment_key.2,
+					});
 				}
+			},
+		}
+	}
+
+	/// Create the next payment of a recurring payment from the on_idle hook.
+	/// The recurring payment is cancelled if the payment cannot be created.
+	pub(crate) fn process_recurring(mandate_key: PaymentKeyOf<T>, now: T::BlockNumber) {
+		let result = frame_support::storage::with_transaction(|| {
+			let result = Self::execute_recurring(&mandate_key, now);
+			if result.is_ok() {
+				TransactionOutcome::Commit(result)
+			} else {
+				TransactionOutcome::Rollback(result)
 			}
 		});
-		remaining_weight
+		match result {
+			Ok(Some(when)) => Self::schedule_task(mandate_key, Task::Recurring, when),
+			Ok(None) => {},
+			Err(_) => {
+				log::warn!(
+					target: "runtime::payments",
+					"Warning: Unable to create a recurring payment!"
+				);
+				RecurringPayments::<T>::remove((&mandate_key.0, &mandate_key.1, mandate_key.2));
+				Self::deposit_event(Event::RecurringPaymentCancelled {
+					from: mandate_key.0,
+					to: mandate_key.1,
+					mandate_id: mandate_key.2,
+				});
+			},
+		}
+	}
+
+	/// Remove an expired payment: a payment request is dropped, a created
+	/// payment is refunded to its creator
+	fn expire_payment(payment_key: &PaymentKeyOf<T>) -> DispatchResult {
+		let (from, to, payment_id) = payment_key;
+		let payment =
+			Payment::<T>::get((from, to, *payment_id)).ok_or(Error::<T>::InvalidPayment)?;
+		match payment.state {
+			PaymentState::Created => <Self as PaymentHandler<T>>::settle_payment(
+				from,
+				to,
+				*payment_id,
+				Percent::from_percent(0),
+				PaymentOutcome::Expired,
+			)?,
+			PaymentState::PaymentRequested => Payment::<T>::remove((from, to, *payment_id)),
+			_ => fail!(Error::<T>::InvalidAction),
+		}
+
+		T::OnPaymentExpiry::on_payment_expired(from, to, *payment_id, &payment);
+		Self::deposit_event(Event::PaymentExpired {
+			from: from.clone(),
+			to: to.clone(),
+			payment_id: *payment_id,
+		});
+		Ok(())
+	}
+
+	/// Schedule `task` at `when` for the payment `payment_key`, replacing
+	/// its current task
+	pub fn schedule_task(payment_key: PaymentKeyOf<T>, task: Task, when: T::BlockNumber) {
+		Self::unschedule_task(&payment_key);
+		// blocks before the queue cursor are not visited anymore
+		let when = when.max(NextTaskBlock::<T>::get());
+		TaskQueue::<T>::insert(when, &payment_key, ());
+		ScheduledTasks::<T>::insert(payment_key, ScheduledTask { task, when });
+	}
+
+	/// Remove the scheduled task of the payment `payment_key`, if any
+	pub fn unschedule_task(payment_key: &PaymentKeyOf<T>) {
+		if let Some(ScheduledTask { when, .. }) = ScheduledTasks::<T>::take(payment_key) {
+			TaskQueue::<T>::remove(when, payment_key);
+		}
+	}
+}
+
+// The fungible assets locked by `reserve_asset` are frozen
+impl<T: Config> FrozenBalance<AssetIdOf<T>, T::AccountId, AssetBalanceOf<T>> for Pallet<T> {
+	fn frozen_balance(asset: AssetIdOf<T>, who: &T::AccountId) -> Option<AssetBalanceOf<T>> {
+		let held = HeldAssets::<T>::get(asset, who);
+		// pallet_assets keeps the minimum balance on top of the frozen balance
+		let min_balance = <T::Assets as fungibles::Inspect<T::AccountId>>::minimum_balance(asset);
+		(!held.is_zero()).then(|| held.saturating_sub(min_balance))
+	}
+
+	fn died(asset: AssetIdOf<T>, who: &T::AccountId) {
+		HeldAssets::<T>::remove(asset, who);
 	}
 }
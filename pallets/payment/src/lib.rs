@@ -12,19 +12,29 @@ mod mock;
 mod tests;
 
 mod functions;
+pub mod migrations;
 mod types;
 pub use crate::types::*;
 pub use functions::*;
 
+pub mod runtime_api;
+pub use runtime_api::PaymentApi;
+
 pub mod weights;
 pub use weights::WeightInfo;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	/// Configure the pallet by specifying the parameters and types on which it depends.
@@ -33,10 +43,18 @@ pub mod pallet {
 		/// Because this pallet emits events, it depends on the runtime's definition of an event.
 		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
 		type Currency: Currency<Self::AccountId> + ReservableCurrency<Self::AccountId>;
+		/// Fungible assets in which payments can be made besides the native currency
+		type Assets: fungibles::Transfer<Self::AccountId>;
+		/// The payment pallet id, used to derive the account holding the fungible
+		/// assets of the active payments
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
 		/// Dispute resolution account
 		type DisputeResolver: DisputeResolver<Self::AccountId>;
 		/// Fee handler trait
 		type FeeHandler: FeeHandler<Self>;
+		/// Origin allowed to set the marketplace fee of a payment asset
+		type FeeOrigin: EnsureOrigin<Self::Origin>;
 		/// Incentive percentage - amount witheld from sender
 		#[pallet::constant]
 		type IncentivePercentage: Get<Percent>;
@@ -47,35 +65,138 @@ pub mod pallet {
 		/// canceled payment
 		#[pallet::constant]
 		type CancelBufferBlockLength: Get<Self::BlockNumber>;
-		/// Buffer period - number of blocks to wait before user can claim
-		/// canceled payment
+		/// Number of blocks after which a payment that was not released is
+		/// refunded to its creator
+		#[pallet::constant]
+		type PaymentExpiry: Get<Self::BlockNumber>;
+		/// Number of blocks after which a payment request that was not
+		/// accepted is removed
+		#[pallet::constant]
+		type PaymentRequestExpiry: Get<Self::BlockNumber>;
+		/// Handler notified when a payment expires
+		type OnPaymentExpiry: OnPaymentExpiry<Self>;
+		/// Maximum number of receipts kept in the payment history of an account
+		#[pallet::constant]
+		type MaxPaymentHistory: Get<u32>;
+		/// Origin deciding on the disputes whose resolution was appealed
+		type AppealOrigin: EnsureOrigin<Self::Origin>;
+		/// Number of blocks the resolver has to decide on a dispute
+		#[pallet::constant]
+		type DisputeResolutionPeriod: Get<Self::BlockNumber>;
+		/// Number of blocks during which the decision of the resolver can be appealed
+		#[pallet::constant]
+		type AppealPeriod: Get<Self::BlockNumber>;
+		/// Number of blocks the `AppealOrigin` has to decide on an appealed dispute
 		#[pallet::constant]
-		type MaxScheduledTaskListLength: Get<u32>;
+		type AppealResolutionPeriod: Get<Self::BlockNumber>;
+		/// Share of the payment given to the recipient when the resolver does not
+		/// decide on a dispute in time
+		#[pallet::constant]
+		type DefaultRecipientShare: Get<Percent>;
+		/// Maximum number of evidences that can be submitted in a dispute
+		#[pallet::constant]
+		type MaxEvidences: Get<u32>;
+		/// Maximum number of payments created by a single `pay_batch`
+		#[pallet::constant]
+		type MaxBatchSize: Get<u32>;
 
 		type WeightInfo: WeightInfo;
 	}
 
+	#[pallet::storage]
+	#[pallet::getter(fn next_payment_id)]
+	/// Id that will be given to the next created payment
+	pub type NextPaymentId<T: Config> = StorageValue<_, PaymentId, ValueQuery>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn payment)]
-	/// Payments created by a user, this method of storageDoubleMap is chosen
-	/// since there is no usecase for listing payments by provider/currency. The
-	/// payment will only be referenced by the creator in any transaction of
-	/// interest. The storage map keys are the creator and the recipient, this
-	/// also ensures that for any (sender,recipient) combo, only a single
-	/// payment is active. The history of payment is not stored.
-	pub type Payment<T: Config> = StorageDoubleMap<
+	/// Active payments created by a user. The storage map keys are the
+	/// creator, the recipient and the payment id, so that several payments
+	/// can be active at the same time for any (sender,recipient) combo.
+	/// Payments are removed from this storage once settled, their receipt
+	/// is then kept in `PaymentHistory`.
+	pub type Payment<T: Config> = StorageNMap<
 		_,
-		Blake2_128Concat,
-		T::AccountId, // payment creator
-		Blake2_128Concat,
-		T::AccountId, // payment recipient
+		(
+			NMapKey<Blake2_128Concat, T::AccountId>, // payment creator
+			NMapKey<Blake2_128Concat, T::AccountId>, // payment recipient
+			NMapKey<Twox64Concat, PaymentId>,        // payment id
+		),
 		PaymentDetail<T>,
 	>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn history)]
+	/// Receipts of the settled payments of an account, as creator or as
+	/// recipient, from the oldest to the most recent one.
+	pub type PaymentHistory<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, PaymentHistoryOf<T>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn disputes)]
+	/// Disputes on active payments, using the same keys as `Payment`
+	pub type Disputes<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::AccountId>, // payment creator
+			NMapKey<Blake2_128Concat, T::AccountId>, // payment recipient
+			NMapKey<Twox64Concat, PaymentId>,        // payment id
+		),
+		Dispute<T>,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn recurring_payments)]
+	/// Active recurring payments, indexed by creator, recipient and mandate id.
+	/// Mandate ids are taken from the payment ids sequence, so that their
+	/// scheduled tasks can share `ScheduledTasks` with the payments ones.
+	pub type RecurringPayments<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::AccountId>, // payment creator
+			NMapKey<Blake2_128Concat, T::AccountId>, // payment recipient
+			NMapKey<Twox64Concat, PaymentId>,        // mandate id
+		),
+		RecurringPayment<T>,
+	>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn tasks)]
-	/// Store the list of tasks to be executed in the on_idle function
-	pub(super) type ScheduledTasks<T: Config> = StorageValue<_, ScheduledTaskList<T>, ValueQuery>;
+	/// Task scheduled for a payment or a recurring payment, to be executed in
+	/// the on_idle function. A payment has at most one task at a time.
+	pub(super) type ScheduledTasks<T: Config> =
+		StorageMap<_, Blake2_128Concat, PaymentKeyOf<T>, ScheduledTaskOf<T>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn task_queue)]
+	/// Keys of the scheduled tasks, indexed by the block at which they are due
+	pub(super) type TaskQueue<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::BlockNumber, Blake2_128Concat, PaymentKeyOf<T>, ()>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn next_task_block)]
+	/// First block of `TaskQueue` which may still contain tasks to execute
+	pub(super) type NextTaskBlock<T: Config> = StorageValue<_, T::BlockNumber, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn asset_fee)]
+	/// Marketplace fee charged on the payments made in an asset
+	pub type AssetFees<T: Config> =
+		StorageMap<_, Blake2_128Concat, PaymentAssetOf<T>, Percent, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn held_asset)]
+	/// Fungible asset balances locked in the account of their owner, as the
+	/// reserved balances of the native currency
+	pub type HeldAssets<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		AssetIdOf<T>,
+		Blake2_128Concat,
+		T::AccountId,
+		AssetBalanceOf<T>,
+		ValueQuery,
+	>;
 
 	// Pallets use events to inform users when important changes are made.
 	// https://docs.substrate.io/main-docs/build/events-errors/
@@ -88,27 +209,90 @@ pub mod pallet {
 		/// A new payment has been created
 		PaymentCreated {
 			from: T::AccountId,
+			to: T::AccountId,
+			payment_id: PaymentId,
+			asset: PaymentAssetOf<T>,
 			amount: BalanceOf<T>,
 			remark: Option<BoundedDataOf<T>>,
 		},
 		/// Payment amount released to the recipient
-		PaymentReleased { from: T::AccountId, to: T::AccountId },
+		PaymentReleased { from: T::AccountId, to: T::AccountId, payment_id: PaymentId },
 		/// Payment has been cancelled by the creator
-		PaymentCancelled { from: T::AccountId, to: T::AccountId },
+		PaymentCancelled { from: T::AccountId, to: T::AccountId, payment_id: PaymentId },
 		/// A payment that NeedsReview has been resolved by Judge
-		PaymentResolved { from: T::AccountId, to: T::AccountId, recipient_share: Percent },
+		PaymentResolved {
+			from: T::AccountId,
+			to: T::AccountId,
+			payment_id: PaymentId,
+			recipient_share: Percent,
+		},
 		/// the payment creator has created a refund request
 		PaymentCreatorRequestedRefund {
 			from: T::AccountId,
 			to: T::AccountId,
+			payment_id: PaymentId,
 			expiry: T::BlockNumber,
 		},
 		/// the refund request from creator was disputed by recipient
-		PaymentRefundDisputed { from: T::AccountId, to: T::AccountId },
+		PaymentRefundDisputed { from: T::AccountId, to: T::AccountId, payment_id: PaymentId },
 		/// Payment request was created by recipient
-		PaymentRequestCreated { from: T::AccountId, to: T::AccountId },
+		PaymentRequestCreated { from: T::AccountId, to: T::AccountId, payment_id: PaymentId },
 		/// Payment request was completed by sender
-		PaymentRequestCompleted { from: T::AccountId, to: T::AccountId },
+		PaymentRequestCompleted { from: T::AccountId, to: T::AccountId, payment_id: PaymentId },
+		/// A party of a disputed payment submitted an evidence
+		DisputeEvidenceSubmitted {
+			from: T::AccountId,
+			to: T::AccountId,
+			payment_id: PaymentId,
+			who: T::AccountId,
+		},
+		/// The resolver decided on a dispute, the decision can be appealed until `appeal_deadline`
+		DisputeResolutionProposed {
+			from: T::AccountId,
+			to: T::AccountId,
+			payment_id: PaymentId,
+			recipient_share: Percent,
+			appeal_deadline: T::BlockNumber,
+		},
+		/// The decision of the resolver was appealed
+		DisputeAppealed {
+			from: T::AccountId,
+			to: T::AccountId,
+			payment_id: PaymentId,
+			appellant: T::AccountId,
+			deadline: T::BlockNumber,
+		},
+		/// The deadline of a dispute passed and its default outcome was applied
+		DisputeDeadlinePassed {
+			from: T::AccountId,
+			to: T::AccountId,
+			payment_id: PaymentId,
+			recipient_share: Percent,
+		},
+		/// A payment or a payment request expired and was removed
+		PaymentExpired { from: T::AccountId, to: T::AccountId, payment_id: PaymentId },
+		/// A recurring payment was created
+		RecurringPaymentCreated {
+			from: T::AccountId,
+			to: T::AccountId,
+			mandate_id: PaymentId,
+			asset: PaymentAssetOf<T>,
+			amount: BalanceOf<T>,
+			period: T::BlockNumber,
+			count: u32,
+		},
+		/// A payment of a recurring payment was created
+		RecurringPaymentExecuted {
+			from: T::AccountId,
+			to: T::AccountId,
+			mandate_id: PaymentId,
+			payment_id: PaymentId,
+			remaining: u32,
+		},
+		/// A recurring payment was cancelled by its creator or because a payment failed
+		RecurringPaymentCancelled { from: T::AccountId, to: T::AccountId, mandate_id: PaymentId },
+		/// The marketplace fee of a payment asset was set
+		AssetFeeSet { asset: PaymentAssetOf<T>, fee: Percent },
 	}
 
 	// Errors inform users that something went wrong.
@@ -134,8 +318,26 @@ pub mod pallet {
 		RefundNotRequested,
 		/// Dispute period has not passed
 		DisputePeriodNotPassed,
-		/// The automatic cancelation queue cannot accept
-		RefundQueueFull,
+		/// The remark is longer than `MaxRemarkLength`
+		RemarkTooLong,
+		/// The payment is not disputed
+		NoDispute,
+		/// The deadline of the current stage of the dispute has passed
+		DisputeDeadlinePassed,
+		/// The dispute already contains `MaxEvidences` evidences
+		TooManyEvidences,
+		/// Only the creator and the recipient of the payment can do this action
+		NotPaymentParty,
+		/// The period and the number of payments of a recurring payment cannot be zero
+		InvalidRecurringPayment,
+		/// The selected recurring payment does not exist
+		NoRecurringPayment,
+		/// A batch must contain at least one payment
+		EmptyBatch,
+		/// The free balance of the asset is too low to be locked
+		InsufficientAssetBalance,
+		/// No account can resolve the disputes of the payment
+		NoDisputeResolver,
 	}
 
 	#[pallet::hooks]
@@ -146,6 +348,10 @@ pub mod pallet {
 		fn on_idle(now: T::BlockNumber, remaining_weight: Weight) -> Weight {
 			Self::check_task(now, remaining_weight)
 		}
+
+		fn on_runtime_upgrade() -> Weight {
+			migrations::migrate::<T>()
+		}
 	}
 
 	// Dispatchable functions allows users to interact with the pallet and invoke state changes.
@@ -159,45 +365,46 @@ pub mod pallet {
 		/// the option to add a remark, this remark can then be used to run
 		/// custom logic and trigger alternate payment flows. the specified
 		/// amount.
+		/// The payment is made in `asset`, either the native currency or one of
+		/// the fungible assets of `Assets`.
 		#[pallet::weight(T::WeightInfo::pay(T::MaxRemarkLength::get()))]
 		pub fn pay(
 			origin: OriginFor<T>,
 			recipient: T::AccountId,
+			asset: PaymentAssetOf<T>,
 			#[pallet::compact] amount: BalanceOf<T>,
 			remark: Option<BoundedDataOf<T>>,
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
-
-			// create PaymentDetail and add to storage
-			let payment_detail = <Self as PaymentHandler<T>>::create_payment(
-				&who,
-				&recipient,
-				amount,
-				PaymentState::Created,
-				T::IncentivePercentage::get(),
-				remark.as_ref().map(|x| x.as_slice()),
-			)?;
-			// reserve funds for payment
-			<Self as PaymentHandler<T>>::reserve_payment_amount(&who, &recipient, payment_detail)?;
-			// emit paymentcreated event
-			Self::deposit_event(Event::PaymentCreated { from: who, amount, remark });
+			Self::do_pay(&who, &recipient, asset, amount, remark)?;
 			Ok(().into())
 		}
 
 		/// Release any created payment, this will transfer the reserved amount
 		/// from the creator of the payment to the assigned recipient
 		#[pallet::weight(T::WeightInfo::release())]
-		pub fn release(origin: OriginFor<T>, to: T::AccountId) -> DispatchResultWithPostInfo {
+		pub fn release(
+			origin: OriginFor<T>,
+			to: T::AccountId,
+			payment_id: PaymentId,
+		) -> DispatchResultWithPostInfo {
 			let from = ensure_signed(origin)?;
 
 			// ensure the payment is in Created state
-			let payment = Payment::<T>::get(&from, &to).ok_or(Error::<T>::InvalidPayment)?;
+			let payment =
+				Payment::<T>::get((&from, &to, payment_id)).ok_or(Error::<T>::InvalidPayment)?;
 			ensure!(payment.state == PaymentState::Created, Error::<T>::InvalidAction);
 
 			// release is a settle_payment with 100% recipient_share
-			<Self as PaymentHandler<T>>::settle_payment(&from, &to, Percent::from_percent(100))?;
+			<Self as PaymentHandler<T>>::settle_payment(
+				&from,
+				&to,
+				payment_id,
+				Percent::from_percent(100),
+				PaymentOutcome::Completed,
+			)?;
 
-			Self::deposit_event(Event::PaymentReleased { from, to });
+			Self::deposit_event(Event::PaymentReleased { from, to, payment_id });
 			Ok(().into())
 		}
 
@@ -205,21 +412,34 @@ pub mod pallet {
 		/// back to creator of the payment. This extrinsic can only be called by
 		/// the recipient of the payment
 		#[pallet::weight(T::WeightInfo::cancel())]
-		pub fn cancel(origin: OriginFor<T>, creator: T::AccountId) -> DispatchResultWithPostInfo {
+		pub fn cancel(
+			origin: OriginFor<T>,
+			creator: T::AccountId,
+			payment_id: PaymentId,
+		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
-			if let Some(payment) = Payment::<T>::get(&creator, &who) {
+			if let Some(payment) = Payment::<T>::get((&creator, &who, payment_id)) {
 				match payment.state {
 					// call settle payment with recipient_share=0, this refunds the sender
 					PaymentState::Created => {
 						<Self as PaymentHandler<T>>::settle_payment(
 							&creator,
 							&who,
+							payment_id,
 							Percent::from_percent(0),
+							PaymentOutcome::Cancelled,
 						)?;
-						Self::deposit_event(Event::PaymentCancelled { from: creator, to: who });
+						Self::deposit_event(Event::PaymentCancelled {
+							from: creator,
+							to: who,
+							payment_id,
+						});
 					},
 					// if the payment is in state PaymentRequested, remove from storage
-					PaymentState::PaymentRequested => Payment::<T>::remove(&creator, &who),
+					PaymentState::PaymentRequested => {
+						Payment::<T>::remove((&creator, &who, payment_id));
+						Self::unschedule_task(&(creator.clone(), who.clone(), payment_id));
+					},
 					_ => fail!(Error::<T>::InvalidAction),
 				}
 			}
@@ -230,34 +450,61 @@ pub mod pallet {
 		/// recipient of the payment.
 		/// This extrinsic allows the assigned judge to
 		/// cancel/release/partial_release the payment.
+		/// On a disputed payment, the decision is only applied at the end of
+		/// `AppealPeriod`, if none of the parties appealed it.
 		#[pallet::weight(T::WeightInfo::resolve_payment())]
 		pub fn resolve_payment(
 			origin: OriginFor<T>,
 			from: T::AccountId,
 			recipient: T::AccountId,
+			payment_id: PaymentId,
 			recipient_share: Percent,
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
-			let account_pair = (from, recipient);
+			let payment_key = (from, recipient, payment_id);
+			// the decision on a disputed payment can be appealed before being applied
+			if let Some(dispute) = Disputes::<T>::get((&payment_key.0, &payment_key.1, payment_id))
+			{
+				let payment = Payment::<T>::get((&payment_key.0, &payment_key.1, payment_id))
+					.ok_or(Error::<T>::InvalidPayment)?;
+				ensure!(who == payment.resolver_account, Error::<T>::InvalidAction);
+				ensure!(dispute.stage == DisputeStage::AwaitingResolver, Error::<T>::InvalidAction);
+				let appeal_deadline = <frame_system::Pallet<T>>::block_number()
+					.saturating_add(T::AppealPeriod::get());
+				Self::update_dispute(
+					&payment_key,
+					DisputeStage::Resolved { recipient_share },
+					appeal_deadline,
+				)?;
+				Self::deposit_event(Event::DisputeResolutionProposed {
+					from: payment_key.0,
+					to: payment_key.1,
+					payment_id,
+					recipient_share,
+					appeal_deadline,
+				});
+				return Ok(().into())
+			}
 			// ensure the caller is the assigned resolver
-			if let Some(payment) = Payment::<T>::get(&account_pair.0, &account_pair.1) {
+			if let Some(payment) = Payment::<T>::get((&payment_key.0, &payment_key.1, payment_id)) {
 				ensure!(who == payment.resolver_account, Error::<T>::InvalidAction);
 				ensure!(payment.state != PaymentState::PaymentRequested, Error::<T>::InvalidAction);
 				if matches!(payment.state, PaymentState::RefundRequested { .. }) {
-					ScheduledTasks::<T>::mutate(|tasks| {
-						tasks.remove(&account_pair);
-					})
+					Self::unschedule_task(&payment_key);
 				}
 			}
 			// try to update the payment to new state
 			<Self as PaymentHandler<T>>::settle_payment(
-				&account_pair.0,
-				&account_pair.1,
+				&payment_key.0,
+				&payment_key.1,
+				payment_id,
 				recipient_share,
+				PaymentOutcome::Resolved { recipient_share },
 			)?;
 			Self::deposit_event(Event::PaymentResolved {
-				from: account_pair.0,
-				to: account_pair.1,
+				from: payment_key.0,
+				to: payment_key.1,
+				payment_id,
 				recipient_share,
 			});
 			Ok(().into())
@@ -270,12 +517,12 @@ pub mod pallet {
 		pub fn request_refund(
 			origin: OriginFor<T>,
 			recipient: T::AccountId,
+			payment_id: PaymentId,
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 
 			Payment::<T>::try_mutate(
-				who.clone(),
-				recipient.clone(),
+				(who.clone(), recipient.clone(), payment_id),
 				|maybe_payment| -> DispatchResult {
 					// ensure the payment exists
 					let payment = maybe_payment.as_mut().ok_or(Error::<T>::InvalidPayment)?;
@@ -288,21 +535,18 @@ pub mod pallet {
 						.checked_add(&T::CancelBufferBlockLength::get())
 						.ok_or(Error::<T>::MathError)?;
 
-					ScheduledTasks::<T>::try_mutate(|task_list| -> DispatchResult {
-						task_list
-							.try_insert(
-								(who.clone(), recipient.clone()),
-								ScheduledTask { task: Task::Cancel, when: cancel_block },
-							)
-							.map_err(|_| Error::<T>::RefundQueueFull)?;
-						Ok(())
-					})?;
+					Self::schedule_task(
+						(who.clone(), recipient.clone(), payment_id),
+						Task::Cancel,
+						cancel_block,
+					);
 
 					payment.state = PaymentState::RefundRequested { cancel_block };
 
 					Self::deposit_event(Event::PaymentCreatorRequestedRefund {
 						from: who,
 						to: recipient,
+						payment_id,
 						expiry: cancel_block,
 					});
 
@@ -317,17 +561,20 @@ pub mod pallet {
 		/// payment creator This does not cancel the request, instead sends the
 		/// payment to a NeedsReview state The assigned resolver account can
 		/// then change the state of the payment after review.
+		/// If the resolver does not decide before the end of
+		/// `DisputeResolutionPeriod`, `DefaultRecipientShare` is applied.
 		#[pallet::weight(T::WeightInfo::dispute_refund())]
 		pub fn dispute_refund(
 			origin: OriginFor<T>,
 			creator: T::AccountId,
+			payment_id: PaymentId,
 		) -> DispatchResultWithPostInfo {
 			use PaymentState::*;
 			let who = ensure_signed(origin)?;
 
+			// should be called by the payment recipient
 			Payment::<T>::try_mutate(
-				creator.clone(),
-				who.clone(), // should be called by the payment recipient
+				(creator.clone(), who.clone(), payment_id),
 				|maybe_payment| -> DispatchResult {
 					// ensure the payment exists
 					let payment = maybe_payment.as_mut().ok_or(Error::<T>::InvalidPayment)?;
@@ -341,17 +588,26 @@ pub mod pallet {
 
 							payment.state = PaymentState::NeedsReview;
 
-							// remove the payment from scheduled tasks
-							ScheduledTasks::<T>::try_mutate(|task_list| -> DispatchResult {
-								task_list
-									.remove(&(creator.clone(), who.clone()))
-									.ok_or(Error::<T>::InvalidAction)?;
-								Ok(())
-							})?;
+							// open a dispute to be decided by the resolver of the payment
+							let deadline = frame_system::Pallet::<T>::block_number()
+								.saturating_add(T::DisputeResolutionPeriod::get());
+							let payment_key = (creator.clone(), who.clone(), payment_id);
+							Disputes::<T>::insert(
+								(&creator, &who, payment_id),
+								Dispute {
+									stage: DisputeStage::AwaitingResolver,
+									deadline,
+									evidences: Default::default(),
+								},
+							);
+
+							// replace the scheduled refund by the deadline of the dispute
+							Self::schedule_task(payment_key, Task::DisputeDeadline, deadline);
 
 							Self::deposit_event(Event::PaymentRefundDisputed {
 								from: creator,
 								to: who,
+								payment_id,
 							});
 						},
 						_ => fail!(Error::<T>::InvalidAction),
@@ -373,21 +629,23 @@ pub mod pallet {
 		pub fn request_payment(
 			origin: OriginFor<T>,
 			from: T::AccountId,
+			asset: PaymentAssetOf<T>,
 			#[pallet::compact] amount: BalanceOf<T>,
 		) -> DispatchResultWithPostInfo {
 			let to = ensure_signed(origin)?;
 
 			// create PaymentDetail and add to storage
-			<Self as PaymentHandler<T>>::create_payment(
+			let (payment_id, _) = <Self as PaymentHandler<T>>::create_payment(
 				&from,
 				&to,
+				asset,
 				amount,
 				PaymentState::PaymentRequested,
 				Percent::from_percent(0),
 				None,
 			)?;
 
-			Self::deposit_event(Event::PaymentRequestCreated { from, to });
+			Self::deposit_event(Event::PaymentRequestCreated { from, to, payment_id });
 
 			Ok(().into())
 		}
@@ -399,10 +657,12 @@ pub mod pallet {
 		pub fn accept_and_pay(
 			origin: OriginFor<T>,
 			to: T::AccountId,
+			payment_id: PaymentId,
 		) -> DispatchResultWithPostInfo {
 			let from = ensure_signed(origin)?;
 
-			let payment = Payment::<T>::get(&from, &to).ok_or(Error::<T>::InvalidPayment)?;
+			let payment =
+				Payment::<T>::get((&from, &to, payment_id)).ok_or(Error::<T>::InvalidPayment)?;
 
 			ensure!(payment.state == PaymentState::PaymentRequested, Error::<T>::InvalidAction);
 
@@ -410,11 +670,237 @@ pub mod pallet {
 			<Self as PaymentHandler<T>>::reserve_payment_amount(&from, &to, payment.clone())?;
 
 			// release the payment and delete the payment from storage
-			<Self as PaymentHandler<T>>::settle_payment(&from, &to, Percent::from_percent(100))?;
-			T::Currency::reserve(&to, payment.amount).ok();
+			<Self as PaymentHandler<T>>::settle_payment(
+				&from,
+				&to,
+				payment_id,
+				Percent::from_percent(100),
+				PaymentOutcome::Completed,
+			)?;
+			// the payment stays locked in the recipient account
+			Self::reserve_asset(&payment.asset, &to, payment.amount)?;
+
+			Self::deposit_event(Event::PaymentRequestCompleted { from, to, payment_id });
+
+			Ok(().into())
+		}
+
+		/// Submit a remark as evidence in the dispute of a payment.
+		/// This extrinsic can only be called by the creator or the recipient of
+		/// the payment, before the deadline of the current stage of the dispute.
+		#[pallet::weight(T::WeightInfo::submit_evidence())]
+		pub fn submit_evidence(
+			origin: OriginFor<T>,
+			from: T::AccountId,
+			to: T::AccountId,
+			payment_id: PaymentId,
+			remark: BoundedDataOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			ensure!(who == from || who == to, Error::<T>::NotPaymentParty);
+
+			Disputes::<T>::try_mutate(
+				(&from, &to, payment_id),
+				|maybe_dispute| -> DispatchResult {
+					let dispute = maybe_dispute.as_mut().ok_or(Error::<T>::NoDispute)?;
+					let now = <frame_system::Pallet<T>>::block_number();
+					ensure!(now <= dispute.deadline, Error::<T>::DisputeDeadlinePassed);
+					dispute
+						.evidences
+						.try_push(Evidence { who: who.clone(), remark, submitted_at: now })
+						.map_err(|_| Error::<T>::TooManyEvidences)?;
+					Ok(())
+				},
+			)?;
+
+			Self::deposit_event(Event::DisputeEvidenceSubmitted { from, to, payment_id, who });
+			Ok(().into())
+		}
+
+		/// Appeal the decision of the resolver on a disputed payment. The
+		/// dispute is then decided by the `AppealOrigin`, the decision of the
+		/// resolver is applied if no decision is taken before the end of
+		/// `AppealResolutionPeriod`.
+		/// This extrinsic can only be called by the creator or the recipient of
+		/// the payment, before the end of `AppealPeriod`.
+		#[pallet::weight(T::WeightInfo::appeal())]
+		pub fn appeal(
+			origin: OriginFor<T>,
+			from: T::AccountId,
+			to: T::AccountId,
+			payment_id: PaymentId,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			ensure!(who == from || who == to, Error::<T>::NotPaymentParty);
+
+			let payment_key = (from, to, payment_id);
+			let dispute = Disputes::<T>::get((&payment_key.0, &payment_key.1, payment_id))
+				.ok_or(Error::<T>::NoDispute)?;
+			let recipient_share = match dispute.stage {
+				DisputeStage::Resolved { recipient_share } => recipient_share,
+				_ => fail!(Error::<T>::InvalidAction),
+			};
+			let now = <frame_system::Pallet<T>>::block_number();
+			ensure!(now <= dispute.deadline, Error::<T>::DisputeDeadlinePassed);
+
+			let deadline = now.saturating_add(T::AppealResolutionPeriod::get());
+			Self::update_dispute(
+				&payment_key,
+				DisputeStage::Appealed { appellant: who.clone(), recipient_share },
+				deadline,
+			)?;
+
+			Self::deposit_event(Event::DisputeAppealed {
+				from: payment_key.0,
+				to: payment_key.1,
+				payment_id,
+				appellant: who,
+				deadline,
+			});
+			Ok(().into())
+		}
+
+		/// Settle an appealed dispute with the given `recipient_share`.
+		/// This extrinsic can only be called by the `AppealOrigin`.
+		#[pallet::weight(T::WeightInfo::resolve_appeal())]
+		pub fn resolve_appeal(
+			origin: OriginFor<T>,
+			from: T::AccountId,
+			to: T::AccountId,
+			payment_id: PaymentId,
+			recipient_share: Percent,
+		) -> DispatchResultWithPostInfo {
+			T::AppealOrigin::ensure_origin(origin)?;
 
-			Self::deposit_event(Event::PaymentRequestCompleted { from, to });
+			let payment_key = (from, to, payment_id);
+			let dispute = Disputes::<T>::get((&payment_key.0, &payment_key.1, payment_id))
+				.ok_or(Error::<T>::NoDispute)?;
+			ensure!(
+				matches!(dispute.stage, DisputeStage::Appealed { .. }),
+				Error::<T>::InvalidAction
+			);
+
+			Self::unschedule_task(&payment_key);
+			Self::settle_dispute(&payment_key, recipient_share)?;
+
+			Self::deposit_event(Event::PaymentResolved {
+				from: payment_key.0,
+				to: payment_key.1,
+				payment_id,
+				recipient_share,
+			});
+			Ok(().into())
+		}
+
+		/// Create a mandate paying `amount` of `asset` to `recipient` every
+		/// `period` blocks, `count` times. The first payment is created
+		/// immediately, the next ones are created by the on_idle hook. Each
+		/// payment is a regular escrowed payment that has to be released.
+		/// The mandate is cancelled if one of its payments cannot be created.
+		#[pallet::weight(T::WeightInfo::create_recurring())]
+		pub fn create_recurring(
+			origin: OriginFor<T>,
+			recipient: T::AccountId,
+			asset: PaymentAssetOf<T>,
+			#[pallet::compact] amount: BalanceOf<T>,
+			period: T::BlockNumber,
+			count: u32,
+			remark: Option<BoundedDataOf<T>>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			ensure!(!period.is_zero() && count > 0, Error::<T>::InvalidRecurringPayment);
+
+			// mandates share the id sequence of the payments
+			let mandate_id = NextPaymentId::<T>::get();
+			NextPaymentId::<T>::put(mandate_id.checked_add(1).ok_or(Error::<T>::MathError)?);
+
+			let now = frame_system::Pallet::<T>::block_number();
+			RecurringPayments::<T>::insert(
+				(&who, &recipient, mandate_id),
+				RecurringPayment {
+					asset,
+					amount,
+					period,
+					remaining: count,
+					next_payment_at: now,
+					remark,
+				},
+			);
+			Self::deposit_event(Event::RecurringPaymentCreated {
+				from: who.clone(),
+				to: recipient.clone(),
+				mandate_id,
+				asset,
+				amount,
+				period,
+				count,
+			});
+
+			let mandate_key = (who, recipient, mandate_id);
+			if let Some(when) = Self::execute_recurring(&mandate_key, now)? {
+				Self::schedule_task(mandate_key, Task::Recurring, when);
+			}
+			Ok(().into())
+		}
+
+		/// Cancel a recurring payment. The payments already created are not
+		/// affected. This extrinsic can only be called by the creator of the
+		/// recurring payment.
+		#[pallet::weight(T::WeightInfo::cancel_recurring())]
+		pub fn cancel_recurring(
+			origin: OriginFor<T>,
+			recipient: T::AccountId,
+			mandate_id: PaymentId,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let mandate_key = (who, recipient, mandate_id);
+			ensure!(
+				RecurringPayments::<T>::contains_key((&mandate_key.0, &mandate_key.1, mandate_id)),
+				Error::<T>::NoRecurringPayment
+			);
+
+			RecurringPayments::<T>::remove((&mandate_key.0, &mandate_key.1, mandate_id));
+			Self::unschedule_task(&mandate_key);
+
+			Self::deposit_event(Event::RecurringPaymentCancelled {
+				from: mandate_key.0,
+				to: mandate_key.1,
+				mandate_id,
+			});
+			Ok(().into())
+		}
+
+		/// Create one escrowed payment of `asset` for each (recipient, amount)
+		/// of `payments`. The batch is atomic: if one of the payments cannot be
+		/// created, none of them is.
+		#[pallet::weight(T::WeightInfo::pay_batch(payments.len() as u32))]
+		pub fn pay_batch(
+			origin: OriginFor<T>,
+			asset: PaymentAssetOf<T>,
+			payments: BatchPaymentsOf<T>,
+			remark: Option<BoundedDataOf<T>>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			ensure!(!payments.is_empty(), Error::<T>::EmptyBatch);
+
+			for (recipient, amount) in payments {
+				Self::do_pay(&who, &recipient, asset, amount, remark.clone())?;
+			}
+			Ok(().into())
+		}
+
+		/// Set the marketplace fee charged on the payments made in `asset`.
+		/// The payments already created keep their fee.
+		#[pallet::weight(T::WeightInfo::set_asset_fee())]
+		pub fn set_asset_fee(
+			origin: OriginFor<T>,
+			asset: PaymentAssetOf<T>,
+			fee: Percent,
+		) -> DispatchResultWithPostInfo {
+			T::FeeOrigin::ensure_origin(origin)?;
 
+			AssetFees::<T>::insert(asset, fee);
+			Self::deposit_event(Event::AssetFeeSet { asset, fee });
 			Ok(().into())
 		}
 	}
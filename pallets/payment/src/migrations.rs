@@ -0,0 +1,109 @@
+pub use super::*;
+use frame_support::traits::{GetStorageVersion, StorageVersion};
+
+/// Apply the migrations from the on chain storage version to the current one
+pub fn migrate<T: Config>() -> Weight {
+	let onchain_version = Pallet::<T>::on_chain_storage_version();
+	let mut weight = T::DbWeight::get().reads(1);
+	if onchain_version >= 1 {
+		return weight
+	}
+
+	weight = weight.saturating_add(v1::migrate::<T>());
+
+	StorageVersion::new(1).put::<Pallet<T>>();
+	weight.saturating_add(T::DbWeight::get().writes(1))
+}
+
+pub mod v1 {
+	use super::*;
+	use sp_std::collections::btree_map::BTreeMap;
+
+	/// Payment stored before the payment ids, the payment assets and the expiries
+	#[derive(Encode, Decode)]
+	pub struct OldPaymentDetail<T: Config> {
+		#[codec(compact)]
+		pub amount: BalanceOf<T>,
+		#[codec(compact)]
+		pub incentive_amount: BalanceOf<T>,
+		pub state: PaymentState<T>,
+		pub resolver_account: T::AccountId,
+		pub fee_detail: Option<(T::AccountId, BalanceOf<T>)>,
+	}
+
+	impl<T: Config> OldPaymentDetail<T> {
+		/// Native currency payment, considered as created at `now`
+		pub fn migrate(self, now: T::BlockNumber) -> PaymentDetail<T> {
+			let expiry = match self.state {
+				PaymentState::PaymentRequested => T::PaymentRequestExpiry::get(),
+				_ => T::PaymentExpiry::get(),
+			};
+			PaymentDetail {
+				asset: PaymentAsset::Native,
+				amount: self.amount,
+				incentive_amount: self.incentive_amount,
+				state: self.state,
+				resolver_account: self.resolver_account,
+				fee_detail: self.fee_detail,
+				remark: None,
+				created_at: now,
+				expires_at: now.saturating_add(expiry),
+			}
+		}
+	}
+
+	/// Payments indexed by creator and recipient, a single one per pair
+	#[frame_support::storage_alias]
+	pub type Payment<T: Config> = StorageDoubleMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		AccountIdOf<T>,
+		Blake2_128Concat,
+		AccountIdOf<T>,
+		OldPaymentDetail<T>,
+	>;
+
+	/// Tasks of all the payments, stored in a single value
+	#[frame_support::storage_alias]
+	pub type ScheduledTasks<T: Config> = StorageValue<
+		Pallet<T>,
+		BTreeMap<(AccountIdOf<T>, AccountIdOf<T>), ScheduledTaskOf<T>>,
+		ValueQuery,
+	>;
+
+	/// Give an id to the existing payments and move them to the `Payment` map indexed by id.
+	/// Their refunds are moved to the tasks queue, the payments and requests which are still
+	/// in their initial state expire after the current expiry periods.
+	pub fn migrate<T: Config>() -> Weight {
+		let now = <frame_system::Pallet<T>>::block_number();
+		// the tasks queue starts at the current block
+		NextTaskBlock::<T>::put(now);
+		let old_tasks = ScheduledTasks::<T>::take();
+		let payments: Vec<_> = Payment::<T>::drain().collect();
+		let mut next_id = NextPaymentId::<T>::get();
+		let mut writes: u64 = 3;
+
+		for (from, to, old) in payments {
+			let payment_id = next_id;
+			next_id = next_id.saturating_add(1);
+			let detail = old.migrate(now);
+			let key = (from.clone(), to.clone(), payment_id);
+			match old_tasks.get(&(from.clone(), to.clone())) {
+				Some(task) => Pallet::<T>::schedule_task(key, task.task.clone(), task.when),
+				None =>
+					if matches!(
+						detail.state,
+						PaymentState::Created | PaymentState::PaymentRequested
+					) {
+						Pallet::<T>::schedule_task(key, Task::Expire, detail.expires_at);
+					},
+			}
+			crate::Payment::<T>::insert((&from, &to, payment_id), detail);
+			// the payment, its task and its queue entry
+			writes += 4;
+		}
+		NextPaymentId::<T>::put(next_id);
+
+		T::DbWeight::get().reads_writes(writes, writes)
+	}
+}
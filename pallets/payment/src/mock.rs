@@ -4,6 +4,7 @@ use frame_support::{
 	parameter_types,
 	traits::{ConstU16, ConstU32, ConstU64, Hooks, OnFinalize},
 	weights::DispatchClass,
+	PalletId,
 };
 use frame_system as system;
 use sp_core::{crypto::AccountId32, H256};
@@ -28,6 +29,9 @@ pub const PAYMENT_RECIPENT_FEE_CHARGED: AccountId = AccountId::new([21u8; 32]);
 pub const INCENTIVE_PERCENTAGE: u8 = 10;
 pub const MARKETPLACE_FEE_PERCENTAGE: u8 = 10;
 pub const CANCEL_BLOCK_BUFFER: u64 = 600;
+pub const STABLECOIN: u32 = 1;
+pub const PAYMENT_EXPIRY: u64 = 1_000;
+pub const REQUEST_EXPIRY: u64 = 100;
 
 // Configure a mock runtime to test the pallet.
 frame_support::construct_runtime!(
@@ -39,6 +43,7 @@ frame_support::construct_runtime!(
 		System: frame_system::{Pallet, Call, Storage, Config,Event<T>},
 		PaymentModule: pallet_payment::{Pallet, Call, Storage, Event<T>},
 		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		Assets: pallet_assets::{Pallet, Call, Storage, Config<T>, Event<T>},
 	}
 );
 
@@ -71,8 +76,8 @@ impl system::Config for Test {
 
 pub struct MockDisputeResolver;
 impl crate::types::DisputeResolver<AccountId> for MockDisputeResolver {
-	fn get_resolver_account() -> AccountId {
-		RESOLVER_ACCOUNT
+	fn get_resolver_account(_from: &AccountId, _to: &AccountId) -> Option<AccountId> {
+		Some(RESOLVER_ACCOUNT)
 	}
 }
 
@@ -96,18 +101,40 @@ parameter_types! {
 	pub const IncentivePercentage: Percent = Percent::from_percent(INCENTIVE_PERCENTAGE);
 	pub const MaxRemarkLength: u32 = 50;
 	pub const CancelBufferBlockLength: u64 = CANCEL_BLOCK_BUFFER;
-	pub const MaxScheduledTaskListLength : u32 = 5;
+	pub const PaymentExpiry: u64 = PAYMENT_EXPIRY;
+	pub const PaymentRequestExpiry: u64 = REQUEST_EXPIRY;
+	pub const MaxPaymentHistory: u32 = 10;
+	pub const DisputeResolutionPeriod: u64 = 20;
+	pub const AppealPeriod: u64 = 10;
+	pub const AppealResolutionPeriod: u64 = 30;
+	pub const DefaultRecipientShare: Percent = Percent::from_percent(0);
+	pub const MaxEvidences: u32 = 3;
+	pub const MaxBatchSize: u32 = 5;
+	pub const PaymentPalletId: PalletId = PalletId(*b"py/paymt");
 }
 
 impl pallet_payment::Config for Test {
 	type Event = Event;
 	type Currency = Balances;
+	type Assets = Assets;
+	type PalletId = PaymentPalletId;
 	type DisputeResolver = MockDisputeResolver;
 	type IncentivePercentage = IncentivePercentage;
 	type FeeHandler = MockFeeHandler;
+	type FeeOrigin = frame_system::EnsureRoot<AccountId>;
 	type MaxRemarkLength = MaxRemarkLength;
 	type CancelBufferBlockLength = CancelBufferBlockLength;
-	type MaxScheduledTaskListLength = MaxScheduledTaskListLength;
+	type PaymentExpiry = PaymentExpiry;
+	type PaymentRequestExpiry = PaymentRequestExpiry;
+	type OnPaymentExpiry = ();
+	type MaxPaymentHistory = MaxPaymentHistory;
+	type AppealOrigin = frame_system::EnsureRoot<AccountId>;
+	type DisputeResolutionPeriod = DisputeResolutionPeriod;
+	type AppealPeriod = AppealPeriod;
+	type AppealResolutionPeriod = AppealResolutionPeriod;
+	type DefaultRecipientShare = DefaultRecipientShare;
+	type MaxEvidences = MaxEvidences;
+	type MaxBatchSize = MaxBatchSize;
 	type WeightInfo = ();
 }
 
@@ -124,6 +151,23 @@ impl pallet_balances::Config for Test {
 	type WeightInfo = ();
 }
 
+impl pallet_assets::Config for Test {
+	type Event = Event;
+	type Balance = u64;
+	type AssetId = u32;
+	type Currency = Balances;
+	type ForceOrigin = frame_system::EnsureRoot<AccountId>;
+	type AssetDeposit = ConstU64<1>;
+	type AssetAccountDeposit = ConstU64<1>;
+	type MetadataDepositBase = ConstU64<1>;
+	type MetadataDepositPerByte = ConstU64<1>;
+	type ApprovalDeposit = ConstU64<1>;
+	type StringLimit = ConstU32<50>;
+	type Freezer = PaymentModule;
+	type Extra = ();
+	type WeightInfo = ();
+}
+
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
 	let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
@@ -140,6 +184,19 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 	}
 	.assimilate_storage(&mut t)
 	.unwrap();
+
+	pallet_assets::GenesisConfig::<Test> {
+		assets: vec![(STABLECOIN, FEE_RECIPIENT_ACCOUNT, true, 1)],
+		metadata: vec![],
+		accounts: vec![
+			(STABLECOIN, PAYMENT_CREATOR, 100_000),
+			(STABLECOIN, PAYMENT_RECIPENT, 1),
+			(STABLECOIN, FEE_RECIPIENT_ACCOUNT, 1),
+			(STABLECOIN, PAYMENT_RECIPENT_FEE_CHARGED, 1),
+		],
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
 	let mut ext: sp_io::TestExternalities = t.into();
 	// need to set block number to 1 to test events
 	ext.execute_with(|| System::set_block_number(1));
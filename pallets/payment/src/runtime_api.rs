@@ -0,0 +1,19 @@
+//! Runtime API definition for the payment pallet.
+//! It gives access to the payment history of an account, which can be used
+//! to build rent receipts or tax reports.
+#![allow(clippy::unnecessary_mut_passed)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	pub trait PaymentApi<AccountId, BlockNumber, Receipt> where
+		AccountId: Codec,
+		BlockNumber: Codec,
+		Receipt: Codec,
+	{
+		/// Receipts of the payments settled between the blocks `start` and `end`
+		/// (both included), in which `who` was the creator or the recipient.
+		fn payments_by_period(who: AccountId, start: BlockNumber, end: BlockNumber) -> Vec<Receipt>;
+	}
+}
@@ -1,9 +1,18 @@
 use crate::{
 	mock::*,
-	types::{PaymentDetail, PaymentState},
-	Payment as PaymentStore, PaymentHandler, ScheduledTask, ScheduledTasks, Task,
+	types::{
+		BatchPaymentsOf, Dispute, DisputeStage, Evidence, PaymentAsset, PaymentDetail,
+		PaymentOutcome, PaymentReceipt, PaymentState, RecurringPayment,
+	},
+	weights::WeightInfo,
+	Disputes, NextPaymentId, NextTaskBlock, Payment as PaymentStore, PaymentHandler,
+	PaymentHistory, RecurringPayments, ScheduledTask, ScheduledTasks, Task, TaskQueue,
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	storage::with_transaction,
+	traits::{Currency, Get, GetStorageVersion, Hooks, StorageVersion},
 };
-use frame_support::{assert_noop, assert_ok, storage::with_transaction};
 use sp_runtime::{Percent, TransactionOutcome};
 type Error = crate::Error<Test>;
 
@@ -11,6 +20,19 @@ fn last_event() -> Event {
 	System::events().pop().expect("Event expected").event
 }
 
+/// Create a payment of `amount` whose refund request is disputed by the recipient
+fn create_disputed_payment(amount: u64) {
+	assert_ok!(PaymentModule::pay(
+		Origin::signed(PAYMENT_CREATOR),
+		PAYMENT_RECIPENT,
+		PaymentAsset::Native,
+		amount,
+		None
+	));
+	assert_ok!(PaymentModule::request_refund(Origin::signed(PAYMENT_CREATOR), PAYMENT_RECIPENT, 0));
+	assert_ok!(PaymentModule::dispute_refund(Origin::signed(PAYMENT_RECIPENT), PAYMENT_CREATOR, 0));
+}
+
 #[test]
 fn test_pay_works() {
 	new_test_ext().execute_with(|| {
@@ -26,6 +48,7 @@ fn test_pay_works() {
 		assert_ok!(PaymentModule::pay(
 			Origin::signed(PAYMENT_CREATOR),
 			PAYMENT_RECIPENT,
+			PaymentAsset::Native,
 			payment_amount,
 			None
 		));
@@ -33,6 +56,9 @@ fn test_pay_works() {
 			last_event(),
 			crate::Event::<Test>::PaymentCreated {
 				from: PAYMENT_CREATOR,
+				to: PAYMENT_RECIPENT,
+				payment_id: 0,
+				asset: PaymentAsset::Native,
 				amount: payment_amount,
 				remark: None
 			}
@@ -40,13 +66,17 @@ fn test_pay_works() {
 		);
 
 		assert_eq!(
-			PaymentStore::<Test>::get(PAYMENT_CREATOR, PAYMENT_RECIPENT),
+			PaymentStore::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT, 0)),
 			Some(PaymentDetail {
+				asset: PaymentAsset::Native,
 				amount: payment_amount,
 				incentive_amount: expected_incentive_amount,
 				state: PaymentState::Created,
 				resolver_account: RESOLVER_ACCOUNT,
 				fee_detail: Some((FEE_RECIPIENT_ACCOUNT, 0)),
+				remark: None,
+				created_at: 1,
+				expires_at: 1 + PAYMENT_EXPIRY,
 			})
 		);
 		// the payment amount should be reserved correctly
@@ -69,25 +99,29 @@ fn test_pay_works() {
 			payment_amount.saturating_add(Balances::free_balance(&PAYMENT_RECIPENT))
 		);
 
-		// the payment should not be overwritten
-		assert_noop!(
-			PaymentModule::pay(
-				Origin::signed(PAYMENT_CREATOR),
-				PAYMENT_RECIPENT,
-				payment_amount,
-				None
-			),
-			crate::Error::<Test>::PaymentAlreadyInProcess
-		);
+		// a second payment to the same recipient gets a new id
+		assert_ok!(PaymentModule::pay(
+			Origin::signed(PAYMENT_CREATOR),
+			PAYMENT_RECIPENT,
+			PaymentAsset::Native,
+			payment_amount,
+			None
+		));
+		assert_eq!(PaymentModule::next_payment_id(), 2);
 
+		// the first payment should not be overwritten
 		assert_eq!(
-			PaymentStore::<Test>::get(PAYMENT_CREATOR, PAYMENT_RECIPENT),
+			PaymentStore::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT, 0)),
 			Some(PaymentDetail {
+				asset: PaymentAsset::Native,
 				amount: payment_amount,
 				incentive_amount: 200,
 				state: PaymentState::Created,
 				resolver_account: RESOLVER_ACCOUNT,
 				fee_detail: Some((FEE_RECIPIENT_ACCOUNT, 0)),
+				remark: None,
+				created_at: 1,
+				expires_at: 1 + PAYMENT_EXPIRY,
 			})
 		);
 	});
@@ -104,18 +138,23 @@ fn test_cancel_works() {
 		assert_ok!(PaymentModule::pay(
 			Origin::signed(PAYMENT_CREATOR),
 			PAYMENT_RECIPENT,
+			PaymentAsset::Native,
 			payment_amount,
 			None
 		));
 
 		assert_eq!(
-			PaymentStore::<Test>::get(PAYMENT_CREATOR, PAYMENT_RECIPENT),
+			PaymentStore::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT, 0)),
 			Some(PaymentDetail {
+				asset: PaymentAsset::Native,
 				amount: payment_amount,
 				incentive_amount: expected_incentive_amount,
 				state: PaymentState::Created,
 				resolver_account: RESOLVER_ACCOUNT,
 				fee_detail: Some((FEE_RECIPIENT_ACCOUNT, 0)),
+				remark: None,
+				created_at: 1,
+				expires_at: 1 + PAYMENT_EXPIRY,
 			})
 		);
 		// the payment amount should be reserved
@@ -126,18 +165,22 @@ fn test_cancel_works() {
 		assert_eq!(Balances::free_balance(&PAYMENT_RECIPENT), 1);
 
 		// cancel should succeed when caller is the recipent
-		assert_ok!(PaymentModule::cancel(Origin::signed(PAYMENT_RECIPENT), PAYMENT_CREATOR));
+		assert_ok!(PaymentModule::cancel(Origin::signed(PAYMENT_RECIPENT), PAYMENT_CREATOR, 0));
 		assert_eq!(
 			last_event(),
-			crate::Event::<Test>::PaymentCancelled { from: PAYMENT_CREATOR, to: PAYMENT_RECIPENT }
-				.into()
+			crate::Event::<Test>::PaymentCancelled {
+				from: PAYMENT_CREATOR,
+				to: PAYMENT_RECIPENT,
+				payment_id: 0
+			}
+			.into()
 		);
 		// the payment amount should be released back to creator
 		assert_eq!(Balances::free_balance(&PAYMENT_CREATOR), creator_initial_balance);
 		assert_eq!(Balances::free_balance(&PAYMENT_RECIPENT), 1);
 
 		// should be released from storage
-		assert_eq!(PaymentStore::<Test>::get(PAYMENT_CREATOR, PAYMENT_RECIPENT), None);
+		assert_eq!(PaymentStore::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT, 0)), None);
 	});
 }
 
@@ -153,17 +196,22 @@ fn test_release_works() {
 		assert_ok!(PaymentModule::pay(
 			Origin::signed(PAYMENT_CREATOR),
 			PAYMENT_RECIPENT,
+			PaymentAsset::Native,
 			payment_amount,
 			None
 		));
 		assert_eq!(
-			PaymentStore::<Test>::get(PAYMENT_CREATOR, PAYMENT_RECIPENT),
+			PaymentStore::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT, 0)),
 			Some(PaymentDetail {
+				asset: PaymentAsset::Native,
 				amount: payment_amount,
 				incentive_amount: expected_incentive_amount,
 				state: PaymentState::Created,
 				resolver_account: RESOLVER_ACCOUNT,
 				fee_detail: Some((FEE_RECIPIENT_ACCOUNT, 0)),
+				remark: None,
+				created_at: 1,
+				expires_at: 1 + PAYMENT_EXPIRY,
 			})
 		);
 		// the payment amount should be reserved
@@ -174,11 +222,15 @@ fn test_release_works() {
 		assert_eq!(Balances::free_balance(&PAYMENT_RECIPENT), 1);
 
 		// should succeed for valid payment
-		assert_ok!(PaymentModule::release(Origin::signed(PAYMENT_CREATOR), PAYMENT_RECIPENT));
+		assert_ok!(PaymentModule::release(Origin::signed(PAYMENT_CREATOR), PAYMENT_RECIPENT, 0));
 		assert_eq!(
 			last_event(),
-			crate::Event::<Test>::PaymentReleased { from: PAYMENT_CREATOR, to: PAYMENT_RECIPENT }
-				.into()
+			crate::Event::<Test>::PaymentReleased {
+				from: PAYMENT_CREATOR,
+				to: PAYMENT_RECIPENT,
+				payment_id: 0
+			}
+			.into()
 		);
 		// the payment amount should be transferred
 		assert_eq!(
@@ -191,12 +243,13 @@ fn test_release_works() {
 		);
 
 		// should be deleted from storage
-		assert_eq!(PaymentStore::<Test>::get(PAYMENT_CREATOR, PAYMENT_RECIPENT), None);
+		assert_eq!(PaymentStore::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT, 0)), None);
 
 		// should be able to create another payment since previous is released
 		assert_ok!(PaymentModule::pay(
 			Origin::signed(PAYMENT_CREATOR),
 			PAYMENT_RECIPENT,
+			PaymentAsset::Native,
 			payment_amount,
 			None
 		));
@@ -223,6 +276,7 @@ fn test_resolve_payment_works() {
 		assert_ok!(PaymentModule::pay(
 			Origin::signed(PAYMENT_CREATOR),
 			PAYMENT_RECIPENT,
+			PaymentAsset::Native,
 			payment_amount,
 			None
 		));
@@ -233,6 +287,7 @@ fn test_resolve_payment_works() {
 				Origin::signed(PAYMENT_CREATOR),
 				PAYMENT_CREATOR,
 				PAYMENT_RECIPENT,
+				0,
 				Percent::from_percent(100)
 			),
 			Error::InvalidAction
@@ -243,6 +298,7 @@ fn test_resolve_payment_works() {
 			Origin::signed(RESOLVER_ACCOUNT),
 			PAYMENT_CREATOR,
 			PAYMENT_RECIPENT,
+			0,
 			Percent::from_percent(100)
 		));
 		assert_eq!(
@@ -250,6 +306,7 @@ fn test_resolve_payment_works() {
 			crate::Event::<Test>::PaymentResolved {
 				from: PAYMENT_CREATOR,
 				to: PAYMENT_RECIPENT,
+				payment_id: 0,
 				recipient_share: Percent::from_percent(100)
 			}
 			.into()
@@ -266,11 +323,12 @@ fn test_resolve_payment_works() {
 		);
 
 		// should be removed from storage
-		assert_eq!(PaymentStore::<Test>::get(PAYMENT_CREATOR, PAYMENT_RECIPENT), None);
+		assert_eq!(PaymentStore::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT, 0)), None);
 
 		assert_ok!(PaymentModule::pay(
 			Origin::signed(PAYMENT_CREATOR),
 			PAYMENT_RECIPENT,
+			PaymentAsset::Native,
 			payment_amount,
 			None
 		));
@@ -280,6 +338,7 @@ fn test_resolve_payment_works() {
 			Origin::signed(RESOLVER_ACCOUNT),
 			PAYMENT_CREATOR,
 			PAYMENT_RECIPENT,
+			1,
 			Percent::from_percent(0)
 		));
 		assert_eq!(
@@ -287,6 +346,7 @@ fn test_resolve_payment_works() {
 			crate::Event::<Test>::PaymentResolved {
 				from: PAYMENT_CREATOR,
 				to: PAYMENT_RECIPENT,
+				payment_id: 1,
 				recipient_share: Percent::from_percent(0)
 			}
 			.into()
@@ -303,7 +363,7 @@ fn test_resolve_payment_works() {
 		);
 
 		// should be released from storage
-		assert_eq!(PaymentStore::<Test>::get(PAYMENT_CREATOR, PAYMENT_RECIPENT), None);
+		assert_eq!(PaymentStore::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT, 1)), None);
 	});
 }
 
@@ -321,17 +381,22 @@ fn test_charging_fee_payment_works() {
 		assert_ok!(PaymentModule::pay(
 			Origin::signed(PAYMENT_CREATOR),
 			PAYMENT_RECIPENT_FEE_CHARGED,
+			PaymentAsset::Native,
 			payment_amount,
 			None
 		));
 		assert_eq!(
-			PaymentStore::<Test>::get(PAYMENT_CREATOR, PAYMENT_RECIPENT_FEE_CHARGED),
+			PaymentStore::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT_FEE_CHARGED, 0)),
 			Some(PaymentDetail {
+				asset: PaymentAsset::Native,
 				amount: payment_amount,
 				incentive_amount: expected_incentive_amount,
 				state: PaymentState::Created,
 				resolver_account: RESOLVER_ACCOUNT,
 				fee_detail: Some((FEE_RECIPIENT_ACCOUNT, expected_fee_amount)),
+				remark: None,
+				created_at: 1,
+				expires_at: 1 + PAYMENT_EXPIRY,
 			})
 		);
 		// the payment amount should be reserved
@@ -349,7 +414,8 @@ fn test_charging_fee_payment_works() {
 		// should succeed for valid payment
 		assert_ok!(PaymentModule::release(
 			Origin::signed(PAYMENT_CREATOR),
-			PAYMENT_RECIPENT_FEE_CHARGED
+			PAYMENT_RECIPENT_FEE_CHARGED,
+			0
 		));
 		// the payment amount should be transferred
 		assert_eq!(
@@ -383,17 +449,22 @@ fn test_charging_fee_payment_works_when_canceled() {
 		assert_ok!(PaymentModule::pay(
 			Origin::signed(PAYMENT_CREATOR),
 			PAYMENT_RECIPENT_FEE_CHARGED,
+			PaymentAsset::Native,
 			payment_amount,
 			None
 		));
 		assert_eq!(
-			PaymentStore::<Test>::get(PAYMENT_CREATOR, PAYMENT_RECIPENT_FEE_CHARGED),
+			PaymentStore::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT_FEE_CHARGED, 0)),
 			Some(PaymentDetail {
+				asset: PaymentAsset::Native,
 				amount: payment_amount,
 				incentive_amount: expected_incentive_amount,
 				state: PaymentState::Created,
 				resolver_account: RESOLVER_ACCOUNT,
 				fee_detail: Some((FEE_RECIPIENT_ACCOUNT, expected_fee_amount)),
+				remark: None,
+				created_at: 1,
+				expires_at: 1 + PAYMENT_EXPIRY,
 			})
 		);
 		// the payment amount should be reserved
@@ -408,7 +479,8 @@ fn test_charging_fee_payment_works_when_canceled() {
 		// should succeed for valid payment
 		assert_ok!(PaymentModule::cancel(
 			Origin::signed(PAYMENT_RECIPENT_FEE_CHARGED),
-			PAYMENT_CREATOR
+			PAYMENT_CREATOR,
+			0
 		));
 		// the payment amount should be transferred
 		assert_eq!(Balances::free_balance(&PAYMENT_CREATOR), creator_initial_balance);
@@ -433,17 +505,22 @@ fn test_pay_with_remark_works() {
 		assert_ok!(PaymentModule::pay(
 			Origin::signed(PAYMENT_CREATOR),
 			PAYMENT_RECIPENT,
+			PaymentAsset::Native,
 			payment_amount,
 			Some(vec![1u8; 10].try_into().unwrap())
 		));
 		assert_eq!(
-			PaymentStore::<Test>::get(PAYMENT_CREATOR, PAYMENT_RECIPENT),
+			PaymentStore::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT, 0)),
 			Some(PaymentDetail {
+				asset: PaymentAsset::Native,
 				amount: payment_amount,
 				incentive_amount: expected_incentive_amount,
 				state: PaymentState::Created,
 				resolver_account: RESOLVER_ACCOUNT,
 				fee_detail: Some((FEE_RECIPIENT_ACCOUNT, 0)),
+				remark: Some(vec![1u8; 10].try_into().unwrap()),
+				created_at: 1,
+				expires_at: 1 + PAYMENT_EXPIRY,
 			})
 		);
 		// the payment amount should be reserved correctly
@@ -466,21 +543,13 @@ fn test_pay_with_remark_works() {
 			Balances::free_balance(&PAYMENT_RECIPENT).saturating_add(payment_amount)
 		);
 
-		// the payment should not be overwritten
-		assert_noop!(
-			PaymentModule::pay(
-				Origin::signed(PAYMENT_CREATOR),
-				PAYMENT_RECIPENT,
-				payment_amount,
-				None
-			),
-			crate::Error::<Test>::PaymentAlreadyInProcess
-		);
-
 		assert_eq!(
 			last_event(),
 			crate::Event::<Test>::PaymentCreated {
 				from: PAYMENT_CREATOR,
+				to: PAYMENT_RECIPENT,
+				payment_id: 0,
+				asset: PaymentAsset::Native,
 				amount: payment_amount,
 				remark: Some(vec![1u8; 10].try_into().unwrap())
 			}
@@ -498,42 +567,56 @@ fn test_do_not_overwrite_logic_works() {
 		assert_ok!(PaymentModule::pay(
 			Origin::signed(PAYMENT_CREATOR),
 			PAYMENT_RECIPENT,
+			PaymentAsset::Native,
 			payment_amount,
 			None
 		));
 
-		assert_noop!(
-			PaymentModule::pay(
-				Origin::signed(PAYMENT_CREATOR),
-				PAYMENT_RECIPENT,
-				payment_amount,
-				None
-			),
-			crate::Error::<Test>::PaymentAlreadyInProcess
-		);
-
 		// set payment state to NeedsReview
 		PaymentStore::<Test>::insert(
-			PAYMENT_CREATOR,
-			PAYMENT_RECIPENT,
+			(PAYMENT_CREATOR, PAYMENT_RECIPENT, 0),
 			PaymentDetail {
+				asset: PaymentAsset::Native,
 				amount: payment_amount,
 				incentive_amount: expected_incentive_amount,
 				state: PaymentState::NeedsReview,
 				resolver_account: RESOLVER_ACCOUNT,
 				fee_detail: Some((FEE_RECIPIENT_ACCOUNT, 0)),
+				remark: None,
+				created_at: 1,
+				expires_at: 1 + PAYMENT_EXPIRY,
 			},
 		);
 
-		// the payment should not be overwritten
-		assert_noop!(
-			PaymentModule::pay(
-				Origin::signed(PAYMENT_CREATOR),
-				PAYMENT_RECIPENT,
-				payment_amount,
-				None
-			),
-			crate::Error::<Test>::PaymentAlreadyInProcess
+		// a new payment between the same accounts gets its own id
+		assert_ok!(PaymentModule::pay(
+			Origin::signed(PAYMENT_CREATOR),
+			PAYMENT_RECIPENT,
+			PaymentAsset::Native,
+			payment_amount,
+			None
+		));
+		assert_eq!(
+			last_event(),
+			crate::Event::<Test>::PaymentCreated {
+				from: PAYMENT_CREATOR,
+				to: PAYMENT_RECIPENT,
+				payment_id: 1,
+				asset: PaymentAsset::Native,
+				amount: payment_amount,
+				remark: None
+			}
+			.into()
+		);
+
+		// the first payment should not be overwritten
+		assert_eq!(
+			PaymentStore::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT, 0)).unwrap().state,
+			PaymentState::NeedsReview
+		);
+		assert_eq!(
+			PaymentStore::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT, 1)).unwrap().state,
+			PaymentState::Created
 		);
 	});
 }
@@ -548,34 +631,29 @@ fn test_request_refund() {
 		assert_ok!(PaymentModule::pay(
 			Origin::signed(PAYMENT_CREATOR),
 			PAYMENT_RECIPENT,
+			PaymentAsset::Native,
 			payment_amount,
 			None
 		));
 
 		assert_ok!(PaymentModule::request_refund(
 			Origin::signed(PAYMENT_CREATOR),
-			PAYMENT_RECIPENT
+			PAYMENT_RECIPENT,
+			0
 		));
 
-		// do not overwrite payment
-		assert_noop!(
-			PaymentModule::pay(
-				Origin::signed(PAYMENT_CREATOR),
-				PAYMENT_RECIPENT,
-				payment_amount,
-				None
-			),
-			crate::Error::<Test>::PaymentAlreadyInProcess
-		);
-
 		assert_eq!(
-			PaymentStore::<Test>::get(PAYMENT_CREATOR, PAYMENT_RECIPENT),
+			PaymentStore::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT, 0)),
 			Some(PaymentDetail {
+				asset: PaymentAsset::Native,
 				amount: payment_amount,
 				incentive_amount: expected_incentive_amount,
 				state: PaymentState::RefundRequested { cancel_block: expected_cancel_block },
 				resolver_account: RESOLVER_ACCOUNT,
 				fee_detail: Some((FEE_RECIPIENT_ACCOUNT, 0)),
+				remark: None,
+				created_at: 1,
+				expires_at: 1 + PAYMENT_EXPIRY,
 			})
 		);
 
@@ -584,10 +662,24 @@ fn test_request_refund() {
 			crate::Event::<Test>::PaymentCreatorRequestedRefund {
 				from: PAYMENT_CREATOR,
 				to: PAYMENT_RECIPENT,
+				payment_id: 0,
 				expiry: expected_cancel_block
 			}
 			.into()
 		);
+
+		// a new payment does not overwrite the refund request
+		assert_ok!(PaymentModule::pay(
+			Origin::signed(PAYMENT_CREATOR),
+			PAYMENT_RECIPENT,
+			PaymentAsset::Native,
+			payment_amount,
+			None
+		));
+		assert_eq!(
+			PaymentStore::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT, 0)).unwrap().state,
+			PaymentState::RefundRequested { cancel_block: expected_cancel_block }
+		);
 	});
 }
 
@@ -601,41 +693,47 @@ fn test_dispute_refund() {
 		assert_ok!(PaymentModule::pay(
 			Origin::signed(PAYMENT_CREATOR),
 			PAYMENT_RECIPENT,
+			PaymentAsset::Native,
 			payment_amount,
 			None
 		));
 
 		// cannot dispute if refund is not requested
 		assert_noop!(
-			PaymentModule::dispute_refund(Origin::signed(PAYMENT_RECIPENT), PAYMENT_CREATOR),
+			PaymentModule::dispute_refund(Origin::signed(PAYMENT_RECIPENT), PAYMENT_CREATOR, 0),
 			Error::InvalidAction
 		);
 		// creator requests a refund
 		assert_ok!(PaymentModule::request_refund(
 			Origin::signed(PAYMENT_CREATOR),
-			PAYMENT_RECIPENT
+			PAYMENT_RECIPENT,
+			0
 		));
 		// ensure the request is added to the refund queue
-		let scheduled_tasks_list = ScheduledTasks::<Test>::get();
 		assert_eq!(
-			scheduled_tasks_list.get(&(PAYMENT_CREATOR, PAYMENT_RECIPENT)).unwrap(),
-			&ScheduledTask { task: Task::Cancel, when: expected_cancel_block }
+			ScheduledTasks::<Test>::get(&(PAYMENT_CREATOR, PAYMENT_RECIPENT, 0)).unwrap(),
+			ScheduledTask { task: Task::Cancel, when: expected_cancel_block }
 		);
 
 		// recipient disputes the refund request
 		assert_ok!(PaymentModule::dispute_refund(
 			Origin::signed(PAYMENT_RECIPENT),
-			PAYMENT_CREATOR
+			PAYMENT_CREATOR,
+			0
 		));
 
 		assert_eq!(
-			PaymentStore::<Test>::get(PAYMENT_CREATOR, PAYMENT_RECIPENT),
+			PaymentStore::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT, 0)),
 			Some(PaymentDetail {
+				asset: PaymentAsset::Native,
 				amount: payment_amount,
 				incentive_amount: expected_incentive_amount,
 				state: PaymentState::NeedsReview,
 				resolver_account: RESOLVER_ACCOUNT,
 				fee_detail: Some((FEE_RECIPIENT_ACCOUNT, 0)),
+				remark: None,
+				created_at: 1,
+				expires_at: 1 + PAYMENT_EXPIRY,
 			})
 		);
 
@@ -644,13 +742,24 @@ fn test_dispute_refund() {
 			crate::Event::<Test>::PaymentRefundDisputed {
 				from: PAYMENT_CREATOR,
 				to: PAYMENT_RECIPENT,
+				payment_id: 0
 			}
 			.into()
 		);
 
-		// ensure the request is removed from the refund queue
-		let scheduled_tasks_list = ScheduledTasks::<Test>::get();
-		assert_eq!(scheduled_tasks_list.get(&(PAYMENT_CREATOR, PAYMENT_RECIPENT)), None);
+		// ensure the refund is replaced by the deadline of the dispute
+		assert_eq!(
+			ScheduledTasks::<Test>::get(&(PAYMENT_CREATOR, PAYMENT_RECIPENT, 0)).unwrap(),
+			ScheduledTask { task: Task::DisputeDeadline, when: DisputeResolutionPeriod::get() + 1 }
+		);
+		assert_eq!(
+			Disputes::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT, 0)),
+			Some(Dispute {
+				stage: DisputeStage::AwaitingResolver,
+				deadline: DisputeResolutionPeriod::get() + 1,
+				evidences: Default::default(),
+			})
+		);
 	});
 }
 
@@ -663,22 +772,27 @@ fn test_request_payment() {
 		assert_ok!(PaymentModule::request_payment(
 			Origin::signed(PAYMENT_RECIPENT),
 			PAYMENT_CREATOR,
+			PaymentAsset::Native,
 			payment_amount,
 		));
 
 		assert_noop!(
-			PaymentModule::request_refund(Origin::signed(PAYMENT_CREATOR), PAYMENT_RECIPENT),
+			PaymentModule::request_refund(Origin::signed(PAYMENT_CREATOR), PAYMENT_RECIPENT, 0),
 			crate::Error::<Test>::InvalidAction
 		);
 
 		assert_eq!(
-			PaymentStore::<Test>::get(PAYMENT_CREATOR, PAYMENT_RECIPENT),
+			PaymentStore::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT, 0)),
 			Some(PaymentDetail {
+				asset: PaymentAsset::Native,
 				amount: payment_amount,
 				incentive_amount: expected_incentive_amount,
 				state: PaymentState::PaymentRequested,
 				resolver_account: RESOLVER_ACCOUNT,
 				fee_detail: Some((FEE_RECIPIENT_ACCOUNT, 0)),
+				remark: None,
+				created_at: 1,
+				expires_at: 1 + REQUEST_EXPIRY,
 			})
 		);
 
@@ -687,6 +801,7 @@ fn test_request_payment() {
 			crate::Event::<Test>::PaymentRequestCreated {
 				from: PAYMENT_CREATOR,
 				to: PAYMENT_RECIPENT,
+				payment_id: 0
 			}
 			.into()
 		);
@@ -701,12 +816,13 @@ fn test_requested_payment_cannot_be_released() {
 		assert_ok!(PaymentModule::request_payment(
 			Origin::signed(PAYMENT_RECIPENT),
 			PAYMENT_CREATOR,
+			PaymentAsset::Native,
 			payment_amount,
 		));
 
 		// requested payment cannot be released
 		assert_noop!(
-			PaymentModule::release(Origin::signed(PAYMENT_CREATOR), PAYMENT_RECIPENT),
+			PaymentModule::release(Origin::signed(PAYMENT_CREATOR), PAYMENT_RECIPENT, 0),
 			Error::InvalidAction
 		);
 	});
@@ -720,13 +836,14 @@ fn test_requested_payment_can_be_cancelled_by_requestor() {
 		assert_ok!(PaymentModule::request_payment(
 			Origin::signed(PAYMENT_RECIPENT),
 			PAYMENT_CREATOR,
+			PaymentAsset::Native,
 			payment_amount,
 		));
 
-		assert_ok!(PaymentModule::cancel(Origin::signed(PAYMENT_RECIPENT), PAYMENT_CREATOR));
+		assert_ok!(PaymentModule::cancel(Origin::signed(PAYMENT_RECIPENT), PAYMENT_CREATOR, 0));
 
 		// the request should be removed from storage
-		assert_eq!(PaymentStore::<Test>::get(PAYMENT_CREATOR, PAYMENT_RECIPENT), None);
+		assert_eq!(PaymentStore::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT, 0)), None);
 	});
 }
 
@@ -741,23 +858,29 @@ fn test_accept_and_pay() {
 		assert_ok!(PaymentModule::request_payment(
 			Origin::signed(PAYMENT_RECIPENT),
 			PAYMENT_CREATOR,
+			PaymentAsset::Native,
 			payment_amount,
 		));
 
 		assert_eq!(
-			PaymentStore::<Test>::get(PAYMENT_CREATOR, PAYMENT_RECIPENT),
+			PaymentStore::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT, 0)),
 			Some(PaymentDetail {
+				asset: PaymentAsset::Native,
 				amount: payment_amount,
 				incentive_amount: expected_incentive_amount,
 				state: PaymentState::PaymentRequested,
 				resolver_account: RESOLVER_ACCOUNT,
 				fee_detail: Some((FEE_RECIPIENT_ACCOUNT, 0)),
+				remark: None,
+				created_at: 1,
+				expires_at: 1 + REQUEST_EXPIRY,
 			})
 		);
 
 		assert_ok!(PaymentModule::accept_and_pay(
 			Origin::signed(PAYMENT_CREATOR),
 			PAYMENT_RECIPENT,
+			0
 		));
 
 		// the payment amount should be transferred
@@ -768,13 +891,14 @@ fn test_accept_and_pay() {
 		assert_eq!(Balances::reserved_balance(&PAYMENT_RECIPENT), payment_amount);
 
 		// should be deleted from storage
-		//assert_eq!(PaymentStore::<Test>::get(PAYMENT_CREATOR, PAYMENT_RECIPENT), None);
+		//assert_eq!(PaymentStore::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT, 0)), None);
 
 		assert_eq!(
 			last_event(),
 			crate::Event::<Test>::PaymentRequestCompleted {
 				from: PAYMENT_CREATOR,
 				to: PAYMENT_RECIPENT,
+				payment_id: 0
 			}
 			.into()
 		);
@@ -784,10 +908,16 @@ fn test_accept_and_pay() {
 #[test]
 fn test_accept_and_pay_should_fail_for_non_payment_requested() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(PaymentModule::pay(Origin::signed(PAYMENT_CREATOR), PAYMENT_RECIPENT, 20, None));
+		assert_ok!(PaymentModule::pay(
+			Origin::signed(PAYMENT_CREATOR),
+			PAYMENT_RECIPENT,
+			PaymentAsset::Native,
+			20,
+			None
+		));
 
 		assert_noop!(
-			PaymentModule::accept_and_pay(Origin::signed(PAYMENT_CREATOR), PAYMENT_RECIPENT,),
+			PaymentModule::accept_and_pay(Origin::signed(PAYMENT_CREATOR), PAYMENT_RECIPENT, 0),
 			Error::InvalidAction
 		);
 	});
@@ -805,23 +935,29 @@ fn test_accept_and_pay_should_charge_fee_correctly() {
 		assert_ok!(PaymentModule::request_payment(
 			Origin::signed(PAYMENT_RECIPENT_FEE_CHARGED),
 			PAYMENT_CREATOR,
+			PaymentAsset::Native,
 			payment_amount,
 		));
 
 		assert_eq!(
-			PaymentStore::<Test>::get(PAYMENT_CREATOR, PAYMENT_RECIPENT_FEE_CHARGED),
+			PaymentStore::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT_FEE_CHARGED, 0)),
 			Some(PaymentDetail {
+				asset: PaymentAsset::Native,
 				amount: payment_amount,
 				incentive_amount: expected_incentive_amount,
 				state: PaymentState::PaymentRequested,
 				resolver_account: RESOLVER_ACCOUNT,
 				fee_detail: Some((FEE_RECIPIENT_ACCOUNT, expected_fee_amount)),
+				remark: None,
+				created_at: 1,
+				expires_at: 1 + REQUEST_EXPIRY,
 			})
 		);
 
 		assert_ok!(PaymentModule::accept_and_pay(
 			Origin::signed(PAYMENT_CREATOR),
 			PAYMENT_RECIPENT_FEE_CHARGED,
+			0
 		));
 
 		// the payment amount should be transferred
@@ -837,7 +973,7 @@ fn test_accept_and_pay_should_charge_fee_correctly() {
 
 		// should be deleted from storage
 		/*assert_eq!(
-			PaymentStore::<Test>::get(PAYMENT_CREATOR, PAYMENT_RECIPENT_FEE_CHARGED),
+			PaymentStore::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT_FEE_CHARGED, 0)),
 			None
 		);*/
 
@@ -846,6 +982,7 @@ fn test_accept_and_pay_should_charge_fee_correctly() {
 			crate::Event::<Test>::PaymentRequestCompleted {
 				from: PAYMENT_CREATOR,
 				to: PAYMENT_RECIPENT_FEE_CHARGED,
+				payment_id: 0
 			}
 			.into()
 		);
@@ -870,6 +1007,7 @@ fn test_create_payment_works() {
 			<PaymentModule as PaymentHandler<Test>>::create_payment(
 				&PAYMENT_CREATOR,
 				&PAYMENT_RECIPENT,
+				PaymentAsset::Native,
 				payment_amount,
 				PaymentState::Created,
 				Percent::from_percent(INCENTIVE_PERCENTAGE),
@@ -878,39 +1016,49 @@ fn test_create_payment_works() {
 		})));
 
 		assert_eq!(
-			PaymentStore::<Test>::get(PAYMENT_CREATOR, PAYMENT_RECIPENT),
+			PaymentStore::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT, 0)),
 			Some(PaymentDetail {
+				asset: PaymentAsset::Native,
 				amount: payment_amount,
 				incentive_amount: expected_incentive_amount,
 				state: PaymentState::Created,
 				resolver_account: RESOLVER_ACCOUNT,
 				fee_detail: Some((FEE_RECIPIENT_ACCOUNT, expected_fee_amount)),
+				remark: Some(vec![1u8; 10].try_into().unwrap()),
+				created_at: 1,
+				expires_at: 1 + PAYMENT_EXPIRY,
 			})
 		);
 
-		// the payment should not be overwritten
-		assert_noop!(
-			with_transaction(|| TransactionOutcome::Commit({
+		// a second payment should get a new id and not overwrite the first one
+		let (payment_id, _) = with_transaction(|| {
+			TransactionOutcome::Commit({
 				<PaymentModule as PaymentHandler<Test>>::create_payment(
 					&PAYMENT_CREATOR,
 					&PAYMENT_RECIPENT,
+					PaymentAsset::Native,
 					payment_amount,
 					PaymentState::Created,
 					Percent::from_percent(INCENTIVE_PERCENTAGE),
 					Some(&[1u8; 10]),
 				)
-			})),
-			Error::PaymentAlreadyInProcess
-		);
+			})
+		})
+		.unwrap();
+		assert_eq!(payment_id, 1);
 
 		assert_eq!(
-			PaymentStore::<Test>::get(PAYMENT_CREATOR, PAYMENT_RECIPENT),
+			PaymentStore::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT, 0)),
 			Some(PaymentDetail {
+				asset: PaymentAsset::Native,
 				amount: payment_amount,
 				incentive_amount: expected_incentive_amount,
 				state: PaymentState::Created,
 				resolver_account: RESOLVER_ACCOUNT,
 				fee_detail: Some((FEE_RECIPIENT_ACCOUNT, expected_fee_amount)),
+				remark: Some(vec![1u8; 10].try_into().unwrap()),
+				created_at: 1,
+				expires_at: 1 + PAYMENT_EXPIRY,
 			})
 		);
 	});
@@ -934,6 +1082,7 @@ fn test_reserve_payment_amount_works() {
 			<PaymentModule as PaymentHandler<Test>>::create_payment(
 				&PAYMENT_CREATOR,
 				&PAYMENT_RECIPENT,
+				PaymentAsset::Native,
 				payment_amount,
 				PaymentState::Created,
 				Percent::from_percent(INCENTIVE_PERCENTAGE),
@@ -942,13 +1091,17 @@ fn test_reserve_payment_amount_works() {
 		})));
 
 		assert_eq!(
-			PaymentStore::<Test>::get(PAYMENT_CREATOR, PAYMENT_RECIPENT),
+			PaymentStore::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT, 0)),
 			Some(PaymentDetail {
+				asset: PaymentAsset::Native,
 				amount: payment_amount,
 				incentive_amount: expected_incentive_amount,
 				state: PaymentState::Created,
 				resolver_account: RESOLVER_ACCOUNT,
 				fee_detail: Some((FEE_RECIPIENT_ACCOUNT, expected_fee_amount)),
+				remark: Some(vec![1u8; 10].try_into().unwrap()),
+				created_at: 1,
+				expires_at: 1 + PAYMENT_EXPIRY,
 			})
 		);
 
@@ -956,7 +1109,7 @@ fn test_reserve_payment_amount_works() {
 			<PaymentModule as PaymentHandler<Test>>::reserve_payment_amount(
 				&PAYMENT_CREATOR,
 				&PAYMENT_RECIPENT,
-				PaymentStore::<Test>::get(PAYMENT_CREATOR, PAYMENT_RECIPENT).unwrap(),
+				PaymentStore::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT, 0)).unwrap(),
 			)
 		})));
 		// the payment amount should be reserved correctly
@@ -979,29 +1132,35 @@ fn test_reserve_payment_amount_works() {
 			payment_amount.saturating_add(Balances::free_balance(&PAYMENT_RECIPENT))
 		);
 
-		// the payment should not be overwritten
-		assert_noop!(
-			with_transaction(|| TransactionOutcome::Commit({
+		// a second payment should get a new id and not overwrite the first one
+		let (payment_id, _) = with_transaction(|| {
+			TransactionOutcome::Commit({
 				<PaymentModule as PaymentHandler<Test>>::create_payment(
 					&PAYMENT_CREATOR,
 					&PAYMENT_RECIPENT,
+					PaymentAsset::Native,
 					payment_amount,
 					PaymentState::Created,
 					Percent::from_percent(INCENTIVE_PERCENTAGE),
 					Some(&[1u8; 10]),
 				)
-			})),
-			Error::PaymentAlreadyInProcess
-		);
+			})
+		})
+		.unwrap();
+		assert_eq!(payment_id, 1);
 
 		assert_eq!(
-			PaymentStore::<Test>::get(PAYMENT_CREATOR, PAYMENT_RECIPENT),
+			PaymentStore::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT, 0)),
 			Some(PaymentDetail {
+				asset: PaymentAsset::Native,
 				amount: payment_amount,
 				incentive_amount: expected_incentive_amount,
 				state: PaymentState::Created,
 				resolver_account: RESOLVER_ACCOUNT,
 				fee_detail: Some((FEE_RECIPIENT_ACCOUNT, expected_fee_amount)),
+				remark: Some(vec![1u8; 10].try_into().unwrap()),
+				created_at: 1,
+				expires_at: 1 + PAYMENT_EXPIRY,
 			})
 		);
 	});
@@ -1023,6 +1182,7 @@ fn test_settle_payment_works_for_cancel() {
 		assert_ok!(PaymentModule::pay(
 			Origin::signed(PAYMENT_CREATOR),
 			PAYMENT_RECIPENT,
+			PaymentAsset::Native,
 			payment_amount,
 			None
 		));
@@ -1031,7 +1191,9 @@ fn test_settle_payment_works_for_cancel() {
 			<PaymentModule as PaymentHandler<Test>>::settle_payment(
 				&PAYMENT_CREATOR,
 				&PAYMENT_RECIPENT,
+				0,
 				Percent::from_percent(0),
+				PaymentOutcome::Cancelled,
 			)
 		})));
 
@@ -1040,7 +1202,7 @@ fn test_settle_payment_works_for_cancel() {
 		assert_eq!(Balances::free_balance(&PAYMENT_RECIPENT), recipient_initial_balance);
 
 		// should be released from storage
-		assert_eq!(PaymentStore::<Test>::get(PAYMENT_CREATOR, PAYMENT_RECIPENT), None);
+		assert_eq!(PaymentStore::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT, 0)), None);
 	});
 }
 
@@ -1060,6 +1222,7 @@ fn test_settle_payment_works_for_release() {
 		assert_ok!(PaymentModule::pay(
 			Origin::signed(PAYMENT_CREATOR),
 			PAYMENT_RECIPENT,
+			PaymentAsset::Native,
 			payment_amount,
 			None
 		));
@@ -1068,7 +1231,9 @@ fn test_settle_payment_works_for_release() {
 			<PaymentModule as PaymentHandler<Test>>::settle_payment(
 				&PAYMENT_CREATOR,
 				&PAYMENT_RECIPENT,
+				0,
 				Percent::from_percent(100),
+				PaymentOutcome::Completed,
 			)
 		})));
 
@@ -1083,7 +1248,7 @@ fn test_settle_payment_works_for_release() {
 		);
 
 		// should be deleted from storage
-		assert_eq!(PaymentStore::<Test>::get(PAYMENT_CREATOR, PAYMENT_RECIPENT), None);
+		assert_eq!(PaymentStore::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT, 0)), None);
 	});
 }
 
@@ -1104,6 +1269,7 @@ fn test_settle_payment_works_for_70_30() {
 		assert_ok!(PaymentModule::pay(
 			Origin::signed(PAYMENT_CREATOR),
 			PAYMENT_RECIPENT_FEE_CHARGED,
+			PaymentAsset::Native,
 			payment_amount,
 			None
 		));
@@ -1112,7 +1278,9 @@ fn test_settle_payment_works_for_70_30() {
 			<PaymentModule as PaymentHandler<Test>>::settle_payment(
 				&PAYMENT_CREATOR,
 				&PAYMENT_RECIPENT_FEE_CHARGED,
+				0,
 				Percent::from_percent(70),
+				PaymentOutcome::Resolved { recipient_share: Percent::from_percent(70) },
 			)
 		})));
 
@@ -1133,7 +1301,10 @@ fn test_settle_payment_works_for_70_30() {
 		);
 
 		// should be deleted from storage
-		assert_eq!(PaymentStore::<Test>::get(PAYMENT_CREATOR, PAYMENT_RECIPENT_FEE_CHARGED), None);
+		assert_eq!(
+			PaymentStore::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT_FEE_CHARGED, 0)),
+			None
+		);
 	});
 }
 
@@ -1154,6 +1325,7 @@ fn test_settle_payment_works_for_50_50() {
 		assert_ok!(PaymentModule::pay(
 			Origin::signed(PAYMENT_CREATOR),
 			PAYMENT_RECIPENT_FEE_CHARGED,
+			PaymentAsset::Native,
 			payment_amount,
 			None
 		));
@@ -1162,7 +1334,9 @@ fn test_settle_payment_works_for_50_50() {
 			<PaymentModule as PaymentHandler<Test>>::settle_payment(
 				&PAYMENT_CREATOR,
 				&PAYMENT_RECIPENT_FEE_CHARGED,
+				0,
 				Percent::from_percent(50),
+				PaymentOutcome::Resolved { recipient_share: Percent::from_percent(50) },
 			)
 		})));
 
@@ -1183,7 +1357,10 @@ fn test_settle_payment_works_for_50_50() {
 		);
 
 		// should be deleted from storage
-		assert_eq!(PaymentStore::<Test>::get(PAYMENT_CREATOR, PAYMENT_RECIPENT_FEE_CHARGED), None);
+		assert_eq!(
+			PaymentStore::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT_FEE_CHARGED, 0)),
+			None
+		);
 	});
 }
 
@@ -1199,59 +1376,66 @@ fn test_automatic_refund_works() {
 		assert_ok!(PaymentModule::pay(
 			Origin::signed(PAYMENT_CREATOR),
 			PAYMENT_RECIPENT,
+			PaymentAsset::Native,
 			payment_amount,
 			None
 		));
 
 		assert_ok!(PaymentModule::request_refund(
 			Origin::signed(PAYMENT_CREATOR),
-			PAYMENT_RECIPENT
+			PAYMENT_RECIPENT,
+			0
 		));
 
 		assert_eq!(
-			PaymentStore::<Test>::get(PAYMENT_CREATOR, PAYMENT_RECIPENT),
+			PaymentStore::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT, 0)),
 			Some(PaymentDetail {
+				asset: PaymentAsset::Native,
 				amount: payment_amount,
 				incentive_amount: expected_incentive_amount,
 				state: PaymentState::RefundRequested { cancel_block: CANCEL_BLOCK },
 				resolver_account: RESOLVER_ACCOUNT,
 				fee_detail: Some((FEE_RECIPIENT_ACCOUNT, 0)),
+				remark: None,
+				created_at: 1,
+				expires_at: 1 + PAYMENT_EXPIRY,
 			})
 		);
 
-		let scheduled_tasks_list = ScheduledTasks::<Test>::get();
 		assert_eq!(
-			scheduled_tasks_list.get(&(PAYMENT_CREATOR, PAYMENT_RECIPENT)).unwrap(),
-			&ScheduledTask { task: Task::Cancel, when: CANCEL_BLOCK }
+			ScheduledTasks::<Test>::get(&(PAYMENT_CREATOR, PAYMENT_RECIPENT, 0)).unwrap(),
+			ScheduledTask { task: Task::Cancel, when: CANCEL_BLOCK }
 		);
 
 		// run to one block before cancel and make sure data is same
 		assert_eq!(run_n_blocks(CANCEL_PERIOD - 1), 600);
-		let scheduled_tasks_list = ScheduledTasks::<Test>::get();
 		assert_eq!(
-			scheduled_tasks_list.get(&(PAYMENT_CREATOR, PAYMENT_RECIPENT)).unwrap(),
-			&ScheduledTask { task: Task::Cancel, when: CANCEL_BLOCK }
+			ScheduledTasks::<Test>::get(&(PAYMENT_CREATOR, PAYMENT_RECIPENT, 0)).unwrap(),
+			ScheduledTask { task: Task::Cancel, when: CANCEL_BLOCK }
 		);
 
 		// run to after cancel block but odd blocks are busy
 		assert_eq!(run_n_blocks(1), 601);
 		// the payment is still not processed since the block was busy
-		assert!(PaymentStore::<Test>::get(PAYMENT_CREATOR, PAYMENT_RECIPENT).is_some());
+		assert!(PaymentStore::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT, 0)).is_some());
 
 		// next block has spare weight to process the payment
 		assert_eq!(run_n_blocks(1), 602);
 		// the payment should be removed from storage
-		assert_eq!(PaymentStore::<Test>::get(PAYMENT_CREATOR, PAYMENT_RECIPENT), None);
+		assert_eq!(PaymentStore::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT, 0)), None);
 
 		// the scheduled storage should be cleared
-		let scheduled_tasks_list = ScheduledTasks::<Test>::get();
-		assert_eq!(scheduled_tasks_list.get(&(PAYMENT_CREATOR, PAYMENT_RECIPENT)), None);
+		assert_eq!(ScheduledTasks::<Test>::get(&(PAYMENT_CREATOR, PAYMENT_RECIPENT, 0)), None);
 
 		// test that the refund happened correctly
 		assert_eq!(
 			last_event(),
-			crate::Event::<Test>::PaymentCancelled { from: PAYMENT_CREATOR, to: PAYMENT_RECIPENT }
-				.into()
+			crate::Event::<Test>::PaymentCancelled {
+				from: PAYMENT_CREATOR,
+				to: PAYMENT_RECIPENT,
+				payment_id: 0
+			}
+			.into()
 		);
 		// the payment amount should be released back to creator
 		assert_eq!(Balances::free_balance(&PAYMENT_CREATOR), creator_initial_balance);
@@ -1264,39 +1448,50 @@ fn test_automatic_refund_works_for_multiple_payments() {
 	new_test_ext().execute_with(|| {
 		const CANCEL_PERIOD: u64 = 600;
 
-		assert_ok!(PaymentModule::pay(Origin::signed(PAYMENT_CREATOR), PAYMENT_RECIPENT, 20, None));
+		assert_ok!(PaymentModule::pay(
+			Origin::signed(PAYMENT_CREATOR),
+			PAYMENT_RECIPENT,
+			PaymentAsset::Native,
+			20,
+			None
+		));
 
 		assert_ok!(PaymentModule::pay(
 			Origin::signed(PAYMENT_CREATOR_TWO),
 			PAYMENT_RECIPENT_TWO,
+			PaymentAsset::Native,
 			20,
 			None
 		));
 
 		assert_ok!(PaymentModule::request_refund(
 			Origin::signed(PAYMENT_CREATOR),
-			PAYMENT_RECIPENT
+			PAYMENT_RECIPENT,
+			0
 		));
 		run_n_blocks(1);
 		assert_ok!(PaymentModule::request_refund(
 			Origin::signed(PAYMENT_CREATOR_TWO),
-			PAYMENT_RECIPENT_TWO
+			PAYMENT_RECIPENT_TWO,
+			1
 		));
 
 		assert_eq!(run_n_blocks(CANCEL_PERIOD - 1), 601);
 
 		// Odd block 601 was busy so we still haven't processed the first payment
-		assert_ok!(PaymentStore::<Test>::get(PAYMENT_CREATOR, PAYMENT_RECIPENT).ok_or(()));
+		assert_ok!(PaymentStore::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT, 0)).ok_or(()));
 
 		// Even block 602 has enough room to process both pending payments
 		assert_eq!(run_n_blocks(1), 602);
-		assert_eq!(PaymentStore::<Test>::get(PAYMENT_CREATOR, PAYMENT_RECIPENT), None);
-		assert_eq!(PaymentStore::<Test>::get(PAYMENT_CREATOR_TWO, PAYMENT_RECIPENT_TWO), None);
+		assert_eq!(PaymentStore::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT, 0)), None);
+		assert_eq!(PaymentStore::<Test>::get((PAYMENT_CREATOR_TWO, PAYMENT_RECIPENT_TWO, 1)), None);
 
 		// the scheduled storage should be cleared
-		let scheduled_tasks_list = ScheduledTasks::<Test>::get();
-		assert_eq!(scheduled_tasks_list.get(&(PAYMENT_CREATOR, PAYMENT_RECIPENT)), None);
-		assert_eq!(scheduled_tasks_list.get(&(PAYMENT_CREATOR_TWO, PAYMENT_RECIPENT_TWO)), None);
+		assert_eq!(ScheduledTasks::<Test>::get(&(PAYMENT_CREATOR, PAYMENT_RECIPENT, 0)), None);
+		assert_eq!(
+			ScheduledTasks::<Test>::get(&(PAYMENT_CREATOR_TWO, PAYMENT_RECIPENT_TWO, 1)),
+			None
+		);
 
 		// test that the refund happened correctly
 		assert_eq!(Balances::free_balance(&PAYMENT_CREATOR), 100_000_000_000);
@@ -1306,3 +1501,1314 @@ fn test_automatic_refund_works_for_multiple_payments() {
 		assert_eq!(Balances::free_balance(&PAYMENT_RECIPENT_TWO), 1);
 	});
 }
+
+#[test]
+fn test_settled_payments_are_stored_in_history() {
+	new_test_ext().execute_with(|| {
+		let payment_amount = 40;
+		let expected_fee_amount = payment_amount / MARKETPLACE_FEE_PERCENTAGE as u64;
+
+		assert_ok!(PaymentModule::pay(
+			Origin::signed(PAYMENT_CREATOR),
+			PAYMENT_RECIPENT_FEE_CHARGED,
+			PaymentAsset::Native,
+			payment_amount,
+			Some(vec![1u8; 10].try_into().unwrap())
+		));
+		// the history is only filled once the payment is settled
+		assert!(PaymentModule::history(PAYMENT_CREATOR).is_empty());
+
+		run_n_blocks(2);
+		assert_ok!(PaymentModule::release(
+			Origin::signed(PAYMENT_CREATOR),
+			PAYMENT_RECIPENT_FEE_CHARGED,
+			0
+		));
+
+		let expected_receipt = PaymentReceipt {
+			payment_id: 0,
+			from: PAYMENT_CREATOR,
+			to: PAYMENT_RECIPENT_FEE_CHARGED,
+			asset: PaymentAsset::Native,
+			amount: payment_amount,
+			recipient_amount: payment_amount,
+			fee_amount: expected_fee_amount,
+			outcome: PaymentOutcome::Completed,
+			remark: Some(vec![1u8; 10].try_into().unwrap()),
+			created_at: 1,
+			settled_at: 3,
+		};
+		// the receipt is kept for both parties of the payment
+		assert_eq!(
+			PaymentModule::history(PAYMENT_CREATOR).into_inner(),
+			vec![expected_receipt.clone()]
+		);
+		assert_eq!(
+			PaymentModule::history(PAYMENT_RECIPENT_FEE_CHARGED).into_inner(),
+			vec![expected_receipt]
+		);
+		// the fee recipient is not a party of the payment
+		assert!(PaymentModule::history(FEE_RECIPIENT_ACCOUNT).is_empty());
+
+		// a cancelled payment is stored without fee and recipient share
+		assert_ok!(PaymentModule::pay(
+			Origin::signed(PAYMENT_CREATOR),
+			PAYMENT_RECIPENT,
+			PaymentAsset::Native,
+			payment_amount,
+			None
+		));
+		assert_ok!(PaymentModule::cancel(Origin::signed(PAYMENT_RECIPENT), PAYMENT_CREATOR, 1));
+
+		let history = PaymentModule::history(PAYMENT_CREATOR);
+		assert_eq!(history.len(), 2);
+		assert_eq!(history[1].payment_id, 1);
+		assert_eq!(history[1].outcome, PaymentOutcome::Cancelled);
+		assert_eq!(history[1].recipient_amount, 0);
+		assert_eq!(history[1].fee_amount, 0);
+	});
+}
+
+#[test]
+fn test_resolved_payment_receipt_keeps_recipient_share() {
+	new_test_ext().execute_with(|| {
+		let payment_amount = 40;
+
+		assert_ok!(PaymentModule::pay(
+			Origin::signed(PAYMENT_CREATOR),
+			PAYMENT_RECIPENT,
+			PaymentAsset::Native,
+			payment_amount,
+			None
+		));
+		assert_ok!(PaymentModule::resolve_payment(
+			Origin::signed(RESOLVER_ACCOUNT),
+			PAYMENT_CREATOR,
+			PAYMENT_RECIPENT,
+			0,
+			Percent::from_percent(25)
+		));
+
+		let receipt = PaymentModule::history(PAYMENT_RECIPENT)[0].clone();
+		assert_eq!(
+			receipt.outcome,
+			PaymentOutcome::Resolved { recipient_share: Percent::from_percent(25) }
+		);
+		assert_eq!(receipt.recipient_amount, Percent::from_percent(25) * payment_amount);
+	});
+}
+
+#[test]
+fn test_payment_history_drops_oldest_receipt_when_full() {
+	new_test_ext().execute_with(|| {
+		let max_history = <Test as crate::Config>::MaxPaymentHistory::get();
+
+		for payment_id in 0..(max_history as u64 + 2) {
+			assert_ok!(PaymentModule::pay(
+				Origin::signed(PAYMENT_CREATOR),
+				PAYMENT_RECIPENT,
+				PaymentAsset::Native,
+				10,
+				None
+			));
+			assert_ok!(PaymentModule::release(
+				Origin::signed(PAYMENT_CREATOR),
+				PAYMENT_RECIPENT,
+				payment_id
+			));
+		}
+
+		let history = PaymentHistory::<Test>::get(PAYMENT_CREATOR);
+		assert_eq!(history.len() as u32, max_history);
+		// the two oldest receipts were dropped
+		assert_eq!(history.first().unwrap().payment_id, 2);
+		assert_eq!(history.last().unwrap().payment_id, max_history as u64 + 1);
+	});
+}
+
+#[test]
+fn test_payments_by_period_works() {
+	new_test_ext().execute_with(|| {
+		// settle one payment at blocks 1, 11 and 21
+		for payment_id in 0..3 {
+			assert_ok!(PaymentModule::pay(
+				Origin::signed(PAYMENT_CREATOR),
+				PAYMENT_RECIPENT,
+				PaymentAsset::Native,
+				10,
+				None
+			));
+			assert_ok!(PaymentModule::release(
+				Origin::signed(PAYMENT_CREATOR),
+				PAYMENT_RECIPENT,
+				payment_id
+			));
+			run_n_blocks(10);
+		}
+
+		let payment_ids = |start, end| {
+			PaymentModule::payments_by_period(&PAYMENT_CREATOR, start, end)
+				.into_iter()
+				.map(|receipt| receipt.payment_id)
+				.collect::<Vec<_>>()
+		};
+
+		assert_eq!(payment_ids(1, 21), vec![0, 1, 2]);
+		assert_eq!(payment_ids(2, 21), vec![1, 2]);
+		assert_eq!(payment_ids(11, 11), vec![1]);
+		assert_eq!(payment_ids(22, 100), Vec::<u64>::new());
+		// the recipient sees the same payments
+		assert_eq!(
+			PaymentModule::payments_by_period(&PAYMENT_RECIPENT, 1, 21).len(),
+			payment_ids(1, 21).len()
+		);
+	});
+}
+
+#[test]
+fn test_submit_evidence_works() {
+	new_test_ext().execute_with(|| {
+		let remark: crate::BoundedDataOf<Test> = vec![1u8; 10].try_into().unwrap();
+
+		assert_ok!(PaymentModule::pay(
+			Origin::signed(PAYMENT_CREATOR),
+			PAYMENT_RECIPENT,
+			PaymentAsset::Native,
+			20,
+			None
+		));
+		// no evidence can be submitted before the payment is disputed
+		assert_noop!(
+			PaymentModule::submit_evidence(
+				Origin::signed(PAYMENT_CREATOR),
+				PAYMENT_CREATOR,
+				PAYMENT_RECIPENT,
+				0,
+				remark.clone()
+			),
+			Error::NoDispute
+		);
+
+		assert_ok!(PaymentModule::request_refund(
+			Origin::signed(PAYMENT_CREATOR),
+			PAYMENT_RECIPENT,
+			0
+		));
+		assert_ok!(PaymentModule::dispute_refund(
+			Origin::signed(PAYMENT_RECIPENT),
+			PAYMENT_CREATOR,
+			0
+		));
+
+		// both parties can submit evidences
+		for who in [PAYMENT_CREATOR, PAYMENT_RECIPENT, PAYMENT_RECIPENT] {
+			assert_ok!(PaymentModule::submit_evidence(
+				Origin::signed(who.clone()),
+				PAYMENT_CREATOR,
+				PAYMENT_RECIPENT,
+				0,
+				remark.clone()
+			));
+		}
+		assert_eq!(
+			last_event(),
+			crate::Event::<Test>::DisputeEvidenceSubmitted {
+				from: PAYMENT_CREATOR,
+				to: PAYMENT_RECIPENT,
+				payment_id: 0,
+				who: PAYMENT_RECIPENT
+			}
+			.into()
+		);
+		let evidences =
+			Disputes::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT, 0)).unwrap().evidences;
+		assert_eq!(
+			evidences[0],
+			Evidence { who: PAYMENT_CREATOR, remark: remark.clone(), submitted_at: 1 }
+		);
+		assert_eq!(evidences.len(), 3);
+
+		// the number of evidences is bounded
+		assert_noop!(
+			PaymentModule::submit_evidence(
+				Origin::signed(PAYMENT_CREATOR),
+				PAYMENT_CREATOR,
+				PAYMENT_RECIPENT,
+				0,
+				remark.clone()
+			),
+			Error::TooManyEvidences
+		);
+		// only the parties of the payment can submit evidences
+		assert_noop!(
+			PaymentModule::submit_evidence(
+				Origin::signed(RESOLVER_ACCOUNT),
+				PAYMENT_CREATOR,
+				PAYMENT_RECIPENT,
+				0,
+				remark
+			),
+			Error::NotPaymentParty
+		);
+	});
+}
+
+#[test]
+fn test_submit_evidence_fails_after_deadline() {
+	new_test_ext().execute_with(|| {
+		create_disputed_payment(20);
+
+		System::set_block_number(DisputeResolutionPeriod::get() + 2);
+		assert_noop!(
+			PaymentModule::submit_evidence(
+				Origin::signed(PAYMENT_CREATOR),
+				PAYMENT_CREATOR,
+				PAYMENT_RECIPENT,
+				0,
+				vec![1u8; 10].try_into().unwrap()
+			),
+			Error::DisputeDeadlinePassed
+		);
+	});
+}
+
+#[test]
+fn test_dispute_default_outcome_applies_without_resolver_decision() {
+	new_test_ext().execute_with(|| {
+		let creator_initial_balance = 100_000_000_000;
+		let deadline = DisputeResolutionPeriod::get() + 1;
+		create_disputed_payment(20);
+
+		// the deadline block is busy, the dispute is processed in the next block
+		assert_eq!(run_n_blocks(deadline), deadline + 1);
+
+		assert_eq!(PaymentStore::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT, 0)), None);
+		assert_eq!(Disputes::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT, 0)), None);
+		assert_eq!(ScheduledTasks::<Test>::get(&(PAYMENT_CREATOR, PAYMENT_RECIPENT, 0)), None);
+		assert_eq!(
+			last_event(),
+			crate::Event::<Test>::DisputeDeadlinePassed {
+				from: PAYMENT_CREATOR,
+				to: PAYMENT_RECIPENT,
+				payment_id: 0,
+				recipient_share: DefaultRecipientShare::get()
+			}
+			.into()
+		);
+
+		// the default share of the mock refunds the creator
+		assert_eq!(Balances::free_balance(&PAYMENT_CREATOR), creator_initial_balance);
+		assert_eq!(Balances::free_balance(&PAYMENT_RECIPENT), 1);
+		assert_eq!(
+			PaymentModule::history(PAYMENT_CREATOR)[0].outcome,
+			PaymentOutcome::Resolved { recipient_share: DefaultRecipientShare::get() }
+		);
+	});
+}
+
+#[test]
+fn test_resolver_decision_applies_after_appeal_period() {
+	new_test_ext().execute_with(|| {
+		let creator_initial_balance = 100_000_000_000;
+		let payment_amount = 20;
+		let appeal_deadline = AppealPeriod::get() + 1;
+		create_disputed_payment(payment_amount);
+
+		// only the resolver of the payment can decide
+		assert_noop!(
+			PaymentModule::resolve_payment(
+				Origin::signed(PAYMENT_CREATOR),
+				PAYMENT_CREATOR,
+				PAYMENT_RECIPENT,
+				0,
+				Percent::from_percent(70)
+			),
+			Error::InvalidAction
+		);
+		assert_ok!(PaymentModule::resolve_payment(
+			Origin::signed(RESOLVER_ACCOUNT),
+			PAYMENT_CREATOR,
+			PAYMENT_RECIPENT,
+			0,
+			Percent::from_percent(70)
+		));
+		assert_eq!(
+			last_event(),
+			crate::Event::<Test>::DisputeResolutionProposed {
+				from: PAYMENT_CREATOR,
+				to: PAYMENT_RECIPENT,
+				payment_id: 0,
+				recipient_share: Percent::from_percent(70),
+				appeal_deadline
+			}
+			.into()
+		);
+		// the decision is not applied before the end of the appeal period
+		assert!(PaymentStore::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT, 0)).is_some());
+		assert_eq!(
+			ScheduledTasks::<Test>::get(&(PAYMENT_CREATOR, PAYMENT_RECIPENT, 0)).unwrap(),
+			ScheduledTask { task: Task::DisputeDeadline, when: appeal_deadline }
+		);
+		// the resolver cannot change its decision
+		assert_noop!(
+			PaymentModule::resolve_payment(
+				Origin::signed(RESOLVER_ACCOUNT),
+				PAYMENT_CREATOR,
+				PAYMENT_RECIPENT,
+				0,
+				Percent::from_percent(10)
+			),
+			Error::InvalidAction
+		);
+
+		assert_eq!(run_n_blocks(appeal_deadline), appeal_deadline + 1);
+
+		assert_eq!(PaymentStore::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT, 0)), None);
+		assert_eq!(Disputes::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT, 0)), None);
+		assert_eq!(
+			last_event(),
+			crate::Event::<Test>::DisputeDeadlinePassed {
+				from: PAYMENT_CREATOR,
+				to: PAYMENT_RECIPENT,
+				payment_id: 0,
+				recipient_share: Percent::from_percent(70)
+			}
+			.into()
+		);
+		let recipient_amount = Percent::from_percent(70) * payment_amount;
+		assert_eq!(
+			Balances::free_balance(&PAYMENT_CREATOR),
+			creator_initial_balance - recipient_amount
+		);
+		assert_eq!(Balances::free_balance(&PAYMENT_RECIPENT), 1 + recipient_amount);
+	});
+}
+
+#[test]
+fn test_appealed_dispute_is_resolved_by_appeal_origin() {
+	new_test_ext().execute_with(|| {
+		let creator_initial_balance = 100_000_000_000;
+		let payment_amount = 20;
+		create_disputed_payment(payment_amount);
+
+		// a dispute cannot be appealed before the decision of the resolver
+		assert_noop!(
+			PaymentModule::appeal(
+				Origin::signed(PAYMENT_CREATOR),
+				PAYMENT_CREATOR,
+				PAYMENT_RECIPENT,
+				0
+			),
+			Error::InvalidAction
+		);
+		assert_ok!(PaymentModule::resolve_payment(
+			Origin::signed(RESOLVER_ACCOUNT),
+			PAYMENT_CREATOR,
+			PAYMENT_RECIPENT,
+			0,
+			Percent::from_percent(70)
+		));
+
+		// only the parties of the payment can appeal
+		assert_noop!(
+			PaymentModule::appeal(
+				Origin::signed(RESOLVER_ACCOUNT),
+				PAYMENT_CREATOR,
+				PAYMENT_RECIPENT,
+				0
+			),
+			Error::NotPaymentParty
+		);
+		assert_ok!(PaymentModule::appeal(
+			Origin::signed(PAYMENT_CREATOR),
+			PAYMENT_CREATOR,
+			PAYMENT_RECIPENT,
+			0
+		));
+		let deadline = AppealResolutionPeriod::get() + 1;
+		assert_eq!(
+			last_event(),
+			crate::Event::<Test>::DisputeAppealed {
+				from: PAYMENT_CREATOR,
+				to: PAYMENT_RECIPENT,
+				payment_id: 0,
+				appellant: PAYMENT_CREATOR,
+				deadline
+			}
+			.into()
+		);
+		assert_eq!(
+			Disputes::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT, 0)).unwrap().stage,
+			DisputeStage::Appealed {
+				appellant: PAYMENT_CREATOR,
+				recipient_share: Percent::from_percent(70)
+			}
+		);
+		// the decision can only be appealed once
+		assert_noop!(
+			PaymentModule::appeal(
+				Origin::signed(PAYMENT_RECIPENT),
+				PAYMENT_CREATOR,
+				PAYMENT_RECIPENT,
+				0
+			),
+			Error::InvalidAction
+		);
+
+		// only the appeal origin can decide on an appealed dispute
+		assert_noop!(
+			PaymentModule::resolve_appeal(
+				Origin::signed(RESOLVER_ACCOUNT),
+				PAYMENT_CREATOR,
+				PAYMENT_RECIPENT,
+				0,
+				Percent::from_percent(20)
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(PaymentModule::resolve_appeal(
+			Origin::root(),
+			PAYMENT_CREATOR,
+			PAYMENT_RECIPENT,
+			0,
+			Percent::from_percent(20)
+		));
+		assert_eq!(
+			last_event(),
+			crate::Event::<Test>::PaymentResolved {
+				from: PAYMENT_CREATOR,
+				to: PAYMENT_RECIPENT,
+				payment_id: 0,
+				recipient_share: Percent::from_percent(20)
+			}
+			.into()
+		);
+
+		assert_eq!(PaymentStore::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT, 0)), None);
+		assert_eq!(Disputes::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT, 0)), None);
+		assert_eq!(ScheduledTasks::<Test>::get(&(PAYMENT_CREATOR, PAYMENT_RECIPENT, 0)), None);
+		let recipient_amount = Percent::from_percent(20) * payment_amount;
+		assert_eq!(
+			Balances::free_balance(&PAYMENT_CREATOR),
+			creator_initial_balance - recipient_amount
+		);
+		assert_eq!(Balances::free_balance(&PAYMENT_RECIPENT), 1 + recipient_amount);
+	});
+}
+
+#[test]
+fn test_appealed_dispute_keeps_resolver_decision_after_deadline() {
+	new_test_ext().execute_with(|| {
+		let payment_amount = 20;
+		create_disputed_payment(payment_amount);
+
+		assert_ok!(PaymentModule::resolve_payment(
+			Origin::signed(RESOLVER_ACCOUNT),
+			PAYMENT_CREATOR,
+			PAYMENT_RECIPENT,
+			0,
+			Percent::from_percent(70)
+		));
+		assert_ok!(PaymentModule::appeal(
+			Origin::signed(PAYMENT_CREATOR),
+			PAYMENT_CREATOR,
+			PAYMENT_RECIPENT,
+			0
+		));
+
+		// the appeal origin did not decide in time
+		let deadline = AppealResolutionPeriod::get() + 1;
+		assert_eq!(run_n_blocks(deadline), deadline + 1);
+
+		assert_eq!(PaymentStore::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT, 0)), None);
+		assert_eq!(
+			last_event(),
+			crate::Event::<Test>::DisputeDeadlinePassed {
+				from: PAYMENT_CREATOR,
+				to: PAYMENT_RECIPENT,
+				payment_id: 0,
+				recipient_share: Percent::from_percent(70)
+			}
+			.into()
+		);
+		assert_eq!(
+			Balances::free_balance(&PAYMENT_RECIPENT),
+			1 + Percent::from_percent(70) * payment_amount
+		);
+		// the appeal origin cannot decide once the dispute is settled
+		assert_noop!(
+			PaymentModule::resolve_appeal(
+				Origin::root(),
+				PAYMENT_CREATOR,
+				PAYMENT_RECIPENT,
+				0,
+				Percent::from_percent(20)
+			),
+			Error::NoDispute
+		);
+	});
+}
+
+#[test]
+fn test_appeal_fails_after_appeal_period() {
+	new_test_ext().execute_with(|| {
+		create_disputed_payment(20);
+
+		assert_ok!(PaymentModule::resolve_payment(
+			Origin::signed(RESOLVER_ACCOUNT),
+			PAYMENT_CREATOR,
+			PAYMENT_RECIPENT,
+			0,
+			Percent::from_percent(70)
+		));
+
+		System::set_block_number(AppealPeriod::get() + 2);
+		assert_noop!(
+			PaymentModule::appeal(
+				Origin::signed(PAYMENT_RECIPENT),
+				PAYMENT_CREATOR,
+				PAYMENT_RECIPENT,
+				0
+			),
+			Error::DisputeDeadlinePassed
+		);
+		// the decision of the resolver cannot be overruled without an appeal
+		assert_noop!(
+			PaymentModule::resolve_appeal(
+				Origin::root(),
+				PAYMENT_CREATOR,
+				PAYMENT_RECIPENT,
+				0,
+				Percent::from_percent(20)
+			),
+			Error::InvalidAction
+		);
+	});
+}
+
+#[test]
+fn test_pay_in_fungible_asset_works() {
+	new_test_ext().execute_with(|| {
+		let asset = PaymentAsset::Fungible(STABLECOIN);
+		let payment_amount = 2000_u64;
+		let expected_incentive_amount = payment_amount / INCENTIVE_PERCENTAGE as u64;
+
+		assert_ok!(PaymentModule::pay(
+			Origin::signed(PAYMENT_CREATOR),
+			PAYMENT_RECIPENT,
+			asset,
+			payment_amount,
+			None
+		));
+		assert_eq!(
+			last_event(),
+			crate::Event::<Test>::PaymentCreated {
+				from: PAYMENT_CREATOR,
+				to: PAYMENT_RECIPENT,
+				payment_id: 0,
+				asset,
+				amount: payment_amount,
+				remark: None
+			}
+			.into()
+		);
+		assert_eq!(
+			PaymentStore::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT, 0)).unwrap().asset,
+			asset
+		);
+
+		// the amount and the incentive are held by the pallet account
+		assert_eq!(
+			Assets::balance(STABLECOIN, PAYMENT_CREATOR),
+			100_000 - payment_amount - expected_incentive_amount
+		);
+		assert_eq!(
+			Assets::balance(STABLECOIN, PaymentModule::account_id()),
+			payment_amount + expected_incentive_amount
+		);
+		assert_eq!(Assets::balance(STABLECOIN, PAYMENT_RECIPENT), 1);
+		// the native balances are untouched
+		assert_eq!(Balances::free_balance(&PAYMENT_CREATOR), 100_000_000_000);
+		assert_eq!(Balances::reserved_balance(&PAYMENT_RECIPENT), 0);
+
+		assert_ok!(PaymentModule::release(Origin::signed(PAYMENT_CREATOR), PAYMENT_RECIPENT, 0));
+
+		// the recipient is paid and the incentive is returned to the creator
+		assert_eq!(Assets::balance(STABLECOIN, PAYMENT_CREATOR), 100_000 - payment_amount);
+		assert_eq!(Assets::balance(STABLECOIN, PAYMENT_RECIPENT), payment_amount + 1);
+		assert_eq!(Assets::balance(STABLECOIN, PaymentModule::account_id()), 0);
+		assert_eq!(PaymentModule::history(PAYMENT_RECIPENT)[0].asset, asset);
+	});
+}
+
+#[test]
+fn test_cancel_fungible_asset_payment_refunds_creator() {
+	new_test_ext().execute_with(|| {
+		let asset = PaymentAsset::Fungible(STABLECOIN);
+
+		assert_ok!(PaymentModule::pay(
+			Origin::signed(PAYMENT_CREATOR),
+			PAYMENT_RECIPENT_FEE_CHARGED,
+			asset,
+			2000,
+			None
+		));
+		// amount + incentive + fee
+		assert_eq!(Assets::balance(STABLECOIN, PaymentModule::account_id()), 2400);
+
+		assert_ok!(PaymentModule::cancel(
+			Origin::signed(PAYMENT_RECIPENT_FEE_CHARGED),
+			PAYMENT_CREATOR,
+			0
+		));
+
+		assert_eq!(Assets::balance(STABLECOIN, PAYMENT_CREATOR), 100_000);
+		assert_eq!(Assets::balance(STABLECOIN, PAYMENT_RECIPENT_FEE_CHARGED), 1);
+		assert_eq!(Assets::balance(STABLECOIN, FEE_RECIPIENT_ACCOUNT), 1);
+		assert_eq!(Assets::balance(STABLECOIN, PaymentModule::account_id()), 0);
+	});
+}
+
+#[test]
+fn test_fungible_asset_payment_charges_fee_in_asset() {
+	new_test_ext().execute_with(|| {
+		let asset = PaymentAsset::Fungible(STABLECOIN);
+		let payment_amount = 2000_u64;
+		let expected_fee_amount = payment_amount / MARKETPLACE_FEE_PERCENTAGE as u64;
+
+		assert_ok!(PaymentModule::pay(
+			Origin::signed(PAYMENT_CREATOR),
+			PAYMENT_RECIPENT_FEE_CHARGED,
+			asset,
+			payment_amount,
+			None
+		));
+		assert_ok!(PaymentModule::release(
+			Origin::signed(PAYMENT_CREATOR),
+			PAYMENT_RECIPENT_FEE_CHARGED,
+			0
+		));
+
+		assert_eq!(
+			Assets::balance(STABLECOIN, PAYMENT_CREATOR),
+			100_000 - payment_amount - expected_fee_amount
+		);
+		assert_eq!(Assets::balance(STABLECOIN, PAYMENT_RECIPENT_FEE_CHARGED), payment_amount + 1);
+		assert_eq!(Assets::balance(STABLECOIN, FEE_RECIPIENT_ACCOUNT), expected_fee_amount + 1);
+		assert_eq!(Assets::balance(STABLECOIN, PaymentModule::account_id()), 0);
+		// the fee is not taken from the native balance
+		assert_eq!(Balances::free_balance(&FEE_RECIPIENT_ACCOUNT), 1);
+	});
+}
+
+#[test]
+fn test_resolve_fungible_asset_payment_splits_amount() {
+	new_test_ext().execute_with(|| {
+		let asset = PaymentAsset::Fungible(STABLECOIN);
+
+		assert_ok!(PaymentModule::pay(
+			Origin::signed(PAYMENT_CREATOR),
+			PAYMENT_RECIPENT,
+			asset,
+			100,
+			None
+		));
+		assert_ok!(PaymentModule::resolve_payment(
+			Origin::signed(RESOLVER_ACCOUNT),
+			PAYMENT_CREATOR,
+			PAYMENT_RECIPENT,
+			0,
+			Percent::from_percent(70)
+		));
+
+		assert_eq!(Assets::balance(STABLECOIN, PAYMENT_CREATOR), 100_000 - 70);
+		assert_eq!(Assets::balance(STABLECOIN, PAYMENT_RECIPENT), 70 + 1);
+		assert_eq!(Assets::balance(STABLECOIN, PaymentModule::account_id()), 0);
+	});
+}
+
+#[test]
+fn test_pay_in_fungible_asset_fails_without_balance() {
+	new_test_ext().execute_with(|| {
+		assert!(PaymentModule::pay(
+			Origin::signed(PAYMENT_CREATOR_TWO),
+			PAYMENT_RECIPENT,
+			PaymentAsset::Fungible(STABLECOIN),
+			20,
+			None
+		)
+		.is_err());
+		assert_eq!(PaymentStore::<Test>::get((PAYMENT_CREATOR_TWO, PAYMENT_RECIPENT, 0)), None);
+	});
+}
+
+#[test]
+fn test_create_recurring_works() {
+	new_test_ext().execute_with(|| {
+		let payment_amount = 20;
+		let period = 10;
+
+		assert_ok!(PaymentModule::create_recurring(
+			Origin::signed(PAYMENT_CREATOR),
+			PAYMENT_RECIPENT,
+			PaymentAsset::Native,
+			payment_amount,
+			period,
+			3,
+			None
+		));
+		// the first payment is created immediately
+		assert_eq!(
+			last_event(),
+			crate::Event::<Test>::RecurringPaymentExecuted {
+				from: PAYMENT_CREATOR,
+				to: PAYMENT_RECIPENT,
+				mandate_id: 0,
+				payment_id: 1,
+				remaining: 2
+			}
+			.into()
+		);
+		assert!(PaymentStore::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT, 1)).is_some());
+		assert_eq!(
+			RecurringPayments::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT, 0)).unwrap(),
+			RecurringPayment {
+				asset: PaymentAsset::Native,
+				amount: payment_amount,
+				period,
+				remaining: 2,
+				next_payment_at: 1 + period,
+				remark: None,
+			}
+		);
+		assert_eq!(
+			ScheduledTasks::<Test>::get(&(PAYMENT_CREATOR, PAYMENT_RECIPENT, 0)),
+			Some(ScheduledTask { task: Task::Recurring, when: 1 + period })
+		);
+
+		// the second payment is created once the period has passed
+		run_n_blocks(period + 1);
+		assert!(PaymentStore::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT, 2)).is_some());
+		assert_eq!(
+			RecurringPayments::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT, 0))
+				.unwrap()
+				.remaining,
+			1
+		);
+
+		// the recurring payment is removed after its last payment
+		run_n_blocks(period);
+		assert!(PaymentStore::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT, 3)).is_some());
+		assert_eq!(RecurringPayments::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT, 0)), None);
+		assert_eq!(ScheduledTasks::<Test>::get(&(PAYMENT_CREATOR, PAYMENT_RECIPENT, 0)), None);
+
+		// the three payments are escrowed
+		let incentive_amount = payment_amount / INCENTIVE_PERCENTAGE as u64;
+		assert_eq!(
+			Balances::free_balance(&PAYMENT_CREATOR),
+			100_000_000_000 - 3 * (payment_amount + incentive_amount)
+		);
+		assert_eq!(Balances::reserved_balance(&PAYMENT_RECIPENT), 3 * payment_amount);
+	});
+}
+
+#[test]
+fn test_create_recurring_fails_with_invalid_parameters() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			PaymentModule::create_recurring(
+				Origin::signed(PAYMENT_CREATOR),
+				PAYMENT_RECIPENT,
+				PaymentAsset::Native,
+				20,
+				0,
+				3,
+				None
+			),
+			Error::InvalidRecurringPayment
+		);
+		assert_noop!(
+			PaymentModule::create_recurring(
+				Origin::signed(PAYMENT_CREATOR),
+				PAYMENT_RECIPENT,
+				PaymentAsset::Native,
+				20,
+				10,
+				0,
+				None
+			),
+			Error::InvalidRecurringPayment
+		);
+	});
+}
+
+#[test]
+fn test_cancel_recurring_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(PaymentModule::create_recurring(
+			Origin::signed(PAYMENT_CREATOR),
+			PAYMENT_RECIPENT,
+			PaymentAsset::Native,
+			20,
+			10,
+			3,
+			None
+		));
+
+		// only the creator can cancel the recurring payment
+		assert_noop!(
+			PaymentModule::cancel_recurring(Origin::signed(PAYMENT_RECIPENT), PAYMENT_CREATOR, 0),
+			Error::NoRecurringPayment
+		);
+		assert_ok!(PaymentModule::cancel_recurring(
+			Origin::signed(PAYMENT_CREATOR),
+			PAYMENT_RECIPENT,
+			0
+		));
+		assert_eq!(
+			last_event(),
+			crate::Event::<Test>::RecurringPaymentCancelled {
+				from: PAYMENT_CREATOR,
+				to: PAYMENT_RECIPENT,
+				mandate_id: 0
+			}
+			.into()
+		);
+		assert_eq!(RecurringPayments::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT, 0)), None);
+		assert_eq!(ScheduledTasks::<Test>::get(&(PAYMENT_CREATOR, PAYMENT_RECIPENT, 0)), None);
+
+		// no new payment is created, the first one is kept
+		run_n_blocks(20);
+		assert!(PaymentStore::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT, 1)).is_some());
+		assert_eq!(PaymentStore::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT, 2)), None);
+	});
+}
+
+#[test]
+fn test_recurring_task_is_charged_its_own_weight() {
+	new_test_ext().execute_with(|| {
+		let period = 10;
+		assert_ok!(PaymentModule::create_recurring(
+			Origin::signed(PAYMENT_CREATOR),
+			PAYMENT_RECIPENT,
+			PaymentAsset::Native,
+			20,
+			period,
+			3,
+			None
+		));
+		let due_block = 1 + period;
+		let read_weight = <() as WeightInfo>::remove_task();
+		let task_weight = <() as WeightInfo>::process_recurring();
+		PaymentModule::on_idle(due_block - 1, (read_weight + task_weight) * 100);
+		assert_eq!(NextTaskBlock::<Test>::get(), due_block);
+
+		// the weight of a refund does not cover the creation of a payment
+		PaymentModule::on_idle(due_block, read_weight + <() as WeightInfo>::cancel());
+		assert_eq!(
+			RecurringPayments::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT, 0))
+				.unwrap()
+				.remaining,
+			2
+		);
+
+		assert_eq!(
+			PaymentModule::on_idle(due_block, read_weight + task_weight),
+			read_weight + task_weight
+		);
+		assert_eq!(
+			RecurringPayments::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT, 0))
+				.unwrap()
+				.remaining,
+			1
+		);
+	});
+}
+
+#[test]
+fn test_recurring_payment_is_cancelled_when_a_payment_fails() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(PaymentModule::create_recurring(
+			Origin::signed(PAYMENT_CREATOR),
+			PAYMENT_RECIPENT,
+			PaymentAsset::Native,
+			20,
+			10,
+			3,
+			None
+		));
+		// the creator cannot afford the next payment
+		Balances::make_free_balance_be(&PAYMENT_CREATOR, 1);
+
+		run_n_blocks(11);
+		assert_eq!(PaymentStore::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT, 2)), None);
+		assert_eq!(RecurringPayments::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT, 0)), None);
+		assert_eq!(
+			last_event(),
+			crate::Event::<Test>::RecurringPaymentCancelled {
+				from: PAYMENT_CREATOR,
+				to: PAYMENT_RECIPENT,
+				mandate_id: 0
+			}
+			.into()
+		);
+	});
+}
+
+#[test]
+fn test_pay_batch_works() {
+	new_test_ext().execute_with(|| {
+		let payments: BatchPaymentsOf<Test> =
+			vec![(PAYMENT_RECIPENT, 20), (PAYMENT_RECIPENT_TWO, 30)].try_into().unwrap();
+
+		assert_ok!(PaymentModule::pay_batch(
+			Origin::signed(PAYMENT_CREATOR),
+			PaymentAsset::Native,
+			payments,
+			None
+		));
+		assert_eq!(
+			PaymentStore::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT, 0))
+				.unwrap()
+				.amount,
+			20
+		);
+		assert_eq!(
+			PaymentStore::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT_TWO, 1))
+				.unwrap()
+				.amount,
+			30
+		);
+		assert_eq!(Balances::reserved_balance(&PAYMENT_RECIPENT), 20);
+		assert_eq!(Balances::reserved_balance(&PAYMENT_RECIPENT_TWO), 30);
+		assert_eq!(
+			last_event(),
+			crate::Event::<Test>::PaymentCreated {
+				from: PAYMENT_CREATOR,
+				to: PAYMENT_RECIPENT_TWO,
+				payment_id: 1,
+				asset: PaymentAsset::Native,
+				amount: 30,
+				remark: None
+			}
+			.into()
+		);
+	});
+}
+
+#[test]
+fn test_pay_batch_is_atomic() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			PaymentModule::pay_batch(
+				Origin::signed(PAYMENT_CREATOR),
+				PaymentAsset::Native,
+				Default::default(),
+				None
+			),
+			Error::EmptyBatch
+		);
+
+		// the second payment cannot be afforded, the first one is not created
+		let payments: BatchPaymentsOf<Test> =
+			vec![(PAYMENT_RECIPENT, 20), (PAYMENT_RECIPENT_TWO, 200_000_000_000)]
+				.try_into()
+				.unwrap();
+		assert_noop!(
+			PaymentModule::pay_batch(
+				Origin::signed(PAYMENT_CREATOR),
+				PaymentAsset::Native,
+				payments,
+				None
+			),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn test_created_payment_expires() {
+	new_test_ext().execute_with(|| {
+		let creator_initial_balance = Balances::free_balance(&PAYMENT_CREATOR);
+		let payment_amount = 20;
+
+		assert_ok!(PaymentModule::pay(
+			Origin::signed(PAYMENT_CREATOR),
+			PAYMENT_RECIPENT,
+			PaymentAsset::Native,
+			payment_amount,
+			None
+		));
+		assert_eq!(
+			ScheduledTasks::<Test>::get(&(PAYMENT_CREATOR, PAYMENT_RECIPENT, 0)),
+			Some(ScheduledTask { task: Task::Expire, when: 1 + PAYMENT_EXPIRY })
+		);
+
+		// the payment is still active before its expiry
+		run_n_blocks(PAYMENT_EXPIRY - 1);
+		assert!(PaymentStore::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT, 0)).is_some());
+
+		run_n_blocks(2);
+		assert_eq!(PaymentStore::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT, 0)), None);
+		assert_eq!(ScheduledTasks::<Test>::get(&(PAYMENT_CREATOR, PAYMENT_RECIPENT, 0)), None);
+		assert_eq!(
+			last_event(),
+			crate::Event::<Test>::PaymentExpired {
+				from: PAYMENT_CREATOR,
+				to: PAYMENT_RECIPENT,
+				payment_id: 0
+			}
+			.into()
+		);
+
+		// the amount is refunded to the creator
+		assert_eq!(Balances::free_balance(&PAYMENT_CREATOR), creator_initial_balance);
+		assert_eq!(Balances::free_balance(&PAYMENT_RECIPENT), 1);
+		assert_eq!(
+			PaymentModule::history(PAYMENT_CREATOR).last().unwrap().outcome,
+			PaymentOutcome::Expired
+		);
+	});
+}
+
+#[test]
+fn test_payment_request_expires() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(PaymentModule::request_payment(
+			Origin::signed(PAYMENT_RECIPENT),
+			PAYMENT_CREATOR,
+			PaymentAsset::Native,
+			20,
+		));
+		assert_eq!(
+			ScheduledTasks::<Test>::get(&(PAYMENT_CREATOR, PAYMENT_RECIPENT, 0)),
+			Some(ScheduledTask { task: Task::Expire, when: 1 + REQUEST_EXPIRY })
+		);
+
+		run_n_blocks(REQUEST_EXPIRY + 1);
+		assert_eq!(PaymentStore::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT, 0)), None);
+		assert_eq!(
+			last_event(),
+			crate::Event::<Test>::PaymentExpired {
+				from: PAYMENT_CREATOR,
+				to: PAYMENT_RECIPENT,
+				payment_id: 0
+			}
+			.into()
+		);
+
+		// the expired request cannot be paid anymore
+		assert_noop!(
+			PaymentModule::accept_and_pay(Origin::signed(PAYMENT_CREATOR), PAYMENT_RECIPENT, 0),
+			crate::Error::<Test>::InvalidPayment
+		);
+	});
+}
+
+#[test]
+fn test_expiries_are_processed_by_block_within_the_idle_weight() {
+	new_test_ext().execute_with(|| {
+		// the number of pending tasks is not bounded by a global list
+		const REQUESTS: u64 = 60;
+		for _ in 0..REQUESTS {
+			assert_ok!(PaymentModule::request_payment(
+				Origin::signed(PAYMENT_RECIPENT),
+				PAYMENT_CREATOR,
+				PaymentAsset::Native,
+				20,
+			));
+		}
+		let expiry_block = 1 + REQUEST_EXPIRY;
+		assert_eq!(TaskQueue::<Test>::iter_prefix(expiry_block).count() as u64, REQUESTS);
+
+		// nothing is due before the expiry block
+		let step_weight = <() as WeightInfo>::remove_task() + <() as WeightInfo>::cancel();
+		PaymentModule::on_idle(expiry_block - 1, step_weight * 100);
+		assert_eq!(NextTaskBlock::<Test>::get(), expiry_block);
+		assert_eq!(TaskQueue::<Test>::iter_prefix(expiry_block).count() as u64, REQUESTS);
+
+		// only the tasks fitting in the idle weight are processed
+		assert_eq!(PaymentModule::on_idle(expiry_block, step_weight * 2), step_weight * 2);
+		assert_eq!(TaskQueue::<Test>::iter_prefix(expiry_block).count() as u64, REQUESTS - 2);
+		assert_eq!(NextTaskBlock::<Test>::get(), expiry_block);
+
+		// the remaining ones are processed by the next blocks
+		PaymentModule::on_idle(expiry_block + 1, step_weight * 100);
+		assert_eq!(TaskQueue::<Test>::iter_prefix(expiry_block).count(), 0);
+		assert_eq!(NextTaskBlock::<Test>::get(), expiry_block + 2);
+		for payment_id in 0..REQUESTS {
+			assert_eq!(
+				PaymentStore::<Test>::get((PAYMENT_CREATOR, PAYMENT_RECIPENT, payment_id)),
+				None
+			);
+			assert_eq!(
+				ScheduledTasks::<Test>::get(&(PAYMENT_CREATOR, PAYMENT_RECIPENT, payment_id)),
+				None
+			);
+		}
+	});
+}
+
+#[test]
+fn test_released_payment_does_not_expire() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(PaymentModule::pay(
+			Origin::signed(PAYMENT_CREATOR),
+			PAYMENT_RECIPENT,
+			PaymentAsset::Native,
+			20,
+			None
+		));
+		assert_ok!(PaymentModule::release(Origin::signed(PAYMENT_CREATOR), PAYMENT_RECIPENT, 0));
+
+		// the expiry task is removed with the payment
+		assert_eq!(ScheduledTasks::<Test>::get(&(PAYMENT_CREATOR, PAYMENT_RECIPENT, 0)), None);
+		run_n_blocks(PAYMENT_EXPIRY + 1);
+		assert_eq!(Balances::free_balance(&PAYMENT_RECIPENT), 21);
+	});
+}
+
+#[test]
+fn test_migration_should_give_an_id_to_existing_payments() {
+	new_test_ext().execute_with(|| {
+		use crate::migrations::v1;
+		let old_payment = |state| v1::OldPaymentDetail::<Test> {
+			amount: 20,
+			incentive_amount: 2,
+			state,
+			resolver_account: RESOLVER_ACCOUNT,
+			fee_detail: None,
+		};
+		let refund_state = PaymentState::RefundRequested { cancel_block: 600 };
+		v1::Payment::<Test>::insert(
+			PAYMENT_CREATOR,
+			PAYMENT_RECIPENT,
+			old_payment(PaymentState::Created),
+		);
+		v1::Payment::<Test>::insert(
+			PAYMENT_CREATOR_TWO,
+			PAYMENT_RECIPENT_TWO,
+			old_payment(refund_state.clone()),
+		);
+		v1::ScheduledTasks::<Test>::put(std::collections::BTreeMap::from([(
+			(PAYMENT_CREATOR_TWO, PAYMENT_RECIPENT_TWO),
+			ScheduledTask { task: Task::Cancel, when: 600 },
+		)]));
+		StorageVersion::new(0).put::<PaymentModule>();
+
+		crate::migrations::migrate::<Test>();
+		assert_eq!(PaymentModule::on_chain_storage_version(), 1);
+		assert_eq!(NextPaymentId::<Test>::get(), 2);
+		assert_eq!(NextTaskBlock::<Test>::get(), 1);
+
+		// the created payment expires after the current expiry period
+		let (payment_id, payment) =
+			PaymentStore::<Test>::iter_prefix((PAYMENT_CREATOR, PAYMENT_RECIPENT))
+				.next()
+				.unwrap();
+		assert_eq!(
+			payment,
+			PaymentDetail {
+				asset: PaymentAsset::Native,
+				amount: 20,
+				incentive_amount: 2,
+				state: PaymentState::Created,
+				resolver_account: RESOLVER_ACCOUNT,
+				fee_detail: None,
+				remark: None,
+				created_at: 1,
+				expires_at: 1 + PAYMENT_EXPIRY,
+			}
+		);
+		assert_eq!(
+			ScheduledTasks::<Test>::get(&(PAYMENT_CREATOR, PAYMENT_RECIPENT, payment_id)),
+			Some(ScheduledTask { task: Task::Expire, when: 1 + PAYMENT_EXPIRY })
+		);
+
+		// the refund is still scheduled
+		let (payment_id, payment) =
+			PaymentStore::<Test>::iter_prefix((PAYMENT_CREATOR_TWO, PAYMENT_RECIPENT_TWO))
+				.next()
+				.unwrap();
+		assert_eq!(payment.state, refund_state);
+		assert_eq!(
+			ScheduledTasks::<Test>::get(&(PAYMENT_CREATOR_TWO, PAYMENT_RECIPENT_TWO, payment_id)),
+			Some(ScheduledTask { task: Task::Cancel, when: 600 })
+		);
+		assert!(TaskQueue::<Test>::contains_key(
+			600,
+			&(PAYMENT_CREATOR_TWO, PAYMENT_RECIPENT_TWO, payment_id)
+		));
+
+		// the migration is only applied once
+		crate::migrations::migrate::<Test>();
+		assert_eq!(NextPaymentId::<Test>::get(), 2);
+	});
+}
+
+#[test]
+fn test_set_asset_fee_works() {
+	new_test_ext().execute_with(|| {
+		let asset = PaymentAsset::Fungible(STABLECOIN);
+		let fee = Percent::from_percent(2);
+		assert_eq!(PaymentModule::asset_fee(asset), Percent::from_percent(0));
+
+		assert_noop!(
+			PaymentModule::set_asset_fee(Origin::signed(PAYMENT_CREATOR), asset, fee),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(PaymentModule::set_asset_fee(Origin::root(), asset, fee));
+		assert_eq!(last_event(), crate::Event::<Test>::AssetFeeSet { asset, fee }.into());
+
+		// the fee is set per asset
+		assert_eq!(PaymentModule::asset_fee(asset), fee);
+		assert_eq!(PaymentModule::asset_fee(PaymentAsset::Native), Percent::from_percent(0));
+	});
+}
+
+#[test]
+fn test_accept_and_pay_in_fungible_asset_locks_the_payment() {
+	new_test_ext().execute_with(|| {
+		let asset = PaymentAsset::Fungible(STABLECOIN);
+		let payment_amount = 2000_u64;
+
+		assert_ok!(PaymentModule::request_payment(
+			Origin::signed(PAYMENT_RECIPENT),
+			PAYMENT_CREATOR,
+			asset,
+			payment_amount,
+		));
+		assert_ok!(PaymentModule::accept_and_pay(
+			Origin::signed(PAYMENT_CREATOR),
+			PAYMENT_RECIPENT,
+			0
+		));
+
+		// the payment is kept locked in the recipient account
+		assert_eq!(Assets::balance(STABLECOIN, PAYMENT_RECIPENT), payment_amount + 1);
+		assert_eq!(PaymentModule::held_asset(STABLECOIN, PAYMENT_RECIPENT), payment_amount);
+		assert_eq!(PaymentModule::free_balance(&asset, &PAYMENT_RECIPENT), 1);
+		assert!(Assets::transfer(
+			Origin::signed(PAYMENT_RECIPENT),
+			STABLECOIN,
+			PAYMENT_CREATOR,
+			payment_amount
+		)
+		.is_err());
+
+		// a locked balance can only be locked once
+		assert_noop!(
+			PaymentModule::reserve_asset(&asset, &PAYMENT_RECIPENT, payment_amount),
+			Error::InsufficientAssetBalance
+		);
+
+		assert_ok!(PaymentModule::unreserve_asset(&asset, &PAYMENT_RECIPENT, payment_amount));
+		assert_eq!(PaymentModule::held_asset(STABLECOIN, PAYMENT_RECIPENT), 0);
+		assert_ok!(Assets::transfer(
+			Origin::signed(PAYMENT_RECIPENT),
+			STABLECOIN,
+			PAYMENT_CREATOR,
+			payment_amount
+		));
+	});
+}
@@ -9,12 +9,12 @@ pub use frame_support::{
 	require_transactional,
 	sp_runtime::{
 		traits::{AccountIdConversion, Hash, One, Saturating, StaticLookup, Zero},
-		FixedU128, PerThing, Percent,
+		FixedU128, PerThing, Percent, TransactionOutcome,
 	},
 	storage::{bounded_btree_map::BoundedBTreeMap, child},
 	traits::{
 		tokens::{
-			BalanceStatus,
+			fungibles, BalanceStatus,
 			ExistenceRequirement::{AllowDeath, KeepAlive},
 		},
 		Contains, Currency, ExistenceRequirement, Get, LockableCurrency, ReservableCurrency,
@@ -33,14 +33,25 @@ pub type BalanceOf<T> =
 pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 pub type BlockNumberOf<T> = <T as frame_system::Config>::BlockNumber;
 pub type HashOf<T> = <T as frame_system::Config>::Hash;
+pub type AssetIdOf<T> =
+	<<T as Config>::Assets as fungibles::Inspect<<T as frame_system::Config>::AccountId>>::AssetId;
+pub type AssetBalanceOf<T> =
+	<<T as Config>::Assets as fungibles::Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+pub type PaymentAssetOf<T> = PaymentAsset<AssetIdOf<T>>;
 pub type BoundedDataOf<T> = BoundedVec<u8, <T as Config>::MaxRemarkLength>;
 pub type ScheduledTaskOf<T> = ScheduledTask<<T as frame_system::Config>::BlockNumber>;
-/// list of ScheduledTasks, stored as a BoundedBTreeMap
-pub type ScheduledTaskList<T> = BoundedBTreeMap<
-	(<T as frame_system::Config>::AccountId, <T as frame_system::Config>::AccountId),
-	ScheduledTaskOf<T>,
-	<T as Config>::MaxRemarkLength,
->;
+/// Identifier of a payment, unique across all the payments created in the pallet
+pub type PaymentId = u64;
+/// Key of a payment: (creator, recipient, payment_id)
+pub type PaymentKeyOf<T> =
+	(<T as frame_system::Config>::AccountId, <T as frame_system::Config>::AccountId, PaymentId);
+/// Bounded list of the receipts of an account
+pub type PaymentHistoryOf<T> = BoundedVec<PaymentReceipt<T>, <T as Config>::MaxPaymentHistory>;
+/// Bounded list of the evidences submitted in a dispute
+pub type EvidenceListOf<T> = BoundedVec<Evidence<T>, <T as Config>::MaxEvidences>;
+/// Bounded list of the (recipient, amount) of the payments of a batch
+pub type BatchPaymentsOf<T> =
+	BoundedVec<(<T as frame_system::Config>::AccountId, BalanceOf<T>), <T as Config>::MaxBatchSize>;
 
 /// The PaymentDetail struct stores information about the payment/escrow
 /// A "payment" in virto network is similar to an escrow, it is used to
@@ -52,6 +63,8 @@ pub type ScheduledTaskList<T> = BoundedBTreeMap<
 #[codec(mel_bound(T: pallet::Config))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PaymentDetail<T: pallet::Config> {
+	/// currency of the payment
+	pub asset: PaymentAssetOf<T>,
 	/// amount used for payment
 	#[codec(compact)]
 	pub amount: BalanceOf<T>,
@@ -65,6 +78,32 @@ pub struct PaymentDetail<T: pallet::Config> {
 	pub resolver_account: T::AccountId,
 	/// fee charged and recipient account details
 	pub fee_detail: Option<(T::AccountId, BalanceOf<T>)>,
+	/// remark attached to the payment by its creator
+	pub remark: Option<BoundedDataOf<T>>,
+	/// block at which the payment was created
+	pub created_at: T::BlockNumber,
+	/// block after which the payment is refunded, or the request removed, if
+	/// it is still in its initial state
+	pub expires_at: T::BlockNumber,
+}
+
+/// The `PaymentAsset` enum is the currency in which a payment is made.
+/// Payments in the native currency are reserved in the accounts of the
+/// parties, payments in a fungible asset are held by the pallet account until
+/// they are settled.
+#[derive(Encode, Decode, Debug, Clone, Copy, PartialEq, Eq, MaxEncodedLen, TypeInfo)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PaymentAsset<AssetId> {
+	/// The native currency of the chain
+	Native,
+	/// An asset of the `Assets` fungibles implementation
+	Fungible(AssetId),
+}
+
+impl<AssetId> Default for PaymentAsset<AssetId> {
+	fn default() -> Self {
+		PaymentAsset::Native
+	}
 }
 
 /// The `PaymentState` enum tracks the possible states that a payment can be in.
@@ -87,20 +126,132 @@ pub enum PaymentState<T: pallet::Config> {
 	PaymentCompleted,
 }
 
+/// The `PaymentOutcome` enum describes how a payment left the `Payment` storage.
+#[derive(Encode, Decode, Debug, Clone, Copy, PartialEq, Eq, MaxEncodedLen, TypeInfo)]
+pub enum PaymentOutcome {
+	/// The payment was released to the recipient
+	Completed,
+	/// The payment was cancelled by the recipient and returned to the creator
+	Cancelled,
+	/// The payment was returned to the creator after a refund request
+	Refunded,
+	/// The payment was split by the dispute resolver
+	Resolved { recipient_share: Percent },
+	/// The payment was returned to the creator after its expiry
+	Expired,
+}
+
+/// The PaymentReceipt struct is the permanent record of a settled payment.
+/// A receipt is stored in the history of both the creator and the recipient
+/// of the payment, and can be used for rent receipts and tax reporting.
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq, MaxEncodedLen, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound(T: pallet::Config))]
+pub struct PaymentReceipt<T: pallet::Config> {
+	/// id of the settled payment
+	pub payment_id: PaymentId,
+	/// creator of the payment
+	pub from: T::AccountId,
+	/// recipient of the payment
+	pub to: T::AccountId,
+	/// currency of the payment
+	pub asset: PaymentAssetOf<T>,
+	/// amount of the payment
+	#[codec(compact)]
+	pub amount: BalanceOf<T>,
+	/// part of the amount received by the recipient
+	#[codec(compact)]
+	pub recipient_amount: BalanceOf<T>,
+	/// fee paid by the creator
+	#[codec(compact)]
+	pub fee_amount: BalanceOf<T>,
+	/// how the payment was settled
+	pub outcome: PaymentOutcome,
+	/// remark attached to the payment by its creator
+	pub remark: Option<BoundedDataOf<T>>,
+	/// block at which the payment was created
+	pub created_at: T::BlockNumber,
+	/// block at which the payment was settled
+	pub settled_at: T::BlockNumber,
+}
+
+/// The `DisputeStage` enum tracks the escalation of a disputed payment.
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq, MaxEncodedLen, TypeInfo)]
+pub enum DisputeStage<AccountId> {
+	/// The resolver of the payment has to take a decision
+	AwaitingResolver,
+	/// The resolver took a decision, which can be appealed until the deadline
+	Resolved { recipient_share: Percent },
+	/// The decision of the resolver was appealed and is reviewed by the `AppealOrigin`
+	Appealed { appellant: AccountId, recipient_share: Percent },
+}
+
+/// Remark submitted as evidence by one of the parties of a disputed payment
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq, MaxEncodedLen, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound(T: pallet::Config))]
+pub struct Evidence<T: pallet::Config> {
+	/// party that submitted the evidence
+	pub who: T::AccountId,
+	/// content of the evidence
+	pub remark: BoundedDataOf<T>,
+	/// block at which the evidence was submitted
+	pub submitted_at: T::BlockNumber,
+}
+
+/// The Dispute struct stores the state of a disputed payment until it is settled.
+/// When the deadline of the current stage is reached without a decision, the
+/// default outcome of the stage is applied to the payment.
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq, MaxEncodedLen, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound(T: pallet::Config))]
+pub struct Dispute<T: pallet::Config> {
+	/// current stage of the dispute
+	pub stage: DisputeStage<T::AccountId>,
+	/// block at which the current stage ends
+	pub deadline: T::BlockNumber,
+	/// evidences submitted by the parties of the payment
+	pub evidences: EvidenceListOf<T>,
+}
+
+/// The `RecurringPayment` struct stores a mandate given by the creator of the
+/// payments to pay `amount` to the recipient every `period` blocks.
+/// Each execution of the mandate creates a new escrowed payment.
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq, MaxEncodedLen, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound(T: pallet::Config))]
+pub struct RecurringPayment<T: pallet::Config> {
+	/// currency of the payments
+	pub asset: PaymentAssetOf<T>,
+	/// amount of each payment
+	#[codec(compact)]
+	pub amount: BalanceOf<T>,
+	/// number of blocks between two payments
+	pub period: T::BlockNumber,
+	/// number of payments left to create
+	pub remaining: u32,
+	/// block at which the next payment is created
+	pub next_payment_at: T::BlockNumber,
+	/// remark attached to each payment
+	pub remark: Option<BoundedDataOf<T>>,
+}
+
 /// trait that defines how to create/release payments for users
 pub trait PaymentHandler<T: pallet::Config> {
 	/// Create a PaymentDetail from the given payment details
-	/// Calculate the fee amount and store PaymentDetail in storage
+	/// Calculate the fee amount and store PaymentDetail in storage under a
+	/// new payment id
 	/// Possible reasons for failure include:
-	/// - Payment already exists and cannot be overwritten
+	/// - The remark is too long
 	fn create_payment(
 		from: &T::AccountId,
 		to: &T::AccountId,
+		asset: PaymentAssetOf<T>,
 		amount: BalanceOf<T>,
 		payment_state: PaymentState<T>,
 		incentive_percentage: Percent,
 		remark: Option<&[u8]>,
-	) -> Result<PaymentDetail<T>, sp_runtime::DispatchError>;
+	) -> Result<(PaymentId, PaymentDetail<T>), sp_runtime::DispatchError>;
 
 	/// Attempt to reserve the amount from the caller
 	/// If not possible then return Error. Possible reasons for failure include:
@@ -113,6 +264,8 @@ pub trait PaymentHandler<T: pallet::Config> {
 
 	// Settle a payment of `from` to `to`. To release a payment, the
 	// recipient_share=100, to cancel a payment recipient_share=0
+	// The receipt of the payment is added to the history of both accounts
+	// with the given `outcome`.
 	// Possible reasonse for failure include
 	///
 	/// - The payment does not exist
@@ -121,20 +274,27 @@ pub trait PaymentHandler<T: pallet::Config> {
 	fn settle_payment(
 		from: &T::AccountId,
 		to: &T::AccountId,
+		payment_id: PaymentId,
 		recipient_share: Percent,
+		outcome: PaymentOutcome,
 	) -> DispatchResult;
 
 	/// Attempt to fetch the details of a payment from the given payment_id
 	/// Possible reasons for failure include:
 	/// - The payment does not exist
-	fn get_payment_details(from: &T::AccountId, to: &T::AccountId) -> Option<PaymentDetail<T>>;
+	fn get_payment_details(
+		from: &T::AccountId,
+		to: &T::AccountId,
+		payment_id: PaymentId,
+	) -> Option<PaymentDetail<T>>;
 }
 
 /// DisputeResolver trait defines how to create/assign judges for solving
 /// payment disputes
 pub trait DisputeResolver<Account> {
-	/// Returns an `Account`
-	fn get_resolver_account() -> Account;
+	/// Returns the `Account` in charge of the disputes of a payment from
+	/// `from` to `to`, if any
+	fn get_resolver_account(from: &Account, to: &Account) -> Option<Account>;
 }
 
 /// Fee Handler trait that defines how to handle marketplace fees to every
@@ -149,11 +309,38 @@ pub trait FeeHandler<T: pallet::Config> {
 	) -> (T::AccountId, Percent);
 }
 
+/// Handler notified when a payment or a payment request expires, so that
+/// the pallets tracking the payment can update their own records
+pub trait OnPaymentExpiry<T: pallet::Config> {
+	fn on_payment_expired(
+		from: &T::AccountId,
+		to: &T::AccountId,
+		payment_id: PaymentId,
+		detail: &PaymentDetail<T>,
+	);
+}
+
+impl<T: pallet::Config> OnPaymentExpiry<T> for () {
+	fn on_payment_expired(
+		_from: &T::AccountId,
+		_to: &T::AccountId,
+		_payment_id: PaymentId,
+		_detail: &PaymentDetail<T>,
+	) {
+	}
+}
+
 /// Types of Tasks that can be scheduled in the pallet
 #[derive(PartialEq, Eq, Clone, Encode, Decode, Debug, TypeInfo, MaxEncodedLen)]
 pub enum Task {
 	// payment `from` to `to` has to be cancelled
 	Cancel,
+	// the current stage of the dispute on the payment has ended
+	DisputeDeadline,
+	// the next payment of the recurring payment has to be created
+	Recurring,
+	// payment `from` to `to` expired before being released or accepted
+	Expire,
 }
 
 /// The details of a scheduled task
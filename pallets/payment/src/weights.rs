@@ -41,6 +41,14 @@ pub trait WeightInfo {
 	fn request_payment() -> Weight;
 	fn accept_and_pay() -> Weight;
 	fn remove_task() -> Weight;
+	fn submit_evidence() -> Weight;
+	fn appeal() -> Weight;
+	fn resolve_appeal() -> Weight;
+	fn create_recurring() -> Weight;
+	fn cancel_recurring() -> Weight;
+	fn pay_batch(x: u32, ) -> Weight;
+	fn process_recurring() -> Weight;
+	fn set_asset_fee() -> Weight;
 }
 
 /// Weights for virto_payment using the Substrate node and recommended hardware.
@@ -79,6 +87,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	}
 	// Storage: Payment Payment (r:1 w:1)
 	// Storage: Payment ScheduledTasks (r:1 w:1)
+	// Storage: Payment TaskQueue (r:0 w:2)
 	fn request_refund() -> Weight {
 		Weight::from_ref_time(20_000_000)
 			.saturating_add(T::DbWeight::get().reads(2 as u64))
@@ -86,6 +95,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	}
 	// Storage: Payment Payment (r:1 w:1)
 	// Storage: Payment ScheduledTasks (r:1 w:1)
+	// Storage: Payment TaskQueue (r:0 w:2)
 	fn dispute_refund() -> Weight {
 		Weight::from_ref_time(21_000_000)
 			.saturating_add(T::DbWeight::get().reads(2 as u64))
@@ -107,11 +117,92 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(4 as u64))
 	}
 	// Storage: Payment ScheduledTasks (r:1 w:1)
+	// Storage: Payment TaskQueue (r:1 w:1)
 	fn remove_task() -> Weight {
 		Weight::from_ref_time(4_000_000)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	// Storage: Payment Disputes (r:1 w:1)
+	fn submit_evidence() -> Weight {
+		Weight::from_ref_time(20_000_000)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: Payment Disputes (r:1 w:1)
+	// Storage: Payment ScheduledTasks (r:1 w:1)
+	// Storage: Payment TaskQueue (r:0 w:2)
+	fn appeal() -> Weight {
+		Weight::from_ref_time(25_000_000)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	// Storage: Payment Disputes (r:1 w:1)
+	// Storage: Payment ScheduledTasks (r:1 w:1)
+	// Storage: Payment TaskQueue (r:0 w:2)
+	// Storage: Payment Payment (r:1 w:1)
+	// Storage: Payment PaymentHistory (r:2 w:2)
+	// Storage: System Account (r:2 w:2)
+	fn resolve_appeal() -> Weight {
+		Weight::from_ref_time(45_000_000)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	// Storage: Payment NextPaymentId (r:1 w:1)
+	// Storage: Payment RecurringPayments (r:1 w:1)
+	// Storage: Sudo Key (r:1 w:0)
+	// Storage: Payment Payment (r:0 w:1)
+	// Storage: Payment ScheduledTasks (r:2 w:2)
+	// Storage: Payment NextTaskBlock (r:1 w:0)
+	// Storage: Payment TaskQueue (r:0 w:2)
+	// Storage: System Account (r:2 w:2)
+	fn create_recurring() -> Weight {
+		Weight::from_ref_time(82_000_000)
+			.saturating_add(T::DbWeight::get().reads(8 as u64))
+			.saturating_add(T::DbWeight::get().writes(9 as u64))
+	}
+	// Storage: Payment RecurringPayments (r:1 w:1)
+	// Storage: Payment ScheduledTasks (r:1 w:1)
+	// Storage: Payment TaskQueue (r:0 w:1)
+	fn cancel_recurring() -> Weight {
+		Weight::from_ref_time(22_000_000)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	// Storage: Payment NextPaymentId (r:1 w:1)
+	// Storage: Payment NextTaskBlock (r:1 w:0)
+	// Storage: Sudo Key (r:1 w:0)
+	// Storage: Payment Payment (r:0 w:1)
+	// Storage: Payment ScheduledTasks (r:1 w:1)
+	// Storage: Payment TaskQueue (r:0 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn pay_batch(x: u32, ) -> Weight {
+		Weight::from_ref_time(14_000_000)
+			// Standard Error: 20_000
+			.saturating_add(Weight::from_ref_time(58_000_000).saturating_mul(x as u64))
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+			.saturating_add(T::DbWeight::get().reads((3 as u64).saturating_mul(x as u64)))
+			.saturating_add(T::DbWeight::get().writes((4 as u64).saturating_mul(x as u64)))
+	}
+	// Storage: Payment RecurringPayments (r:1 w:1)
+	// Storage: Payment NextPaymentId (r:1 w:1)
+	// Storage: Sudo Key (r:1 w:0)
+	// Storage: Payment Payment (r:0 w:1)
+	// Storage: Payment ScheduledTasks (r:2 w:2)
+	// Storage: Payment NextTaskBlock (r:1 w:0)
+	// Storage: Payment TaskQueue (r:0 w:2)
+	// Storage: System Account (r:2 w:2)
+	fn process_recurring() -> Weight {
+		Weight::from_ref_time(76_000_000)
+			.saturating_add(T::DbWeight::get().reads(8 as u64))
+			.saturating_add(T::DbWeight::get().writes(9 as u64))
+	}
+	// Storage: Payment AssetFees (r:0 w:1)
+	fn set_asset_fee() -> Weight {
+		Weight::from_ref_time(12_000_000)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -149,6 +240,7 @@ impl WeightInfo for () {
 	}
 	// Storage: Payment Payment (r:1 w:1)
 	// Storage: Payment ScheduledTasks (r:1 w:1)
+	// Storage: Payment TaskQueue (r:0 w:2)
 	fn request_refund() -> Weight {
 		Weight::from_ref_time(20_000_000)
 			.saturating_add(RocksDbWeight::get().reads(2 as u64))
@@ -156,6 +248,7 @@ impl WeightInfo for () {
 	}
 	// Storage: Payment Payment (r:1 w:1)
 	// Storage: Payment ScheduledTasks (r:1 w:1)
+	// Storage: Payment TaskQueue (r:0 w:2)
 	fn dispute_refund() -> Weight {
 		Weight::from_ref_time(21_000_000)
 			.saturating_add(RocksDbWeight::get().reads(2 as u64))
@@ -177,9 +270,90 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(4 as u64))
 	}
 	// Storage: Payment ScheduledTasks (r:1 w:1)
+	// Storage: Payment TaskQueue (r:1 w:1)
 	fn remove_task() -> Weight {
 		Weight::from_ref_time(4_000_000)
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	// Storage: Payment Disputes (r:1 w:1)
+	fn submit_evidence() -> Weight {
+		Weight::from_ref_time(20_000_000)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: Payment Disputes (r:1 w:1)
+	// Storage: Payment ScheduledTasks (r:1 w:1)
+	// Storage: Payment TaskQueue (r:0 w:2)
+	fn appeal() -> Weight {
+		Weight::from_ref_time(25_000_000)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	// Storage: Payment Disputes (r:1 w:1)
+	// Storage: Payment ScheduledTasks (r:1 w:1)
+	// Storage: Payment TaskQueue (r:0 w:2)
+	// Storage: Payment Payment (r:1 w:1)
+	// Storage: Payment PaymentHistory (r:2 w:2)
+	// Storage: System Account (r:2 w:2)
+	fn resolve_appeal() -> Weight {
+		Weight::from_ref_time(45_000_000)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	// Storage: Payment NextPaymentId (r:1 w:1)
+	// Storage: Payment RecurringPayments (r:1 w:1)
+	// Storage: Sudo Key (r:1 w:0)
+	// Storage: Payment Payment (r:0 w:1)
+	// Storage: Payment ScheduledTasks (r:2 w:2)
+	// Storage: Payment NextTaskBlock (r:1 w:0)
+	// Storage: Payment TaskQueue (r:0 w:2)
+	// Storage: System Account (r:2 w:2)
+	fn create_recurring() -> Weight {
+		Weight::from_ref_time(82_000_000)
+			.saturating_add(RocksDbWeight::get().reads(8 as u64))
+			.saturating_add(RocksDbWeight::get().writes(9 as u64))
+	}
+	// Storage: Payment RecurringPayments (r:1 w:1)
+	// Storage: Payment ScheduledTasks (r:1 w:1)
+	// Storage: Payment TaskQueue (r:0 w:1)
+	fn cancel_recurring() -> Weight {
+		Weight::from_ref_time(22_000_000)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	// Storage: Payment NextPaymentId (r:1 w:1)
+	// Storage: Payment NextTaskBlock (r:1 w:0)
+	// Storage: Sudo Key (r:1 w:0)
+	// Storage: Payment Payment (r:0 w:1)
+	// Storage: Payment ScheduledTasks (r:1 w:1)
+	// Storage: Payment TaskQueue (r:0 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn pay_batch(x: u32, ) -> Weight {
+		Weight::from_ref_time(14_000_000)
+			// Standard Error: 20_000
+			.saturating_add(Weight::from_ref_time(58_000_000).saturating_mul(x as u64))
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+			.saturating_add(RocksDbWeight::get().reads((3 as u64).saturating_mul(x as u64)))
+			.saturating_add(RocksDbWeight::get().writes((4 as u64).saturating_mul(x as u64)))
+	}
+	// Storage: Payment RecurringPayments (r:1 w:1)
+	// Storage: Payment NextPaymentId (r:1 w:1)
+	// Storage: Sudo Key (r:1 w:0)
+	// Storage: Payment Payment (r:0 w:1)
+	// Storage: Payment ScheduledTasks (r:2 w:2)
+	// Storage: Payment NextTaskBlock (r:1 w:0)
+	// Storage: Payment TaskQueue (r:0 w:2)
+	// Storage: System Account (r:2 w:2)
+	fn process_recurring() -> Weight {
+		Weight::from_ref_time(76_000_000)
+			.saturating_add(RocksDbWeight::get().reads(8 as u64))
+			.saturating_add(RocksDbWeight::get().writes(9 as u64))
+	}
+	// Storage: Payment AssetFees (r:0 w:1)
+	fn set_asset_fee() -> Weight {
+		Weight::from_ref_time(12_000_000)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
 }
\ No newline at end of file
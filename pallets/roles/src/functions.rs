@@ -73,6 +73,30 @@ impl<T: Config> Pallet<T> {
 		exist
 	}
 
+	// Helper function for approving appraisers
+	pub fn approve_appraiser(sender: T::AccountId, who: T::AccountId) -> bool {
+		let appraisers = Self::get_pending_appraisers();
+		let mut exist = false;
+
+		for (index, appraiser) in appraisers.iter().enumerate() {
+			if appraiser.account_id == who.clone() {
+				let mut appraiser_ = appraiser.clone();
+				appraiser_.activated = true;
+				appraiser_.verifier = sender;
+				AppraiserLog::<T>::insert(&who, appraiser_);
+				AppraiserApprovalList::<T>::mutate(|list| {
+					list.remove(index);
+				});
+				AccountsRolesLog::<T>::insert(&who, Accounts::APPRAISER);
+				let now = <frame_system::Pallet<T>>::block_number();
+				Self::deposit_event(Event::AppraiserCreated(now, who));
+				exist = true;
+				break;
+			}
+		}
+		exist
+	}
+
 	//Helper function for account creation approval by admin only
 	pub fn approve_account(sender: T::AccountId, who: T::AccountId) -> DispatchResult {
 		let role = Self::get_requested_role(who.clone());
@@ -82,6 +106,7 @@ impl<T: Config> Pallet<T> {
 			Accounts::SELLER => Self::approve_seller(sender, who),
 			Accounts::SERVICER => Self::approve_servicer(sender, who),
 			Accounts::NOTARY => Self::approve_notary(sender, who),
+			Accounts::APPRAISER => Self::approve_appraiser(sender, who),
 			_ => false,
 		};
 		ensure!(success, Error::<T>::NotInWaitingList);
@@ -153,6 +178,25 @@ impl<T: Config> Pallet<T> {
 		exist
 	}
 
+	pub fn reject_appraiser(who: T::AccountId) -> bool {
+		let appraisers = Self::get_pending_appraisers();
+		let mut exist = false;
+
+		for (index, appraiser) in appraisers.iter().enumerate() {
+			if appraiser.account_id == who.clone() {
+				AppraiserApprovalList::<T>::mutate(|list| {
+					list.remove(index);
+				});
+				let now = <frame_system::Pallet<T>>::block_number();
+				Self::deposit_event(Event::AppraiserAccountCreationRejected(now, who));
+				exist = true;
+				break;
+			}
+		}
+
+		exist
+	}
+
 	// Helper function for account creation rejection by admin only
 	pub fn reject_account(who: T::AccountId) -> DispatchResult {
 		let role = Self::get_requested_role(who.clone());
@@ -162,6 +206,7 @@ impl<T: Config> Pallet<T> {
 			Accounts::SELLER => Self::reject_seller(who),
 			Accounts::SERVICER => Self::reject_servicer(who),
 			Accounts::NOTARY => Self::reject_notary(who),
+			Accounts::APPRAISER => Self::reject_appraiser(who),
 			_ => false,
 		};
 		ensure!(success, Error::<T>::NotInWaitingList);
@@ -195,6 +240,15 @@ impl<T: Config> Pallet<T> {
 		RepNumber::<T>::put(reps.saturating_add(rep_count));
 	}
 
+	// Helper function recording that an investor was selected to buy an asset
+	pub fn increase_investor_selections(who: &T::AccountId) {
+		InvestorLog::<T>::mutate(who, |investor| {
+			if let Some(investor) = investor {
+				investor.selections = investor.selections.saturating_add(1);
+			}
+		});
+	}
+
 	pub fn increase_total_members() -> DispatchResult {
 		let members: u32 = Self::total_members();
 		ensure!(members < T::MaxMembers::get(), Error::<T>::TotalMembersExceeded);
@@ -16,8 +16,8 @@
 //! - SERVICER
 //! - SELLER
 //! The 5th role which is the accounts administrator role is not available during role setting.
-//! Sellers and Servicers roles, must be verified/approved by an administrator in order to become
-//! active
+//! Sellers, Servicers, Notaries and Appraisers roles, must be verified/approved by an administrator
+//! in order to become active
 //!
 //! ### Dispatchable Functions
 //! #### Role setting
@@ -101,6 +101,12 @@ pub mod pallet {
 	pub(super) type NotaryLog<T: Config> =
 		StorageMap<_, Twox64Concat, AccountIdOf<T>, Notary<T>, OptionQuery>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn appraisers)]
+	///Registry of Appraisers organized by AccountId
+	pub(super) type AppraiserLog<T: Config> =
+		StorageMap<_, Twox64Concat, AccountIdOf<T>, Appraiser<T>, OptionQuery>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn reps)]
 	///Registry of Sellers organized by AccountId
@@ -137,6 +143,12 @@ pub mod pallet {
 		Vec::new()
 	}
 
+	#[pallet::type_value]
+	/// Initializer for the approval list of appraisers
+	pub(super) fn InitPendingAppraiserList<T: Config>() -> Vec<Appraiser<T>> {
+		Vec::new()
+	}
+
 	#[pallet::type_value]
 	/// Initializer for the approval list of representatives
 	pub(super) fn InitRepApprovalList<T: Config>() -> Vec<Representative<T>> {
@@ -158,6 +170,11 @@ pub mod pallet {
 	pub(super) type NotaryApprovalList<T: Config> =
 		StorageValue<_, Vec<Notary<T>>, ValueQuery, InitPendingNotaryList<T>>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn get_pending_appraisers)]
+	pub(super) type AppraiserApprovalList<T: Config> =
+		StorageValue<_, Vec<Appraiser<T>>, ValueQuery, InitPendingAppraiserList<T>>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn get_pending_representatives)]
 	///Approval waiting list for Representatives
@@ -237,6 +254,8 @@ pub mod pallet {
 		ServicerCreated(T::BlockNumber, T::AccountId),
 		/// Notary role successfully attributed
 		NotaryCreated(T::BlockNumber, T::AccountId),
+		/// Appraiser role successfully attributed
+		AppraiserCreated(T::BlockNumber, T::AccountId),
 		/// Request for new role accepted
 		AccountCreationApproved(T::BlockNumber, T::AccountId),
 		/// Request for new role Rejected
@@ -247,6 +266,8 @@ pub mod pallet {
 		ServicerAccountCreationRejected(T::BlockNumber, T::AccountId),
 		/// Notary role request rejected
 		NotaryAccountCreationRejected(T::BlockNumber, T::AccountId),
+		/// Appraiser role request rejected
+		AppraiserAccountCreationRejected(T::BlockNumber, T::AccountId),
 		/// Role request added to the role approval waiting list
 		CreationRequestCreated(T::BlockNumber, T::AccountId),
 	}
@@ -336,6 +357,14 @@ pub mod pallet {
 					Notary::<T>::new(notary).map_err(|_| <Error<T>>::InitializationError)?;
 					Self::deposit_event(Event::CreationRequestCreated(now, account.clone()));
 				},
+				Accounts::APPRAISER => {
+					ensure!(!requested, <Error<T>>::AlreadyWaiting);
+					let appraiser = <T as frame_system::Config>::Origin::from(RawOrigin::Signed(
+						account.clone(),
+					));
+					Appraiser::<T>::new(appraiser).map_err(|_| <Error<T>>::InitializationError)?;
+					Self::deposit_event(Event::CreationRequestCreated(now, account.clone()));
+				},
 				Accounts::REPRESENTATIVE => {
 					ensure!(!requested, <Error<T>>::AlreadyWaiting);
 					let representative = <T as frame_system::Config>::Origin::from(
@@ -361,7 +390,7 @@ pub mod pallet {
 		}
 
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::approval(5))]
-		///Approval function for Sellers, Servicers, and Notary. Only for admin level.
+		///Approval function for Sellers, Servicers, Notaries and Appraisers. Only for admin level.
 		pub fn account_approval(origin: OriginFor<T>, account: T::AccountId) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
 			ensure!(
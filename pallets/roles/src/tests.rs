@@ -241,6 +241,59 @@ fn test_role_notary() {
 	});
 }
 
+#[test]
+fn test_role_appraiser() {
+	new_test_ext(0).execute_with(|| {
+		let admin = 0;
+		let user1 = 1;
+		let user2 = 2;
+
+		assert_ok!(RoleModule::set_role(Origin::signed(user1), user1, Acc::APPRAISER));
+		assert_ok!(RoleModule::set_role(Origin::signed(user2), user2, Acc::APPRAISER));
+		assert_noop!(
+			RoleModule::set_role(Origin::signed(user1), user1, Acc::APPRAISER),
+			Error::<Test>::AlreadyWaiting
+		);
+
+		// check appraiser approval list
+		assert_eq!(
+			RoleModule::get_pending_appraisers(),
+			vec![
+				Appraiser {
+					account_id: user1,
+					activated: false,
+					verifier: admin,
+					age: System::block_number()
+				},
+				Appraiser {
+					account_id: user2,
+					activated: false,
+					verifier: admin,
+					age: System::block_number()
+				}
+			]
+		);
+
+		assert_ok!(RoleModule::account_approval(Origin::signed(admin), user1));
+		assert_ok!(RoleModule::account_rejection(Origin::signed(admin), user2));
+
+		// check appraiser storage
+		assert_eq!(
+			RoleModule::appraisers(user1).unwrap(),
+			Appraiser {
+				account_id: user1,
+				activated: true,
+				verifier: admin,
+				age: System::block_number()
+			}
+		);
+		assert_eq!(RoleModule::get_roles(user1), Some(Acc::APPRAISER));
+		assert!(RoleModule::appraisers(user2).is_none());
+		assert_eq!(RoleModule::get_pending_appraisers().len(), 0);
+		assert_eq!(RoleModule::total_members(), 1);
+	});
+}
+
 #[test]
 fn test_set_manager() {
 	new_test_ext(4).execute_with(|| {
@@ -264,3 +317,18 @@ fn test_genesis_config() {
 		assert_eq!(RoleModule::reps(HENRY).unwrap().index, 1);
 	})
 }
+
+#[test]
+fn test_increase_investor_selections() {
+	new_test_ext(4).execute_with(|| {
+		assert_ok!(Investor::<Test>::new(Origin::signed(1)));
+
+		RoleModule::increase_investor_selections(&1);
+		RoleModule::increase_investor_selections(&1);
+		assert_eq!(RoleModule::investors(1).unwrap().selections, 2);
+
+		//--accounts without the investor role are ignored----
+		RoleModule::increase_investor_selections(&2);
+		assert!(RoleModule::investors(2).is_none());
+	});
+}
@@ -36,6 +36,7 @@ pub enum Accounts {
 	SERVICER,
 	NOTARY,
 	REPRESENTATIVE,
+	APPRAISER,
 }
 
 //-------------------------------------------------------------------------------------
@@ -259,3 +260,38 @@ where
 }
 //-------------------------------------------------------------------------------------
 //-------------NOTARY STRUCT DECLARATION & IMPLEMENTATION_BEGIN----------------------
+
+//-------------------------------------------------------------------------------------
+//-------------APPRAISER STRUCT DECLARATION & IMPLEMENTATION_BEGIN----------------------
+#[derive(Clone, Encode, Decode, Default, PartialEq, Eq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct Appraiser<T: Config> {
+	pub account_id: T::AccountId,
+	pub age: BlockNumberOf<T>,
+	pub activated: bool,
+	pub verifier: T::AccountId,
+}
+impl<T: Config> Appraiser<T>
+where
+	types::Appraiser<T>: EncodeLike<types::Appraiser<T>>,
+{
+	pub fn new(acc: OriginFor<T>) -> DispatchResult {
+		let caller = ensure_signed(acc)?;
+		let now = <frame_system::Pallet<T>>::block_number();
+
+		ensure!(!AppraiserLog::<T>::contains_key(&caller), Error::<T>::NoneValue);
+
+		let admin = SUDO::Pallet::<T>::key().unwrap();
+		let appraiser =
+			Appraiser { account_id: caller.clone(), age: now, activated: false, verifier: admin };
+		AppraiserApprovalList::<T>::mutate(|list| {
+			list.push(appraiser);
+		});
+		RequestedRoles::<T>::insert(caller, Accounts::APPRAISER);
+
+		Ok(())
+	}
+}
+//-------------APPRAISER STRUCT DECLARATION & IMPLEMENTATION_END----------------------
+//-------------------------------------------------------------------------------------
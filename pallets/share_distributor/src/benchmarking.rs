@@ -11,13 +11,12 @@ pub use frame_support::{
 	pallet_prelude::*,
 };
 use frame_system::RawOrigin;
-pub type NftColl = Nft::PossibleCollections;
 
 benchmarks! {
 	create_virtual{
 		let s in 0 .. 100;
 		let caller: T::AccountId = whitelisted_caller();
-		let coll_id:T::NftCollectionId = NftColl::APPARTMENTSTEST.value().into();
+		let coll_id:T::NftCollectionId = 6u32.into();
 
 	}: _(RawOrigin::Root,coll_id,s.into())
 
@@ -3,7 +3,6 @@
 //3) Use onboarding do_buy
 //4) transfer tokens to owners
 use super::*;
-use enum_iterator::all;
 #[allow(unused_imports)]
 use num_traits::float::FloatCore;
 use sp_runtime::{traits::SaturatedConversion, FixedPointNumber, FixedU128};
@@ -51,18 +50,9 @@ impl<T: Config> Pallet<T> {
 		item_id: T::NftItemId,
 		virtual_id: T::AccountId,
 	) -> DispatchResult {
-		//Get collection
-		let collection_vec = all::<Nft::PossibleCollections>().collect::<Vec<_>>();
 		let _infos = Onboarding::Houses::<T>::get(collection_id, item_id).unwrap();
-		let mut coll_id = Nft::PossibleCollections::HOUSES;
-		for i in collection_vec.iter() {
-			let val: T::NftCollectionId = i.value().into();
-			if val == collection_id {
-				coll_id = *i;
-			}
-		}
 		//Execute NFT and money transfer
-		Onboarding::Pallet::do_buy(coll_id, item_id, virtual_id, _infos).ok();
+		Onboarding::Pallet::do_buy(collection_id, item_id, virtual_id, _infos).ok();
 
 		Ok(())
 	}
@@ -229,3 +219,22 @@ impl<T: Config> Pallet<T> {
 		Some(float)
 	}
 }
+
+// The ownership tokens of a locked asset are frozen by its virtual account
+impl<T: Config> Nft::TokenFreezer<T::NftCollectionId, T::NftItemId> for Pallet<T> {
+	fn freeze(collection_id: T::NftCollectionId, item_id: T::NftItemId) -> DispatchResult {
+		if let Some(ownership) = Virtual::<T>::get(collection_id, item_id) {
+			let origin = RawOrigin::Signed(ownership.virtual_account);
+			Assets::Pallet::<T>::freeze_asset(origin.into(), ownership.token_id.into())?;
+		}
+		Ok(())
+	}
+
+	fn thaw(collection_id: T::NftCollectionId, item_id: T::NftItemId) -> DispatchResult {
+		if let Some(ownership) = Virtual::<T>::get(collection_id, item_id) {
+			let origin = RawOrigin::Signed(ownership.virtual_account);
+			Assets::Pallet::<T>::thaw_asset(origin.into(), ownership.token_id.into())?;
+		}
+		Ok(())
+	}
+}
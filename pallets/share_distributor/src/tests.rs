@@ -2,6 +2,7 @@ pub use super::*;
 pub use crate::mock::*;
 pub use frame_support::{assert_noop, assert_ok};
 use frame_system::pallet_prelude::OriginFor;
+use Onboarding::AssetStatus;
 
 pub type Bvec<Test> = BoundedVec<u8, <Test as pallet_uniques::Config>::StringLimit>;
 
@@ -37,33 +38,37 @@ pub fn prep_test(
 	//Charlie creates a collection
 	assert_ok!(NftModule::create_collection(
 		Origin::signed(CHARLIE),
-		NftColl::OFFICESTEST,
+		OFFICESTEST,
+		CollectionClass::new(AssetClass::OFFICE, 3),
 		metadata0.clone()
 	));
 	//Charlie creates a second collection
 	assert_ok!(NftModule::create_collection(
 		Origin::signed(CHARLIE),
-		NftColl::APPARTMENTSTEST,
+		APPARTMENTSTEST,
+		CollectionClass::new(AssetClass::APPARTMENT, 3),
 		metadata0
 	));
 	// Bob creates a proposal without submiting for review
 
 	assert_ok!(OnboardingModule::create_and_submit_proposal(
 		Origin::signed(BOB),
-		NftColl::OFFICESTEST,
+		OFFICESTEST,
 		Some(price1),
 		metadata1,
 		false,
-		3
+		Some(3),
+		None
 	));
 
 	assert_ok!(OnboardingModule::create_and_submit_proposal(
 		Origin::signed(BOB),
-		NftColl::APPARTMENTSTEST,
+		APPARTMENTSTEST,
 		Some(price2),
 		metadata2,
 		false,
-		3
+		Some(3),
+		None
 	));
 }
 
@@ -80,19 +85,23 @@ fn share_distributor0() {
 		let price1 = 40_000;
 		let price2 = 30_000;
 		prep_test(price1, price2, metadata0, metadata1, metadata2);
-		let coll_id0 = NftColl::OFFICESTEST.value();
-		let item_id0 = pallet_nft::ItemsCount::<Test>::get()[coll_id0 as usize] - 1;
+		let coll_id0 = OFFICESTEST;
+		let item_id0 = NftModule::next_item_id(coll_id0) - 1;
 		let origin: OriginFor<Test> = frame_system::RawOrigin::Root.into();
-		let origin2 = Origin::signed(BOB);
 
 		//Change first asset status to FINALISED
-		Onboarding::Pallet::<Test>::change_status(
-			origin2.clone(),
-			NftColl::OFFICESTEST,
+		assert_ok!(Onboarding::Pallet::<Test>::move_asset(
+			coll_id0,
 			item_id0,
-			Onboarding::AssetStatus::FINALISED,
-		)
-		.ok();
+			&[
+				AssetStatus::APPRAISING,
+				AssetStatus::REVIEWING,
+				AssetStatus::VOTING,
+				AssetStatus::ONBOARDED,
+				AssetStatus::FINALISING,
+				AssetStatus::FINALISED,
+			],
+		));
 
 		//Store initial owner
 		let old_owner0 = pallet_nft::Pallet::<Test>::owner(coll_id0, item_id0).unwrap();
@@ -139,20 +148,25 @@ fn share_distributor0() {
 		println!("Total supply {:?}", Assets::Pallet::<Test>::total_supply(id));
 
 		// Bob creates a second proposal without submiting for review
-		let coll_id1 = NftColl::APPARTMENTSTEST.value();
-		let item_id1 = pallet_nft::ItemsCount::<Test>::get()[coll_id1 as usize] - 1;
+		let coll_id1 = APPARTMENTSTEST;
+		let item_id1 = NftModule::next_item_id(coll_id1) - 1;
 
 		//Store initial owner
 		let old_owner1 = pallet_nft::Pallet::<Test>::owner(coll_id1, item_id1).unwrap();
 
 		//Change first asset status to FINALISED
-		Onboarding::Pallet::<Test>::change_status(
-			origin2,
-			NftColl::APPARTMENTSTEST,
+		assert_ok!(Onboarding::Pallet::<Test>::move_asset(
+			coll_id1,
 			item_id1,
-			Onboarding::AssetStatus::FINALISED,
-		)
-		.ok();
+			&[
+				AssetStatus::APPRAISING,
+				AssetStatus::REVIEWING,
+				AssetStatus::VOTING,
+				AssetStatus::ONBOARDED,
+				AssetStatus::FINALISING,
+				AssetStatus::FINALISED,
+			],
+		));
 
 		//Execute virtual account transactions
 		assert_ok!(ShareDistributor::virtual_account(coll_id1, item_id1));
@@ -192,10 +206,9 @@ fn share_distributor1() {
 		let price1 = 40_000;
 		let price2 = 30_000;
 		prep_test(price1, price2, metadata0, metadata1, metadata2);
-		let coll_id0 = NftColl::OFFICESTEST.value();
-		let item_id0 = pallet_nft::ItemsCount::<Test>::get()[coll_id0 as usize] - 1;
+		let coll_id0 = OFFICESTEST;
+		let item_id0 = NftModule::next_item_id(coll_id0) - 1;
 		let origin: OriginFor<Test> = frame_system::RawOrigin::Root.into();
-		let origin2 = Origin::signed(BOB);
 
 		let contribution_eve = HousingFund::Contribution {
 			account_id: EVE,
@@ -207,6 +220,7 @@ fn share_distributor1() {
 			contributions: vec![HousingFund::ContributionLog {
 				amount: HousingFund::Pallet::<Test>::u64_to_balance_option(15_000).unwrap(),
 				block_number: 1,
+				lock: None,
 			}],
 			withdraws: Vec::new(),
 		};
@@ -221,6 +235,7 @@ fn share_distributor1() {
 			contributions: vec![HousingFund::ContributionLog {
 				amount: HousingFund::Pallet::<Test>::u64_to_balance_option(25_000).unwrap(),
 				block_number: 1,
+				lock: None,
 			}],
 			withdraws: Vec::new(),
 		};
@@ -247,13 +262,18 @@ fn share_distributor1() {
 		});
 
 		//Change first asset status to FINALISED
-		Onboarding::Pallet::<Test>::change_status(
-			origin2,
-			NftColl::OFFICESTEST,
+		assert_ok!(Onboarding::Pallet::<Test>::move_asset(
+			coll_id0,
 			item_id0,
-			Onboarding::AssetStatus::FINALISED,
-		)
-		.ok();
+			&[
+				AssetStatus::APPRAISING,
+				AssetStatus::REVIEWING,
+				AssetStatus::VOTING,
+				AssetStatus::ONBOARDED,
+				AssetStatus::FINALISING,
+				AssetStatus::FINALISED,
+			],
+		));
 
 		//Store initial owner
 		let old_owner0 = pallet_nft::Pallet::<Test>::owner(coll_id0, item_id0).unwrap();
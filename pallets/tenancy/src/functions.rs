@@ -29,15 +29,12 @@ impl<T: Config> Pallet<T> {
 		let total_rent = tenant.remaining_rent;
 		let remaining_p = tenant.remaining_payments;
 		let rent0: u128 = Self::roles_bal_to_u128(tenant.rent).unwrap();
-		let rent = Self::u128_to_balance_option(rent0).unwrap();
+		let rent = Payment::Pallet::<T>::u128_to_balance_option(rent0).unwrap();
 		let asset_account = tenant.asset_account.unwrap();
-		<T as Config>::Currency::transfer(
-			&tenant_account,
-			&asset_account,
-			rent,
-			ExistenceRequirement::AllowDeath,
-		)
-		.ok();
+		//the rent is paid in the currency chosen for the asset
+		let payment_asset = Assets::Pallet::<T>::payment_asset(&asset_account);
+		Payment::Pallet::<T>::transfer_asset(&payment_asset, &tenant_account, &asset_account, rent)
+			.ok();
 
 		Roles::TenantLog::<T>::mutate(tenant_account, |val| {
 			let mut val0 = val.clone().unwrap();
@@ -72,13 +69,15 @@ impl<T: Config> Pallet<T> {
 		let tenant = ensure_signed(from.clone())?;
 
 		//Accept and pay the guaranty
-		Payment::Pallet::<T>::accept_and_pay(from, virtual_account.clone()).ok();
+		let payment_id =
+			Assets::Pallet::<T>::guaranty(&tenant, &virtual_account).unwrap().payment_id;
+		Payment::Pallet::<T>::accept_and_pay(from, virtual_account.clone(), payment_id).ok();
 		let origin2 = frame_system::RawOrigin::Signed(virtual_account.clone());
 
 		//Change payment state in Asset_Management storage
 		Assets::GuarantyPayment::<T>::mutate(tenant.clone(), virtual_account, |val| {
 			let mut infos = val.clone().unwrap();
-			infos.state = Payment::PaymentState::PaymentCompleted;
+			infos.detail.state = Payment::PaymentState::PaymentCompleted;
 			*val = Some(infos);
 		});
 
@@ -161,13 +161,13 @@ pub mod pallet {
 		/// after providing personal information requested by the Representative.
 		/// The origin must be the tenant accountId.
 		/// - info: Tenant personnal information requested by the asset Representative
-		/// - asset_type: Asset class requested by the tenant.
+		/// - collection_id: collection of the asset requested by the tenant.
 		/// - asset_id: ID of the Asset requested by the tenant.
 		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1).ref_time())]
 		pub fn request_asset(
 			origin: OriginFor<T>,
 			info: Box<IdentityInfo<T::MaxAdditionalFields>>,
-			asset_type: Nft::PossibleCollections,
+			collection_id: T::NftCollectionId,
 			asset_id: T::NftItemId,
 		) -> DispatchResult {
 			let caller = ensure_signed(origin.clone())?;
@@ -176,7 +176,6 @@ pub mod pallet {
 			
 
 			// Ensure that the asset is valid
-			let collection_id: T::NftCollectionId = asset_type.value().into();
 			let ownership = Share::Pallet::<T>::virtual_acc(collection_id, asset_id);
 			ensure!(ownership.is_some(), Error::<T>::NotAnAsset);
 			let virtual_account = ownership.unwrap().virtual_account;
@@ -215,12 +214,12 @@ pub mod pallet {
 		/// The function below allows the newly selected tenant to pay for a guaranty deposit
 		/// request and confirms the start of his contract.
 		/// The origin must be the tenant.
-		/// - asset_type: Asset class requested by the tenant.
+		/// - collection_id: collection of the asset requested by the tenant.
 		/// - asset_id: ID of the Asset requested by the tenant.
 		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1).ref_time())]
 		pub fn pay_guaranty_deposit(
 			origin: OriginFor<T>,
-			asset_type: Nft::PossibleCollections,
+			collection_id: T::NftCollectionId,
 			asset_id: T::NftItemId,
 		) -> DispatchResult {
 			let caller = ensure_signed(origin.clone())?;
@@ -228,7 +227,6 @@ pub mod pallet {
 			ensure!(Roles::TenantLog::<T>::contains_key(&caller), Error::<T>::NotATenant);
 
 			// Ensure that the asset is valid
-			let collection_id: T::NftCollectionId = asset_type.value().into();
 			let ownership = Share::Pallet::<T>::virtual_acc(collection_id, asset_id);
 			ensure!(ownership.is_some(), Error::<T>::NotAnAsset);
 			let virtual_account = ownership.unwrap().virtual_account;
@@ -239,7 +237,7 @@ pub mod pallet {
 				Error::<T>::NotAValidPayment
 			);
 			let payment_infos = Assets::Pallet::<T>::guaranty(&caller, &virtual_account).unwrap();
-			let status = payment_infos.state;
+			let status = payment_infos.detail.state;
 			ensure!(
 				status == Payment::PaymentState::PaymentRequested,
 				Error::<T>::NotAValidPayment
@@ -252,7 +250,7 @@ pub mod pallet {
 				tenant: caller,
 				when: now,
 				asset_account: virtual_account,
-				amount: payment_infos.amount,
+				amount: payment_infos.detail.amount,
 			});
 
 			Ok(())
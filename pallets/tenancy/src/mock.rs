@@ -2,12 +2,14 @@ use super::*;
 pub use crate as pallet_tenancy;
 use frame_support::{
 	parameter_types,
-	traits::{AsEnsureOriginWithArg, ConstU16, ConstU32, ConstU64, EqualPrivilegeOnly},
+	traits::{
+		AsEnsureOriginWithArg, ConstU16, ConstU32, ConstU64, EitherOfDiverse, EqualPrivilegeOnly,
+	},
 	weights::Weight,
 	PalletId,
 };
 
-use crate::Nft::NftPermissions;
+pub use crate::Nft::{AssetClass, CollectionClass, NftPermissions};
 use frame_system as system;
 use frame_system::{EnsureRoot, EnsureSigned};
 use pallet_collective::{Instance1, PrimeDefaultVote};
@@ -30,7 +32,8 @@ pub type MaxProposals = u32;
 pub type BlockNumber = u64;
 pub type CollectionId = u32;
 pub type ItemId = u32;
-pub type NftColl = Nft::PossibleCollections;
+pub const OFFICESTEST: CollectionId = 5;
+pub const APPARTMENTSTEST: CollectionId = 6;
 
 pub const RETURN_ON_RENT: u8 = 3;
 
@@ -53,9 +56,10 @@ frame_support::construct_runtime!(
 		Uniques: pallet_uniques::{Pallet, Call, Storage, Event<T>},
 		Payment: pallet_payment::{Pallet, Call, Storage, Event<T>},
 		OnboardingModule: pallet_onboarding::{Pallet, Call, Storage, Event<T>},
-		VotingModule: pallet_voting::{Pallet, Call, Storage, Event<T>},
+		VotingModule: pallet_voting::{Pallet, Call, Storage, Event<T>, Origin<T>},
 		Assets: pallet_assets::{Pallet, Storage, Config<T>, Event<T>},
 		HousingFund: pallet_housing_fund::{Pallet, Call, Storage,Event<T>},
+		FundAssets: pallet_assets::<Instance1>::{Pallet, Storage, Event<T>},
 		Collective: pallet_collective::<Instance1>::{Pallet, Call, Event<T>, Origin<T>, Config<T>},
 		Democracy: pallet_democracy::{Pallet, Call, Storage, Config<T>, Event<T>},
 		Scheduler: pallet_scheduler::{Pallet, Call, Storage, Event<T>},
@@ -105,8 +109,8 @@ impl pallet_tenancy::Config for Test {
 
 pub struct MockDisputeResolver;
 impl pallet_payment::DisputeResolver<AccountId> for MockDisputeResolver {
-	fn get_resolver_account() -> AccountId {
-		RESOLVER_ACCOUNT
+	fn get_resolver_account(_from: &AccountId, _to: &AccountId) -> Option<AccountId> {
+		Some(RESOLVER_ACCOUNT)
 	}
 }
 
@@ -130,18 +134,40 @@ parameter_types! {
 	pub const IncentivePercentage: Percent = Percent::from_percent(INCENTIVE_PERCENTAGE);
 	pub const MaxRemarkLength: u32 = 50;
 	pub const CancelBufferBlockLength: u64 = CANCEL_BLOCK_BUFFER;
-	pub const MaxScheduledTaskListLength : u32 = 5;
+	pub const PaymentExpiry: u64 = 100_000;
+	pub const PaymentRequestExpiry: u64 = 10_000;
+	pub const MaxPaymentHistory: u32 = 10;
+	pub const DisputeResolutionPeriod: u64 = 20;
+	pub const AppealPeriod: u64 = 10;
+	pub const AppealResolutionPeriod: u64 = 30;
+	pub const DefaultRecipientShare: Percent = Percent::from_percent(0);
+	pub const MaxEvidences: u32 = 3;
+	pub const MaxBatchSize: u32 = 5;
+	pub const PaymentPalletId: PalletId = PalletId(*b"py/paymt");
 }
 
 impl pallet_payment::Config for Test {
 	type Event = Event;
 	type Currency = Balances;
+	type Assets = Assets;
+	type PalletId = PaymentPalletId;
 	type DisputeResolver = MockDisputeResolver;
 	type IncentivePercentage = IncentivePercentage;
 	type FeeHandler = MockFeeHandler;
+	type FeeOrigin = EnsureRoot<AccountId>;
 	type MaxRemarkLength = MaxRemarkLength;
 	type CancelBufferBlockLength = CancelBufferBlockLength;
-	type MaxScheduledTaskListLength = MaxScheduledTaskListLength;
+	type PaymentExpiry = PaymentExpiry;
+	type PaymentRequestExpiry = PaymentRequestExpiry;
+	type OnPaymentExpiry = AssetManagement;
+	type MaxPaymentHistory = MaxPaymentHistory;
+	type AppealOrigin = EnsureRoot<AccountId>;
+	type DisputeResolutionPeriod = DisputeResolutionPeriod;
+	type AppealPeriod = AppealPeriod;
+	type AppealResolutionPeriod = AppealResolutionPeriod;
+	type DefaultRecipientShare = DefaultRecipientShare;
+	type MaxEvidences = MaxEvidences;
+	type MaxBatchSize = MaxBatchSize;
 	type WeightInfo = ();
 }
 
@@ -232,6 +258,8 @@ parameter_types! {
 	pub const MaximumSharePerInvestor: u64 = 20;
 	pub const MinimumSharePerInvestor: u64 = 10;
 	pub const NewAssetScanPeriod: u64 = 20;
+	pub const MaxAssetsProcessedPerBlock: u32 = 3;
+	pub const MaxQueuedAssets: u32 = 5;
 }
 
 impl pallet_bidding::Config for Test {
@@ -243,7 +271,11 @@ impl pallet_bidding::Config for Test {
 	type MaxTriesAseemblingInvestor = MaxTriesAseemblingInvestor;
 	type MaximumSharePerInvestor = MaximumSharePerInvestor;
 	type MinimumSharePerInvestor = MinimumSharePerInvestor;
+	type SelectionStrategy = pallet_bidding::OldestFirst;
 	type NewAssetScanPeriod = NewAssetScanPeriod;
+	type MaxAssetsProcessedPerBlock = MaxAssetsProcessedPerBlock;
+	type MaxQueuedAssets = MaxQueuedAssets;
+	type PriorityOrigin = EnsureRoot<AccountId>;
 }
 
 parameter_types! {
@@ -316,6 +348,8 @@ impl pallet_balances::Config for Test {
 
 parameter_types! {
 	pub ReserveCollectionIdUpTo: u32 = 3;
+	pub const MaxMetadataRevisions: u32 = 3;
+	pub const MaxAttributesPerItem: u32 = 5;
 }
 impl pallet_nft::Config for Test {
 	type Event = Event;
@@ -325,6 +359,10 @@ impl pallet_nft::Config for Test {
 	type ProtocolOrigin = EnsureRoot<AccountId>;
 	type Permissions = NftPermissions;
 	type ReserveCollectionIdUpTo = ReserveCollectionIdUpTo;
+	type ItemStages = ();
+	type TokenFreezer = ShareDistributor;
+	type MaxMetadataRevisions = MaxMetadataRevisions;
+	type MaxAttributesPerItem = MaxAttributesPerItem;
 }
 
 parameter_types! {
@@ -346,11 +384,18 @@ impl pallet_voting::Config for Test {
 		pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 1, 2>;
 	type MinimumDepositVote = MinimumDeposit;
 	type CheckPeriod = CheckPeriod;
+	type VotingOrigin = Origin;
 }
 
 parameter_types! {
 	pub const ProposalFee:Percent = Percent::from_percent(5);
 	pub const SlashedFee: Percent = Percent::from_percent(10);
+	pub const SaleFee: Percent = Percent::from_percent(20);
+	pub const FundFeeShare: Percent = Percent::from_percent(0);
+	pub const AppraisalBand: Percent = Percent::from_percent(10);
+	pub const MaxAppraisalFee: Percent = Percent::from_percent(1);
+	pub const CounterOfferPeriod: BlockNumber = 10;
+	pub const ExpiryScanLimit: u32 = 2;
 	pub const FeesAccount: PalletId = PalletId(*b"feeslash");
 }
 
@@ -360,8 +405,22 @@ impl pallet_onboarding::Config for Test {
 	type Prop = Call;
 	type ProposalFee = ProposalFee;
 	type Slash = SlashedFee;
+	type SaleFee = SaleFee;
+	type FundFeeShare = FundFeeShare;
+	type MaxInspectionReports = frame_support::traits::ConstU32<5>;
+	type AppraisalBand = AppraisalBand;
+	type MaxAppraisalFee = MaxAppraisalFee;
+	type CounterOfferPeriod = CounterOfferPeriod;
+	type MaxCounterOffers = frame_support::traits::ConstU32<10>;
+	type ExpiryScanLimit = ExpiryScanLimit;
 	type WeightInfo = ();
 	type FeesAccount = FeesAccount;
+	type OnStatusChange = (Bidding, Finalise);
+	type MaxStatusHistory = frame_support::traits::ConstU32<20>;
+	type CouncilOrigin =
+		EitherOfDiverse<EnsureRoot<AccountId>, pallet_voting::EnsureCouncilDecision<Test>>;
+	type DemocracyOrigin =
+		EitherOfDiverse<EnsureRoot<AccountId>, pallet_voting::EnsureInvestorsDecision<Test>>;
 }
 
 //---implementing pallet sudo---------
@@ -401,7 +460,7 @@ impl pallet_assets::Config for Test {
 	type MetadataDepositPerByte = MetadataDepositPerByte;
 	type ApprovalDeposit = ApprovalDeposit;
 	type StringLimit = StringLimit;
-	type Freezer = ();
+	type Freezer = Payment;
 	type Extra = ();
 	type WeightInfo = ();
 }
@@ -421,6 +480,8 @@ parameter_types! {
 	pub const FundThreshold: u64 = 100;
 	pub const MaxFundContribution: u64 = 20;
 	pub const MaxInvestorPerHouse: u32 = 10;
+	pub const MaxExcludedSellers: u32 = 5;
+	pub const MaxPreferredCollections: u32 = 10;
 	pub const HousingFundPalletId: PalletId = PalletId(*b"housfund");
 }
 
@@ -434,6 +495,38 @@ impl pallet_housing_fund::Config for Test {
 	type WeightInfo = ();
 	type PalletId = HousingFundPalletId;
 	type MaxInvestorPerHouse = MaxInvestorPerHouse;
+	type MaxExcludedSellers = MaxExcludedSellers;
+	type MaxPreferredCollections = MaxPreferredCollections;
+	type FundAssets = FundAssets;
+	type FundAssetId = FundAssetId;
+	type MaxWithdrawalsProcessedPerBlock = MaxWithdrawalsProcessedPerBlock;
+	type MaxQueuedWithdrawals = MaxQueuedWithdrawals;
+	type BlocksPerMonth = BlocksPerMonth;
+	type PauseOrigin = EnsureRoot<AccountId>;
+}
+
+parameter_types! {
+	pub const FundAssetId: u32 = 0;
+	pub const MaxWithdrawalsProcessedPerBlock: u32 = 2;
+	pub const MaxQueuedWithdrawals: u32 = 10;
+	pub const BlocksPerMonth: u64 = 10;
+}
+
+impl pallet_assets::Config<pallet_assets::Instance1> for Test {
+	type Event = Event;
+	type Balance = Balance;
+	type AssetId = u32;
+	type Currency = Balances;
+	type ForceOrigin = EnsureRoot<AccountId>;
+	type AssetDeposit = ();
+	type AssetAccountDeposit = ();
+	type MetadataDepositBase = ();
+	type MetadataDepositPerByte = ();
+	type ApprovalDeposit = ();
+	type StringLimit = frame_support::traits::ConstU32<50>;
+	type Freezer = HousingFund;
+	type Extra = ();
+	type WeightInfo = ();
 }
 
 parameter_types! {
@@ -444,6 +537,7 @@ parameter_types! {
 	pub const ContractLength: BlockNumber = 365;
 	pub const Lease: u32 = 12;
 	pub const Maintenance:Percent = Percent::from_percent(3);
+	pub const FundRentShare: Percent = Percent::from_percent(10);
 }
 
 impl pallet_asset_management::Config for Test {
@@ -462,6 +556,7 @@ impl pallet_asset_management::Config for Test {
 	type RoR = RoR;
 	type Lease = Lease;
 	type Maintenance = Maintenance;
+	type FundRentShare = FundRentShare;
 	type WeightInfo = ();
 }
 pub const ALICE: AccountId = AccountId::new([1u8; 32]);
@@ -481,6 +576,7 @@ pub const FEE_RECIPIENT_ACCOUNT: AccountId = AccountId::new([20u8; 32]);
 pub const PAYMENT_RECIPENT_FEE_CHARGED: AccountId = AccountId::new([21u8; 32]);
 pub const NOTARY: AccountId = AccountId::new([30u8; 32]);
 pub const SALIM: AccountId = AccountId::new([31u8; 32]);
+pub const APPRAISER: AccountId = AccountId::new([32u8; 32]);
 
 pub const INCENTIVE_PERCENTAGE: u8 = 10;
 pub const MARKETPLACE_FEE_PERCENTAGE: u8 = 10;
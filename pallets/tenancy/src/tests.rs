@@ -11,6 +11,8 @@ pub fn prep_roles() {
 	RoleModule::account_approval(Origin::signed(ALICE), BOB).ok();
 	assert_ok!(RoleModule::set_role(Origin::signed(NOTARY), NOTARY, Acc::NOTARY));
 	assert_ok!(RoleModule::account_approval(Origin::signed(ALICE), NOTARY));
+	assert_ok!(RoleModule::set_role(Origin::signed(APPRAISER), APPRAISER, Acc::APPRAISER));
+	assert_ok!(RoleModule::account_approval(Origin::signed(ALICE), APPRAISER));
 	assert_ok!(RoleModule::set_role(
 		Origin::signed(REPRESENTATIVE),
 		REPRESENTATIVE,
@@ -27,6 +29,20 @@ pub fn prep_roles() {
 	RoleModule::set_role(Origin::signed(TENANT1), TENANT1, Acc::TENANT).ok();
 }
 
+/// Details of a property, valid for the offices and the appartments
+pub fn property_details() -> pallet_onboarding::PropertyDetails<Test> {
+	pallet_onboarding::PropertyDetails {
+		address: b"1 Dam Square, Amsterdam".to_vec().try_into().unwrap(),
+		geohash: None,
+		surface: 120,
+		rooms: 3,
+		year_built: None,
+		energy_rating: Some(pallet_onboarding::EnergyRating::B),
+		deed_hash: Some(sp_core::H256::repeat_byte(1)),
+		inspection_reports: Default::default(),
+	}
+}
+
 pub fn prep_test(price1: u64, price2: u64, metadata0: Bvec<Test>, metadata1: Bvec<Test>) {
 	prep_roles();
 
@@ -44,33 +60,59 @@ pub fn prep_test(price1: u64, price2: u64, metadata0: Bvec<Test>, metadata1: Bve
 	//Charlie creates a collection
 	assert_ok!(NftModule::create_collection(
 		Origin::signed(CHARLIE),
-		NftColl::OFFICESTEST,
+		OFFICESTEST,
+		CollectionClass::new(AssetClass::OFFICE, 3),
 		metadata0.clone()
 	));
 	//Charlie creates a second collection
 	assert_ok!(NftModule::create_collection(
 		Origin::signed(CHARLIE),
-		NftColl::APPARTMENTSTEST,
+		APPARTMENTSTEST,
+		CollectionClass::new(AssetClass::APPARTMENT, 3),
 		metadata0
 	));
 	// Bob creates and submit a proposal
 
 	assert_ok!(OnboardingModule::create_and_submit_proposal(
 		Origin::signed(BOB),
-		NftColl::OFFICESTEST,
+		OFFICESTEST,
 		Some(price1),
 		metadata1.clone(),
 		true,
-		3
+		Some(3),
+		Some(property_details())
 	));
 
 	assert_ok!(OnboardingModule::create_and_submit_proposal(
 		Origin::signed(BOB),
-		NftColl::APPARTMENTSTEST,
+		APPARTMENTSTEST,
 		Some(price2),
 		metadata1,
 		true,
-		3
+		Some(3),
+		Some(property_details())
+	));
+
+	// The appraiser values both assets at their price
+	let coll_id0 = OFFICESTEST;
+	let item_id0 = NftModule::next_item_id(coll_id0) - 1;
+	let coll_id1 = APPARTMENTSTEST;
+	let item_id1 = NftModule::next_item_id(coll_id1) - 1;
+	assert_ok!(OnboardingModule::submit_appraisal(
+		Origin::signed(APPRAISER),
+		OFFICESTEST,
+		item_id0,
+		price1,
+		Default::default(),
+		0
+	));
+	assert_ok!(OnboardingModule::submit_appraisal(
+		Origin::signed(APPRAISER),
+		APPARTMENTSTEST,
+		item_id1,
+		price2,
+		Default::default(),
+		0
 	));
 
 	//Get the proposal hash
@@ -85,13 +127,9 @@ pub fn prep_test(price1: u64, price2: u64, metadata0: Bvec<Test>, metadata1: Bve
 	assert_eq!(infos.proposal_hash, hash0);
 	assert_eq!(infos1.proposal_hash, hash1);
 
-	let coll_id0 = NftColl::OFFICESTEST.value();
-	let item_id0 = pallet_nft::ItemsCount::<Test>::get()[coll_id0 as usize] - 1;
 	let mut house = OnboardingModule::houses(coll_id0, item_id0).unwrap();
 	assert_eq!(house.status, pallet_onboarding::AssetStatus::REVIEWING);
 
-	let coll_id1 = NftColl::APPARTMENTSTEST.value();
-	let item_id1 = pallet_nft::ItemsCount::<Test>::get()[coll_id1 as usize] - 1;
 	let mut house1 = OnboardingModule::houses(coll_id1, item_id1).unwrap();
 	assert_eq!(house1.status, pallet_onboarding::AssetStatus::REVIEWING);
 
@@ -299,7 +337,7 @@ pub fn prep_test(price1: u64, price2: u64, metadata0: Bvec<Test>, metadata1: Bve
 
 	assert_ok!(AssetManagement::launch_representative_session(
 		Origin::signed(SALIM),
-		NftColl::OFFICESTEST,
+		OFFICESTEST,
 		item_id0,
 		REPRESENTATIVE,
 		pallet_asset_management::VoteProposals::Election,
@@ -361,7 +399,7 @@ pub fn prep_test(price1: u64, price2: u64, metadata0: Bvec<Test>, metadata1: Bve
 
 	assert_ok!(AssetManagement::launch_representative_session(
 		Origin::signed(SALIM),
-		NftColl::APPARTMENTSTEST,
+		APPARTMENTSTEST,
 		item_id1,
 		REPRESENTATIVE,
 		pallet_asset_management::VoteProposals::Election,
@@ -414,14 +452,14 @@ pub fn prep_test(price1: u64, price2: u64, metadata0: Bvec<Test>, metadata1: Bve
 	assert_ok!(crate::Pallet::<Test>::request_asset(
 		Origin::signed(TENANT0),
 		Box::new(ten0()),
-		NftColl::OFFICESTEST,
+		OFFICESTEST,
 		item_id0,
 	));
 
 	assert_ok!(crate::Pallet::<Test>::request_asset(
 		Origin::signed(TENANT1),
 		Box::new(ten1()),
-		NftColl::OFFICESTEST,
+		OFFICESTEST,
 		item_id0,
 	));
 
@@ -436,7 +474,7 @@ pub fn prep_test(price1: u64, price2: u64, metadata0: Bvec<Test>, metadata1: Bve
 	//Representative gives a positive Judgement and start a referendum for the tenant0
 	assert_ok!(AssetManagement::launch_tenant_session(
 		Origin::signed(REPRESENTATIVE),
-		NftColl::OFFICESTEST,
+		OFFICESTEST,
 		item_id0,
 		TENANT0,
 		pallet_asset_management::VoteProposals::Election,
@@ -446,7 +484,7 @@ pub fn prep_test(price1: u64, price2: u64, metadata0: Bvec<Test>, metadata1: Bve
 	//Representative gives a positive Judgement and start a referendum for the tenant1
 	assert_ok!(AssetManagement::launch_tenant_session(
 		Origin::signed(REPRESENTATIVE),
-		NftColl::OFFICESTEST,
+		OFFICESTEST,
 		item_id0,
 		TENANT1,
 		pallet_asset_management::VoteProposals::Election,
@@ -491,10 +529,10 @@ pub fn prep_test(price1: u64, price2: u64, metadata0: Bvec<Test>, metadata1: Bve
 
 	//Check that a guaranty_payment request was sent to the tenant
 	let payment_info0 = AssetManagement::guaranty(TENANT0, asset_account.clone()).unwrap();
-	assert_eq!(payment_info0.state, pallet_payment::PaymentState::PaymentRequested);
+	assert_eq!(payment_info0.detail.state, pallet_payment::PaymentState::PaymentRequested);
 
 	let payment_info1 = AssetManagement::guaranty(TENANT1, asset_account.clone()).unwrap();
-	assert_eq!(payment_info1.state, pallet_payment::PaymentState::PaymentRequested);
+	assert_eq!(payment_info1.detail.state, pallet_payment::PaymentState::PaymentRequested);
 
 	//Tenants pay the Guaranty Deposit
 	let tenant_init_balance = Balances::free_balance(TENANT0);
@@ -502,13 +540,13 @@ pub fn prep_test(price1: u64, price2: u64, metadata0: Bvec<Test>, metadata1: Bve
 
 	assert_ok!(crate::Pallet::<Test>::pay_guaranty_deposit(
 		Origin::signed(TENANT0),
-		NftColl::OFFICESTEST,
+		OFFICESTEST,
 		item_id0,
 	));
 
 	assert_ok!(crate::Pallet::<Test>::pay_guaranty_deposit(
 		Origin::signed(TENANT1),
-		NftColl::OFFICESTEST,
+		OFFICESTEST,
 		item_id0,
 	));
 	let payed_amount = tenant_init_balance.saturating_sub(Balances::free_balance(TENANT0));
@@ -633,12 +671,13 @@ fn test_00() {
 
 		//Let's check that rent transfer toward virtual account occured
 		let virtual_balance = Balances::free_balance(tenant0_inf.asset_account.unwrap());
-		let coll_id0 = NftColl::OFFICESTEST.value();
-		let item_id0 = pallet_nft::ItemsCount::<Test>::get()[coll_id0 as usize] - 1;
+		let coll_id0 = OFFICESTEST;
+		let item_id0 = NftModule::next_item_id(coll_id0) - 1;
 		let asset_ownership = ShareDistributor::virtual_acc(coll_id0, item_id0).unwrap();
 		let owners = asset_ownership.owners;
 		let owner0 = &owners[0];
 		let owner0_initial_balance = Balances::free_balance(owner0);
+		let fund_initial_balance = Balances::free_balance(HousingFund::fund_account_id());
 
 		assert_ne!(virtual_initial_balance, virtual_balance);
 		assert_eq!(virtual_balance, virtual_initial_balance.saturating_add(tenant0_inf.rent));
@@ -652,6 +691,9 @@ fn test_00() {
 		let owner0_balance = Balances::free_balance(owner0);
 
 		assert_ne!(owner0_initial_balance, owner0_balance);
+
+		//Part of the rent went to the housing fund contributors
+		assert!(Balances::free_balance(HousingFund::fund_account_id()) > fund_initial_balance);
 		println!(
 			"\n\nOwner0 received:{:?}\nThe rent is:{:?}",
 			owner0_balance.saturating_sub(owner0_initial_balance),
@@ -7,14 +7,33 @@
 //!
 //! This pallet manage the voting of a proposal by the House Council and an investor assemblee
 //!
+//! The calls attached to the steps of a proposal are dispatched with the origin of this pallet,
+//! `RawOrigin::Council` or `RawOrigin::Investors` depending on who took the decision. Other pallets
+//! accept these decisions with `EnsureCouncilDecision` and `EnsureInvestorsDecision`.
+//!
 //! #### Dispatchable Functions
-//! * 'submit_proposal' - an account with the seller role submit a proposal for a house purchase
 //! * 'call_democracy_proposal' - configure a proposal to go through the democracy vote processing
 //! * 'call_dispatch' - execute the house purchase proposal
 //! * 'council_vote' - a member of the House Council vote for the first step going through the
 //!   Collective pallet
 //! * 'council_close_vote' - a member of the House Council close the collective vote session
 //! * 'investor_vote' - an investor vote for the proposal during the democracy voting step
+//! * 'council_motion' - a member of the House Council submits a proposal to the council vote only
+//! * 'call_council_decision' - execute a proposal approved by the House Council
+//!
+//! A proposal for a house purchase is submitted by the onboarding pallet with `submit_proposal`,
+//! on behalf of the seller.
+//!
+//! The pending proposal of a withdrawn asset is removed with `cancel_proposal`, which stops its
+//! collective motion or referendum.
+//!
+//! A proposal already approved by the House Council, like a price it offered, goes straight to the
+//! investors vote with `submit_investor_proposal`.
+//!
+//! A member of the House Council submits a decision of the council alone, like a counter-offer on
+//! the price of an asset, with `council_motion`. The proposal is dispatched with
+//! `RawOrigin::Council` once the members approve the motion with `council_vote` and
+//! `council_close_vote`.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -77,6 +96,9 @@ pub mod pallet {
 		type InvestorVoteAmount: Get<u128>;
 		type LocalCurrency: ReservableCurrency<Self::AccountId>;
 		type HouseCouncilOrigin: EnsureOrigin<<Self as frame_system::Config>::Origin>;
+		/// The runtime origin built from the origin of this pallet
+		type VotingOrigin: From<RawOrigin<Self::Hash>>
+			+ Into<<Self as frame_system::Config>::Origin>;
 
 		#[pallet::constant]
 		type CheckPeriod: Get<Self::BlockNumber>;
@@ -90,6 +112,10 @@ pub mod pallet {
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
+	/// Origin of the calls dispatched at the end of a step of a proposal
+	#[pallet::origin]
+	pub type Origin<T> = RawOrigin<<T as frame_system::Config>::Hash>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn voting_proposals)]
 	pub type VotingProposals<T: Config> = StorageMap<
@@ -110,6 +136,17 @@ pub mod pallet {
 	pub type DemocracyProposals<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::Hash, BlockNumberOf<T>, OptionQuery>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn council_motions)]
+	/// Stores the motions submitted to the House Council vote only, by hash of their proposal
+	pub type CouncilMotions<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::Hash,
+		CouncilMotion<T, Box<<T as COLL::Config<Instance1>>::Proposal>>,
+		OptionQuery,
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -131,6 +168,8 @@ pub mod pallet {
 		CollectiveMotionFailed(BlockNumberOf<T>),
 		/// TODO: to remove, Event for test purpose
 		DemocracyMotionFailed(BlockNumberOf<T>),
+		/// A proposal has been cancelled before the end of its vote
+		ProposalCancelled(T::Hash, BlockNumberOf<T>),
 	}
 
 	// Errors inform users that something went wrong.
@@ -151,6 +190,8 @@ pub mod pallet {
 		ProposalDoesNotExist,
 		/// The collective proposal have failed
 		FailedToCreateCollectiveProposal,
+		/// The proposal is already submitted
+		ProposalAlreadyExists,
 	}
 
 	#[pallet::hooks]
@@ -163,8 +204,10 @@ pub mod pallet {
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
-		/// Submit a proposal through the voting process
-		/// The origin must be signed and have the Seller role
+		/// Submit a proposal through the voting process on behalf of a seller
+		/// The origin must be root, the proposals of the assets being built and submitted by the
+		/// onboarding pallet with `do_submit_proposal`
+		/// - account_id : the account with the Seller role issuing the proposal
 		/// - proposal : the proposal to be executed at the end of the vote process
 		/// - collective_passed_call : action to be executed when the proposal pass the collective
 		///   vote
@@ -175,96 +218,21 @@ pub mod pallet {
 		#[pallet::weight(10_000)]
 		pub fn submit_proposal(
 			origin: OriginFor<T>,
+			account_id: AccountIdOf<T>,
 			proposal: Box<<T as Config>::Call>,
 			collective_passed_call: Box<<T as Config>::Call>,
 			collective_failed_call: Box<<T as Config>::Call>,
 			democracy_failed_call: Box<<T as Config>::Call>,
 		) -> DispatchResultWithPostInfo {
-			// Check that the extrinsic was signed and get the signer
-			let who = ensure_signed(origin)?;
-
-			// Check that the account has the seller role
-			ensure!(ROLES::Pallet::<T>::sellers(who.clone()).is_some(), Error::<T>::NotASeller);
-
-			let proposal_hash = T::Hashing::hash_of(&proposal);
-
-			let council_member = COLL::Pallet::<T, Instance1>::members()[0].clone();
-			// create the final dispatch call of the proposal in democracy
-			let call = Call::<T>::call_dispatch {
-				account_id: council_member.clone(),
-				proposal_hash,
-				proposal: proposal.clone(),
-			};
-			let call_formatted = Self::get_formatted_call(call.into());
-			let call_dispatch = Box::new(call_formatted);
-
-			// create the democracy call to be proposed in collective
-			let democracy_call = Call::<T>::call_democracy_proposal {
-				account_id: who.clone(),
-				proposal_id: proposal_hash,
-				proposal: call_dispatch.clone(),
-			};
-
-			// call the collective propose
-			let democracy_call_formatted_wrap =
-				Self::get_formatted_collective_proposal(democracy_call.into());
-
-			// Check that the call to the democracy pallet is correctly created
-			ensure!(
-				democracy_call_formatted_wrap.is_some(),
-				Error::<T>::FailedToCreateCollectiveProposal
-			);
-
-			let democracy_call_formatted = Box::new(democracy_call_formatted_wrap.unwrap());
-
-			// Retrieve the index of the proposal in Collective pallet
-			let collective_index = COLL::Pallet::<T, Instance1>::proposal_count();
-
-			let collective_origin = Self::get_origin(council_member);
-
-			let result = COLL::Pallet::<T, Instance1>::propose(
-				collective_origin,
-				2,
-				democracy_call_formatted.clone(),
-				democracy_call_formatted.encoded_size() as u32,
-			);
-
-			match result {
-				Ok(_) => {},
-				Err(e) => return Err(e),
-			}
-
-			// create the VotingProposal
-			let voting_proposal: VotingProposal<T, Box<<T as COLL::Config<Instance1>>::Proposal>> =
-				VotingProposal::new(
-					who.clone(),
-					ProposalParams { call: proposal, hash: proposal_hash },
-					CollectiveParams {
-						call: democracy_call_formatted.clone(),
-						call_pass: collective_passed_call,
-						call_fail: collective_failed_call,
-						index: collective_index,
-						hash: T::Hashing::hash_of(&democracy_call_formatted),
-					},
-					DemocracyParams {
-						call_fail: democracy_failed_call,
-						hash: T::Hashing::hash_of(&call_dispatch),
-					},
-				);
-
-			VotingProposals::<T>::insert(proposal_hash, voting_proposal);
-
-			let block_number = <frame_system::Pallet<T>>::block_number();
-
-			let collective_motion_duration = block_number
-				.saturating_add(<T as COLL::Config<Instance1>>::MotionDuration::get())
-				.saturating_add(T::Delay::get());
-
-			// Add the proposal to the collective watchlist
-			CollectiveProposals::<T>::insert(proposal_hash, collective_motion_duration);
+			ensure_root(origin)?;
 
-			// deposit event
-			Self::deposit_event(Event::HouseCouncilAddedProposal(who, proposal_hash, block_number));
+			Self::do_submit_proposal(
+				account_id,
+				proposal,
+				collective_passed_call,
+				collective_failed_call,
+				democracy_failed_call,
+			)?;
 
 			Ok(().into())
 		}
@@ -288,52 +256,21 @@ pub mod pallet {
 				Error::<T>::ProposalDoesNotExist
 			);
 
-			let proposal_hash = T::Hashing::hash_of(&proposal);
-			let proposal_encoded: Vec<u8> = proposal.encode();
-
-			// Call Democracy note_pre_image
-			DEMO::Pallet::<T>::note_preimage(
-				Self::get_origin(account_id.clone()),
-				proposal_encoded,
-			)?;
-
-			let deposit = T::MinimumDepositVote::get();
-
 			// A part of the initial deposit is freed to be reserved in the Democracy::propose()
 			// function
-			T::LocalCurrency::unreserve(&account_id, deposit);
-
-			let threshold = DEMO::VoteThreshold::SimpleMajority;
-			let delay = <T as Config>::Delay::get();
-
-			// Start Democracy referendum
-
-			let referendum_index =
-				DEMO::Pallet::<T>::internal_start_referendum(proposal_hash, threshold, delay);
-
-			// Update the voting
-			let mut proposal = VotingProposals::<T>::get(proposal_id).unwrap();
-			proposal.democracy_referendum_index = referendum_index;
-			proposal.collective_step = true;
-
-			VotingProposals::<T>::mutate(proposal_id, |val| {
-				*val = Some(proposal.clone());
-			});
+			T::LocalCurrency::unreserve(&account_id, T::MinimumDepositVote::get());
 
-			let block_number = <frame_system::Pallet<T>>::block_number();
-			let democration_motion_duration = block_number
-				.saturating_add(<T as DEMO::Config>::VotingPeriod::get())
-				.saturating_add(delay);
-
-			// Set the the storage to be watched for the democracy process
-			DemocracyProposals::<T>::insert(proposal_id, democration_motion_duration);
+			let proposal_hash = T::Hashing::hash_of(&proposal);
+			Self::start_referendum(account_id, proposal_id, proposal)?;
 
-			// Execute the dispatch for collective vote passed
+			// Execute the dispatch for collective vote passed, on behalf of the council
+			let proposal = VotingProposals::<T>::get(proposal_id).unwrap();
 			proposal
 				.collective_passed_call
-				.dispatch_bypass_filter(frame_system::RawOrigin::Signed(account_id).into())
+				.dispatch_bypass_filter(Self::decision_origin(RawOrigin::Council(proposal_id)))
 				.ok();
 
+			let block_number = <frame_system::Pallet<T>>::block_number();
 			Self::deposit_event(Event::InvestorVoteSessionStarted(proposal_hash, block_number));
 
 			Ok(().into())
@@ -347,7 +284,7 @@ pub mod pallet {
 		#[pallet::weight(10_000)]
 		pub fn call_dispatch(
 			origin: OriginFor<T>,
-			account_id: AccountIdOf<T>,
+			_account_id: AccountIdOf<T>,
 			proposal_hash: T::Hash,
 			proposal: Box<<T as Config>::Call>,
 		) -> DispatchResultWithPostInfo {
@@ -361,9 +298,9 @@ pub mod pallet {
 				*val = Some(vote_proposal);
 			});
 
-			// The proposal is executed
+			// The proposal is executed on behalf of the referendum
 			proposal
-				.dispatch_bypass_filter(frame_system::RawOrigin::Signed(account_id).into())
+				.dispatch_bypass_filter(Self::decision_origin(RawOrigin::Investors(proposal_hash)))
 				.ok();
 			Ok(().into())
 		}
@@ -387,18 +324,14 @@ pub mod pallet {
 			);
 
 			// Check that the proposal exists
-			ensure!(
-				VotingProposals::<T>::contains_key(proposal_hash),
-				Error::<T>::ProposalDoesNotExist
-			);
-
-			let proposal = VotingProposals::<T>::get(proposal_hash).unwrap();
+			let (collective_hash, collective_index, _) =
+				Self::collective_motion(proposal_hash).ok_or(Error::<T>::ProposalDoesNotExist)?;
 
 			// Execute the collective vote
 			let result = COLL::Pallet::<T, Instance1>::vote(
 				origin,
-				proposal.collective_hash,
-				proposal.collective_index,
+				collective_hash,
+				collective_index,
 				approve,
 			);
 
@@ -430,19 +363,15 @@ pub mod pallet {
 			);
 
 			// Check that the proposal exists in the storage
-			ensure!(
-				VotingProposals::<T>::contains_key(proposal_hash),
-				Error::<T>::ProposalDoesNotExist
-			);
-
-			let proposal = VotingProposals::<T>::get(proposal_hash).unwrap();
-			let proposal_len = proposal.collective_call.encoded_size();
-			let proposal_weight = proposal.collective_call.get_dispatch_info().weight;
+			let (collective_hash, collective_index, collective_call) =
+				Self::collective_motion(proposal_hash).ok_or(Error::<T>::ProposalDoesNotExist)?;
+			let proposal_len = collective_call.encoded_size();
+			let proposal_weight = collective_call.get_dispatch_info().weight;
 
 			let result = COLL::Pallet::<T, Instance1>::close(
 				origin,
-				proposal.collective_hash,
-				proposal.collective_index,
+				collective_hash,
+				collective_index,
 				proposal_weight,
 				proposal_len as u32,
 			);
@@ -460,12 +389,13 @@ pub mod pallet {
 			}
 
 			// We set the flag making the democracy pass vote
-			let mut vote_proposal = VotingProposals::<T>::get(proposal_hash).unwrap();
-			vote_proposal.collective_closed = true;
-
 			VotingProposals::<T>::mutate(proposal_hash, |val| {
-				*val = Some(vote_proposal);
+				if let Some(vote_proposal) = val {
+					vote_proposal.collective_closed = true;
+				}
 			});
+			// A council motion is over once closed, approved or not
+			CouncilMotions::<T>::remove(proposal_hash);
 
 			Ok(().into())
 		}
@@ -522,10 +452,94 @@ pub mod pallet {
 
 			Ok(().into())
 		}
+
+		/// Submit a proposal to the House Council vote only, the proposal being dispatched as a
+		/// decision of the council once the motion is approved
+		/// The origin must be signed and member of the House Council
+		/// - proposal : the proposal to be executed when the House Council approves the motion
+		#[pallet::weight(10_000)]
+		pub fn council_motion(
+			origin: OriginFor<T>,
+			proposal: Box<<T as Config>::Call>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin.clone())?;
+
+			// Check that the caller is a member of the House Council
+			ensure!(
+				COLL::Pallet::<T, Instance1>::members().contains(&who),
+				Error::<T>::NotAHouseCouncilMember
+			);
+
+			let proposal_hash = T::Hashing::hash_of(&proposal);
+			ensure!(
+				!VotingProposals::<T>::contains_key(proposal_hash) &&
+					!CouncilMotions::<T>::contains_key(proposal_hash),
+				Error::<T>::ProposalAlreadyExists
+			);
+
+			// create the call dispatching the proposal, proposed in collective
+			let call = Call::<T>::call_council_decision { proposal_hash, proposal };
+			let collective_call = Box::new(
+				Self::get_formatted_collective_proposal(call.into())
+					.ok_or(Error::<T>::FailedToCreateCollectiveProposal)?,
+			);
+
+			// Retrieve the index of the proposal in Collective pallet
+			let collective_index = COLL::Pallet::<T, Instance1>::proposal_count();
+
+			COLL::Pallet::<T, Instance1>::propose(
+				origin,
+				2,
+				collective_call.clone(),
+				collective_call.encoded_size() as u32,
+			)
+			.map_err(|e| e.error)?;
+
+			CouncilMotions::<T>::insert(
+				proposal_hash,
+				CouncilMotion {
+					account_id: who.clone(),
+					collective_hash: T::Hashing::hash_of(&collective_call),
+					collective_index,
+					collective_call,
+				},
+			);
+
+			let block_number = <frame_system::Pallet<T>>::block_number();
+			Self::deposit_event(Event::HouseCouncilAddedProposal(who, proposal_hash, block_number));
+
+			Ok(().into())
+		}
+
+		/// Execute a proposal approved by the House Council, on behalf of the council
+		/// The origin must come from the collective palllet
+		/// - proposal_hash : hash of the proposal call
+		/// - proposal : the proposal approved by the House Council
+		#[pallet::weight(10_000)]
+		pub fn call_council_decision(
+			origin: OriginFor<T>,
+			proposal_hash: T::Hash,
+			proposal: Box<<T as Config>::Call>,
+		) -> DispatchResultWithPostInfo {
+			T::HouseCouncilOrigin::ensure_origin(origin)?;
+
+			CouncilMotions::<T>::take(proposal_hash).ok_or(Error::<T>::ProposalDoesNotExist)?;
+
+			proposal
+				.dispatch_bypass_filter(Self::decision_origin(RawOrigin::Council(proposal_hash)))
+				.map_err(|e| e.error)?;
+
+			Ok(().into())
+		}
 	}
 }
 
-use frame_support::dispatch::UnfilteredDispatchable;
+use frame_support::{
+	dispatch::{DispatchResult, UnfilteredDispatchable},
+	ensure,
+	sp_runtime::traits::Hash,
+};
+use scale_info::prelude::boxed::Box;
 
 impl<T: Config> Pallet<T> {
 	// Conversion of u64 to BalanxceOf<T>
@@ -561,6 +575,274 @@ impl<T: Config> Pallet<T> {
 		frame_system::RawOrigin::Signed(account_id).into()
 	}
 
+	/// The collective motion of a proposal or of a council motion: its hash, its index and its call
+	fn collective_motion(
+		proposal_hash: T::Hash,
+	) -> Option<(T::Hash, u32, Box<<T as COLL::Config<Instance1>>::Proposal>)> {
+		match VotingProposals::<T>::get(proposal_hash) {
+			Some(proposal) => Some((
+				proposal.collective_hash,
+				proposal.collective_index,
+				proposal.collective_call,
+			)),
+			None => CouncilMotions::<T>::get(proposal_hash).map(|motion| {
+				(motion.collective_hash, motion.collective_index, motion.collective_call)
+			}),
+		}
+	}
+
+	/// The origin of the calls dispatched on a decision taken on a proposal
+	fn decision_origin(origin: RawOrigin<T::Hash>) -> <T as frame_system::Config>::Origin {
+		T::VotingOrigin::from(origin).into()
+	}
+
+	/// Submit a proposal through the voting process
+	/// - who : the account with the Seller role issuing the proposal
+	/// - proposal : the proposal to be executed at the end of the vote process
+	/// - collective_passed_call : action to be executed when the proposal pass the collective vote
+	/// - collective_failed_call : action to be executed when the proposal fail the collective vote
+	/// - democracy_failed_call : action to be executed when the proposal fail the democracy vote
+	pub fn do_submit_proposal(
+		who: AccountIdOf<T>,
+		proposal: Box<<T as Config>::Call>,
+		collective_passed_call: Box<<T as Config>::Call>,
+		collective_failed_call: Box<<T as Config>::Call>,
+		democracy_failed_call: Box<<T as Config>::Call>,
+	) -> DispatchResult {
+		// Check that the account has the seller role
+		ensure!(ROLES::Pallet::<T>::sellers(who.clone()).is_some(), Error::<T>::NotASeller);
+
+		let proposal_hash = T::Hashing::hash_of(&proposal);
+
+		let council_member = COLL::Pallet::<T, Instance1>::members()[0].clone();
+		// create the final dispatch call of the proposal in democracy
+		let call = Call::<T>::call_dispatch {
+			_account_id: council_member.clone(),
+			proposal_hash,
+			proposal: proposal.clone(),
+		};
+		let call_formatted = Self::get_formatted_call(call.into());
+		let call_dispatch = Box::new(call_formatted);
+
+		// create the democracy call to be proposed in collective
+		let democracy_call = Call::<T>::call_democracy_proposal {
+			account_id: who.clone(),
+			proposal_id: proposal_hash,
+			proposal: call_dispatch.clone(),
+		};
+
+		// call the collective propose
+		let democracy_call_formatted_wrap =
+			Self::get_formatted_collective_proposal(democracy_call.into());
+
+		// Check that the call to the democracy pallet is correctly created
+		ensure!(
+			democracy_call_formatted_wrap.is_some(),
+			Error::<T>::FailedToCreateCollectiveProposal
+		);
+
+		let democracy_call_formatted = Box::new(democracy_call_formatted_wrap.unwrap());
+
+		// Retrieve the index of the proposal in Collective pallet
+		let collective_index = COLL::Pallet::<T, Instance1>::proposal_count();
+
+		let collective_origin = Self::get_origin(council_member);
+
+		COLL::Pallet::<T, Instance1>::propose(
+			collective_origin,
+			2,
+			democracy_call_formatted.clone(),
+			democracy_call_formatted.encoded_size() as u32,
+		)
+		.map_err(|e| e.error)?;
+
+		// create the VotingProposal
+		let voting_proposal: VotingProposal<T, Box<<T as COLL::Config<Instance1>>::Proposal>> =
+			VotingProposal::new(
+				who.clone(),
+				ProposalParams { call: proposal, hash: proposal_hash },
+				CollectiveParams {
+					call: democracy_call_formatted.clone(),
+					call_pass: collective_passed_call,
+					call_fail: collective_failed_call,
+					index: collective_index,
+					hash: T::Hashing::hash_of(&democracy_call_formatted),
+				},
+				DemocracyParams {
+					call_fail: democracy_failed_call,
+					hash: T::Hashing::hash_of(&call_dispatch),
+				},
+			);
+
+		VotingProposals::<T>::insert(proposal_hash, voting_proposal);
+
+		let block_number = <frame_system::Pallet<T>>::block_number();
+
+		let collective_motion_duration = block_number
+			.saturating_add(<T as COLL::Config<Instance1>>::MotionDuration::get())
+			.saturating_add(T::Delay::get());
+
+		// Add the proposal to the collective watchlist
+		CollectiveProposals::<T>::insert(proposal_hash, collective_motion_duration);
+
+		// deposit event
+		Self::deposit_event(Event::HouseCouncilAddedProposal(who, proposal_hash, block_number));
+
+		Ok(())
+	}
+
+	/// Submit a proposal directly to the investors vote, the House Council having already approved
+	/// it
+	/// - who : the account of the issuer of the proposal
+	/// - proposal : the proposal to be executed at the end of the vote process
+	/// - democracy_failed_call : action to be executed when the proposal fail the democracy vote
+	pub fn submit_investor_proposal(
+		who: AccountIdOf<T>,
+		proposal: Box<<T as Config>::Call>,
+		democracy_failed_call: Box<<T as Config>::Call>,
+	) -> DispatchResult {
+		ensure!(ROLES::Pallet::<T>::sellers(who.clone()).is_some(), Error::<T>::NotASeller);
+
+		let proposal_hash = T::Hashing::hash_of(&proposal);
+
+		let council_member = COLL::Pallet::<T, Instance1>::members()[0].clone();
+		// create the final dispatch call of the proposal in democracy
+		let call = Call::<T>::call_dispatch {
+			_account_id: council_member,
+			proposal_hash,
+			proposal: proposal.clone(),
+		};
+		let call_dispatch = Box::new(Self::get_formatted_call(call.into()));
+		let democracy_hash = T::Hashing::hash_of(&call_dispatch);
+		let collective_call = Box::new(
+			Self::get_formatted_collective_proposal(*call_dispatch.clone())
+				.ok_or(Error::<T>::FailedToCreateCollectiveProposal)?,
+		);
+
+		// The collective calls are never dispatched, the proposal skipping the House Council vote
+		let mut voting_proposal: VotingProposal<T, Box<<T as COLL::Config<Instance1>>::Proposal>> =
+			VotingProposal::new(
+				who.clone(),
+				ProposalParams { call: proposal, hash: proposal_hash },
+				CollectiveParams {
+					call_pass: democracy_failed_call.clone(),
+					call_fail: democracy_failed_call.clone(),
+					index: Default::default(),
+					hash: T::Hashing::hash_of(&collective_call),
+					call: collective_call,
+				},
+				DemocracyParams { call_fail: democracy_failed_call, hash: democracy_hash },
+			);
+		voting_proposal.collective_closed = true;
+		VotingProposals::<T>::insert(proposal_hash, voting_proposal);
+
+		Self::start_referendum(who, proposal_hash, call_dispatch)?;
+
+		let block_number = <frame_system::Pallet<T>>::block_number();
+		Self::deposit_event(Event::InvestorVoteSessionStarted(democracy_hash, block_number));
+
+		Ok(())
+	}
+
+	/// Start the referendum of the proposal `proposal_id` on the democracy `proposal`
+	fn start_referendum(
+		account_id: AccountIdOf<T>,
+		proposal_id: T::Hash,
+		proposal: Box<<T as Config>::Call>,
+	) -> DispatchResult {
+		let proposal_hash = T::Hashing::hash_of(&proposal);
+		let proposal_encoded: Vec<u8> = proposal.encode();
+
+		// Call Democracy note_pre_image
+		DEMO::Pallet::<T>::note_preimage(Self::get_origin(account_id), proposal_encoded)?;
+
+		let threshold = DEMO::VoteThreshold::SimpleMajority;
+		let delay = <T as Config>::Delay::get();
+
+		// Start Democracy referendum
+		let referendum_index =
+			DEMO::Pallet::<T>::internal_start_referendum(proposal_hash, threshold, delay);
+
+		// Update the voting
+		VotingProposals::<T>::mutate(proposal_id, |val| {
+			if let Some(proposal) = val {
+				proposal.democracy_referendum_index = referendum_index;
+				proposal.collective_step = true;
+			}
+		});
+
+		let block_number = <frame_system::Pallet<T>>::block_number();
+		let democration_motion_duration = block_number
+			.saturating_add(<T as DEMO::Config>::VotingPeriod::get())
+			.saturating_add(delay);
+
+		// Set the the storage to be watched for the democracy process
+		DemocracyProposals::<T>::insert(proposal_id, democration_motion_duration);
+
+		Ok(())
+	}
+
+	/// Cancel a proposal before the end of its vote
+	/// The running collective motion is disapproved and the running referendum is cancelled, none
+	/// of the calls of the proposal are dispatched
+	/// - proposal_hash : hash of the initial proposal call
+	pub fn cancel_proposal(proposal_hash: T::Hash) -> DispatchResult {
+		let proposal =
+			VotingProposals::<T>::take(proposal_hash).ok_or(Error::<T>::ProposalDoesNotExist)?;
+
+		if !proposal.collective_closed {
+			COLL::Pallet::<T, Instance1>::disapprove_proposal(
+				frame_system::RawOrigin::Root.into(),
+				proposal.collective_hash,
+			)
+			.map_err(|e| e.error)?;
+		}
+
+		if proposal.collective_step && !proposal.proposal_executed {
+			DEMO::Pallet::<T>::internal_cancel_referendum(proposal.democracy_referendum_index);
+		}
+
+		CollectiveProposals::<T>::remove(proposal_hash);
+		DemocracyProposals::<T>::remove(proposal_hash);
+
+		let block_number = <frame_system::Pallet<T>>::block_number();
+		Self::deposit_event(Event::ProposalCancelled(proposal_hash, block_number));
+
+		Ok(())
+	}
+
+	/// Remove a proposal whose stored calls cannot be decoded anymore, e.g. after a change of
+	/// the arguments of the proposed calls. Its collective motion and referendum are left as is.
+	/// - proposal_hash : hash of the initial proposal call
+	pub fn remove_stale_proposal(proposal_hash: T::Hash) {
+		VotingProposals::<T>::remove(proposal_hash);
+		CollectiveProposals::<T>::remove(proposal_hash);
+		DemocracyProposals::<T>::remove(proposal_hash);
+	}
+
+	/// Disapprove the collective motions whose call cannot be decoded anymore, so that they do
+	/// not hold a slot of the collective until they are closed
+	pub fn disapprove_undecodable_motions() -> Weight {
+		let motions = COLL::Pallet::<T, Instance1>::proposals();
+		let mut reads: u64 = 1;
+		let mut writes: u64 = 0;
+		for hash in motions {
+			reads += 1;
+			if COLL::ProposalOf::<T, Instance1>::contains_key(hash) &&
+				COLL::Pallet::<T, Instance1>::proposal_of(hash).is_none()
+			{
+				COLL::Pallet::<T, Instance1>::disapprove_proposal(
+					frame_system::RawOrigin::Root.into(),
+					hash,
+				)
+				.ok();
+				reads += 1;
+				writes += 3;
+			}
+		}
+		T::DbWeight::get().reads_writes(reads, writes)
+	}
+
 	/// Current era is ending; check if the proposal has passed some steps
 	/// Check the proposals being processed in the collective pallet
 	/// Check the proposals being processed in the democracy pallet
@@ -581,10 +863,9 @@ impl<T: Config> Pallet<T> {
 						if !voting.collective_step {
 							voting
 								.collective_failed_call
-								.dispatch_bypass_filter(
-									frame_system::RawOrigin::Signed(voting.account_id.clone())
-										.into(),
-								)
+								.dispatch_bypass_filter(Self::decision_origin(RawOrigin::Council(
+									elt.0,
+								)))
 								.ok();
 						}
 
@@ -610,9 +891,9 @@ impl<T: Config> Pallet<T> {
 					if !voting.proposal_executed {
 						voting
 							.democracy_failed_call
-							.dispatch_bypass_filter(
-								frame_system::RawOrigin::Signed(voting.account_id.clone()).into(),
-							)
+							.dispatch_bypass_filter(Self::decision_origin(RawOrigin::Investors(
+								elt.0,
+							)))
 							.ok();
 					}
 
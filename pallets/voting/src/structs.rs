@@ -1,10 +1,11 @@
 pub use super::*;
 
 pub use frame_support::{
-	codec::{Decode, Encode},
-	traits::Currency,
+	codec::{Decode, Encode, MaxEncodedLen},
+	traits::{Currency, EnsureOrigin},
 	RuntimeDebug,
 };
+use sp_std::marker::PhantomData;
 
 use scale_info::{prelude::boxed::Box, TypeInfo};
 
@@ -79,3 +80,68 @@ impl<T: Config, U> VotingProposal<T, U> {
 		}
 	}
 }
+
+/// Motion submitted to the House Council vote only
+#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct CouncilMotion<T: Config, U> {
+	/// The member of the House Council who submitted the motion
+	pub account_id: AccountIdOf<T>,
+	/// The call dispatching the proposal, proposed in collective
+	pub collective_call: U,
+	pub collective_index: u32,
+	pub collective_hash: T::Hash,
+}
+
+/// Origin of the calls dispatched when a decision is taken on a proposal, carrying the hash of the
+/// proposal
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum RawOrigin<Hash> {
+	/// The House Council decided on the proposal
+	Council(Hash),
+	/// The investors referendum decided on the proposal
+	Investors(Hash),
+}
+
+/// Ensure that the origin is a decision of the House Council on a proposal, returns its hash
+pub struct EnsureCouncilDecision<T>(PhantomData<T>);
+impl<
+		O: Into<Result<RawOrigin<T::Hash>, O>> + From<RawOrigin<T::Hash>>,
+		T: frame_system::Config,
+	> EnsureOrigin<O> for EnsureCouncilDecision<T>
+{
+	type Success = T::Hash;
+	fn try_origin(o: O) -> Result<Self::Success, O> {
+		o.into().and_then(|o| match o {
+			RawOrigin::Council(hash) => Ok(hash),
+			r => Err(O::from(r)),
+		})
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn successful_origin() -> O {
+		O::from(RawOrigin::Council(Default::default()))
+	}
+}
+
+/// Ensure that the origin is a decision of the investors referendum on a proposal, returns its
+/// hash
+pub struct EnsureInvestorsDecision<T>(PhantomData<T>);
+impl<
+		O: Into<Result<RawOrigin<T::Hash>, O>> + From<RawOrigin<T::Hash>>,
+		T: frame_system::Config,
+	> EnsureOrigin<O> for EnsureInvestorsDecision<T>
+{
+	type Success = T::Hash;
+	fn try_origin(o: O) -> Result<Self::Success, O> {
+		o.into().and_then(|o| match o {
+			RawOrigin::Investors(hash) => Ok(hash),
+			r => Err(O::from(r)),
+		})
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn successful_origin() -> O {
+		O::from(RawOrigin::Investors(Default::default()))
+	}
+}
@@ -1,6 +1,6 @@
-use crate::{mock::*, Error};
+use crate::{mock::*, EnsureCouncilDecision, EnsureInvestorsDecision, Error, RawOrigin};
 use codec::Encode;
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{assert_noop, assert_ok, traits::EnsureOrigin};
 use pallet_roles::{Hash, Hooks};
 
 fn make_proposal(value: i32) -> Box<Call> {
@@ -12,7 +12,8 @@ fn submit_proposal_not_seller_should_fail() {
 	new_test_ext().execute_with(|| {
 		assert_noop!(
 			VotingModule::submit_proposal(
-				Origin::signed(EVE),
+				Origin::root(),
+				EVE,
 				make_proposal(1),
 				make_proposal(2),
 				make_proposal(3),
@@ -41,7 +42,8 @@ fn submit_proposal_should_succeed() {
 
 		assert_ok!(
 			VotingModule::submit_proposal(
-				Origin::signed(EVE),
+				Origin::root(),
+				EVE,
 				proposal.clone(),
 				make_proposal(2),
 				make_proposal(3),
@@ -91,7 +93,8 @@ fn council_vote_not_house_council_member_should_fail() {
 		let proposal = make_proposal(1);
 
 		assert_ok!(VotingModule::submit_proposal(
-			Origin::signed(EVE),
+			Origin::root(),
+			EVE,
 			proposal.clone(),
 			make_proposal(2),
 			make_proposal(3),
@@ -136,7 +139,8 @@ fn council_vote_proposal_should_succeed() {
 		let proposal = make_proposal(1);
 
 		assert_ok!(VotingModule::submit_proposal(
-			Origin::signed(EVE),
+			Origin::root(),
+			EVE,
 			proposal.clone(),
 			make_proposal(2),
 			make_proposal(3),
@@ -217,7 +221,8 @@ fn council_close_vote_proposal_not_pass_should_succeed() {
 
 		assert_ok!(
 			VotingModule::submit_proposal(
-				Origin::signed(EVE),
+				Origin::root(),
+				EVE,
 				proposal.clone(),
 				make_proposal(2),
 				make_proposal(3),
@@ -287,7 +292,8 @@ fn council_close_vote_proposal_pass_should_succeed() {
 
 		assert_ok!(
 			VotingModule::submit_proposal(
-				Origin::signed(EVE),
+				Origin::root(),
+				EVE,
 				proposal.clone(),
 				make_proposal(2),
 				make_proposal(3),
@@ -381,7 +387,8 @@ fn investor_vote_without_having_investor_role_should_fail() {
 		let proposal = make_proposal(1);
 
 		assert_ok!(VotingModule::submit_proposal(
-			Origin::signed(EVE),
+			Origin::root(),
+			EVE,
 			proposal.clone(),
 			make_proposal(2),
 			make_proposal(3),
@@ -445,7 +452,8 @@ fn investor_vote_should_succeed() {
 
 		assert_ok!(
 			VotingModule::submit_proposal(
-				Origin::signed(EVE),
+				Origin::root(),
+				EVE,
 				proposal,
 				make_proposal(2),
 				make_proposal(3),
@@ -543,7 +551,8 @@ fn investor_vote_proposal_fail_should_succeed() {
 
 		assert_ok!(
 			VotingModule::submit_proposal(
-				Origin::signed(EVE),
+				Origin::root(),
+				EVE,
 				proposal,
 				make_proposal(2),
 				make_proposal(3),
@@ -614,3 +623,226 @@ fn investor_vote_proposal_fail_should_succeed() {
 		assert!(VotingModule::democracy_proposals(hash).is_none());
 	});
 }
+
+#[test]
+fn cancel_proposal_during_council_vote_should_succeed() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(RoleModule::set_role(Origin::signed(EVE), EVE, crate::ROLES::Accounts::SELLER));
+		assert_ok!(RoleModule::account_approval(Origin::signed(ALICE), EVE));
+
+		let proposal = make_proposal(1);
+		let hash = <Test as frame_system::Config>::Hashing::hash_of(&proposal);
+
+		assert_ok!(VotingModule::submit_proposal(
+			Origin::root(),
+			EVE,
+			proposal,
+			make_proposal(2),
+			make_proposal(3),
+			make_proposal(4)
+		));
+		let collective_hash = VotingModule::voting_proposals(hash).unwrap().collective_hash;
+		assert_ok!(VotingModule::council_vote(Origin::signed(ALICE), hash, true));
+
+		assert_ok!(VotingModule::cancel_proposal(hash));
+
+		// the motion is removed from the collective and the proposal is not watched anymore
+		assert!(Collective::proposal_of(collective_hash).is_none());
+		assert!(VotingModule::voting_proposals(hash).is_none());
+		assert!(VotingModule::collective_proposals(hash).is_none());
+		assert_eq!(
+			<frame_system::Pallet<Test>>::events().pop().unwrap().event,
+			crate::mock::Event::VotingModule(crate::Event::ProposalCancelled(
+				hash,
+				System::block_number()
+			)),
+		);
+
+		assert_noop!(VotingModule::cancel_proposal(hash), Error::<Test>::ProposalDoesNotExist);
+		assert_noop!(
+			VotingModule::council_vote(Origin::signed(BOB), hash, true),
+			Error::<Test>::ProposalDoesNotExist
+		);
+	});
+}
+
+#[test]
+fn cancel_proposal_during_investor_vote_should_succeed() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(RoleModule::set_role(Origin::signed(EVE), EVE, crate::ROLES::Accounts::SELLER));
+		assert_ok!(RoleModule::account_approval(Origin::signed(ALICE), EVE));
+
+		let proposal = make_proposal(1);
+		let hash = <Test as frame_system::Config>::Hashing::hash_of(&proposal);
+
+		assert_ok!(VotingModule::submit_proposal(
+			Origin::root(),
+			EVE,
+			proposal,
+			make_proposal(2),
+			make_proposal(3),
+			make_proposal(4)
+		));
+		assert_ok!(VotingModule::council_vote(Origin::signed(ALICE), hash, true));
+		assert_ok!(VotingModule::council_vote(Origin::signed(BOB), hash, true));
+		assert_ok!(VotingModule::council_vote(Origin::signed(CHARLIE), hash, true));
+
+		let end_block_number = System::block_number()
+			.saturating_add(<Test as crate::Config>::Delay::get())
+			.saturating_add(<Test as pallet_collective::Config<pallet_collective::Instance1>>::MotionDuration::get());
+		System::set_block_number(end_block_number);
+		assert_ok!(VotingModule::council_close_vote(Origin::signed(ALICE), hash));
+
+		let referendum_index = VotingModule::voting_proposals(hash).unwrap().democracy_referendum_index;
+		assert!(Democracy::referendum_info(referendum_index).is_some());
+		assert!(VotingModule::democracy_proposals(hash).is_some());
+
+		assert_ok!(VotingModule::cancel_proposal(hash));
+
+		// the referendum is cancelled and the proposal is not watched anymore
+		assert!(Democracy::referendum_info(referendum_index).is_none());
+		assert!(VotingModule::voting_proposals(hash).is_none());
+		assert!(VotingModule::democracy_proposals(hash).is_none());
+	});
+}
+
+#[test]
+fn submit_investor_proposal_should_start_a_referendum() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			VotingModule::submit_investor_proposal(EVE, make_proposal(1), make_proposal(4)),
+			Error::<Test>::NotASeller
+		);
+		assert_ok!(RoleModule::set_role(Origin::signed(EVE), EVE, crate::ROLES::Accounts::SELLER));
+		assert_ok!(RoleModule::account_approval(Origin::signed(ALICE), EVE));
+
+		let proposal = make_proposal(1);
+		let hash = <Test as frame_system::Config>::Hashing::hash_of(&proposal);
+
+		assert_ok!(VotingModule::submit_investor_proposal(EVE, proposal, make_proposal(4)));
+
+		// the proposal skips the House Council vote
+		let voting_proposal = VotingModule::voting_proposals(hash).unwrap();
+		assert!(voting_proposal.collective_closed);
+		assert!(voting_proposal.collective_step);
+		assert!(VotingModule::collective_proposals(hash).is_none());
+		assert!(Democracy::referendum_info(voting_proposal.democracy_referendum_index).is_some());
+		assert!(VotingModule::democracy_proposals(hash).is_some());
+
+		assert_ok!(VotingModule::cancel_proposal(hash));
+		assert!(Democracy::referendum_info(voting_proposal.democracy_referendum_index).is_none());
+	});
+}
+
+#[test]
+fn submit_proposal_should_be_reserved_to_root() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(RoleModule::set_role(Origin::signed(EVE), EVE, crate::ROLES::Accounts::SELLER));
+		assert_ok!(RoleModule::account_approval(Origin::signed(ALICE), EVE));
+
+		assert_noop!(
+			VotingModule::submit_proposal(
+				Origin::signed(EVE),
+				EVE,
+				make_proposal(1),
+				make_proposal(2),
+				make_proposal(3),
+				make_proposal(4)
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn decision_calls_should_not_be_dispatched_as_root() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(RoleModule::set_role(Origin::signed(EVE), EVE, crate::ROLES::Accounts::SELLER));
+		assert_ok!(RoleModule::account_approval(Origin::signed(ALICE), EVE));
+
+		let proposal = make_proposal(1);
+		let hash = <Test as frame_system::Config>::Hashing::hash_of(&proposal);
+		let key = b"voting".to_vec();
+		let set_storage = Box::new(Call::System(frame_system::Call::set_storage {
+			items: vec![(key.clone(), 1u32.encode())],
+		}));
+
+		assert_ok!(VotingModule::submit_proposal(
+			Origin::root(),
+			EVE,
+			proposal,
+			make_proposal(2),
+			set_storage,
+			make_proposal(4)
+		));
+
+		let end_block_number = System::block_number()
+			.saturating_add(<Test as crate::Config>::Delay::get())
+			.saturating_add(<Test as pallet_collective::Config<pallet_collective::Instance1>>::MotionDuration::get());
+		System::set_block_number(end_block_number);
+		assert_ok!(VotingModule::council_close_vote(Origin::signed(ALICE), hash));
+		VotingModule::begin_block(end_block_number + 1);
+
+		// the failed call has been dispatched with the council decision origin, not as root
+		assert!(VotingModule::collective_proposals(hash).is_none());
+		assert!(sp_io::storage::get(&key).is_none());
+	});
+}
+
+#[test]
+fn decision_origins_should_carry_the_proposal_hash() {
+	new_test_ext().execute_with(|| {
+		let hash = <Test as frame_system::Config>::Hashing::hash_of(&make_proposal(1));
+
+		assert_eq!(
+			EnsureCouncilDecision::<Test>::try_origin(Origin::from(RawOrigin::Council(hash))).ok(),
+			Some(hash)
+		);
+		let investors_decision = Origin::from(RawOrigin::Investors(hash));
+		assert!(EnsureCouncilDecision::<Test>::try_origin(investors_decision).is_err());
+		assert_eq!(
+			EnsureInvestorsDecision::<Test>::try_origin(Origin::from(RawOrigin::Investors(hash)))
+				.ok(),
+			Some(hash)
+		);
+		assert!(EnsureInvestorsDecision::<Test>::try_origin(Origin::root()).is_err());
+		assert!(EnsureCouncilDecision::<Test>::try_origin(Origin::signed(EVE)).is_err());
+	});
+}
+
+#[test]
+fn council_motion_should_dispatch_the_proposal_once_approved() {
+	new_test_ext().execute_with(|| {
+		let proposal = make_proposal(1);
+		let hash = <Test as frame_system::Config>::Hashing::hash_of(&proposal);
+
+		assert_noop!(
+			VotingModule::council_motion(Origin::signed(EVE), proposal.clone()),
+			Error::<Test>::NotAHouseCouncilMember
+		);
+		assert_ok!(VotingModule::council_motion(Origin::signed(ALICE), proposal.clone()));
+		assert_noop!(
+			VotingModule::council_motion(Origin::signed(BOB), proposal.clone()),
+			Error::<Test>::ProposalAlreadyExists
+		);
+		let motion = VotingModule::council_motions(hash).unwrap();
+		assert_eq!(motion.account_id, ALICE);
+		assert!(Collective::proposal_of(motion.collective_hash).is_some());
+
+		// the decision is only dispatched by the House Council
+		assert_noop!(
+			VotingModule::call_council_decision(Origin::signed(ALICE), hash, proposal),
+			sp_runtime::DispatchError::BadOrigin
+		);
+
+		assert_ok!(VotingModule::council_vote(Origin::signed(ALICE), hash, true));
+		assert_ok!(VotingModule::council_vote(Origin::signed(BOB), hash, true));
+		assert_ok!(VotingModule::council_close_vote(Origin::signed(ALICE), hash));
+
+		// the motion is over, no referendum is started
+		assert!(VotingModule::council_motions(hash).is_none());
+		assert!(Collective::proposal_of(motion.collective_hash).is_none());
+		assert!(VotingModule::voting_proposals(hash).is_none());
+		assert!(VotingModule::democracy_proposals(hash).is_none());
+	});
+}
@@ -44,6 +44,7 @@ use frame_system::{
 };
 pub use pallet_balances::Call as BalancesCall;
 pub use pallet_democracy;
+use pallet_assets::FrozenBalance;
 use pallet_nft::NftPermissions;
 pub use pallet_nft::{self, Acc, CollectionId, ItemId, NftPermission};
 pub use pallet_timestamp::Call as TimestampCall;
@@ -325,7 +326,7 @@ impl pallet_uniques::Config for Runtime {
 	//#[cfg(feature = "runtime-benchmarks")]
 	//type Helper = ();
 	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId>>;
-	type Locker = ();
+	type Locker = NftModule;
 }
 
 parameter_types! {
@@ -375,7 +376,14 @@ parameter_types! {
 	pub const FundThreshold: u128 = 100_000 * DOLLARS;
 	pub const MaxFundContribution: u128 = 20_000 * DOLLARS;
 	pub const MaxInvestorPerHouse: u32 = 10;
+	pub const MaxExcludedSellers: u32 = 50;
+	pub const MaxPreferredCollections: u32 = 10;
 	pub const HousingFundPalletId: PalletId = PalletId(*b"housfund");
+	// Kept away from the ids of the ownership tokens, allocated from 0
+	pub const HousingFundAssetId: u32 = u32::MAX;
+	pub const MaxWithdrawalsProcessedPerBlock: u32 = 20;
+	pub const MaxQueuedWithdrawals: u32 = 1000;
+	pub const BlocksPerMonth: BlockNumber = 30 * DAYS;
 }
 
 /// Configure the pallet-housing_fund in pallets/housing_fund.
@@ -388,10 +396,23 @@ impl pallet_housing_fund::Config for Runtime {
 	type WeightInfo = pallet_housing_fund::weights::SubstrateWeight<Runtime>;
 	type PalletId = HousingFundPalletId;
 	type MaxInvestorPerHouse = MaxInvestorPerHouse;
+	type MaxExcludedSellers = MaxExcludedSellers;
+	type MaxPreferredCollections = MaxPreferredCollections;
+	type FundAssets = Assets;
+	type FundAssetId = HousingFundAssetId;
+	type MaxWithdrawalsProcessedPerBlock = MaxWithdrawalsProcessedPerBlock;
+	type MaxQueuedWithdrawals = MaxQueuedWithdrawals;
+	type BlocksPerMonth = BlocksPerMonth;
+	type PauseOrigin = EitherOfDiverse<
+		EnsureRoot<AccountId>,
+		pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 1, 2>,
+	>;
 }
 
 parameter_types! {
 	pub ReserveCollectionIdUpTo: u32 = 500;
+	pub const MaxMetadataRevisions: u32 = 20;
+	pub const MaxAttributesPerItem: u32 = 32;
 }
 impl pallet_nft::Config for Runtime {
 	type Event = Event;
@@ -401,6 +422,10 @@ impl pallet_nft::Config for Runtime {
 	type ProtocolOrigin = EnsureRoot<AccountId>;
 	type Permissions = NftPermissions;
 	type ReserveCollectionIdUpTo = ReserveCollectionIdUpTo;
+	type ItemStages = OnboardingModule;
+	type TokenFreezer = ShareDistributor;
+	type MaxMetadataRevisions = MaxMetadataRevisions;
+	type MaxAttributesPerItem = MaxAttributesPerItem;
 }
 
 parameter_types! {
@@ -422,6 +447,7 @@ impl pallet_voting::Config for Runtime {
 		pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 1, 2>;
 	type CheckPeriod = CheckPeriod;
 	type MinimumDepositVote = MinimumDeposit;
+	type VotingOrigin = Origin;
 }
 
 parameter_types! {
@@ -593,6 +619,8 @@ impl Contains<Call> for DontAllowCollectiveAndDemocracy {
 			// Call::OnboardingModule(pallet_onboarding::Call::change_status { .. }) => false,
 			Call::OnboardingModule(pallet_onboarding::Call::reject_edit { .. }) => false,
 			Call::OnboardingModule(pallet_onboarding::Call::reject_destroy { .. }) => false,
+			// The paused operations of the housing fund are rejected before their dispatch
+			Call::HousingFundModule(call) => !HousingFundModule::is_call_paused(call),
 			_ => true,
 		}
 	}
@@ -601,6 +629,12 @@ impl Contains<Call> for DontAllowCollectiveAndDemocracy {
 parameter_types! {
 	pub const ProposalFee: Percent= Percent::from_percent(15);
 	pub const SlashedFee: Percent = Percent::from_percent(10);
+	pub const SaleFee: Percent = Percent::from_percent(20);
+	pub const FundFeeShare: Percent = Percent::from_percent(50);
+	pub const AppraisalBand: Percent = Percent::from_percent(10);
+	pub const MaxAppraisalFee: Percent = Percent::from_percent(1);
+	pub const CounterOfferPeriod: BlockNumber = 3 * DAYS;
+	pub const ExpiryScanLimit: u32 = 50;
 	pub const FeesAccount: PalletId = PalletId(*b"feeslash");
 }
 
@@ -610,8 +644,27 @@ impl pallet_onboarding::Config for Runtime {
 	type Prop = Call;
 	type ProposalFee = ProposalFee;
 	type Slash = SlashedFee;
+	type SaleFee = SaleFee;
+	type FundFeeShare = FundFeeShare;
+	type MaxInspectionReports = ConstU32<5>;
+	type AppraisalBand = AppraisalBand;
+	type MaxAppraisalFee = MaxAppraisalFee;
+	type CounterOfferPeriod = CounterOfferPeriod;
+	type MaxCounterOffers = ConstU32<20>;
+	type ExpiryScanLimit = ExpiryScanLimit;
 	type WeightInfo = ();
 	type FeesAccount = FeesAccount;
+	type OnStatusChange = (BiddingModule, FinalizerModule);
+	type MaxStatusHistory = ConstU32<50>;
+	type CouncilOrigin = EitherOfDiverse<
+		EnsureRoot<AccountId>,
+		EitherOfDiverse<
+			pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 1, 2>,
+			pallet_voting::EnsureCouncilDecision<Runtime>,
+		>,
+	>;
+	type DemocracyOrigin =
+		EitherOfDiverse<EnsureRoot<AccountId>, pallet_voting::EnsureInvestorsDecision<Runtime>>;
 }
 
 parameter_types! {
@@ -643,7 +696,7 @@ impl pallet_assets::Config for Runtime {
 	type MetadataDepositPerByte = MetadataDepositPerByte;
 	type ApprovalDeposit = ApprovalDeposit;
 	type StringLimit = StringLimit;
-	type Freezer = ();
+	type Freezer = AssetsFreezer;
 	type Extra = ();
 	type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
 }
@@ -655,6 +708,8 @@ parameter_types! {
 	pub const MaximumSharePerInvestor: u64 = 40;
 	pub const MinimumSharePerInvestor: u64 = 5;
 	pub const NewAssetScanPeriod: u32 = EPOCH_DURATION_IN_BLOCKS;
+	pub const MaxAssetsProcessedPerBlock: u32 = 10;
+	pub const MaxQueuedAssets: u32 = 100;
 }
 
 impl pallet_bidding::Config for Runtime {
@@ -666,7 +721,12 @@ impl pallet_bidding::Config for Runtime {
 	type MaxTriesAseemblingInvestor = MaxTriesAseemblingInvestor;
 	type MaximumSharePerInvestor = MaximumSharePerInvestor;
 	type MinimumSharePerInvestor = MinimumSharePerInvestor;
+	type SelectionStrategy = pallet_bidding::Lottery<RandomnessCollectiveFlip>;
 	type NewAssetScanPeriod = NewAssetScanPeriod;
+	type MaxAssetsProcessedPerBlock = MaxAssetsProcessedPerBlock;
+	type MaxQueuedAssets = MaxQueuedAssets;
+	type PriorityOrigin =
+		pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 1, 2>;
 }
 
 parameter_types! {
@@ -684,6 +744,8 @@ parameter_types! {
 	pub const Lease: u32 = 12;
 	//Maintenance fees taken on monthly rent
 	pub const Maintenance:Percent = Percent::from_percent(3);
+	//Part of the rent credited to the housing fund contributors
+	pub const FundRentShare: Percent = Percent::from_percent(10);
 }
 impl pallet_asset_management::Config for Runtime {
 	type Event = Event;
@@ -701,6 +763,7 @@ impl pallet_asset_management::Config for Runtime {
 	type RoR = RoR;
 	type Lease = Lease;
 	type Maintenance = Maintenance;
+	type FundRentShare = FundRentShare;
 	type WeightInfo = ();
 }
 
@@ -718,8 +781,14 @@ impl pallet_tenancy::Config for Runtime {
 
 pub struct PaymentsDisputeResolver;
 impl pallet_payment::DisputeResolver<AccountId> for PaymentsDisputeResolver {
-	fn get_resolver_account() -> AccountId {
-		Sudo::key().expect("Sudo key not set!")
+	fn get_resolver_account(from: &AccountId, to: &AccountId) -> Option<AccountId> {
+		// disputes on payments involving the virtual account of an asset are
+		// handled by the active representative of the asset
+		let representative = |account: &AccountId| {
+			AssetManagementModule::asset_representative(account)
+				.filter(|rep| RoleModule::reps(rep).map_or(false, |infos| infos.activated))
+		};
+		representative(from).or_else(|| representative(to)).or_else(Sudo::key)
 	}
 }
 
@@ -728,13 +797,33 @@ impl pallet_payment::FeeHandler<Runtime> for PaymentsFeeHandler {
 	fn apply_fees(
 		_from: &AccountId,
 		_to: &AccountId,
-		_detail: &pallet_payment::PaymentDetail<Runtime>,
+		detail: &pallet_payment::PaymentDetail<Runtime>,
 		_remark: Option<&[u8]>,
 	) -> (AccountId, Percent) {
-		// we do not charge any fee
-		const MARKETPLACE_FEE_PERCENT: Percent = Percent::from_percent(0);
-		let fee_receiver = Sudo::key().expect("Sudo key not set!");
-		(fee_receiver, MARKETPLACE_FEE_PERCENT)
+		// the fee of each payment asset is set by the council, it goes to the fees account
+		let fee_receiver = OnboardingModule::account_id();
+		(fee_receiver, PaymentModule::asset_fee(detail.asset))
+	}
+}
+
+/// The fund share tokens are frozen by the housing fund, the locked guaranties and maintenance
+/// fees by the payments
+pub struct AssetsFreezer;
+impl FrozenBalance<u32, AccountId, u128> for AssetsFreezer {
+	fn frozen_balance(asset: u32, who: &AccountId) -> Option<u128> {
+		match (
+			HousingFundModule::frozen_balance(asset, who),
+			PaymentModule::frozen_balance(asset, who),
+		) {
+			(None, None) => None,
+			(fund, payment) =>
+				Some(fund.unwrap_or_default().saturating_add(payment.unwrap_or_default())),
+		}
+	}
+
+	fn died(asset: u32, who: &AccountId) {
+		HousingFundModule::died(asset, who);
+		PaymentModule::died(asset, who);
 	}
 }
 
@@ -743,18 +832,44 @@ parameter_types! {
 	pub const MaxRemarkLength: u32 = 10;
 	// 1hr buffer period (60*60)/12
 	pub const CancelBufferBlockLength: BlockNumber = 300;
-	pub const MaxScheduledTaskListLength : u32 = 5;
+	pub const PaymentExpiry: BlockNumber = 30 * DAYS;
+	pub const PaymentRequestExpiry: BlockNumber = 7 * DAYS;
+	pub const MaxPaymentHistory: u32 = 100;
+	pub const DisputeResolutionPeriod: BlockNumber = 2 * DAYS;
+	pub const AppealPeriod: BlockNumber = DAYS;
+	pub const AppealResolutionPeriod: BlockNumber = 7 * DAYS;
+	pub const DefaultRecipientShare: Percent = Percent::from_percent(0);
+	pub const MaxEvidences: u32 = 10;
+	pub const MaxBatchSize: u32 = 20;
+	pub const PaymentPalletId: PalletId = PalletId(*b"py/paymt");
 }
 
 impl pallet_payment::Config for Runtime {
 	type Event = Event;
 	type Currency = Balances;
+	type Assets = Assets;
+	type PalletId = PaymentPalletId;
 	type DisputeResolver = PaymentsDisputeResolver;
 	type IncentivePercentage = IncentivePercentage;
 	type FeeHandler = PaymentsFeeHandler;
+	type FeeOrigin = EitherOfDiverse<
+		EnsureRoot<AccountId>,
+		pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 1, 2>,
+	>;
 	type MaxRemarkLength = MaxRemarkLength;
 	type CancelBufferBlockLength = CancelBufferBlockLength;
-	type MaxScheduledTaskListLength = MaxScheduledTaskListLength;
+	type PaymentExpiry = PaymentExpiry;
+	type PaymentRequestExpiry = PaymentRequestExpiry;
+	type OnPaymentExpiry = AssetManagementModule;
+	type MaxPaymentHistory = MaxPaymentHistory;
+	type AppealOrigin =
+		pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 1, 2>;
+	type DisputeResolutionPeriod = DisputeResolutionPeriod;
+	type AppealPeriod = AppealPeriod;
+	type AppealResolutionPeriod = AppealResolutionPeriod;
+	type DefaultRecipientShare = DefaultRecipientShare;
+	type MaxEvidences = MaxEvidences;
+	type MaxBatchSize = MaxBatchSize;
 	type WeightInfo = pallet_payment::weights::SubstrateWeight<Runtime>;
 }
 
@@ -848,6 +963,7 @@ mod benches {
 		[pallet_share_distributor,ShareDistributor]
 		[pallet_identity, Identity]
 		[pallet_utility, Utility]
+		[pallet_payment, PaymentModule]
 		//[pallet_asset_management, AssetManagementModule]
 		// [pallet_finalizer, FinalizerModule]
 		//[pallet_tenancy, TenancyModule]
@@ -1003,6 +1119,49 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_payment::PaymentApi<Block, AccountId, BlockNumber, pallet_payment::PaymentReceipt<Runtime>>
+		for Runtime
+	{
+		fn payments_by_period(
+			who: AccountId,
+			start: BlockNumber,
+			end: BlockNumber,
+		) -> Vec<pallet_payment::PaymentReceipt<Runtime>> {
+			PaymentModule::payments_by_period(&who, start, end)
+		}
+	}
+
+	impl pallet_onboarding::OnboardingApi<
+		Block,
+		CollectionId,
+		ItemId,
+		pallet_onboarding::PropertyDetails<Runtime>,
+	> for Runtime
+	{
+		fn property_details(
+			collection_id: CollectionId,
+			item_id: ItemId,
+		) -> Option<pallet_onboarding::PropertyDetails<Runtime>> {
+			OnboardingModule::property_details(collection_id, item_id)
+		}
+
+		fn assets_by_attributes(
+			filter: pallet_onboarding::PropertyFilter,
+		) -> Vec<(CollectionId, ItemId)> {
+			OnboardingModule::assets_by_attributes(filter)
+		}
+	}
+
+	impl pallet_bidding::BiddingApi<Block, AccountId, Balance, CollectionId, ItemId> for Runtime {
+		fn simulate_bid(
+			collection_id: CollectionId,
+			item_id: ItemId,
+			price: Option<Balance>,
+		) -> pallet_bidding::BidSimulation<AccountId, Balance> {
+			BiddingModule::simulate_bid(collection_id, item_id, price)
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (
@@ -1054,6 +1213,7 @@ impl_runtime_apis! {
 			add_benchmark!(params, batches, pallet_nft, NftModule);
 			add_benchmark!(params, batches, pallet_onboarding, OnboardingModule);
 			add_benchmark!(params, batches, pallet_share_distributor, ShareDistributor);
+			add_benchmark!(params, batches, pallet_payment, PaymentModule);
 			//add_benchmark!(params, batches, pallet_asset_management, AssetManagementModule);
 			// add_benchmark!(params, batches, pallet_finalizer, FinalizerModule);
 			//add_benchmark!(params, batches, pallet_tenancy, TenancyModule);